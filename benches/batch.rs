@@ -0,0 +1,32 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![feature(test)]
+
+extern crate test;
+
+use test::Bencher;
+
+const CORPUS: [&[u8]; 8] = [
+    include_bytes!("../tests/data/ferris/corro.svg"),
+    include_bytes!("../tests/data/ferris/corro.svgz"),
+    include_bytes!("../tests/data/ferris/rustacean-flat-happy.svg"),
+    include_bytes!("../tests/data/usvg/path-simple-case.svg"),
+    include_bytes!("../tests/data/w3/svg-logo-v.png"),
+    include_bytes!("../tests/data/w3/svg-logo-v.svg"),
+    include_bytes!("../tests/data/w3/svg-logo-v.svgz"),
+    include_bytes!("../tests/data/wikipedia/SVG_animation_using_SMIL.svg"),
+];
+
+#[bench]
+fn sequential(b: &mut Bencher) {
+    let corpus = CORPUS.repeat(40);
+    b.iter(|| corpus.iter().map(is_svg::is_svg).collect::<Vec<_>>());
+}
+
+#[bench]
+fn are_svgs(b: &mut Bencher) {
+    let corpus = CORPUS.repeat(40);
+    b.iter(|| is_svg::are_svgs(&corpus));
+}
@@ -0,0 +1,296 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The benchmarks which run on the stable toolchain.
+//!
+//! The data which is too large to check in is generated deterministically
+//! before measuring.
+
+// The dependencies of `criterion` duplicate some crates.
+#![allow(clippy::multiple_crate_versions)]
+
+use std::{io::Write, num::NonZeroUsize, thread};
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use flate2::{write::GzEncoder, Compression};
+use is_svg::Validator;
+
+const MIB: usize = 1024 * 1024;
+
+/// The number of the threads which share or own the validators.
+const THREADS: usize = 8;
+
+/// Returns the gzip-compressed `data`.
+fn gzip(data: &[u8]) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap()
+}
+
+/// Returns the SVG data which is larger than `size` bytes, and has the
+/// elements returned by `element` for each index.
+fn generate(size: usize, element: impl Fn(usize) -> String) -> Vec<u8> {
+    let mut svg =
+        String::from(r#"<svg xmlns="http://www.w3.org/2000/svg" width="1024" height="1024">"#);
+    for i in 0.. {
+        if svg.len() >= size {
+            break;
+        }
+        svg.push_str(&element(i));
+    }
+    svg.push_str("</svg>");
+    svg.into_bytes()
+}
+
+fn large_svg(size: usize) -> Vec<u8> {
+    generate(size, |i| {
+        let (x, y) = (i % 1024, i / 1024 % 1024);
+        let fill = i.wrapping_mul(2_654_435_761) % 0x0100_0000;
+        format!(r##"<rect x="{x}" y="{y}" width="1" height="1" fill="#{fill:06x}"/>"##)
+    })
+}
+
+fn text_svg(size: usize) -> Vec<u8> {
+    generate(size, |i| {
+        let y = i % 64 * 16;
+        format!(
+            r#"<text x="8" y="{y}" font-family="sans-serif" font-size="12">Line {i}: <tspan font-weight="bold">The quick brown fox</tspan> jumps over the lazy dog.</text>"#
+        )
+    })
+}
+
+/// Returns the minified SVG data which is larger than `size` bytes, and its
+/// pretty-printed equivalent.
+fn minified_svg(size: usize) -> (Vec<u8>, Vec<u8>) {
+    let mut elements = vec![
+        String::from(r#"<svg xmlns="http://www.w3.org/2000/svg" width="1024" height="1024">"#),
+        String::from("<style>.a{color:#2a7ae2;fill:currentColor}.b{stroke:#000}</style>"),
+    ];
+    let mut len = elements.iter().map(String::len).sum::<usize>();
+    for i in 0.. {
+        if len >= size {
+            break;
+        }
+        let (x, y) = (i % 1024, i / 1024 % 1024);
+        let element = if i % 2 == 0 {
+            format!(r#"<rect class="a" x="{x}" y="{y}" width="1" height="1"/>"#)
+        } else {
+            format!(r#"<path class="b" d="M{x} {y}l1 1-1 1z"/>"#)
+        };
+        len += element.len();
+        elements.push(element);
+    }
+    elements.push(String::from("</svg>"));
+    (
+        elements.concat().into_bytes(),
+        elements.join("\n  ").into_bytes(),
+    )
+}
+
+/// Returns the gzip-compressed SVG data which decompresses to more than
+/// `size` bytes.
+fn gzip_bomb(size: usize) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::fast());
+    encoder
+        .write_all(br#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16"><!--"#)
+        .unwrap();
+    let chunk = vec![b' '; MIB];
+    for _ in 0..size / chunk.len() {
+        encoder.write_all(&chunk).unwrap();
+    }
+    encoder.write_all(b"--></svg>").unwrap();
+    encoder.finish().unwrap()
+}
+
+/// Returns the categories of the data.
+fn corpus() -> Vec<(&'static str, Vec<u8>)> {
+    let icon = include_bytes!("../tests/data/usvg/path-simple-case.svg");
+    let text = text_svg(256 * 1024);
+    vec![
+        ("tiny-icon", icon.to_vec()),
+        ("tiny-icon-svgz", gzip(icon)),
+        ("text-heavy-svgz", gzip(&text)),
+        ("text-heavy", text),
+        ("large-generated", large_svg(4 * MIB)),
+        ("large-generated-svgz", gzip(&large_svg(4 * MIB))),
+        // The decompression stops at the default limit of 64 MiB.
+        ("gzip-bomb", gzip_bomb(128 * MIB)),
+        (
+            "binary",
+            include_bytes!("../tests/data/w3/svg-logo-v.png").to_vec(),
+        ),
+    ]
+}
+
+fn bench(c: &mut Criterion) {
+    for (name, data) in corpus() {
+        let mut group = c.benchmark_group(name);
+        group.throughput(Throughput::Bytes(u64::try_from(data.len()).unwrap()));
+        if data.len() > MIB {
+            group.sample_size(10);
+        }
+        group.bench_function("is_svg", |b| b.iter(|| is_svg::is_svg(black_box(&data))));
+        group.bench_function("is_svg_string", |b| {
+            b.iter(|| is_svg::is_svg_string(black_box(&data)));
+        });
+        group.bench_function("is_svgz", |b| b.iter(|| is_svg::is_svgz(black_box(&data))));
+        group.bench_function("detect", |b| b.iter(|| is_svg::detect(black_box(&data))));
+        group.finish();
+    }
+}
+
+fn bench_fast(c: &mut Criterion) {
+    let data = large_svg(10 * MIB);
+    let mut group = c.benchmark_group("large-generated-10mib");
+    group.throughput(Throughput::Bytes(u64::try_from(data.len()).unwrap()));
+    group.sample_size(10);
+    let validator = Validator::new();
+    group.bench_function("validator_is_svg", |b| {
+        b.iter(|| validator.is_svg(black_box(&data)));
+    });
+    let validator = Validator::builder().fast(true).build();
+    group.bench_function("validator_is_svg_fast", |b| {
+        b.iter(|| validator.is_svg(black_box(&data)));
+    });
+    group.finish();
+}
+
+fn bench_minified(c: &mut Criterion) {
+    let (minified, pretty) = minified_svg(16 * MIB);
+    let mut group = c.benchmark_group("minified-16mib");
+    group.sample_size(10);
+    let validator = Validator::new();
+    for (name, data) in [("minified", minified), ("pretty", pretty)] {
+        group.throughput(Throughput::Bytes(u64::try_from(data.len()).unwrap()));
+        group.bench_function(name, |b| b.iter(|| validator.is_svg(black_box(&data))));
+    }
+    group.finish();
+}
+
+fn bench_cache(c: &mut Criterion) {
+    let icon = include_bytes!("../tests/data/usvg/path-simple-case.svg");
+    // More than the capacity of the cache, so that every lookup misses.
+    let unique = (0..4096)
+        .map(|i| {
+            let mut data = icon.to_vec();
+            data.extend_from_slice(format!("<!-- {i} -->").as_bytes());
+            data
+        })
+        .collect::<Vec<_>>();
+    let mut group = c.benchmark_group("cache");
+    for (name, capacity) in [("uncached", None), ("cached", NonZeroUsize::new(1024))] {
+        let validator = Validator::builder().cache(capacity).build();
+        group.bench_function(format!("{name}/repeated"), |b| {
+            b.iter(|| validator.is_svg(black_box(icon)));
+        });
+        let mut data = unique.iter().cycle();
+        group.bench_function(format!("{name}/unique"), |b| {
+            b.iter(|| validator.is_svg(black_box(data.next().unwrap())));
+        });
+    }
+    group.finish();
+}
+
+fn validate_in_threads(validators: Vec<Validator>) {
+    let handles = validators
+        .into_iter()
+        .map(|validator| {
+            thread::spawn(move || {
+                for _ in 0..16 {
+                    black_box(validator.is_svg(include_str!("../tests/data/w3/svg-logo-v.svg")));
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+}
+
+fn bench_validator(c: &mut Criterion) {
+    let mut group = c.benchmark_group("validator");
+    let validator = Validator::new();
+    for (name, data) in [
+        (
+            "svg",
+            &include_bytes!("../tests/data/w3/svg-logo-v.svg")[..],
+        ),
+        ("svgz", include_bytes!("../tests/data/w3/svg-logo-v.svgz")),
+        ("non-svg", include_bytes!("../tests/data/w3/svg-logo-v.png")),
+    ] {
+        group.bench_function(format!("is_svg/{name}"), |b| {
+            b.iter(|| is_svg::is_svg(black_box(data)));
+        });
+        group.bench_function(format!("validator_is_svg/{name}"), |b| {
+            b.iter(|| validator.is_svg(black_box(data)));
+        });
+    }
+    group.bench_function("shared-between-threads", |b| {
+        b.iter(|| validate_in_threads(vec![validator.clone(); THREADS]));
+    });
+    let validators = (0..THREADS).map(|_| Validator::new()).collect::<Vec<_>>();
+    group.bench_function("per-thread", |b| {
+        b.iter(|| validate_in_threads(validators.clone()));
+    });
+    group.finish();
+}
+
+#[cfg(feature = "text")]
+fn bench_fontdb(c: &mut Criterion) {
+    fn system_fonts() -> is_svg::fontdb::Database {
+        let mut fontdb = is_svg::fontdb::Database::new();
+        fontdb.load_system_fonts();
+        fontdb
+    }
+
+    let mut group = c.benchmark_group("fontdb");
+    group.sample_size(10);
+    for (name, data) in [
+        (
+            "text-free",
+            include_str!("../tests/data/usvg/path-simple-case.svg"),
+        ),
+        (
+            "text",
+            include_str!("../tests/data/usvg/preserve-text-simple-case.svg"),
+        ),
+    ] {
+        group.bench_function(format!("eager/{name}"), |b| {
+            b.iter(|| {
+                let validator = Validator::builder()
+                    .fontdb(std::sync::Arc::new(system_fonts()))
+                    .build();
+                validator.is_svg(black_box(data))
+            });
+        });
+        group.bench_function(format!("lazy/{name}"), |b| {
+            b.iter(|| {
+                let validator = Validator::builder().fontdb_with(system_fonts).build();
+                validator.is_svg(black_box(data))
+            });
+        });
+    }
+    group.finish();
+}
+
+#[cfg(not(feature = "text"))]
+criterion_group!(
+    benches,
+    bench,
+    bench_fast,
+    bench_minified,
+    bench_cache,
+    bench_validator
+);
+#[cfg(feature = "text")]
+criterion_group!(
+    benches,
+    bench,
+    bench_fast,
+    bench_minified,
+    bench_cache,
+    bench_validator,
+    bench_fontdb
+);
+criterion_main!(benches);
@@ -6,6 +6,7 @@
 
 extern crate test;
 
+use is_svg::Options;
 use test::Bencher;
 
 #[bench]
@@ -13,6 +14,18 @@ fn is_svg_from_svg(b: &mut Bencher) {
     b.iter(|| is_svg::is_svg(include_str!("../tests/data/w3/svg-logo-v.svg")));
 }
 
+/// The same as `is_svg_from_svg`, but builds the default options for each
+/// call, which `is_svg` did before sharing them.
+#[bench]
+fn is_svg_with_new_options_from_svg(b: &mut Bencher) {
+    b.iter(|| {
+        is_svg::is_svg_with_options(
+            include_str!("../tests/data/w3/svg-logo-v.svg"),
+            &Options::default(),
+        )
+    });
+}
+
 #[bench]
 fn is_svg_from_svgz(b: &mut Bencher) {
     b.iter(|| is_svg::is_svg(include_bytes!("../tests/data/w3/svg-logo-v.svgz")));
@@ -0,0 +1,53 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![feature(test)]
+
+extern crate test;
+
+use test::Bencher;
+
+/// Returns the SVG string which is larger than 4 MiB.
+fn large_svg() -> String {
+    let rect = r##"<rect x="1" y="2" width="3" height="4" fill="#ff0000" stroke="#0000ff"/>"##;
+    let mut svg =
+        String::from(r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16"><g>"#);
+    while svg.len() < 4 * 1024 * 1024 {
+        svg.push_str(rect);
+    }
+    svg.push_str("</g></svg>");
+    svg
+}
+
+#[bench]
+fn is_svg_str_from_svg(b: &mut Bencher) {
+    b.iter(|| is_svg::is_svg_str(include_str!("../tests/data/w3/svg-logo-v.svg")));
+}
+
+#[bench]
+fn is_svg_from_svg(b: &mut Bencher) {
+    b.iter(|| is_svg::is_svg(include_str!("../tests/data/w3/svg-logo-v.svg")));
+}
+
+#[bench]
+fn is_svg_str_from_invalid_svg(b: &mut Bencher) {
+    b.iter(|| is_svg::is_svg_str(include_str!("../tests/data/resources/unclosed.svg")));
+}
+
+#[bench]
+fn is_svg_from_invalid_svg(b: &mut Bencher) {
+    b.iter(|| is_svg::is_svg(include_str!("../tests/data/resources/unclosed.svg")));
+}
+
+#[bench]
+fn is_svg_str_from_large_svg(b: &mut Bencher) {
+    let data = large_svg();
+    b.iter(|| is_svg::is_svg_str(&data));
+}
+
+#[bench]
+fn is_svg_from_large_svg(b: &mut Bencher) {
+    let data = large_svg();
+    b.iter(|| is_svg::is_svg(&data));
+}
@@ -0,0 +1,49 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![feature(test)]
+
+extern crate test;
+
+use test::Bencher;
+
+const SVG: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16"/>"#;
+
+#[bench]
+fn looks_like_svg_from_svg(b: &mut Bencher) {
+    b.iter(|| is_svg::looks_like_svg(include_str!("../tests/data/w3/svg-logo-v.svg")));
+}
+
+#[bench]
+fn is_svg_from_svg(b: &mut Bencher) {
+    b.iter(|| is_svg::is_svg(include_str!("../tests/data/w3/svg-logo-v.svg")));
+}
+
+#[bench]
+fn looks_like_svg_from_png(b: &mut Bencher) {
+    b.iter(|| is_svg::looks_like_svg(include_bytes!("../tests/data/w3/svg-logo-v.png")));
+}
+
+#[bench]
+fn is_svg_from_png(b: &mut Bencher) {
+    b.iter(|| is_svg::is_svg(include_bytes!("../tests/data/w3/svg-logo-v.png")));
+}
+
+#[bench]
+fn looks_like_svg_from_long_comment(b: &mut Bencher) {
+    let data = format!("<!--{}-->{SVG}", "-".repeat(8 * 1024 * 1024));
+    b.iter(|| is_svg::looks_like_svg(&data));
+}
+
+#[bench]
+fn looks_like_svg_from_leading_whitespace(b: &mut Bencher) {
+    let data = format!("{}{SVG}", " ".repeat(8 * 1024 * 1024));
+    b.iter(|| is_svg::looks_like_svg(&data));
+}
+
+#[bench]
+fn looks_like_svg_within_from_long_comment(b: &mut Bencher) {
+    let data = format!("<!--{}-->{SVG}", "-".repeat(8 * 1024 * 1024));
+    b.iter(|| is_svg::looks_like_svg_within(&data, data.len()));
+}
@@ -6,17 +6,32 @@
 //! is a file or the standard input.
 
 use std::{
-    fs,
     io::{self, Read},
     path::PathBuf,
 };
 
 use anyhow::{anyhow, Context};
 use clap::Parser;
+use is_svg::KindFilter;
 
 #[derive(Debug, Parser)]
 #[command(version, about)]
 struct Opt {
+    /// Kind of the SVG image to accept.
+    #[arg(
+        short('t'),
+        long("type"),
+        value_enum,
+        default_value("any"),
+        value_name("TYPE")
+    )]
+    pub kind: KindFilter,
+
+    /// Print the version of the validation backend and the compiled features,
+    /// then exit.
+    #[arg(long)]
+    pub backend_info: bool,
+
     /// File to test.
     ///
     /// If [FILE] is not specified, data will be read from standard input.
@@ -27,20 +42,43 @@ struct Opt {
 fn main() -> anyhow::Result<()> {
     let opt = Opt::parse();
 
-    let input = if let Some(file) = opt.input {
-        fs::read(&file).with_context(|| format!("could not read data from {}", file.display()))
-    } else {
-        let mut buf = Vec::new();
-        io::stdin()
-            .read_to_end(&mut buf)
-            .context("could not read data from standard input")?;
-        Ok(buf)
-    }?;
-
-    if is_svg::is_svg(input) {
-        println!("given data is a valid SVG image");
-        Ok(())
-    } else {
-        Err(anyhow!("given data is not a valid SVG image"))
+    if opt.backend_info {
+        let capabilities = is_svg::capabilities();
+        let features = [
+            ("gzip", capabilities.gzip),
+            ("text", capabilities.text),
+            ("light", capabilities.light),
+        ]
+        .into_iter()
+        .filter_map(|(name, enabled)| enabled.then_some(name))
+        .collect::<Vec<_>>();
+        println!("usvg {}", is_svg::backend_version());
+        println!("features: {}", features.join(", "));
+        return Ok(());
+    }
+
+    let kind = opt.input.map_or_else(
+        || {
+            let mut buf = Vec::new();
+            io::stdin()
+                .read_to_end(&mut buf)
+                .context("could not read data from standard input")?;
+            Ok(is_svg::detect(buf))
+        },
+        |file| {
+            is_svg::detect_file(&file)
+                .with_context(|| format!("could not read data from {}", file.display()))
+        },
+    )?;
+
+    match kind {
+        Some(kind) if opt.kind.matches(kind) => {
+            println!("given data is a valid SVG image ({kind})");
+            Ok(())
+        }
+        Some(kind) => Err(anyhow!(
+            "given data is a valid SVG image, but is not the expected type ({kind})"
+        )),
+        None => Err(anyhow!("given data is not a valid SVG image")),
     }
 }
@@ -0,0 +1,20 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let result = is_svg::decompress_svgz(data);
+    assert_eq!(
+        is_svg::decompress_svgz(data).ok(),
+        result.as_ref().ok().cloned(),
+        "result should be deterministic"
+    );
+    assert_eq!(result.is_ok(), is_svg::is_svgz(data));
+    if let Ok(text) = result {
+        assert!(is_svg::is_svg_string(text));
+    }
+});
@@ -0,0 +1,30 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let is_svg = is_svg::is_svg(data);
+    assert_eq!(
+        is_svg::is_svg(data),
+        is_svg,
+        "verdict should be deterministic"
+    );
+
+    let is_svg_string = is_svg::is_svg_string(data);
+    let is_svgz = is_svg::is_svgz(data);
+    assert!(
+        !(is_svg_string && is_svgz),
+        "string and svgz verdicts should be mutually exclusive"
+    );
+    assert_eq!(is_svg, is_svg_string || is_svgz);
+
+    // The panics in the dependencies are contained, but are still bugs.
+    assert!(
+        !matches!(is_svg::check_svg(data), Err(is_svg::Error::Panicked)),
+        "dependency should not panic"
+    );
+});
@@ -0,0 +1,24 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let is_svg_string = is_svg::is_svg_string(data);
+    assert_eq!(
+        is_svg::is_svg_string(data),
+        is_svg_string,
+        "verdict should be deterministic"
+    );
+    if is_svg_string {
+        assert!(!is_svg::is_svgz(data));
+        assert!(!is_svg::has_gzip_magic(data));
+    }
+
+    if let Ok(text) = std::str::from_utf8(data) {
+        assert_eq!(is_svg::is_svg_str(text), is_svg_string);
+    }
+});
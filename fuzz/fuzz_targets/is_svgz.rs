@@ -0,0 +1,20 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let is_svgz = is_svg::is_svgz(data);
+    assert_eq!(
+        is_svg::is_svgz(data),
+        is_svgz,
+        "verdict should be deterministic"
+    );
+    if is_svgz {
+        assert!(!is_svg::is_svg_string(data));
+        assert!(is_svg::has_gzip_magic(data));
+    }
+});
@@ -0,0 +1,142 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Asynchronous validation of the data read from a reader.
+
+use std::io;
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use crate::{reader::DEFAULT_READ_LIMIT, Error, Limit};
+
+/// Returns [`Ok(true)`](Ok) if the data read from `reader` is a valid [SVG]
+/// data, and [`Ok(false)`](Ok) otherwise.
+///
+/// This is the asynchronous version of
+/// [`is_svg_reader`](crate::is_svg_reader()). This function also supports the
+/// [gzip-compressed] SVG image (`.svgz`).
+///
+/// At most 64 MiB are read from `reader`. If `reader` has more data than
+/// this, the data is not considered to be a valid SVG data.
+///
+/// Only reading from `reader` is asynchronous. The data is parsed on the
+/// current task, which blocks the executor while parsing. If the `tokio-rt`
+/// feature is enabled, the data is parsed on the blocking thread pool via
+/// [`tokio::task::spawn_blocking`] instead, so this function must be called
+/// from the context of a Tokio runtime.
+///
+/// # Errors
+///
+/// Returns [`Err`] if an error occurred while reading from `reader`.
+///
+/// # Examples
+///
+/// ```
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// assert!(
+///     is_svg::is_svg_reader_async(&include_bytes!("../tests/data/w3/svg-logo-v.svg")[..])
+///         .await
+///         .unwrap()
+/// );
+/// assert!(
+///     !is_svg::is_svg_reader_async(&include_bytes!("../tests/data/w3/svg-logo-v.png")[..])
+///         .await
+///         .unwrap()
+/// );
+///
+/// # #[cfg(feature = "gzip")]
+/// assert!(
+///     is_svg::is_svg_reader_async(&include_bytes!("../tests/data/w3/svg-logo-v.svgz")[..])
+///         .await
+///         .unwrap()
+/// );
+/// # });
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+#[inline]
+pub async fn is_svg_reader_async<R: AsyncRead + Unpin>(reader: R) -> io::Result<bool> {
+    match check_svg_reader_async(reader).await {
+        Ok(()) => Ok(true),
+        Err(Error::Io(err)) => Err(err),
+        Err(_) => Ok(false),
+    }
+}
+
+/// Tests whether the data read from `reader` is a valid [SVG] data.
+///
+/// This is the asynchronous version of
+/// [`check_svg_reader`](crate::check_svg_reader()). See
+/// [`is_svg_reader_async`] for more details.
+///
+/// # Errors
+///
+/// Returns [`Err`] if any of the following are true:
+///
+/// - An error occurred while reading from `reader`.
+/// - `reader` has more than 64 MiB of data.
+/// - The data is not a valid SVG data. See [`check_svg`](crate::check_svg())
+///   for more details.
+///
+/// # Examples
+///
+/// ```
+/// # use is_svg::{Error, Limit};
+/// #
+/// # tokio::runtime::Builder::new_current_thread()
+/// #     .build()
+/// #     .unwrap()
+/// #     .block_on(async {
+/// assert!(
+///     is_svg::check_svg_reader_async(&include_bytes!("../tests/data/w3/svg-logo-v.svg")[..])
+///         .await
+///         .is_ok()
+/// );
+/// assert!(matches!(
+///     is_svg::check_svg_reader_async(&include_bytes!("../tests/data/w3/svg-logo-v.png")[..])
+///         .await,
+///     Err(Error::NotUtf8(_))
+/// ));
+/// # });
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+#[inline]
+pub async fn check_svg_reader_async<R: AsyncRead + Unpin>(reader: R) -> Result<(), Error> {
+    let data = read_limited(reader, DEFAULT_READ_LIMIT).await?;
+    check(data).await
+}
+
+/// Reads all bytes from `reader`, but at most `limit` bytes.
+async fn read_limited(reader: impl AsyncRead + Unpin, limit: u64) -> Result<Vec<u8>, Error> {
+    let mut buf = Vec::new();
+    reader
+        .take(limit.saturating_add(1))
+        .read_to_end(&mut buf)
+        .await
+        .map_err(Error::Io)?;
+    if u64::try_from(buf.len()).map_or(true, |len| len > limit) {
+        return Err(Error::LimitExceeded(Limit::InputSize(limit)));
+    }
+    Ok(buf)
+}
+
+#[cfg(feature = "tokio-rt")]
+async fn check(data: Vec<u8>) -> Result<(), Error> {
+    match tokio::task::spawn_blocking(move || crate::check_svg(data)).await {
+        Ok(result) => result,
+        Err(err) if err.is_panic() => std::panic::resume_unwind(err.into_panic()),
+        Err(err) => Err(Error::Io(io::Error::new(io::ErrorKind::Other, err))),
+    }
+}
+
+#[cfg(not(feature = "tokio-rt"))]
+#[allow(clippy::unused_async)]
+async fn check(data: Vec<u8>) -> Result<(), Error> {
+    crate::check_svg(data)
+}
@@ -0,0 +1,68 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Information about the validation backend.
+
+/// The version of `usvg` which this crate is built against.
+///
+/// This must be updated together with the version of `usvg` in `Cargo.toml`.
+const USVG_VERSION: &str = "0.44";
+
+/// Returns the version of [`usvg`] which is used for the validation.
+///
+/// This is the major and the minor version (e.g., `"0.44"`), since the patch
+/// version which Cargo selected is not known to this crate. Whether a data is
+/// considered to be a valid SVG data may change when the minor version of
+/// `usvg` changes, so this should be included in the bug reports.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(is_svg::backend_version(), "0.44");
+/// ```
+#[must_use]
+#[inline]
+pub const fn backend_version() -> &'static str {
+    USVG_VERSION
+}
+
+/// Returns the optional features which are compiled in.
+///
+/// # Examples
+///
+/// ```
+/// let capabilities = is_svg::capabilities();
+/// assert_eq!(capabilities.gzip, cfg!(feature = "gzip"));
+/// ```
+#[must_use]
+#[inline]
+pub const fn capabilities() -> Capabilities {
+    Capabilities {
+        gzip: cfg!(feature = "gzip"),
+        text: cfg!(feature = "text"),
+        light: cfg!(feature = "light"),
+    }
+}
+
+/// The optional features which are compiled in.
+///
+/// This is returned by [`capabilities`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[non_exhaustive]
+pub struct Capabilities {
+    /// Whether the [gzip-compressed] SVG image (`.svgz`) is supported, which is
+    /// enabled by the `gzip` feature.
+    ///
+    /// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+    pub gzip: bool,
+
+    /// Whether the text elements are converted using the fonts, which is
+    /// enabled by the `text` feature.
+    pub text: bool,
+
+    /// Whether the lightweight validation (e.g., `is_svg_light`) is available,
+    /// which is enabled by the `light` feature.
+    pub light: bool,
+}
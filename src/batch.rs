@@ -0,0 +1,100 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Validation of multiple data at once.
+
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::{Error, SvgKind};
+
+/// Tests whether each item of `items` is a valid [SVG] data.
+///
+/// The order of the returned results is the same as the order of `items`. If
+/// the `rayon` feature is enabled, the items are tested in parallel.
+/// Otherwise, they are tested sequentially.
+///
+/// # Examples
+///
+/// ```
+/// let items = [
+///     include_bytes!("../tests/data/w3/svg-logo-v.svg").as_slice(),
+///     include_bytes!("../tests/data/w3/svg-logo-v.png"),
+///     include_bytes!("../tests/data/w3/svg-logo-v.svgz"),
+/// ];
+/// # #[cfg(feature = "gzip")]
+/// assert_eq!(is_svg::are_svgs(items), [true, false, true]);
+/// # #[cfg(not(feature = "gzip"))]
+/// # assert_eq!(is_svg::are_svgs(items), [true, false, false]);
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+#[inline]
+pub fn are_svgs<I, T>(items: I) -> Vec<bool>
+where
+    I: IntoIterator<Item = T>,
+    T: AsRef<[u8]> + Send,
+{
+    map(items, |data| crate::detect(data).is_some())
+}
+
+/// Tests whether each item of `items` is a valid [SVG] data, and returns the
+/// kind of the SVG image or the error.
+///
+/// The order of the returned results is the same as the order of `items`. If
+/// the `rayon` feature is enabled, the items are tested in parallel.
+/// Otherwise, they are tested sequentially.
+///
+/// # Examples
+///
+/// ```
+/// # use is_svg::{Error, SvgKind};
+/// #
+/// let items = [
+///     include_bytes!("../tests/data/w3/svg-logo-v.svg").as_slice(),
+///     include_bytes!("../tests/data/w3/svg-logo-v.png"),
+///     include_bytes!("../tests/data/w3/svg-logo-v.svgz"),
+/// ];
+/// let results = is_svg::check_all(items);
+/// assert!(matches!(results[0], Ok(SvgKind::Svg)));
+/// assert!(matches!(results[1], Err(Error::NotUtf8(_))));
+/// # #[cfg(feature = "gzip")]
+/// assert!(matches!(results[2], Ok(SvgKind::Svgz)));
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+#[inline]
+pub fn check_all<I, T>(items: I) -> Vec<Result<SvgKind, Error>>
+where
+    I: IntoIterator<Item = T>,
+    T: AsRef<[u8]> + Send,
+{
+    map(items, |data| {
+        crate::validate(data, crate::default_options())
+    })
+}
+
+#[cfg(feature = "rayon")]
+fn map<I, T, U>(items: I, f: impl Fn(&[u8]) -> U + Send + Sync) -> Vec<U>
+where
+    I: IntoIterator<Item = T>,
+    T: AsRef<[u8]> + Send,
+    U: Send,
+{
+    items
+        .into_iter()
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .map(|item| f(item.as_ref()))
+        .collect()
+}
+
+#[cfg(not(feature = "rayon"))]
+fn map<I, T, U>(items: I, f: impl Fn(&[u8]) -> U) -> Vec<U>
+where
+    I: IntoIterator<Item = T>,
+    T: AsRef<[u8]> + Send,
+{
+    items.into_iter().map(|item| f(item.as_ref())).collect()
+}
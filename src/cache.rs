@@ -0,0 +1,205 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A cache of the verdicts for the repeated data.
+//!
+//! The entries are keyed by the hash of the data, but the data itself is also
+//! stored and compared on each hit, so a hash collision is only a miss and
+//! never changes the verdict.
+
+use std::{
+    collections::{hash_map::RandomState, HashMap},
+    fmt,
+    hash::{BuildHasher, Hasher},
+    num::NonZeroUsize,
+    sync::{Mutex, PoisonError},
+};
+
+use crate::{ErrorKind, SvgKind};
+
+/// The maximum size of the data which is cached in bytes.
+///
+/// This bounds the memory which the cache takes to the capacity times this.
+pub const MAX_DATA_SIZE: usize = 64 * 1024;
+
+/// A verdict on the data, without the details of the error.
+pub type Verdict = Result<SvgKind, ErrorKind>;
+
+/// The statistics of the cache of [`Validator`](crate::Validator).
+///
+/// This is returned by
+/// [`Validator::cache_stats`](crate::Validator::cache_stats).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[non_exhaustive]
+pub struct CacheStats {
+    /// The number of the lookups which found the verdict.
+    pub hits: u64,
+
+    /// The number of the lookups which did not find the verdict, including the
+    /// lookups of the data which is too large to cache.
+    pub misses: u64,
+}
+
+/// A bounded cache of the verdicts, which evicts the least recently used entry.
+pub struct Cache {
+    hasher: RandomState,
+    state: Mutex<State>,
+}
+
+struct State {
+    capacity: usize,
+    slots: Vec<Slot>,
+    indices: HashMap<u64, usize>,
+    /// The most recently used slot.
+    head: Option<usize>,
+    /// The least recently used slot.
+    tail: Option<usize>,
+    stats: CacheStats,
+}
+
+struct Slot {
+    hash: u64,
+    data: Box<[u8]>,
+    verdict: Verdict,
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+impl Cache {
+    /// Creates a new `Cache` which holds up to `capacity` verdicts.
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            hasher: RandomState::new(),
+            state: Mutex::new(State {
+                capacity: capacity.get(),
+                slots: Vec::new(),
+                indices: HashMap::new(),
+                head: None,
+                tail: None,
+                stats: CacheStats::default(),
+            }),
+        }
+    }
+
+    /// Returns the verdict on `data` if it is cached.
+    pub fn get(&self, data: &[u8]) -> Option<Verdict> {
+        let hash = (data.len() <= MAX_DATA_SIZE).then(|| self.hash(data));
+        let mut state = self.lock();
+        let found = hash
+            .and_then(|hash| state.indices.get(&hash).copied())
+            .filter(|&index| *state.slots[index].data == *data);
+        if let Some(index) = found {
+            state.stats.hits += 1;
+            state.touch(index);
+            Some(state.slots[index].verdict)
+        } else {
+            state.stats.misses += 1;
+            None
+        }
+    }
+
+    /// Caches `verdict` on `data`, evicting the least recently used entry if
+    /// the cache is full.
+    ///
+    /// The timeout is not cached, since it does not depend only on `data`.
+    pub fn insert(&self, data: &[u8], verdict: Verdict) {
+        if data.len() > MAX_DATA_SIZE || verdict == Err(ErrorKind::Timeout) {
+            return;
+        }
+        let hash = self.hash(data);
+        let mut state = self.lock();
+        let index = if let Some(&index) = state.indices.get(&hash) {
+            // The same data, or the data whose hash collides.
+            state.unlink(index);
+            index
+        } else if state.slots.len() < state.capacity {
+            state.slots.push(Slot {
+                hash,
+                data: Box::default(),
+                verdict,
+                prev: None,
+                next: None,
+            });
+            state.slots.len() - 1
+        } else {
+            let index = state.tail.expect("the full cache should have an entry");
+            state.unlink(index);
+            let evicted = state.slots[index].hash;
+            state.indices.remove(&evicted);
+            index
+        };
+        let slot = &mut state.slots[index];
+        slot.hash = hash;
+        slot.data = data.into();
+        slot.verdict = verdict;
+        state.indices.insert(hash, index);
+        state.push_front(index);
+    }
+
+    /// Returns the statistics of this cache.
+    pub fn stats(&self) -> CacheStats {
+        self.lock().stats
+    }
+
+    fn hash(&self, data: &[u8]) -> u64 {
+        let mut hasher = self.hasher.build_hasher();
+        hasher.write(data);
+        hasher.finish()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        // The state is consistent between the statements which can panic.
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl fmt::Debug for Cache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.lock();
+        f.debug_struct("Cache")
+            .field("capacity", &state.capacity)
+            .field("len", &state.slots.len())
+            .field("stats", &state.stats)
+            .finish_non_exhaustive()
+    }
+}
+
+impl State {
+    /// Marks the slot at `index` as the most recently used.
+    fn touch(&mut self, index: usize) {
+        if self.head != Some(index) {
+            self.unlink(index);
+            self.push_front(index);
+        }
+    }
+
+    fn unlink(&mut self, index: usize) {
+        let (prev, next) = (self.slots[index].prev, self.slots[index].next);
+        match prev {
+            Some(prev) => self.slots[prev].next = next,
+            None if self.head == Some(index) => self.head = next,
+            None => (),
+        }
+        match next {
+            Some(next) => self.slots[next].prev = prev,
+            None if self.tail == Some(index) => self.tail = prev,
+            None => (),
+        }
+        let slot = &mut self.slots[index];
+        slot.prev = None;
+        slot.next = None;
+    }
+
+    fn push_front(&mut self, index: usize) {
+        self.slots[index].next = self.head;
+        if let Some(head) = self.head {
+            self.slots[head].prev = Some(index);
+        }
+        self.head = Some(index);
+        if self.tail.is_none() {
+            self.tail = Some(index);
+        }
+    }
+}
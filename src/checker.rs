@@ -0,0 +1,309 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Incremental validation of the chunked data.
+
+use std::{mem, str};
+
+#[cfg(feature = "gzip")]
+use crate::{gzip, limits::XmlLimits, location, member, unwind};
+use crate::{gzip::GZIP_MAGIC_NUMBER, reader::DEFAULT_READ_LIMIT, Error, Limit, SvgKind};
+
+/// The UTF-8 encoded byte order mark.
+const BOM: &[u8] = b"\xef\xbb\xbf";
+
+/// A push-based checker for testing whether the chunked data is a [SVG] image.
+///
+/// The data is fed with [`push`](Self::push) as it arrives, and the result is
+/// obtained with [`finish`](Self::finish). If the `gzip` feature is enabled,
+/// this also supports the [gzip-compressed] SVG image (`.svgz`), which is
+/// detected from the first bytes and decompressed incrementally. If it has
+/// multiple gzip members, all of them are decompressed and concatenated.
+///
+/// The data which obviously is not a SVG data (e.g., starts with something
+/// other than `<`) is rejected as soon as it is detected, and the subsequent
+/// chunks are ignored. Whether the data has been rejected can be tested with
+/// [`is_rejected`](Self::is_rejected), so that the rest of the data does not
+/// have to be received. Since the rest of the data is not examined, the error
+/// for the rejected data may differ from the one returned by
+/// [`check_svg`](crate::check_svg()).
+///
+/// By default, at most 64 MiB are accepted, both before and after the
+/// decompression.
+///
+/// # Examples
+///
+/// ```
+/// # use is_svg::{SvgChecker, SvgKind};
+/// #
+/// # #[cfg(feature = "gzip")]
+/// # {
+/// let mut checker = SvgChecker::new();
+/// for chunk in include_bytes!("../tests/data/w3/svg-logo-v.svgz").chunks(64) {
+///     checker.push(chunk);
+/// }
+/// assert_eq!(checker.finish().unwrap(), SvgKind::Svgz);
+/// # }
+///
+/// let mut checker = SvgChecker::new();
+/// checker.push(&include_bytes!("../tests/data/w3/svg-logo-v.png")[..64]);
+/// assert!(checker.is_rejected());
+/// assert!(checker.finish().is_err());
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+#[derive(Debug)]
+pub struct SvgChecker {
+    max_size: u64,
+    len: u64,
+    started: bool,
+    /// The length of the leading byte order mark and whitespace which have
+    /// already been skipped.
+    skipped: usize,
+    state: State,
+}
+
+#[derive(Debug)]
+enum State {
+    Pending(Vec<u8>),
+    Svg(Vec<u8>),
+    #[cfg(feature = "gzip")]
+    Svgz(member::Decoder, Vec<u8>),
+    Rejected(Error),
+}
+
+impl SvgChecker {
+    /// Creates a new `SvgChecker`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::SvgChecker;
+    /// #
+    /// let mut checker = SvgChecker::new();
+    /// checker.push(include_bytes!("../tests/data/w3/svg-logo-v.svg"));
+    /// assert!(checker.finish().is_ok());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            max_size: DEFAULT_READ_LIMIT,
+            len: 0,
+            started: false,
+            skipped: 0,
+            state: State::Pending(Vec::new()),
+        }
+    }
+
+    /// Sets the maximum size of the data in bytes.
+    ///
+    /// This limit applies to both the pushed data and the decompressed data.
+    /// The default is 64 MiB.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::{Error, Limit, SvgChecker};
+    /// #
+    /// let mut checker = SvgChecker::new().max_size(1024);
+    /// checker.push(include_bytes!("../tests/data/w3/svg-logo-v.svg"));
+    /// assert!(checker.is_rejected());
+    /// assert!(matches!(
+    ///     checker.finish(),
+    ///     Err(Error::LimitExceeded(Limit::InputSize(1024)))
+    /// ));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn max_size(mut self, limit: u64) -> Self {
+        self.max_size = limit;
+        self
+    }
+
+    /// Feeds the next chunk of the data.
+    ///
+    /// This does nothing if the data has already been rejected.
+    pub fn push(&mut self, chunk: &[u8]) {
+        if let Err(err) = self.try_push(chunk) {
+            self.state = State::Rejected(err);
+        }
+    }
+
+    /// Returns [`true`] if the data has already been rejected, and [`false`]
+    /// otherwise.
+    ///
+    /// If this returns [`true`], [`finish`](Self::finish) always returns
+    /// [`Err`].
+    #[must_use]
+    #[inline]
+    pub const fn is_rejected(&self) -> bool {
+        matches!(self.state, State::Rejected(_))
+    }
+
+    /// Finishes feeding the data, and returns the kind of the [SVG] image.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the data exceeds the maximum size, or is not a valid
+    /// SVG data. See [`check_svg`](crate::check_svg()) for more details.
+    ///
+    /// [SVG]: https://www.w3.org/Graphics/SVG/
+    pub fn finish(self) -> Result<SvgKind, Error> {
+        match self.state {
+            State::Pending(data) | State::Svg(data) => {
+                crate::validate(&data, crate::default_options())
+            }
+            #[cfg(feature = "gzip")]
+            State::Svgz(decoder, mut data) => {
+                unwind::contain(|| decoder.finish())?;
+                gzip::check_decompressed_size(&data, Some(self.max_size))?;
+                // The nested gzip-compressed data is decompressed as a whole.
+                let nesting = gzip::decompress_inner(
+                    None,
+                    self.max_size,
+                    gzip::StreamPolicy::default(),
+                    &mut data,
+                )?;
+                let text = crate::utf16::to_str(&data)?;
+                crate::parse_str(&text, crate::default_options(), XmlLimits::default())
+                    .map_err(location::decompressed)?;
+                Ok(if nesting == 0 {
+                    SvgKind::Svgz
+                } else {
+                    SvgKind::NestedSvgz
+                })
+            }
+            State::Rejected(err) => Err(err),
+        }
+    }
+
+    fn try_push(&mut self, chunk: &[u8]) -> Result<(), Error> {
+        if self.is_rejected() {
+            return Ok(());
+        }
+        self.len = self
+            .len
+            .saturating_add(u64::try_from(chunk.len()).unwrap_or(u64::MAX));
+        if self.len > self.max_size {
+            return Err(Error::LimitExceeded(Limit::InputSize(self.max_size)));
+        }
+
+        if let State::Pending(data) = &mut self.state {
+            data.extend_from_slice(chunk);
+            #[cfg(feature = "gzip")]
+            if data.starts_with(&GZIP_MAGIC_NUMBER) {
+                let data = mem::take(data);
+                self.state = State::Svgz(
+                    member::Decoder::new(gzip::StreamPolicy::default()),
+                    Vec::new(),
+                );
+                return self.push_svgz(&data);
+            }
+            // Without the `gzip` feature, the gzip-compressed data is tested as
+            // is, as well as the slice API.
+            if !cfg!(feature = "gzip") || !GZIP_MAGIC_NUMBER.starts_with(data) {
+                self.state = State::Svg(mem::take(data));
+            }
+            return self.check_start();
+        }
+        match &mut self.state {
+            State::Svg(data) => data.extend_from_slice(chunk),
+            #[cfg(feature = "gzip")]
+            State::Svgz(..) => return self.push_svgz(chunk),
+            State::Pending(_) | State::Rejected(_) => unreachable!(),
+        }
+        self.check_start()
+    }
+
+    #[cfg(feature = "gzip")]
+    fn push_svgz(&mut self, chunk: &[u8]) -> Result<(), Error> {
+        if let State::Svgz(decoder, data) = &mut self.state {
+            // All the gzip members are decompressed and concatenated, and the
+            // data after the last member which is not a gzip member is
+            // rejected, as well as the slice API. The decoder stops once the
+            // data exceeds the maximum size, rather than decompressing all of
+            // a large chunk.
+            unwind::contain(|| decoder.write(chunk, data, self.max_size))?;
+            gzip::check_decompressed_size(data, Some(self.max_size))?;
+        }
+        self.check_start()
+    }
+
+    /// Rejects the data if the first non-whitespace character is not `<`.
+    fn check_start(&mut self) -> Result<(), Error> {
+        if self.started {
+            return Ok(());
+        }
+        let data = match &self.state {
+            State::Svg(data) => data,
+            #[cfg(feature = "gzip")]
+            State::Svgz(_, data) => data,
+            State::Pending(_) | State::Rejected(_) => return Ok(()),
+        };
+        if BOM.starts_with(data) || crate::utf16::is_undecided(data) {
+            return Ok(());
+        }
+        // The nested gzip-compressed data is tested as a whole.
+        #[cfg(feature = "gzip")]
+        if matches!(self.state, State::Svgz(..)) && GZIP_MAGIC_NUMBER.starts_with(data) {
+            return Ok(());
+        }
+        #[cfg(feature = "gzip")]
+        if matches!(self.state, State::Svgz(..)) && gzip::has_gzip_magic(data) {
+            self.started = true;
+            return Ok(());
+        }
+        // The UTF-16 encoded data is tested as a whole.
+        if crate::utf16::detect(data).is_some() {
+            self.started = true;
+            return Ok(());
+        }
+        // The leading whitespace is not scanned again for every chunk.
+        let offset = if data.starts_with(BOM) { BOM.len() } else { 0 }.max(self.skipped);
+        let Some(pos) = data[offset..]
+            .iter()
+            .position(|b| !matches!(b, b' ' | b'\t' | b'\r' | b'\n'))
+            .map(|pos| offset + pos)
+        else {
+            self.skipped = data.len();
+            return Ok(());
+        };
+        if data[pos] == b'<' {
+            self.started = true;
+            return Ok(());
+        }
+
+        // Keeps only the first character, so that the error is the same as
+        // the one for the whole data.
+        let end = match str::from_utf8(&data[pos..data.len().min(pos + 4)]) {
+            Ok(c) => pos + c.chars().next().map_or(0, char::len_utf8),
+            Err(err) if err.valid_up_to() > 0 => pos + err.valid_up_to(),
+            Err(err) if err.error_len().is_some() => pos + 1,
+            Err(_) => return Ok(()),
+        };
+        let prefix = &data[..end];
+        let result = match &self.state {
+            #[cfg(feature = "gzip")]
+            State::Svgz(..) => str::from_utf8(prefix)
+                .map_err(Error::NotUtf8)
+                .and_then(|text| {
+                    crate::parse_str(text, crate::default_options(), XmlLimits::default())
+                        .map(|_| ())
+                        .map_err(location::decompressed)
+                }),
+            _ => crate::validate(prefix, crate::default_options()).map(|_| ()),
+        };
+        self.started = true;
+        result
+    }
+}
+
+impl Default for SvgChecker {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
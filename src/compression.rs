@@ -0,0 +1,239 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The compression format of the data.
+
+use std::fmt;
+
+use crate::gzip::GZIP_MAGIC_NUMBER;
+
+/// Magic number of zstd defined in [RFC 8878].
+///
+/// [RFC 8878]: https://datatracker.ietf.org/doc/html/rfc8878
+const ZSTD_MAGIC_NUMBER: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+/// Magic number of the [xz file format].
+///
+/// [xz file format]: https://tukaani.org/xz/xz-file-format.txt
+const XZ_MAGIC_NUMBER: [u8; 6] = [0xfd, b'7', b'z', b'X', b'Z', 0x00];
+
+/// Magic number of the [lzip file format].
+///
+/// [lzip file format]: https://www.nongnu.org/lzip/manual/lzip_manual.html#File-format
+const LZIP_MAGIC_NUMBER: [u8; 4] = *b"LZIP";
+
+/// Returns [`true`] if `data` starts with the [zlib] header which is written by
+/// the usual encoders.
+///
+/// zlib has no magic number, so only the header with deflate, the 32 KiB
+/// window and no preset dictionary (e.g., `78 9c`) is recognized, which is
+/// also checked by its check bits.
+///
+/// [zlib]: https://datatracker.ietf.org/doc/html/rfc1950
+const fn has_zlib_header(data: &[u8]) -> bool {
+    /// The compression method and flags with deflate and the 32 KiB window.
+    const CMF: u8 = 0x78;
+
+    /// The flag which indicates the preset dictionary.
+    const FDICT: u8 = 0x20;
+
+    match data {
+        [CMF, flg, ..] => *flg & FDICT == 0 && u16::from_be_bytes([CMF, *flg]) % 31 == 0,
+        _ => false,
+    }
+}
+
+/// The compression format of the data.
+///
+/// This is detected from the magic number at the start of the data by
+/// [`CompressionKind::from_magic`], and is used to select the formats which
+/// are accepted by [`ValidatorBuilder::accepted_compressions`].
+///
+/// Only [`CompressionKind::None`], [`CompressionKind::Gzip`],
+/// [`CompressionKind::Zlib`] and [`CompressionKind::Deflate`] can be validated
+/// for now. The data which is compressed in the other formats is always
+/// rejected, but is distinguished from the data which is not a SVG data at
+/// all.
+///
+/// If the `serde` feature is enabled, this is serialized as the lowercase name
+/// of the format (e.g., `"gzip"`), which is the same as
+/// [`Display`](fmt::Display).
+///
+/// [`ValidatorBuilder::accepted_compressions`]: crate::ValidatorBuilder::accepted_compressions
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "lowercase")
+)]
+#[non_exhaustive]
+pub enum CompressionKind {
+    /// The data is not compressed.
+    None,
+
+    /// The data is [gzip-compressed] (e.g., `.svgz`).
+    ///
+    /// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+    Gzip,
+
+    /// The data is [zlib-compressed] (e.g., `.svg.zz`).
+    ///
+    /// This is not `.svgz`, but some toolchains emit this instead. This is
+    /// detected by the zlib header, and is only accepted if it is in
+    /// [`ValidatorBuilder::accepted_compressions`].
+    ///
+    /// [zlib-compressed]: https://datatracker.ietf.org/doc/html/rfc1950
+    /// [`ValidatorBuilder::accepted_compressions`]: crate::ValidatorBuilder::accepted_compressions
+    Zlib,
+
+    /// The data is compressed in the raw [deflate] format without any wrapper
+    /// (e.g., `.svg.deflate`).
+    ///
+    /// This has no magic number, so this is never returned by
+    /// [`CompressionKind::from_magic`]. If this is in
+    /// [`ValidatorBuilder::accepted_compressions`], the data which is not a
+    /// valid SVG data as is is decompressed and tested again.
+    ///
+    /// [deflate]: https://datatracker.ietf.org/doc/html/rfc1951
+    /// [`ValidatorBuilder::accepted_compressions`]: crate::ValidatorBuilder::accepted_compressions
+    Deflate,
+
+    /// The data is [zstd-compressed] (e.g., `.svg.zst`).
+    ///
+    /// [zstd-compressed]: https://datatracker.ietf.org/doc/html/rfc8878
+    Zstd,
+
+    /// The data is compressed in the [xz file format] (e.g., `.svg.xz`).
+    ///
+    /// [xz file format]: https://tukaani.org/xz/xz-file-format.txt
+    Xz,
+
+    /// The data is compressed in the [lzip file format] (e.g., `.svg.lz`).
+    ///
+    /// [lzip file format]: https://www.nongnu.org/lzip/manual/lzip_manual.html#File-format
+    Lzip,
+}
+
+impl CompressionKind {
+    /// Detects the compression format of `data` from its magic number.
+    ///
+    /// This only looks at the first few bytes. It does not test whether `data`
+    /// can be decompressed. If `data` does not start with any of the known
+    /// magic numbers, this returns [`CompressionKind::None`]. The [zlib]
+    /// header is also recognized, but [`CompressionKind::Deflate`] is never
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::CompressionKind;
+    /// #
+    /// assert_eq!(
+    ///     CompressionKind::from_magic(include_str!("../tests/data/w3/svg-logo-v.svg")),
+    ///     CompressionKind::None
+    /// );
+    /// assert_eq!(
+    ///     CompressionKind::from_magic(include_bytes!("../tests/data/w3/svg-logo-v.svgz")),
+    ///     CompressionKind::Gzip
+    /// );
+    /// assert_eq!(
+    ///     CompressionKind::from_magic(include_bytes!("../tests/data/w3/svg-logo-v.svg.zst")),
+    ///     CompressionKind::Zstd
+    /// );
+    /// assert_eq!(
+    ///     CompressionKind::from_magic(include_bytes!("../tests/data/deflate/svg-logo-v.svg.zz")),
+    ///     CompressionKind::Zlib
+    /// );
+    /// ```
+    ///
+    /// [zlib]: https://datatracker.ietf.org/doc/html/rfc1950
+    #[must_use]
+    #[inline]
+    pub fn from_magic(data: impl AsRef<[u8]>) -> Self {
+        let inner = |data: &[u8]| -> Self {
+            if data.starts_with(&GZIP_MAGIC_NUMBER) {
+                Self::Gzip
+            } else if data.starts_with(&ZSTD_MAGIC_NUMBER) {
+                Self::Zstd
+            } else if data.starts_with(&XZ_MAGIC_NUMBER) {
+                Self::Xz
+            } else if data.starts_with(&LZIP_MAGIC_NUMBER) {
+                Self::Lzip
+            } else if has_zlib_header(data) {
+                Self::Zlib
+            } else {
+                Self::None
+            }
+        };
+        inner(data.as_ref())
+    }
+
+    /// Returns the lowercase name of this format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::CompressionKind;
+    /// #
+    /// assert_eq!(CompressionKind::None.name(), "none");
+    /// assert_eq!(CompressionKind::Gzip.name(), "gzip");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Gzip => "gzip",
+            Self::Zlib => "zlib",
+            Self::Deflate => "deflate",
+            Self::Zstd => "zstd",
+            Self::Xz => "xz",
+            Self::Lzip => "lzip",
+        }
+    }
+
+    /// Returns [`true`] if the data in this format can be validated.
+    ///
+    /// The [gzip-compressed] data also requires the `gzip` feature, or the
+    /// [`Decompressor`](crate::Decompressor) set by
+    /// [`ValidatorBuilder::decompressor`](crate::ValidatorBuilder::decompressor).
+    /// [`CompressionKind::Zlib`] and [`CompressionKind::Deflate`] are only
+    /// supported if the `gzip` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::CompressionKind;
+    /// #
+    /// assert!(CompressionKind::None.is_supported());
+    /// assert!(CompressionKind::Gzip.is_supported());
+    /// # #[cfg(feature = "gzip")]
+    /// assert!(CompressionKind::Zlib.is_supported());
+    /// assert!(!CompressionKind::Zstd.is_supported());
+    /// ```
+    ///
+    /// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+    #[must_use]
+    #[inline]
+    pub const fn is_supported(self) -> bool {
+        matches!(self, Self::None | Self::Gzip)
+            || (cfg!(feature = "gzip") && matches!(self, Self::Zlib | Self::Deflate))
+    }
+}
+
+impl fmt::Display for CompressionKind {
+    /// Formats the format as its lowercase name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::CompressionKind;
+    /// #
+    /// assert_eq!(CompressionKind::Gzip.to_string(), "gzip");
+    /// assert_eq!(CompressionKind::Zstd.to_string(), "zstd");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
@@ -0,0 +1,102 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Inspection of the content of the converted tree.
+//!
+//! `usvg` removes the elements which are not rendered, such as the `defs`
+//! element, the elements with `display="none"` and the invalid shapes, but
+//! keeps the empty groups and the nodes which are transparent or outside the
+//! canvas.
+
+use usvg::{roxmltree::Document, Group, Node, Rect, Tree};
+
+/// Returns [`true`] if `group` has any node to render other than the groups.
+pub fn has_content(group: &Group) -> bool {
+    group.children().iter().any(|node| match node {
+        Node::Group(group) => has_content(group),
+        _ => true,
+    })
+}
+
+/// Returns [`true`] if any node of `tree` is visible on the canvas.
+///
+/// This is an estimation from the bounding boxes, so the node which is
+/// invisible for the other reasons (e.g., masked away, or painted with the
+/// transparent gradient) is considered to be visible. The node is invisible if
+/// any of the following are true:
+///
+/// - The node or any of its ancestors is transparent, or is hidden with the
+///   `visibility` property.
+/// - The node has no area within the canvas, or within the bounding boxes of
+///   the clip paths of its ancestors.
+/// - The `viewBox` of the root element in `doc` has zero width or height, which
+///   disables rendering.
+pub fn is_renderable(doc: &Document<'_>, tree: &Tree) -> bool {
+    if has_empty_view_box(doc) {
+        return false;
+    }
+    let size = tree.size();
+    Rect::from_xywh(0.0, 0.0, size.width(), size.height())
+        .map_or(false, |canvas| has_visible(tree.root(), canvas))
+}
+
+/// Returns [`true`] if any descendant of `group` has an area within `region`,
+/// which is in the canvas coordinates.
+fn has_visible(group: &Group, region: Rect) -> bool {
+    if group.opacity().get() <= 0.0 {
+        return false;
+    }
+    let region = match group.clip_path() {
+        Some(clip_path) => {
+            let ts = group.abs_transform().pre_concat(clip_path.transform());
+            match clip_path
+                .root()
+                .abs_bounding_box()
+                .transform(ts)
+                .and_then(|clip| clip.intersect(&region))
+            {
+                Some(region) => region,
+                None => return false,
+            }
+        }
+        None => region,
+    };
+    group.children().iter().any(|node| match node {
+        Node::Group(group) => has_visible(group, region),
+        Node::Path(path) => {
+            let painted = path.fill().map_or(false, |fill| fill.opacity().get() > 0.0)
+                || path
+                    .stroke()
+                    .map_or(false, |stroke| stroke.opacity().get() > 0.0);
+            path.is_visible() && painted && has_area(node.abs_stroke_bounding_box(), region)
+        }
+        Node::Image(image) => {
+            image.is_visible() && has_area(node.abs_stroke_bounding_box(), region)
+        }
+        Node::Text(text) => has_visible(text.flattened(), region),
+    })
+}
+
+/// Returns [`true`] if the intersection of `rect` and `region` has an area.
+fn has_area(rect: Rect, region: Rect) -> bool {
+    rect.intersect(&region)
+        .map_or(false, |rect| rect.width() > 0.0 && rect.height() > 0.0)
+}
+
+/// Returns [`true`] if the `viewBox` attribute of the root element has zero
+/// width or height.
+///
+/// `usvg` ignores such `viewBox` attribute, but it disables rendering of the
+/// element.
+fn has_empty_view_box(doc: &Document<'_>) -> bool {
+    let Some(view_box) = doc.root_element().attribute("viewBox") else {
+        return false;
+    };
+    let values = view_box
+        .split(|c: char| c.is_ascii_whitespace() || c == ',')
+        .filter(|value| !value.is_empty())
+        .map(str::parse::<f64>)
+        .collect::<Result<Vec<_>, _>>();
+    matches!(values.as_deref(), Ok([_, _, width, height]) if *width == 0.0 || *height == 0.0)
+}
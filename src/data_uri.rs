@@ -0,0 +1,88 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Validation of the `data:` URI.
+
+use data_url::DataUrl;
+
+use crate::Error;
+
+/// Returns [`true`] if `uri` is a [`data:` URI] of a valid [SVG] image, and
+/// [`false`] otherwise.
+///
+/// Both base64-encoded and percent-encoded data are supported. The media type
+/// must be `image/svg+xml`, and the decoded data may also be a
+/// [gzip-compressed] SVG image (`.svgz`).
+///
+/// # Examples
+///
+/// ```
+/// assert!(is_svg::is_svg_data_uri(
+///     "data:image/svg+xml,%3Csvg%20xmlns='http://www.w3.org/2000/svg'%20viewBox='0%200%201%201'/%3E"
+/// ));
+/// assert!(is_svg::is_svg_data_uri(
+///     "data:image/svg+xml;base64,PHN2ZyB4bWxucz0naHR0cDovL3d3dy53My5vcmcvMjAwMC9zdmcnIHZpZXdCb3g9JzAgMCAxIDEnLz4="
+/// ));
+///
+/// assert!(!is_svg::is_svg_data_uri(
+///     "data:text/plain,%3Csvg%20xmlns='http://www.w3.org/2000/svg'%20viewBox='0%200%201%201'/%3E"
+/// ));
+/// assert!(!is_svg::is_svg_data_uri("data:image/svg+xml;base64"));
+/// ```
+///
+/// [`data:` URI]: https://datatracker.ietf.org/doc/html/rfc2397
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+#[must_use]
+#[inline]
+pub fn is_svg_data_uri(uri: &str) -> bool {
+    check_svg_data_uri(uri).is_ok()
+}
+
+/// Tests whether `uri` is a [`data:` URI] of a valid [SVG] image.
+///
+/// See [`is_svg_data_uri`] for more details.
+///
+/// # Errors
+///
+/// Returns [`Err`] if any of the following are true:
+///
+/// - `uri` is not a valid `data:` URI, e.g., has no comma or has invalid
+///   base64.
+/// - The media type of `uri` is not `image/svg+xml`.
+/// - The decoded data is not a valid SVG data. See
+///   [`check_svg`](crate::check_svg()) for more details.
+///
+/// # Examples
+///
+/// ```
+/// # use is_svg::Error;
+/// #
+/// assert!(is_svg::check_svg_data_uri(
+///     "data:image/svg+xml,%3Csvg%20xmlns='http://www.w3.org/2000/svg'%20viewBox='0%200%201%201'/%3E"
+/// )
+/// .is_ok());
+///
+/// assert!(matches!(
+///     is_svg::check_svg_data_uri("data:image/png;base64,iVBORw0KGgo="),
+///     Err(Error::NotSvgMediaType)
+/// ));
+/// assert!(matches!(
+///     is_svg::check_svg_data_uri("data:image/svg+xml;base64,!!!"),
+///     Err(Error::InvalidDataUri)
+/// ));
+/// ```
+///
+/// [`data:` URI]: https://datatracker.ietf.org/doc/html/rfc2397
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+#[inline]
+pub fn check_svg_data_uri(uri: &str) -> Result<(), Error> {
+    let url = DataUrl::process(uri).map_err(|_| Error::InvalidDataUri)?;
+    let mime = url.mime_type();
+    if mime.type_ != "image" || mime.subtype != "svg+xml" {
+        return Err(Error::NotSvgMediaType);
+    }
+    let (data, _) = url.decode_to_vec().map_err(|_| Error::InvalidDataUri)?;
+    crate::check_svg(data)
+}
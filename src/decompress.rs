@@ -0,0 +1,112 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Pluggable decompression of the gzip-compressed SVG image.
+
+use std::{fmt, io};
+
+#[cfg(feature = "gzip")]
+use crate::{
+    gzip::{self, StreamPolicy},
+    member, Error,
+};
+
+/// A decompressor for the [gzip-compressed] [SVG] image (`.svgz`).
+///
+/// By default, this crate decompresses the data using `GzipDecompressor` if the
+/// `gzip` feature is enabled. Implementing this trait allows the caller to use
+/// another implementation of inflate, such as the one provided by the platform,
+/// with [`ValidatorBuilder::decompressor`](crate::ValidatorBuilder::decompressor).
+/// This works even if the `gzip` feature is disabled.
+///
+/// Unlike the built-in decompressor, panics from an implementation of this
+/// trait are not contained, and propagate to the caller.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// #
+/// # use is_svg::{Decompressor, Error, Validator};
+/// #
+/// #[derive(Debug)]
+/// struct Unsupported;
+///
+/// impl Decompressor for Unsupported {
+///     fn decompress(&self, _: &[u8], _: Option<u64>, _: &mut Vec<u8>) -> io::Result<()> {
+///         Err(io::Error::new(
+///             io::ErrorKind::Unsupported,
+///             "gzip is not supported",
+///         ))
+///     }
+/// }
+///
+/// let validator = Validator::builder().decompressor(Unsupported).build();
+/// assert!(validator.is_svg(include_str!("../tests/data/w3/svg-logo-v.svg")));
+/// assert!(matches!(
+///     validator.check_svg(include_bytes!("../tests/data/w3/svg-logo-v.svgz")),
+///     Err(Error::GzipDecode(_))
+/// ));
+/// ```
+///
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+pub trait Decompressor: fmt::Debug + Send + Sync {
+    /// Decompresses the gzip-compressed `data`, and appends the decompressed
+    /// data to `buf`.
+    ///
+    /// `data` always starts with the gzip magic number. If `limit` is
+    /// [`Some`], the data is rejected if the decompressed data exceeds `limit`
+    /// bytes, so the implementation may stop decompressing once more than
+    /// `limit` bytes have been appended.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `data` could not be decompressed.
+    fn decompress(&self, data: &[u8], limit: Option<u64>, buf: &mut Vec<u8>) -> io::Result<()>;
+}
+
+/// The built-in [`Decompressor`] using [`flate2`].
+///
+/// If the data has multiple gzip members (e.g., the files which are
+/// concatenated by `cat`), all the members are decompressed and concatenated.
+///
+/// # Examples
+///
+/// ```
+/// # use is_svg::{Decompressor, GzipDecompressor};
+/// #
+/// let mut buf = Vec::new();
+/// GzipDecompressor
+///     .decompress(
+///         include_bytes!("../tests/data/w3/svg-logo-v.svgz"),
+///         None,
+///         &mut buf,
+///     )
+///     .unwrap();
+/// assert_eq!(buf, include_bytes!("../tests/data/w3/svg-logo-v.svg"));
+/// ```
+#[cfg(feature = "gzip")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct GzipDecompressor;
+
+#[cfg(feature = "gzip")]
+impl Decompressor for GzipDecompressor {
+    fn decompress(&self, data: &[u8], limit: Option<u64>, buf: &mut Vec<u8>) -> io::Result<()> {
+        let mut decoder = member::Decoder::new(StreamPolicy::default());
+        decoder
+            .write(data, buf, limit.unwrap_or(u64::MAX))
+            .and_then(|()| {
+                // The caller rejects the data which exceeds the limit.
+                match gzip::check_decompressed_size(buf, limit) {
+                    Ok(()) => decoder.finish(),
+                    Err(_) => Ok(()),
+                }
+            })
+            .map_err(|err| match err {
+                Error::GzipDecode(err) => err,
+                err => io::Error::new(io::ErrorKind::InvalidData, err),
+            })
+    }
+}
@@ -0,0 +1,44 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Decompression of the zlib-compressed and the raw deflate data.
+//!
+//! These are not `.svgz`, but both are deflate streams like the gzip members,
+//! so the errors are reported as the ones of the gzip-compressed data.
+
+use std::io::Read;
+
+use flate2::bufread::{DeflateDecoder, ZlibDecoder};
+
+use crate::{gzip, unwind, CompressionKind, Error};
+
+/// Decompresses `data` which is compressed in `format`, and appends the
+/// decompressed data to `buf`.
+///
+/// At most `limit` bytes are decompressed. The data after the end of the
+/// stream is ignored.
+///
+/// # Panics
+///
+/// Panics if `format` is neither [`CompressionKind::Zlib`] nor
+/// [`CompressionKind::Deflate`].
+pub fn decompress(
+    data: &[u8],
+    format: CompressionKind,
+    limit: u64,
+    buf: &mut Vec<u8>,
+) -> Result<(), Error> {
+    let reader: Box<dyn Read + '_> = match format {
+        CompressionKind::Zlib => Box::new(ZlibDecoder::new(data)),
+        CompressionKind::Deflate => Box::new(DeflateDecoder::new(data)),
+        _ => unreachable!("{format} is not a deflate stream"),
+    };
+    unwind::contain(|| {
+        reader
+            .take(limit.saturating_add(1))
+            .read_to_end(buf)
+            .map_err(Error::GzipDecode)?;
+        gzip::check_decompressed_size(buf, Some(limit))
+    })
+}
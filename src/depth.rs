@@ -0,0 +1,188 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Estimation of the nesting depth of the XML document.
+//!
+//! [`roxmltree`](usvg::roxmltree) parses the nested elements recursively, so a
+//! deeply nested document overflows the stack, which aborts the process
+//! rather than panicking. The depth is estimated before parsing, so that such
+//! a document can be parsed on a thread with a large stack, or rejected.
+
+use std::thread;
+
+/// The maximum depth which is parsed on the current thread.
+///
+/// The documents which are nested up to about 300 levels fit in the default
+/// stack size of the spawned threads (2 MiB) even in the unoptimized build, so
+/// this leaves some room for the caller. Almost all documents are shallower
+/// than this, so they never spawn a thread.
+pub const INLINE_LIMIT: usize = 256;
+
+/// The maximum depth which is parsed at all.
+///
+/// `usvg` rejects the SVG elements which are nested deeper than 1024, but
+/// ignores the unknown elements, so this is larger than that.
+pub const MAX_DEPTH: usize = 4096;
+
+/// The stack size of the thread for parsing up to [`MAX_DEPTH`].
+const STACK_SIZE: usize = 256 * 1024 * 1024;
+
+/// The maximum depth of the nested entity references of `roxmltree`.
+const ENTITY_DEPTH_LIMIT: usize = 10;
+
+/// The estimated structure of the XML document.
+#[derive(Clone, Copy, Debug)]
+pub struct Estimate {
+    /// The upper bound of the nesting depth of the elements.
+    pub depth: usize,
+
+    /// The number of the elements, excluding the elements which are expanded
+    /// from the entities.
+    pub elements: usize,
+
+    /// The length of the longest attribute value in bytes, as it is written in
+    /// the document.
+    pub attribute_len: usize,
+}
+
+/// Estimates the nesting depth, the number of the elements and the length of
+/// the longest attribute value in `text`.
+///
+/// This does not validate `text`, but the depth is never less than the depth
+/// which `roxmltree` reaches while parsing `text`, including the elements
+/// which are expanded from the entities.
+pub fn estimate(text: &str) -> Estimate {
+    let (depth, entity_depth, elements, attribute_len) = scan(text.as_bytes());
+    Estimate {
+        depth: depth.saturating_add(entity_depth.saturating_mul(ENTITY_DEPTH_LIMIT)),
+        elements,
+        attribute_len,
+    }
+}
+
+/// Calls `f` on a new thread with a stack which is large enough for parsing up
+/// to [`MAX_DEPTH`].
+///
+/// Returns [`None`] if the thread cannot be spawned, e.g., on the platform
+/// which does not support the threads, such as `wasm32-unknown-unknown`.
+pub fn with_large_stack<T: Send>(f: impl FnOnce() -> T + Send) -> Option<T> {
+    thread::scope(|scope| {
+        let handle = thread::Builder::new()
+            .name(String::from("is-svg"))
+            .stack_size(STACK_SIZE)
+            .spawn_scoped(scope, f)
+            .ok()?;
+        Some(
+            handle
+                .join()
+                .unwrap_or_else(|payload| std::panic::resume_unwind(payload)),
+        )
+    })
+}
+
+/// Returns the maximum depth of the elements in `data`, the maximum depth of
+/// the elements in the entity values, the number of the elements in `data`,
+/// and the length of the longest attribute value in `data` and the entity
+/// values.
+fn scan(data: &[u8]) -> (usize, usize, usize, usize) {
+    let (mut depth, mut max_depth, mut max_entity_depth) = (0_usize, 0, 0);
+    let (mut elements, mut attribute_len) = (0_usize, 0);
+    let mut pos = 0;
+    while let Some(offset) = memchr::memchr(b'<', &data[pos..]) {
+        pos += offset;
+        let rest = &data[pos..];
+        pos = if rest.starts_with(b"<!--") {
+            skip_past(data, pos + 4, b"-->")
+        } else if rest.starts_with(b"<![CDATA[") {
+            skip_past(data, pos + 9, b"]]>")
+        } else if rest.starts_with(b"<?") {
+            skip_past(data, pos + 2, b"?>")
+        } else if rest.starts_with(b"<!ENTITY") {
+            let (end, value) = entity_value(data, pos + 8);
+            let (entity_depth, _, _, entity_attribute_len) = scan(value);
+            max_entity_depth = max_entity_depth.max(entity_depth);
+            attribute_len = attribute_len.max(entity_attribute_len);
+            end
+        } else if rest.starts_with(b"<!") {
+            pos + 2
+        } else if rest.starts_with(b"</") {
+            depth = depth.saturating_sub(1);
+            pos + 2
+        } else {
+            let (end, value_len) = scan_tag(data, pos + 1);
+            elements += 1;
+            attribute_len = attribute_len.max(value_len);
+            if data[..end].ends_with(b"/>") {
+                // An empty element does not increase the depth of its children,
+                // but is itself one level deeper.
+                max_depth = max_depth.max(depth + 1);
+            } else {
+                depth += 1;
+                max_depth = max_depth.max(depth);
+            }
+            end
+        };
+    }
+    (max_depth, max_entity_depth, elements, attribute_len)
+}
+
+/// Returns the position after `pattern` which first appears at or after
+/// `start`, or the end of `data` if it does not appear.
+pub fn skip_past(data: &[u8], start: usize, pattern: &[u8]) -> usize {
+    data.get(start..)
+        .and_then(|rest| rest.windows(pattern.len()).position(|w| w == pattern))
+        .map_or(data.len(), |pos| start + pos + pattern.len())
+}
+
+/// Returns the position after the `>` which ends the tag, ignoring the `>` in
+/// the attribute values.
+pub fn tag_end(data: &[u8], start: usize) -> usize {
+    scan_tag(data, start).0
+}
+
+/// Returns the position after the `>` which ends the tag, and the length of
+/// the longest attribute value in the tag.
+///
+/// The value which is not closed before the end of `data` is also counted.
+fn scan_tag(data: &[u8], start: usize) -> (usize, usize) {
+    let mut value_len = 0;
+    let mut pos = start.min(data.len());
+    while let Some(offset) = memchr::memchr3(b'"', b'\'', b'>', &data[pos..]) {
+        pos += offset;
+        if data[pos] == b'>' {
+            return (pos + 1, value_len);
+        }
+        let value_start = pos + 1;
+        let Some(end) = memchr::memchr(data[pos], &data[value_start..]) else {
+            return (data.len(), value_len.max(data.len() - value_start));
+        };
+        value_len = value_len.max(end);
+        pos = value_start + end + 1;
+    }
+    (data.len(), value_len)
+}
+
+/// Returns the position after the entity declaration, and the first quoted
+/// literal in it.
+pub fn entity_value(data: &[u8], start: usize) -> (usize, &[u8]) {
+    let mut value: &[u8] = &[];
+    let mut pos = start;
+    while let Some(&b) = data.get(pos) {
+        match b {
+            b'"' | b'\'' => {
+                let end = data[pos + 1..]
+                    .iter()
+                    .position(|&c| c == b)
+                    .map_or(data.len(), |len| pos + 1 + len);
+                if value.is_empty() {
+                    value = &data[pos + 1..end];
+                }
+                pos = end + 1;
+            }
+            b'>' => return (pos + 1, value),
+            _ => pos += 1,
+        }
+    }
+    (data.len(), value)
+}
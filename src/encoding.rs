@@ -0,0 +1,69 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Transcoding of the data in the legacy encodings (e.g., ISO-8859-1).
+//!
+//! The encoding is sniffed from the XML declaration. The byte order mark takes
+//! precedence over the declaration, so this is not used for the data which
+//! starts with it.
+
+use std::borrow::Cow;
+
+use encoding_rs::{Encoding, UTF_16BE, UTF_16LE, UTF_8};
+
+use crate::{heuristic, Error};
+
+/// The maximum length of the XML declaration which is examined in bytes.
+const MAX_DECLARATION_LEN: usize = 1024;
+
+/// Converts `data` to a string if the encoding declared in it is neither UTF-8
+/// nor UTF-16.
+///
+/// Returns [`None`] if `data` should be treated as UTF-8.
+pub fn transcode(data: &[u8]) -> Result<Option<Cow<'_, str>>, Error> {
+    let Some(label) = declared_encoding(data) else {
+        return Ok(None);
+    };
+    // The labels of the replacement encoding are for the encodings which are
+    // unsafe to decode (e.g., ISO-2022-KR), so these are not supported.
+    let encoding = Encoding::for_label_no_replacement(label)
+        .ok_or_else(|| Error::UnsupportedEncoding(String::from_utf8_lossy(label).into_owned()))?;
+    // The data which declares UTF-16 but is not detected as UTF-16 is encoded
+    // in an ASCII-compatible encoding, so it is treated as UTF-8 as before.
+    if [UTF_8, UTF_16LE, UTF_16BE].contains(&encoding) {
+        return Ok(None);
+    }
+    encoding
+        .decode_without_bom_handling_and_without_replacement(data)
+        .map(Some)
+        .ok_or_else(|| Error::InvalidEncoding(encoding.name()))
+}
+
+/// Returns the value of the `encoding` of the XML declaration which `data`
+/// starts with.
+fn declared_encoding(data: &[u8]) -> Option<&[u8]> {
+    let data = &data[..data.len().min(MAX_DECLARATION_LEN)];
+    let mut rest = data.strip_prefix(b"<?xml")?;
+    if heuristic::trim_start(rest).len() == rest.len() {
+        return None;
+    }
+    loop {
+        rest = heuristic::trim_start(rest);
+        let name_len = rest
+            .iter()
+            .position(|b| !b.is_ascii_alphabetic())
+            .filter(|&len| len > 0)?;
+        let (name, value) = rest.split_at(name_len);
+        let value = heuristic::trim_start(heuristic::trim_start(value).strip_prefix(b"=")?);
+        let (&quote, value) = value.split_first()?;
+        if !matches!(quote, b'"' | b'\'') {
+            return None;
+        }
+        let end = memchr::memchr(quote, value)?;
+        if name == b"encoding" {
+            return Some(&value[..end]);
+        }
+        rest = &value[end + 1..];
+    }
+}
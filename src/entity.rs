@@ -0,0 +1,178 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Limits on the expansion of the entities declared in the DTD.
+//!
+//! [`roxmltree`](usvg::roxmltree) expands the entities while parsing, and has
+//! only the fixed checks for the entity reference loop. The expansion is
+//! estimated before parsing, so that a document such as the billion laughs is
+//! rejected without expanding it.
+
+use std::collections::HashMap;
+
+use crate::{
+    depth::{entity_value, skip_past, tag_end},
+    limits::XmlLimits,
+    Error, Limit,
+};
+
+/// The maximum depth of the nested entity references.
+///
+/// This is the same as the depth which `roxmltree` supports, so a larger depth
+/// is never parsed.
+pub const MAX_DEPTH: u32 = 10;
+
+/// The default maximum size of the expanded entities in bytes.
+pub const DEFAULT_MAX_SIZE: u64 = 1024 * 1024;
+
+/// Tests whether the expansion of the entities in `text` is within `limits`.
+///
+/// This does not validate `text`. The references in the comments and the
+/// `CDATA` sections are not counted, since they are not expanded.
+pub fn check(text: &str, limits: XmlLimits<'_>) -> Result<(), Error> {
+    // Only the predefined entities can be referenced without the declarations.
+    if !text.contains("<!ENTITY") {
+        return Ok(());
+    }
+
+    let data = text.as_bytes();
+    let (mut decls, mut refs) = (HashMap::new(), Vec::new());
+    let mut pos = 0;
+    loop {
+        let next = data[pos..]
+            .iter()
+            .position(|&b| b == b'<')
+            .map_or(data.len(), |offset| pos + offset);
+        references(&data[pos..next], &mut refs);
+        let rest = &data[next..];
+        pos = if rest.is_empty() {
+            break;
+        } else if rest.starts_with(b"<!--") {
+            skip_past(data, next + 4, b"-->")
+        } else if rest.starts_with(b"<![CDATA[") {
+            skip_past(data, next + 9, b"]]>")
+        } else if rest.starts_with(b"<?") {
+            skip_past(data, next + 2, b"?>")
+        } else if rest.starts_with(b"<!ENTITY") {
+            let (end, decl) = declaration(data, next + 8);
+            if let Some((name, value)) = decl {
+                // The first declaration is binding.
+                decls.entry(name).or_insert(value);
+            }
+            end
+        } else if rest.starts_with(b"<!") {
+            next + 2
+        } else {
+            let end = tag_end(data, next + 1);
+            references(&data[next..end], &mut refs);
+            end
+        };
+    }
+
+    let mut expander = Expander {
+        decls,
+        expanded: HashMap::new(),
+        limits,
+    };
+    let mut total = 0_u64;
+    for name in refs {
+        if let Some((size, _)) = expander.expand(name, 1)? {
+            total = total.saturating_add(size);
+            if total > limits.entity_expansion {
+                return Err(Error::LimitExceeded(Limit::EntityExpansion(
+                    limits.entity_expansion,
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Computes the size and the depth of the expanded entities.
+struct Expander<'a> {
+    decls: HashMap<&'a [u8], &'a [u8]>,
+    expanded: HashMap<&'a [u8], (u64, u32)>,
+    limits: XmlLimits<'a>,
+}
+
+impl<'a> Expander<'a> {
+    /// Returns the size of the text which is expanded from the entity `name`
+    /// referenced at `depth`, and the depth of the entity references in it
+    /// including itself, or [`None`] if `name` is not declared.
+    fn expand(&mut self, name: &'a [u8], depth: u32) -> Result<Option<(u64, u32)>, Error> {
+        let depth_exceeded = Error::LimitExceeded(Limit::EntityDepth(self.limits.entity_depth));
+        if depth > self.limits.entity_depth {
+            return Err(depth_exceeded);
+        }
+        if let Some(&(size, height)) = self.expanded.get(name) {
+            if depth.saturating_add(height - 1) > self.limits.entity_depth {
+                return Err(depth_exceeded);
+            }
+            return Ok(Some((size, height)));
+        }
+        let Some(&value) = self.decls.get(name) else {
+            return Ok(None);
+        };
+
+        let mut refs = Vec::new();
+        references(value, &mut refs);
+        let (mut size, mut height) = (value.len() as u64, 1);
+        for r in refs {
+            if let Some((ref_size, ref_height)) = self.expand(r, depth + 1)? {
+                // The reference `&name;` is replaced with the expanded text.
+                size = size
+                    .saturating_sub(r.len() as u64 + 2)
+                    .saturating_add(ref_size);
+                height = height.max(ref_height + 1);
+            }
+        }
+        if size > self.limits.entity_expansion {
+            return Err(Error::LimitExceeded(Limit::EntityExpansion(
+                self.limits.entity_expansion,
+            )));
+        }
+        self.expanded.insert(name, (size, height));
+        Ok(Some((size, height)))
+    }
+}
+
+/// The name and the value of the entity.
+type Declaration<'a> = (&'a [u8], &'a [u8]);
+
+/// Returns the position after the entity declaration, and the name and the
+/// value of the general entity whose value is given as a literal.
+fn declaration(data: &[u8], start: usize) -> (usize, Option<Declaration<'_>>) {
+    let (end, value) = entity_value(data, start);
+    let mut parts = data[start..end]
+        .split(u8::is_ascii_whitespace)
+        .filter(|part| !part.is_empty());
+    let decl = match (parts.next(), parts.next()) {
+        // The parameter entities are only referenced in the DTD.
+        (Some(b"%"), _) => None,
+        (Some(name), Some([b'"' | b'\'', ..])) => Some((name, value)),
+        // The external entities are not loaded.
+        _ => None,
+    };
+    (end, decl)
+}
+
+/// Appends the names of the entity references in `data` to `refs`.
+///
+/// The character references and the malformed references are ignored.
+fn references<'a>(data: &'a [u8], refs: &mut Vec<&'a [u8]>) {
+    let mut pos = 0;
+    while let Some(offset) = data[pos..].iter().position(|&b| b == b'&') {
+        let start = pos + offset + 1;
+        let len = data[start..]
+            .iter()
+            .position(|&b| matches!(b, b';' | b'&' | b'<') || b.is_ascii_whitespace());
+        match len {
+            Some(len) if len > 0 && data[start + len] == b';' && data[start] != b'#' => {
+                refs.push(&data[start..start + len]);
+                pos = start + len + 1;
+            }
+            _ => pos = start,
+        }
+    }
+}
@@ -0,0 +1,634 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Error types for this crate.
+
+use std::{error, fmt, io, str::Utf8Error};
+
+use usvg::roxmltree;
+
+use crate::CompressionKind;
+
+/// The error type for this crate.
+///
+/// If the `serde` feature is enabled, this is serialized as a struct with the
+/// following fields:
+///
+/// - `kind`: The name of the variant (e.g., `"NotUtf8"`).
+/// - `message`: The message of this error, which is the same as
+///   [`Display`](fmt::Display).
+/// - `source`: The message of the underlying error, or `null` if there is no
+///   underlying error.
+///
+/// This cannot be deserialized, since the underlying errors cannot be
+/// reconstructed from their messages.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// An I/O error occurred.
+    Io(io::Error),
+
+    /// The data was not a [gzip-compressed] data.
+    ///
+    /// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+    NotGzip,
+
+    /// The data was already [gzip-compressed].
+    ///
+    /// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+    AlreadyCompressed,
+
+    /// The data could not be decompressed as a [gzip-compressed] data.
+    ///
+    /// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+    GzipDecode(io::Error),
+
+    /// The [gzip-compressed] data was decompressed, but did not match the
+    /// CRC-32 or the size in the trailer of the gzip member.
+    ///
+    /// This means that the data was corrupted, even if the decompressed data
+    /// looks valid. This is also returned if the header has the CRC-16 which
+    /// does not match it.
+    ///
+    /// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+    GzipIntegrity,
+
+    /// The data was compressed in the format which is not accepted or not
+    /// supported.
+    UnsupportedCompression(CompressionKind),
+
+    /// The data was not a valid UTF-8.
+    NotUtf8(Utf8Error),
+
+    /// The data started with the byte order mark of UTF-16, or with the XML
+    /// declaration encoded in UTF-16, but was not a valid UTF-16.
+    NotUtf16,
+
+    /// The encoding declared in the XML declaration was not supported.
+    ///
+    /// This holds the name of the encoding as declared (e.g., `EBCDIC-US`).
+    #[cfg(feature = "encoding")]
+    UnsupportedEncoding(String),
+
+    /// The data was not valid in the encoding declared in the XML declaration.
+    ///
+    /// This holds the canonical name of the encoding (e.g., `Shift_JIS`).
+    #[cfg(feature = "encoding")]
+    InvalidEncoding(&'static str),
+
+    /// The data was empty, or had only whitespace and comments.
+    ///
+    /// This is also returned if the gzip-compressed data is decompressed to
+    /// such data.
+    Empty,
+
+    /// The data was not a valid XML document.
+    NotXml {
+        /// The error of the XML parser.
+        source: roxmltree::Error,
+
+        /// The location where the error occurred, or [`None`] if the error
+        /// does not refer to a location (e.g., the document has no root
+        /// element).
+        location: Option<Location>,
+    },
+
+    /// The data was a valid XML document, but was not a valid SVG document.
+    Parse(usvg::Error),
+
+    /// The data exceeded the limit.
+    LimitExceeded(Limit),
+
+    /// The validation did not finish within the timeout.
+    Timeout,
+
+    /// The string was not a valid [`data:` URI].
+    ///
+    /// [`data:` URI]: https://datatracker.ietf.org/doc/html/rfc2397
+    InvalidDataUri,
+
+    /// The media type of the [`data:` URI] was not `image/svg+xml`.
+    ///
+    /// [`data:` URI]: https://datatracker.ietf.org/doc/html/rfc2397
+    NotSvgMediaType,
+
+    /// The root element of the XML document was not the `svg` element in the
+    /// [SVG] namespace.
+    ///
+    /// [SVG]: https://www.w3.org/Graphics/SVG/
+    NotSvgRoot {
+        /// The qualified name of the root element (e.g., `math`).
+        found: String,
+    },
+
+    /// The SVG document had no renderable content, such as the shapes, the
+    /// text or the images.
+    EmptyDocument,
+
+    /// The SVG document had no content which is visible on the canvas.
+    NothingToRender,
+
+    /// The XML document had the element which is not allowed.
+    DisallowedElement {
+        /// The qualified name of the element (e.g., `sodipodi:namedview`).
+        name: String,
+
+        /// The location of the start tag of the element.
+        location: Location,
+    },
+
+    /// The XML document had the reference to the external resource.
+    ExternalReference {
+        /// The URL of the resource (e.g., `../image.png`).
+        href: String,
+
+        /// The location of the start tag of the element which has the
+        /// reference.
+        location: Location,
+    },
+
+    /// The `viewBox` attribute of the root element was not a list of four
+    /// numbers whose width and height are positive.
+    ///
+    /// This is only returned by [`view_box`](crate::view_box()), since `usvg`
+    /// ignores such an attribute.
+    InvalidViewBox {
+        /// The value of the attribute (e.g., `0 0 16`).
+        value: String,
+    },
+
+    /// The size which was resolved from the aspect ratio of the `viewBox`
+    /// attribute was not a finite positive size.
+    ///
+    /// This is only returned by [`intrinsic_size`](crate::intrinsic_size()),
+    /// since `usvg` does not resolve the size in this way.
+    InvalidIntrinsicSize {
+        /// The resolved width in pixels.
+        width: f32,
+
+        /// The resolved height in pixels.
+        height: f32,
+    },
+
+    /// A dependency panicked while processing the data.
+    ///
+    /// This means a bug in the dependencies such as [`usvg`], which was
+    /// triggered by the data. The panic is contained, but the panic hook has
+    /// already been called, so the panic message is still printed by default.
+    /// This is never returned if the panic strategy is `abort`, since the
+    /// process is aborted instead.
+    Panicked,
+
+    /// An error occurred while fetching the resource.
+    #[cfg(feature = "http")]
+    Http(reqwest::Error),
+}
+
+impl Error {
+    /// Returns the kind of this error.
+    ///
+    /// Unlike the variants of this type, the kind groups the related errors
+    /// (e.g., all the errors about the gzip-compressed data are
+    /// [`ErrorKind::Gzip`]), and does not hold the underlying error, so it is
+    /// suitable for matching on programmatically.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::ErrorKind;
+    /// #
+    /// let err = is_svg::check_svg(include_bytes!("../tests/data/w3/svg-logo-v.png")).unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::NotUtf8);
+    ///
+    /// let err = is_svg::check_svg(r#"<svg width="16" height="16"/>"#).unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::NotSvgRoot);
+    /// ```
+    #[must_use]
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Io(_) => ErrorKind::Io,
+            Self::NotGzip | Self::AlreadyCompressed | Self::GzipDecode(_) => ErrorKind::Gzip,
+            Self::GzipIntegrity => ErrorKind::GzipIntegrity,
+            Self::UnsupportedCompression(_) => ErrorKind::UnsupportedCompression,
+            Self::NotUtf8(_) => ErrorKind::NotUtf8,
+            Self::NotUtf16 => ErrorKind::NotUtf16,
+            #[cfg(feature = "encoding")]
+            Self::UnsupportedEncoding(_) => ErrorKind::UnsupportedEncoding,
+            #[cfg(feature = "encoding")]
+            Self::InvalidEncoding(_) => ErrorKind::InvalidEncoding,
+            Self::Empty => ErrorKind::Empty,
+            // `roxmltree` returns this for the entity references which are
+            // nested too deeply, or referenced too many times.
+            Self::NotXml {
+                source: roxmltree::Error::EntityReferenceLoop(_),
+                ..
+            }
+            | Self::LimitExceeded(Limit::EntityDepth(_) | Limit::EntityExpansion(_)) => {
+                ErrorKind::EntityExpansionLimit
+            }
+            Self::NotXml { .. } => ErrorKind::NotXml,
+            // `usvg` returns this if the root element is not the `svg` element
+            // in the SVG namespace.
+            Self::Parse(usvg::Error::ParsingFailed(roxmltree::Error::NoRootNode)) => {
+                ErrorKind::NotSvgRoot
+            }
+            Self::NotSvgRoot { .. } => ErrorKind::NotSvgRoot,
+            Self::EmptyDocument => ErrorKind::EmptyDocument,
+            Self::NothingToRender => ErrorKind::NothingToRender,
+            Self::DisallowedElement { .. } => ErrorKind::DisallowedElement,
+            Self::ExternalReference { .. } => ErrorKind::ExternalReference,
+            Self::InvalidViewBox { .. } => ErrorKind::InvalidViewBox,
+            Self::InvalidIntrinsicSize { .. } => ErrorKind::InvalidIntrinsicSize,
+            Self::Parse(_) => ErrorKind::Parse,
+            Self::LimitExceeded(_) => ErrorKind::LimitExceeded,
+            Self::Timeout => ErrorKind::Timeout,
+            Self::InvalidDataUri | Self::NotSvgMediaType => ErrorKind::DataUri,
+            Self::Panicked => ErrorKind::Panicked,
+            #[cfg(feature = "http")]
+            Self::Http(_) => ErrorKind::Http,
+        }
+    }
+
+    /// Returns the location in the document where this error occurred.
+    ///
+    /// Returns [`None`] if this error does not refer to a location.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::usvg::roxmltree::TextPos;
+    /// #
+    /// let err =
+    ///     is_svg::check_svg("<svg xmlns=\"http://www.w3.org/2000/svg\">\n  <rect x=1/>\n</svg>")
+    ///         .unwrap_err();
+    /// let location = err.location().unwrap();
+    /// assert_eq!(location.position, TextPos::new(2, 11));
+    /// assert_eq!(location.offset, 51);
+    /// assert!(!location.decompressed);
+    ///
+    /// let err = is_svg::check_svg(include_bytes!("../tests/data/w3/svg-logo-v.png")).unwrap_err();
+    /// assert!(err.location().is_none());
+    /// ```
+    #[must_use]
+    pub const fn location(&self) -> Option<Location> {
+        match self {
+            Self::NotXml { location, .. } => *location,
+            Self::DisallowedElement { location, .. } | Self::ExternalReference { location, .. } => {
+                Some(*location)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the line and the column in the document where this error
+    /// occurred.
+    ///
+    /// This is the same as the [`position`](Location::position) of
+    /// [`Error::location`].
+    #[must_use]
+    pub const fn position(&self) -> Option<roxmltree::TextPos> {
+        match self.location() {
+            Some(location) => Some(location.position),
+            None => None,
+        }
+    }
+
+    /// Returns the byte offset in the document where this error occurred.
+    ///
+    /// This is the same as the [`offset`](Location::offset) of
+    /// [`Error::location`].
+    #[must_use]
+    pub const fn byte_offset(&self) -> Option<usize> {
+        match self.location() {
+            Some(location) => Some(location.offset),
+            None => None,
+        }
+    }
+
+    /// Returns the name of the variant.
+    #[cfg(feature = "serde")]
+    const fn variant_name(&self) -> &'static str {
+        match self {
+            Self::Io(_) => "Io",
+            Self::NotGzip => "NotGzip",
+            Self::AlreadyCompressed => "AlreadyCompressed",
+            Self::GzipDecode(_) => "GzipDecode",
+            Self::GzipIntegrity => "GzipIntegrity",
+            Self::UnsupportedCompression(_) => "UnsupportedCompression",
+            Self::NotUtf8(_) => "NotUtf8",
+            Self::NotUtf16 => "NotUtf16",
+            #[cfg(feature = "encoding")]
+            Self::UnsupportedEncoding(_) => "UnsupportedEncoding",
+            #[cfg(feature = "encoding")]
+            Self::InvalidEncoding(_) => "InvalidEncoding",
+            Self::Empty => "Empty",
+            Self::NotXml { .. } => "NotXml",
+            Self::Parse(_) => "Parse",
+            Self::LimitExceeded(_) => "LimitExceeded",
+            Self::Timeout => "Timeout",
+            Self::InvalidDataUri => "InvalidDataUri",
+            Self::NotSvgMediaType => "NotSvgMediaType",
+            Self::NotSvgRoot { .. } => "NotSvgRoot",
+            Self::EmptyDocument => "EmptyDocument",
+            Self::NothingToRender => "NothingToRender",
+            Self::DisallowedElement { .. } => "DisallowedElement",
+            Self::ExternalReference { .. } => "ExternalReference",
+            Self::InvalidViewBox { .. } => "InvalidViewBox",
+            Self::InvalidIntrinsicSize { .. } => "InvalidIntrinsicSize",
+            Self::Panicked => "Panicked",
+            #[cfg(feature = "http")]
+            Self::Http(_) => "Http",
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(_) => write!(f, "an I/O error occurred"),
+            Self::NotGzip => write!(f, "data was not gzip-compressed"),
+            Self::AlreadyCompressed => write!(f, "data was already gzip-compressed"),
+            Self::GzipDecode(_) => write!(f, "could not decompress the gzip-compressed data"),
+            Self::GzipIntegrity => write!(
+                f,
+                "gzip-compressed data did not match the checksum or the size"
+            ),
+            Self::UnsupportedCompression(kind) => {
+                write!(f, "compression format `{kind}` was not accepted")
+            }
+            Self::NotUtf8(_) => write!(f, "data was not a valid UTF-8"),
+            Self::NotUtf16 => write!(f, "data was not a valid UTF-16"),
+            #[cfg(feature = "encoding")]
+            Self::UnsupportedEncoding(label) => write!(f, "encoding `{label}` was not supported"),
+            #[cfg(feature = "encoding")]
+            Self::InvalidEncoding(name) => write!(f, "data was not a valid {name}"),
+            Self::Empty => write!(f, "data was empty"),
+            Self::NotXml {
+                location: Some(location),
+                ..
+            } => write!(f, "data was not a valid XML document at {location}"),
+            Self::NotXml { location: None, .. } => {
+                write!(f, "data was not a valid XML document")
+            }
+            Self::Parse(_) => write!(f, "data was not a valid SVG document"),
+            Self::LimitExceeded(limit) => write!(f, "data exceeded {limit}"),
+            Self::Timeout => write!(f, "validation did not finish within the timeout"),
+            Self::InvalidDataUri => write!(f, "string was not a valid data URI"),
+            Self::NotSvgMediaType => write!(f, "media type was not image/svg+xml"),
+            Self::NotSvgRoot { found } => {
+                write!(f, "root element `{found}` was not the svg element")
+            }
+            Self::EmptyDocument => write!(f, "SVG document had no renderable content"),
+            Self::NothingToRender => write!(f, "SVG document had no visible content"),
+            Self::DisallowedElement { name, location } => {
+                write!(f, "element `{name}` at {location} was not allowed")
+            }
+            Self::ExternalReference { href, location } => {
+                write!(f, "reference to `{href}` at {location} was not allowed")
+            }
+            Self::InvalidViewBox { value } => write!(f, "viewBox `{value}` was not valid"),
+            Self::InvalidIntrinsicSize { width, height } => {
+                write!(f, "intrinsic size {width}x{height} was not valid")
+            }
+            Self::Panicked => write!(f, "a dependency panicked while processing the data"),
+            #[cfg(feature = "http")]
+            Self::Http(_) => write!(f, "could not fetch the resource"),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io(err) | Self::GzipDecode(err) => Some(err),
+            Self::NotUtf8(err) => Some(err),
+            Self::NotXml { source, .. } => Some(source),
+            Self::Parse(err) => Some(err),
+            #[cfg(feature = "http")]
+            Self::Http(err) => Some(err),
+            #[cfg(feature = "encoding")]
+            Self::UnsupportedEncoding(_) | Self::InvalidEncoding(_) => None,
+            Self::NotGzip
+            | Self::GzipIntegrity
+            | Self::UnsupportedCompression(_)
+            | Self::AlreadyCompressed
+            | Self::NotUtf16
+            | Self::Empty
+            | Self::LimitExceeded(_)
+            | Self::Timeout
+            | Self::InvalidDataUri
+            | Self::NotSvgMediaType
+            | Self::NotSvgRoot { .. }
+            | Self::EmptyDocument
+            | Self::NothingToRender
+            | Self::DisallowedElement { .. }
+            | Self::ExternalReference { .. }
+            | Self::InvalidViewBox { .. }
+            | Self::InvalidIntrinsicSize { .. }
+            | Self::Panicked => None,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Error {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("Error", 3)?;
+        state.serialize_field("kind", self.variant_name())?;
+        state.serialize_field("message", &self.to_string())?;
+        state.serialize_field(
+            "source",
+            &error::Error::source(self).map(ToString::to_string),
+        )?;
+        state.end()
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// A list specifying the general categories of [`Error`].
+///
+/// This is returned by [`Error::kind`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// An I/O error occurred.
+    Io,
+
+    /// The [gzip-compressed] data was expected but not given, was not
+    /// expected but given, or could not be decompressed.
+    ///
+    /// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+    Gzip,
+
+    /// The [gzip-compressed] data did not match the CRC-32 or the size in the
+    /// trailer.
+    ///
+    /// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+    GzipIntegrity,
+
+    /// The data was compressed in the format which is not accepted or not
+    /// supported.
+    UnsupportedCompression,
+
+    /// The data was not a valid UTF-8.
+    NotUtf8,
+
+    /// The data was not a valid UTF-16.
+    NotUtf16,
+
+    /// The encoding declared in the XML declaration was not supported.
+    #[cfg(feature = "encoding")]
+    UnsupportedEncoding,
+
+    /// The data was not valid in the encoding declared in the XML declaration.
+    #[cfg(feature = "encoding")]
+    InvalidEncoding,
+
+    /// The data was empty, or had only whitespace and comments.
+    Empty,
+
+    /// The data was not a valid XML document.
+    NotXml,
+
+    /// The root element of the XML document was not the `svg` element in the
+    /// [SVG] namespace.
+    ///
+    /// [SVG]: https://www.w3.org/Graphics/SVG/
+    NotSvgRoot,
+
+    /// The data was a valid XML document whose root element was the `svg`
+    /// element, but was not a valid SVG document.
+    Parse,
+
+    /// The SVG document had no renderable content.
+    EmptyDocument,
+
+    /// The SVG document had no content which is visible on the canvas.
+    NothingToRender,
+
+    /// The XML document had the element which is not allowed.
+    DisallowedElement,
+
+    /// The XML document had the reference to the external resource.
+    ExternalReference,
+
+    /// The `viewBox` attribute of the root element was not valid.
+    InvalidViewBox,
+
+    /// The resolved intrinsic size was not valid.
+    InvalidIntrinsicSize,
+
+    /// The data exceeded the limit.
+    LimitExceeded,
+
+    /// The expansion of the entities declared in the DTD exceeded the limit.
+    EntityExpansionLimit,
+
+    /// The validation did not finish within the timeout.
+    Timeout,
+
+    /// The string was not a valid [`data:` URI] of a SVG image.
+    ///
+    /// [`data:` URI]: https://datatracker.ietf.org/doc/html/rfc2397
+    DataUri,
+
+    /// A dependency panicked while processing the data.
+    Panicked,
+
+    /// An error occurred while fetching the resource.
+    #[cfg(feature = "http")]
+    Http,
+}
+
+/// The limit which was exceeded.
+///
+/// If the `serde` feature is enabled, this is serialized as a map with the
+/// name of the variant as the key and the limit as the value (e.g.,
+/// `{"InputSize":1024}`).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[non_exhaustive]
+pub enum Limit {
+    /// The size of the input data in bytes.
+    InputSize(u64),
+
+    /// The size of the decompressed data in bytes.
+    DecompressedSize(u64),
+
+    /// The number of the gzip-compressed layers inside the outermost one.
+    GzipNesting(u8),
+
+    /// The nesting depth of the elements.
+    Depth(usize),
+
+    /// The number of the elements.
+    Elements(usize),
+
+    /// The length of an attribute value in bytes.
+    AttributeLength(usize),
+
+    /// The depth of the nested entity references.
+    EntityDepth(u32),
+
+    /// The total size of the text which is expanded from the entity references
+    /// in bytes.
+    EntityExpansion(u64),
+}
+
+/// The location in the document.
+///
+/// If the data is gzip-compressed, or is not encoded in UTF-8, this refers to
+/// the document after it is decompressed and decoded to UTF-8.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Location {
+    /// The line and the column, which are counted in characters from 1.
+    pub position: roxmltree::TextPos,
+
+    /// The byte offset from the start of the document.
+    pub offset: usize,
+
+    /// Whether the document was decompressed (e.g., from the
+    /// [gzip-compressed] data).
+    ///
+    /// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+    pub decompressed: bool,
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.position)?;
+        if self.decompressed {
+            write!(f, " of the decompressed data")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Limit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InputSize(limit) => write!(f, "the input size limit of {limit} bytes"),
+            Self::DecompressedSize(limit) => {
+                write!(f, "the decompressed size limit of {limit} bytes")
+            }
+            Self::GzipNesting(limit) => write!(f, "the gzip nesting limit of {limit}"),
+            Self::Depth(limit) => write!(f, "the depth limit of {limit}"),
+            Self::Elements(limit) => write!(f, "the element count limit of {limit}"),
+            Self::AttributeLength(limit) => {
+                write!(f, "the attribute length limit of {limit} bytes")
+            }
+            Self::EntityDepth(limit) => write!(f, "the entity depth limit of {limit}"),
+            Self::EntityExpansion(limit) => {
+                write!(f, "the entity expansion limit of {limit} bytes")
+            }
+        }
+    }
+}
@@ -0,0 +1,453 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Extension traits.
+
+use std::{io, iter::FusedIterator, path::Path};
+
+use crate::SvgKind;
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for [u8] {}
+    impl Sealed for str {}
+    impl Sealed for Vec<u8> {}
+    impl Sealed for String {}
+    impl Sealed for std::path::Path {}
+}
+
+/// An extension trait for testing whether the data is a [SVG] image.
+///
+/// This trait is sealed and cannot be implemented for types outside of this
+/// crate.
+///
+/// # Examples
+///
+/// ```
+/// use is_svg::IsSvg;
+///
+/// let svg = include_str!("../tests/data/w3/svg-logo-v.svg");
+/// assert!(svg.is_svg());
+/// assert_eq!(svg.is_svg(), is_svg::is_svg(svg));
+///
+/// let png = include_bytes!("../tests/data/w3/svg-logo-v.png");
+/// assert!(!png.is_svg());
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+pub trait IsSvg: private::Sealed {
+    /// Returns [`true`] if `self` is a valid [SVG] data, and [`false`]
+    /// otherwise.
+    ///
+    /// See [`is_svg`](crate::is_svg()) for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use is_svg::IsSvg;
+    ///
+    /// assert!(include_str!("../tests/data/w3/svg-logo-v.svg").is_svg());
+    /// assert!(!include_bytes!("../tests/data/w3/svg-logo-v.png").is_svg());
+    ///
+    /// # #[cfg(feature = "gzip")]
+    /// assert!(include_bytes!("../tests/data/w3/svg-logo-v.svgz").is_svg());
+    /// ```
+    ///
+    /// [SVG]: https://www.w3.org/Graphics/SVG/
+    fn is_svg(&self) -> bool;
+
+    /// Returns [`true`] if `self` is a valid non [gzip-compressed] [SVG] data
+    /// (`.svg`), and [`false`] otherwise.
+    ///
+    /// See [`is_svg_string`](crate::is_svg_string()) for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use is_svg::IsSvg;
+    ///
+    /// assert!(include_str!("../tests/data/w3/svg-logo-v.svg").is_svg_string());
+    /// assert!(!include_bytes!("../tests/data/w3/svg-logo-v.png").is_svg_string());
+    ///
+    /// assert!(!include_bytes!("../tests/data/w3/svg-logo-v.svgz").is_svg_string());
+    /// ```
+    ///
+    /// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+    /// [SVG]: https://www.w3.org/Graphics/SVG/
+    fn is_svg_string(&self) -> bool;
+
+    /// Returns [`true`] if `self` is a valid [gzip-compressed] [SVG] data
+    /// (`.svgz`), and [`false`] otherwise.
+    ///
+    /// See [`is_svgz`](crate::is_svgz()) for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use is_svg::IsSvg;
+    ///
+    /// # #[cfg(feature = "gzip")]
+    /// assert!(include_bytes!("../tests/data/w3/svg-logo-v.svgz").is_svgz());
+    /// assert!(!include_bytes!("../tests/data/w3/svg-logo-v.png").is_svgz());
+    ///
+    /// assert!(!include_str!("../tests/data/w3/svg-logo-v.svg").is_svgz());
+    /// ```
+    ///
+    /// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+    /// [SVG]: https://www.w3.org/Graphics/SVG/
+    fn is_svgz(&self) -> bool;
+
+    /// Detects the kind of the [SVG] image.
+    ///
+    /// See [`detect`](crate::detect()) for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use is_svg::{IsSvg, SvgKind};
+    ///
+    /// assert_eq!(
+    ///     include_str!("../tests/data/w3/svg-logo-v.svg").detect(),
+    ///     Some(SvgKind::Svg)
+    /// );
+    /// # #[cfg(feature = "gzip")]
+    /// assert_eq!(
+    ///     include_bytes!("../tests/data/w3/svg-logo-v.svgz").detect(),
+    ///     Some(SvgKind::Svgz)
+    /// );
+    ///
+    /// assert_eq!(
+    ///     include_bytes!("../tests/data/w3/svg-logo-v.png").detect(),
+    ///     None
+    /// );
+    /// ```
+    ///
+    /// [SVG]: https://www.w3.org/Graphics/SVG/
+    fn detect(&self) -> Option<SvgKind>;
+}
+
+macro_rules! impl_is_svg {
+    ($T:ty) => {
+        impl IsSvg for $T {
+            #[inline]
+            fn is_svg(&self) -> bool {
+                crate::is_svg(self)
+            }
+
+            #[inline]
+            fn is_svg_string(&self) -> bool {
+                crate::is_svg_string(self)
+            }
+
+            #[inline]
+            fn is_svgz(&self) -> bool {
+                crate::is_svgz(self)
+            }
+
+            #[inline]
+            fn detect(&self) -> Option<SvgKind> {
+                crate::detect(self)
+            }
+        }
+    };
+}
+impl_is_svg!([u8]);
+impl_is_svg!(str);
+impl_is_svg!(Vec<u8>);
+impl_is_svg!(String);
+
+/// An extension trait for testing whether the file is a [SVG] image.
+///
+/// This trait is sealed and cannot be implemented for types outside of this
+/// crate.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use is_svg::IsSvgFile;
+///
+/// assert!(Path::new("tests/data/w3/svg-logo-v.svg")
+///     .is_svg_file()
+///     .unwrap());
+/// assert!(!Path::new("tests/data/w3/svg-logo-v.png")
+///     .is_svg_file()
+///     .unwrap());
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+pub trait IsSvgFile: private::Sealed {
+    /// Returns [`Ok(true)`](Ok) if the file at `self` is a valid [SVG] image,
+    /// and [`Ok(false)`](Ok) otherwise.
+    ///
+    /// See [`is_svg_file`](crate::is_svg_file()) for more details.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the file could not be opened or read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use is_svg::IsSvgFile;
+    ///
+    /// assert!(Path::new("tests/data/w3/svg-logo-v.svg")
+    ///     .is_svg_file()
+    ///     .unwrap());
+    /// assert!(!Path::new("tests/data/w3/svg-logo-v.png")
+    ///     .is_svg_file()
+    ///     .unwrap());
+    ///
+    /// # #[cfg(feature = "gzip")]
+    /// assert!(Path::new("tests/data/w3/svg-logo-v.svgz")
+    ///     .is_svg_file()
+    ///     .unwrap());
+    /// ```
+    ///
+    /// [SVG]: https://www.w3.org/Graphics/SVG/
+    fn is_svg_file(&self) -> io::Result<bool>;
+
+    /// Detects the kind of the [SVG] image of the file at `self`.
+    ///
+    /// See [`detect_file`](crate::detect_file()) for more details.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the file could not be opened or read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use is_svg::{IsSvgFile, SvgKind};
+    ///
+    /// assert_eq!(
+    ///     Path::new("tests/data/w3/svg-logo-v.svg")
+    ///         .detect_file()
+    ///         .unwrap(),
+    ///     Some(SvgKind::Svg)
+    /// );
+    /// # #[cfg(feature = "gzip")]
+    /// assert_eq!(
+    ///     Path::new("tests/data/w3/svg-logo-v.svgz")
+    ///         .detect_file()
+    ///         .unwrap(),
+    ///     Some(SvgKind::Svgz)
+    /// );
+    /// ```
+    ///
+    /// [SVG]: https://www.w3.org/Graphics/SVG/
+    fn detect_file(&self) -> io::Result<Option<SvgKind>>;
+}
+
+impl IsSvgFile for Path {
+    #[inline]
+    fn is_svg_file(&self) -> io::Result<bool> {
+        crate::is_svg_file(self)
+    }
+
+    #[inline]
+    fn detect_file(&self) -> io::Result<Option<SvgKind>> {
+        crate::detect_file(self)
+    }
+}
+
+/// An extension trait for iterators over the data which may be [SVG] images.
+///
+/// This trait is implemented for all iterators.
+///
+/// # Examples
+///
+/// ```
+/// use is_svg::SvgIterExt;
+///
+/// let items = vec![
+///     include_bytes!("../tests/data/w3/svg-logo-v.svg").to_vec(),
+///     include_bytes!("../tests/data/w3/svg-logo-v.png").to_vec(),
+///     include_bytes!("../tests/data/w3/svg-logo-v.svgz").to_vec(),
+/// ];
+/// # #[cfg(feature = "gzip")]
+/// assert_eq!(items.into_iter().filter_svg().count(), 2);
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+pub trait SvgIterExt: Iterator {
+    /// Creates an iterator which yields only the items which are valid [SVG]
+    /// data.
+    ///
+    /// The returned iterator is lazy. Each item is tested once when it is
+    /// yielded. See [`is_svg`](crate::is_svg()) for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use is_svg::SvgIterExt;
+    ///
+    /// let items = [
+    ///     &include_bytes!("../tests/data/w3/svg-logo-v.svg")[..],
+    ///     &include_bytes!("../tests/data/w3/svg-logo-v.png")[..],
+    /// ];
+    /// let mut svgs = items.into_iter().filter_svg();
+    /// assert_eq!(
+    ///     svgs.next(),
+    ///     Some(&include_bytes!("../tests/data/w3/svg-logo-v.svg")[..])
+    /// );
+    /// assert_eq!(svgs.next(), None);
+    /// ```
+    ///
+    /// [SVG]: https://www.w3.org/Graphics/SVG/
+    #[inline]
+    fn filter_svg(self) -> FilterSvg<Self>
+    where
+        Self: Sized,
+        Self::Item: AsRef<[u8]>,
+    {
+        FilterSvg { iter: self }
+    }
+
+    /// Creates an iterator which yields only the items which are valid [SVG]
+    /// data, paired with the kind of the SVG image.
+    ///
+    /// The returned iterator is lazy. Each item is tested once when it is
+    /// yielded. See [`detect`](crate::detect()) for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use is_svg::{SvgIterExt, SvgKind};
+    ///
+    /// # #[cfg(feature = "gzip")]
+    /// # {
+    /// let items = [
+    ///     &include_bytes!("../tests/data/w3/svg-logo-v.png")[..],
+    ///     &include_bytes!("../tests/data/w3/svg-logo-v.svgz")[..],
+    /// ];
+    /// let mut svgs = items.into_iter().filter_detect();
+    /// assert_eq!(
+    ///     svgs.next(),
+    ///     Some((
+    ///         &include_bytes!("../tests/data/w3/svg-logo-v.svgz")[..],
+    ///         SvgKind::Svgz
+    ///     ))
+    /// );
+    /// assert_eq!(svgs.next(), None);
+    /// # }
+    /// ```
+    ///
+    /// [SVG]: https://www.w3.org/Graphics/SVG/
+    #[inline]
+    fn filter_detect(self) -> FilterDetect<Self>
+    where
+        Self: Sized,
+        Self::Item: AsRef<[u8]>,
+    {
+        FilterDetect { iter: self }
+    }
+}
+
+impl<I: Iterator> SvgIterExt for I {}
+
+/// An iterator which yields only the items which are valid [SVG] data.
+///
+/// This `struct` is created by [`SvgIterExt::filter_svg`].
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FilterSvg<I> {
+    iter: I,
+}
+
+impl<I> Iterator for FilterSvg<I>
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.find(|item| crate::is_svg(item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I> DoubleEndedIterator for FilterSvg<I>
+where
+    I: DoubleEndedIterator,
+    I::Item: AsRef<[u8]>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.rfind(|item| crate::is_svg(item))
+    }
+}
+
+impl<I> FusedIterator for FilterSvg<I>
+where
+    I: FusedIterator,
+    I::Item: AsRef<[u8]>,
+{
+}
+
+/// An iterator which yields only the items which are valid [SVG] data, paired
+/// with the kind of the SVG image.
+///
+/// This `struct` is created by [`SvgIterExt::filter_detect`].
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FilterDetect<I> {
+    iter: I,
+}
+
+impl<I> Iterator for FilterDetect<I>
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+    type Item = (I::Item, SvgKind);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .find_map(|item| crate::detect(&item).map(|kind| (item, kind)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I> DoubleEndedIterator for FilterDetect<I>
+where
+    I: DoubleEndedIterator,
+    I::Item: AsRef<[u8]>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter
+            .by_ref()
+            .rev()
+            .find_map(|item| crate::detect(&item).map(|kind| (item, kind)))
+    }
+}
+
+impl<I> FusedIterator for FilterDetect<I>
+where
+    I: FusedIterator,
+    I::Item: AsRef<[u8]>,
+{
+}
@@ -0,0 +1,84 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A fast path for testing whether the XML document can be converted to the
+//! tree.
+//!
+//! Once the root element is the `svg` element, `usvg` only rejects the document
+//! whose root element has an invalid size, or which has too many nodes or too
+//! deeply nested elements. The size only depends on the attributes of the root
+//! element, so it is tested by converting the root element without its
+//! content. The document which may have too many nodes, or whose size may
+//! depend on anything other than the root element, is converted as a whole.
+
+use usvg::{
+    roxmltree::{self, Document, Node},
+    Options, Tree,
+};
+
+use crate::{depth, SVG_NAMESPACE};
+
+/// The maximum number of the nodes which `usvg` accepts.
+const MAX_NODES: usize = 1_000_000;
+
+/// The maximum nesting depth of the elements which `usvg` accepts.
+const MAX_DEPTH: usize = 1024;
+
+/// The elements which make `usvg` create the nodes which are not in the
+/// document, or whose style sheets may apply to the root element.
+const EXPANDING_ELEMENTS: [&str; 3] = ["style", "tref", "use"];
+
+/// Tests whether `doc` can be converted to the tree, without converting its
+/// content.
+///
+/// Returns [`None`] if the result may differ from [`Tree::from_xmltree`].
+pub fn check(doc: &Document<'_>, opt: &Options<'_>) -> Option<Result<(), usvg::Error>> {
+    if opt.style_sheet.is_some() || !is_simple(doc) {
+        return None;
+    }
+    let root = doc.root_element();
+    let text = doc.input_text();
+    let start = root.range().start;
+    let tag = &text[start..depth::tag_end(text.as_bytes(), start + 1)];
+    // The entities are not declared in the skeleton.
+    if tag.contains('&') {
+        return None;
+    }
+    let tag = tag.strip_suffix("/>").or_else(|| tag.strip_suffix('>'))?;
+    let skeleton = format!("{tag}/>");
+    let skeleton = roxmltree::Document::parse(&skeleton).ok()?;
+    Some(Tree::from_xmltree(&skeleton, opt).map(|_| ()))
+}
+
+/// Returns [`true`] if `doc` has neither too many nodes nor too deeply nested
+/// elements, and has none of [`EXPANDING_ELEMENTS`].
+fn is_simple(doc: &Document<'_>) -> bool {
+    // The depths of the nodes, which are indexed by their IDs. The parent of a
+    // node always precedes it in the document order.
+    let mut depths = Vec::<usize>::new();
+    for node in doc.descendants() {
+        if depths.len() >= MAX_NODES {
+            return false;
+        }
+        let parent_depth = node
+            .parent()
+            .and_then(|parent| depths.get(parent.id().get_usize()).copied())
+            .unwrap_or_default();
+        let depth = parent_depth + usize::from(node.is_element());
+        if depth > MAX_DEPTH || (node.is_element() && is_expanding(node)) {
+            return false;
+        }
+        let id = node.id().get_usize();
+        if depths.len() <= id {
+            depths.resize(id + 1, 0);
+        }
+        depths[id] = depth;
+    }
+    true
+}
+
+fn is_expanding(node: Node<'_, '_>) -> bool {
+    let name = node.tag_name();
+    name.namespace() == Some(SVG_NAMESPACE) && EXPANDING_ELEMENTS.contains(&name.name())
+}
@@ -0,0 +1,147 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Validation of the file.
+
+use std::{fs::File, io, path::Path};
+
+use crate::{
+    reader::{self, DEFAULT_READ_LIMIT},
+    Error, SvgKind,
+};
+
+/// Returns [`Ok(true)`](Ok) if the file at `path` is a valid [SVG] image, and
+/// [`Ok(false)`](Ok) otherwise.
+///
+/// This function also supports the [gzip-compressed] SVG image (`.svgz`).
+///
+/// The file extension is not taken into account, so the content of the file
+/// is always tested. If the file is larger than 64 MiB, it is not considered to
+/// be a valid SVG image. Use [`is_svg_file_limited`] for changing the limit.
+///
+/// # Errors
+///
+/// Returns [`Err`] if the file could not be opened or read.
+///
+/// # Examples
+///
+/// ```
+/// assert!(is_svg::is_svg_file("tests/data/w3/svg-logo-v.svg").unwrap());
+/// assert!(!is_svg::is_svg_file("tests/data/w3/svg-logo-v.png").unwrap());
+///
+/// # #[cfg(feature = "gzip")]
+/// assert!(is_svg::is_svg_file("tests/data/w3/svg-logo-v.svgz").unwrap());
+///
+/// assert!(is_svg::is_svg_file("tests/data/w3/non-existent.svg").is_err());
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+#[inline]
+pub fn is_svg_file(path: impl AsRef<Path>) -> io::Result<bool> {
+    is_svg_file_limited(path, DEFAULT_READ_LIMIT)
+}
+
+/// Returns [`Ok(true)`](Ok) if the file at `path` is a valid [SVG] image whose
+/// size is at most `limit` bytes, and [`Ok(false)`](Ok) otherwise.
+///
+/// At most `limit` bytes are read from the file, so the large file is rejected
+/// without reading it all. For the [gzip-compressed] SVG image (`.svgz`),
+/// `limit` applies to the compressed size. See [`is_svg_file`] for more
+/// details.
+///
+/// # Errors
+///
+/// Returns [`Err`] if the file could not be opened or read.
+///
+/// # Examples
+///
+/// ```
+/// let path = "tests/data/w3/svg-logo-v.svg";
+/// assert!(is_svg::is_svg_file_limited(path, u64::MAX).unwrap());
+/// assert!(!is_svg::is_svg_file_limited(path, 1024).unwrap());
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+#[inline]
+pub fn is_svg_file_limited(path: impl AsRef<Path>, limit: u64) -> io::Result<bool> {
+    detect_file_limited(path, limit).map(|kind| kind.is_some())
+}
+
+/// Detects the kind of the [SVG] image of the file at `path`.
+///
+/// Returns [`Ok(None)`](Ok) if the file is not a valid SVG image. The file
+/// extension is not taken into account, so the content of the file is always
+/// tested. If the file is larger than 64 MiB, it is not considered to be a
+/// valid SVG image. Use [`detect_file_limited`] for changing the limit.
+///
+/// # Errors
+///
+/// Returns [`Err`] if the file could not be opened or read.
+///
+/// # Examples
+///
+/// ```
+/// # use is_svg::SvgKind;
+/// #
+/// assert_eq!(
+///     is_svg::detect_file("tests/data/w3/svg-logo-v.svg").unwrap(),
+///     Some(SvgKind::Svg)
+/// );
+/// # #[cfg(feature = "gzip")]
+/// assert_eq!(
+///     is_svg::detect_file("tests/data/w3/svg-logo-v.svgz").unwrap(),
+///     Some(SvgKind::Svgz)
+/// );
+///
+/// assert_eq!(
+///     is_svg::detect_file("tests/data/w3/svg-logo-v.png").unwrap(),
+///     None
+/// );
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+#[inline]
+pub fn detect_file(path: impl AsRef<Path>) -> io::Result<Option<SvgKind>> {
+    detect_file_limited(path, DEFAULT_READ_LIMIT)
+}
+
+/// Detects the kind of the [SVG] image of the file at `path`, whose size is at
+/// most `limit` bytes.
+///
+/// Returns [`Ok(None)`](Ok) if the file is not a valid SVG image, or if it is
+/// larger than `limit` bytes. See [`detect_file`] and
+/// [`is_svg_file_limited`] for more details.
+///
+/// # Errors
+///
+/// Returns [`Err`] if the file could not be opened or read.
+///
+/// # Examples
+///
+/// ```
+/// # use is_svg::SvgKind;
+/// #
+/// let path = "tests/data/w3/svg-logo-v.svg";
+/// assert_eq!(
+///     is_svg::detect_file_limited(path, 4273).unwrap(),
+///     Some(SvgKind::Svg)
+/// );
+/// assert_eq!(is_svg::detect_file_limited(path, 4272).unwrap(), None);
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+#[inline]
+pub fn detect_file_limited(path: impl AsRef<Path>, limit: u64) -> io::Result<Option<SvgKind>> {
+    let inner = |path: &Path| -> io::Result<Option<SvgKind>> {
+        let file = File::open(path)?;
+        match reader::read_limited(file, limit) {
+            Ok(data) => Ok(crate::detect(data)),
+            Err(Error::Io(err)) => Err(err),
+            Err(_) => Ok(None),
+        }
+    };
+    inner(path.as_ref())
+}
@@ -0,0 +1,518 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Utilities for the gzip-compressed SVG image.
+
+#[cfg(feature = "gzip")]
+use std::io::{Read, Write};
+use std::{cell::RefCell, io, mem};
+
+#[cfg(feature = "gzip")]
+pub use flate2::Compression;
+#[cfg(feature = "gzip")]
+use flate2::{read::MultiGzDecoder, write::GzEncoder};
+
+#[cfg(feature = "gzip")]
+use crate::{limits::XmlLimits, location, member, unwind};
+use crate::{Decompressor, Error, Limit};
+
+/// Magic number of gzip defined in [RFC 1952].
+///
+/// # Examples
+///
+/// ```
+/// assert!(
+///     include_bytes!("../tests/data/w3/svg-logo-v.svgz").starts_with(&is_svg::GZIP_MAGIC_NUMBER)
+/// );
+/// ```
+///
+/// [RFC 1952]: https://datatracker.ietf.org/doc/html/rfc1952
+pub const GZIP_MAGIC_NUMBER: [u8; 2] = [0x1f, 0x8b];
+
+/// The maximum number of bytes to decompress for the heuristics.
+#[cfg(feature = "gzip")]
+pub const PEEK_LIMIT: u64 = 1024;
+
+/// The maximum capacity of the buffer which is reused by [`with_scratch`].
+const SCRATCH_CAPACITY: usize = 1024 * 1024;
+
+/// The default maximum size of the decompressed data in bytes.
+pub const DEFAULT_DECOMPRESS_LIMIT: u64 = 64 * 1024 * 1024;
+
+/// The default maximum number of the gzip-compressed layers inside the
+/// outermost one which are decompressed.
+///
+/// Some servers and exporters compress the data which is already
+/// gzip-compressed, so one more layer is decompressed by default.
+pub const DEFAULT_MAX_NESTING: u8 = 1;
+
+/// Decompresses the [gzip-compressed] [SVG] data (`.svgz`), and returns the
+/// SVG string.
+///
+/// The decompressed data is also tested whether it is a valid SVG string. At
+/// most 64 MiB are decompressed. If the decompressed data is gzip-compressed
+/// again, it is also decompressed, as well as [`is_svg`](crate::is_svg()).
+///
+/// # Errors
+///
+/// Returns [`Err`] if any of the following are true:
+///
+/// - `data` is not gzip-compressed.
+/// - `data` could not be decompressed.
+/// - `data` does not match the CRC-32 or the size in the trailer.
+/// - `data` is gzip-compressed more than twice.
+/// - The decompressed data exceeds 64 MiB.
+/// - The decompressed data is neither a valid UTF-8 nor a valid UTF-16.
+/// - The decompressed data is not a valid SVG string.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(
+///     is_svg::decompress_svgz(include_bytes!("../tests/data/w3/svg-logo-v.svgz")).unwrap(),
+///     include_str!("../tests/data/w3/svg-logo-v.svg")
+/// );
+///
+/// assert!(matches!(
+///     is_svg::decompress_svgz(include_str!("../tests/data/w3/svg-logo-v.svg")),
+///     Err(is_svg::Error::NotGzip)
+/// ));
+/// ```
+///
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+#[cfg(feature = "gzip")]
+#[inline]
+pub fn decompress_svgz(data: impl AsRef<[u8]>) -> Result<String, Error> {
+    let inner = |data: &[u8]| -> Result<String, Error> {
+        if !data.starts_with(&GZIP_MAGIC_NUMBER) {
+            return Err(Error::NotGzip);
+        }
+        let mut buf = Vec::new();
+        decompress_nested(
+            None,
+            data,
+            DEFAULT_DECOMPRESS_LIMIT,
+            StreamPolicy::default(),
+            &mut buf,
+        )?;
+        let text = if crate::utf16::detect(&buf).is_some() {
+            crate::utf16::to_str(&buf)?.into_owned()
+        } else {
+            String::from_utf8(buf).map_err(|err| Error::NotUtf8(err.utf8_error()))?
+        };
+        crate::parse_str(&text, crate::default_options(), XmlLimits::default())
+            .map_err(location::decompressed)?;
+        Ok(text)
+    };
+    inner(data.as_ref())
+}
+
+/// Returns [`true`] if `data` starts with the [gzip] magic number, and
+/// [`false`] otherwise.
+///
+/// This is a heuristic which only looks at the first two bytes. It does not
+/// test whether `data` can be decompressed, nor whether the decompressed data
+/// is a SVG image.
+///
+/// # Examples
+///
+/// ```
+/// assert!(is_svg::has_gzip_magic(include_bytes!(
+///     "../tests/data/w3/svg-logo-v.svgz"
+/// )));
+/// assert!(is_svg::has_gzip_magic(include_bytes!(
+///     "../tests/data/resources/empty.gz"
+/// )));
+///
+/// assert!(!is_svg::has_gzip_magic(include_str!(
+///     "../tests/data/w3/svg-logo-v.svg"
+/// )));
+/// ```
+///
+/// [gzip]: https://datatracker.ietf.org/doc/html/rfc1952
+#[must_use]
+#[inline]
+pub fn has_gzip_magic(data: impl AsRef<[u8]>) -> bool {
+    data.as_ref().starts_with(&GZIP_MAGIC_NUMBER)
+}
+
+/// Returns [`true`] if `data` looks like a [gzip-compressed] [SVG] data
+/// (`.svgz`), and [`false`] otherwise.
+///
+/// This is a heuristic which tests whether `data` starts with the gzip magic
+/// number, and whether the first 1 KiB of the decompressed data starts with
+/// `<svg` or `<?xml`, ignoring a byte order mark, whitespace and the comments
+/// before `<svg`. It does not fully validate `data`, so this may disagree with
+/// [`is_svgz`](crate::is_svgz()). For example, this returns [`true`] for a
+/// truncated data, and for a XML document which is not a SVG document, and
+/// returns [`false`] for a SVG data whose comments or whitespace before the
+/// first element exceed the first 1 KiB.
+///
+/// # Examples
+///
+/// ```
+/// assert!(is_svg::looks_like_svgz(include_bytes!(
+///     "../tests/data/w3/svg-logo-v.svgz"
+/// )));
+///
+/// assert!(!is_svg::looks_like_svgz(include_bytes!(
+///     "../tests/data/resources/empty.gz"
+/// )));
+/// assert!(!is_svg::looks_like_svgz(include_str!(
+///     "../tests/data/w3/svg-logo-v.svg"
+/// )));
+/// ```
+///
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+#[cfg(feature = "gzip")]
+#[must_use]
+#[inline]
+pub fn looks_like_svgz(data: impl AsRef<[u8]>) -> bool {
+    let inner = |data: &[u8]| -> bool {
+        if !has_gzip_magic(data) {
+            return false;
+        }
+        let buf = peek(data, PEEK_LIMIT);
+        let mut text = buf.strip_prefix(b"\xef\xbb\xbf").unwrap_or(&buf);
+        let mut has_comment = false;
+        loop {
+            let start = text
+                .iter()
+                .position(|b| !b.is_ascii_whitespace())
+                .unwrap_or(text.len());
+            text = &text[start..];
+            let Some(rest) = text.strip_prefix(b"<!--") else {
+                break;
+            };
+            let Some(rest) = crate::heuristic::skip_past(rest, b"-->") else {
+                return false;
+            };
+            text = rest;
+            has_comment = true;
+        }
+        // The XML declaration is only allowed at the beginning of the data.
+        text.starts_with(b"<svg") || (!has_comment && text.starts_with(b"<?xml"))
+    };
+    inner(data.as_ref())
+}
+
+/// Compresses the [SVG] data into the [gzip-compressed] SVG image (`.svgz`).
+///
+/// `data` is tested whether it is a valid non gzip-compressed SVG data before
+/// compressing. The returned data is always accepted by
+/// [`is_svgz`](crate::is_svgz()).
+///
+/// This is the same as [`compress_svg_with_level`] with
+/// [`Compression::default`].
+///
+/// # Errors
+///
+/// Returns [`Err`] if any of the following are true:
+///
+/// - `data` is already gzip-compressed.
+/// - `data` is not a valid SVG data. See [`check_svg`](crate::check_svg()) for
+///   more details.
+///
+/// # Examples
+///
+/// ```
+/// let svgz = is_svg::compress_svg(include_str!("../tests/data/w3/svg-logo-v.svg")).unwrap();
+/// assert!(is_svg::is_svgz(svgz));
+///
+/// assert!(matches!(
+///     is_svg::compress_svg(include_bytes!("../tests/data/w3/svg-logo-v.svgz")),
+///     Err(is_svg::Error::AlreadyCompressed)
+/// ));
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+#[cfg(feature = "gzip")]
+#[inline]
+pub fn compress_svg(data: impl AsRef<[u8]>) -> Result<Vec<u8>, Error> {
+    compress_svg_with_level(data, Compression::default())
+}
+
+/// Compresses the [SVG] data into the [gzip-compressed] SVG image (`.svgz`)
+/// with the given compression level.
+///
+/// See [`compress_svg`] for more details.
+///
+/// # Errors
+///
+/// Returns [`Err`] if any of the following are true:
+///
+/// - `data` is already gzip-compressed.
+/// - `data` is not a valid SVG data. See [`check_svg`](crate::check_svg()) for
+///   more details.
+///
+/// # Examples
+///
+/// ```
+/// # use is_svg::Compression;
+/// #
+/// let svg = include_str!("../tests/data/w3/svg-logo-v.svg");
+///
+/// let fast = is_svg::compress_svg_with_level(svg, Compression::fast()).unwrap();
+/// assert!(is_svg::is_svgz(&fast));
+///
+/// let best = is_svg::compress_svg_with_level(svg, Compression::best()).unwrap();
+/// assert!(is_svg::is_svgz(&best));
+/// assert!(best.len() <= fast.len());
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+#[cfg(feature = "gzip")]
+#[inline]
+pub fn compress_svg_with_level(
+    data: impl AsRef<[u8]>,
+    level: Compression,
+) -> Result<Vec<u8>, Error> {
+    let inner = |data: &[u8], level: Compression| -> Result<Vec<u8>, Error> {
+        if data.starts_with(&GZIP_MAGIC_NUMBER) {
+            return Err(Error::AlreadyCompressed);
+        }
+        crate::check_svg(data)?;
+        let mut encoder = GzEncoder::new(Vec::new(), level);
+        encoder.write_all(data)?;
+        encoder.finish().map_err(Error::from)
+    };
+    inner(data.as_ref(), level)
+}
+
+/// The policy on the gzip members of the [gzip-compressed] data.
+///
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct StreamPolicy {
+    /// Whether the data which has multiple gzip members is rejected.
+    pub single_member: bool,
+
+    /// Whether the data after the last gzip member which is not a gzip member
+    /// is ignored.
+    pub allow_trailing_garbage: bool,
+
+    /// The maximum number of the gzip-compressed layers inside the outermost
+    /// one which are decompressed.
+    pub max_nesting: u8,
+}
+
+impl Default for StreamPolicy {
+    fn default() -> Self {
+        Self {
+            single_member: bool::default(),
+            allow_trailing_garbage: bool::default(),
+            max_nesting: DEFAULT_MAX_NESTING,
+        }
+    }
+}
+
+/// Decompresses the [gzip-compressed] data, and appends the decompressed data
+/// to `buf`.
+///
+/// If `limit` is [`Some`], at most `limit` bytes are decompressed in total.
+/// All the gzip members are decompressed and concatenated unless `policy`
+/// allows only one member. The data after the last member is a gzip member
+/// if it starts with the gzip magic number, and otherwise it is rejected
+/// unless `policy` allows the trailing garbage.
+///
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+#[cfg(feature = "gzip")]
+pub fn decompress(
+    data: &[u8],
+    limit: Option<u64>,
+    policy: StreamPolicy,
+    buf: &mut Vec<u8>,
+) -> Result<(), Error> {
+    unwind::contain(|| {
+        buf.reserve(size_hint(data, limit));
+        let mut decoder = member::Decoder::new(policy);
+        decoder.write(data, buf, limit.unwrap_or(u64::MAX))?;
+        check_decompressed_size(buf, limit)?;
+        // The decoder has read all of the data, since the data did not exceed
+        // the limit.
+        decoder.finish()
+    })
+}
+
+/// Decompresses the [gzip-compressed] data using `decompressor`, or the
+/// built-in decompressor if it is [`None`], and appends the decompressed data
+/// to `buf`.
+///
+/// If the decompressed data is gzip-compressed again, it is also decompressed
+/// by [`decompress_inner`]. Returns the number of the nested layers which were
+/// decompressed.
+///
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+pub fn decompress_nested(
+    decompressor: Option<&dyn Decompressor>,
+    data: &[u8],
+    limit: u64,
+    policy: StreamPolicy,
+    buf: &mut Vec<u8>,
+) -> Result<u8, Error> {
+    decompress_layer(decompressor, data, limit, policy, buf)?;
+    decompress_inner(decompressor, limit, policy, buf)
+}
+
+/// Decompresses `buf`, which is decompressed from the outermost layer, in
+/// place while it is [gzip-compressed], up to the nesting allowed by `policy`.
+///
+/// `limit` applies to each layer. Returns the number of the nested layers
+/// which were decompressed.
+///
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+pub fn decompress_inner(
+    decompressor: Option<&dyn Decompressor>,
+    limit: u64,
+    policy: StreamPolicy,
+    buf: &mut Vec<u8>,
+) -> Result<u8, Error> {
+    let mut nesting = 0;
+    while has_gzip_magic(&buf) {
+        if nesting >= policy.max_nesting {
+            return Err(Error::LimitExceeded(Limit::GzipNesting(policy.max_nesting)));
+        }
+        let outer = mem::take(buf);
+        decompress_layer(decompressor, &outer, limit, policy, buf)?;
+        nesting += 1;
+    }
+    Ok(nesting)
+}
+
+/// Decompresses one layer of the [gzip-compressed] data using `decompressor`,
+/// or the built-in decompressor if it is [`None`].
+///
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+#[cfg_attr(not(feature = "gzip"), allow(unused_variables))]
+fn decompress_layer(
+    decompressor: Option<&dyn Decompressor>,
+    data: &[u8],
+    limit: u64,
+    policy: StreamPolicy,
+    buf: &mut Vec<u8>,
+) -> Result<(), Error> {
+    match decompressor {
+        Some(decompressor) => decompress_with(decompressor, data, Some(limit), buf)?,
+        #[cfg(feature = "gzip")]
+        None => decompress(data, Some(limit), policy, buf)?,
+        #[cfg(not(feature = "gzip"))]
+        None => unreachable!(),
+    }
+    Ok(())
+}
+
+/// Decompresses the [gzip-compressed] data using `decompressor`, and appends
+/// the decompressed data to `buf`.
+///
+/// If `limit` is [`Some`], at most `limit` bytes are decompressed.
+///
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+pub fn decompress_with(
+    decompressor: &dyn Decompressor,
+    data: &[u8],
+    limit: Option<u64>,
+    buf: &mut Vec<u8>,
+) -> Result<(), Error> {
+    buf.reserve(size_hint(data, limit));
+    decompressor
+        .decompress(data, limit, buf)
+        .map_err(decode_error)?;
+    check_decompressed_size(buf, limit)
+}
+
+/// Calls `f` with the empty buffer which is reused by the calls on the current
+/// thread.
+///
+/// If the buffer is already in use (e.g., `f` calls this again), a new buffer
+/// is used instead. The buffer which has grown larger than 1 MiB is not kept,
+/// so that a large data does not hold the memory after the call.
+pub fn with_scratch<T>(f: impl FnOnce(&mut Vec<u8>) -> T) -> T {
+    thread_local! {
+        static SCRATCH: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+    }
+
+    SCRATCH.with(|scratch| {
+        let Ok(mut buf) = scratch.try_borrow_mut() else {
+            return f(&mut Vec::new());
+        };
+        buf.clear();
+        let result = f(&mut buf);
+        if buf.capacity() > SCRATCH_CAPACITY {
+            *buf = Vec::new();
+        }
+        result
+    })
+}
+
+/// Returns the expected size of the decompressed data of `data`.
+///
+/// This is the ISIZE field at the end of the last gzip member, which is the
+/// size of the decompressed data of the member modulo 2^32. Since `data` may
+/// have multiple members or the trailing garbage, this is only a hint, so the
+/// value which exceeds the maximum compression ratio of deflate is ignored,
+/// and then twice the size of `data` is returned. The hint never exceeds
+/// `limit` by more than 1 byte.
+fn size_hint(data: &[u8], limit: Option<u64>) -> usize {
+    /// The size of the gzip header and the trailer.
+    const MIN_MEMBER_LEN: usize = 18;
+
+    /// The maximum compression ratio of deflate.
+    const MAX_RATIO: usize = 1032;
+
+    let isize = data
+        .len()
+        .checked_sub(4)
+        .filter(|_| data.len() >= MIN_MEMBER_LEN)
+        .and_then(|start| <[u8; 4]>::try_from(&data[start..]).ok())
+        .and_then(|trailer| usize::try_from(u32::from_le_bytes(trailer)).ok())
+        .filter(|&size| size <= data.len().saturating_mul(MAX_RATIO));
+    let limit = limit
+        .and_then(|limit| usize::try_from(limit.saturating_add(1)).ok())
+        .unwrap_or(usize::MAX);
+    isize
+        .unwrap_or_else(|| data.len().saturating_mul(2))
+        .min(limit)
+}
+
+/// Returns [`Err`] if `buf` exceeds `limit` bytes.
+pub fn check_decompressed_size(buf: &[u8], limit: Option<u64>) -> Result<(), Error> {
+    match limit {
+        Some(limit) if u64::try_from(buf.len()).map_or(true, |len| len > limit) => {
+            Err(Error::LimitExceeded(Limit::DecompressedSize(limit)))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Converts the error which is returned by [`Decompressor`] to [`Error`].
+///
+/// [`GzipDecompressor`](crate::GzipDecompressor) reports the mismatch of the
+/// checksums as the [`io::Error`] which wraps [`Error::GzipIntegrity`], so it
+/// is unwrapped.
+pub fn decode_error(err: io::Error) -> Error {
+    let inner = err.get_ref().and_then(|err| err.downcast_ref::<Error>());
+    if matches!(inner, Some(Error::GzipIntegrity)) {
+        Error::GzipIntegrity
+    } else {
+        Error::GzipDecode(err)
+    }
+}
+
+/// Decompresses at most the first `limit` bytes of `data`.
+///
+/// The data decompressed before an error is also returned, so that the
+/// truncated data can still be examined.
+#[cfg(feature = "gzip")]
+pub fn peek(data: &[u8], limit: u64) -> Vec<u8> {
+    unwind::contain(|| {
+        let mut buf = Vec::new();
+        let _ = MultiGzDecoder::new(data).take(limit).read_to_end(&mut buf);
+        Ok(buf)
+    })
+    .unwrap_or_default()
+}
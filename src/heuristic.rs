@@ -0,0 +1,347 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Heuristics for testing the data without parsing.
+
+use memchr::memmem;
+
+use crate::{gzip, Decompressor};
+
+/// The number of bytes which are examined by [`looks_like_svg`].
+const DEFAULT_WINDOW: usize = 4 * 1024;
+
+/// The maximum number of bytes of the resource header defined in the
+/// [WHATWG MIME Sniffing Standard].
+///
+/// [WHATWG MIME Sniffing Standard]: https://mimesniff.spec.whatwg.org/#reading-the-resource-header
+const RESOURCE_HEADER_LEN: usize = 1445;
+
+/// Returns [`true`] if `data` looks like a [SVG] data, and [`false`]
+/// otherwise.
+///
+/// This is a heuristic which skips the byte order mark, the XML declaration,
+/// the processing instructions, the comments, the document type declaration
+/// and whitespace, and then tests whether the name of the first element is
+/// `svg`, optionally with a namespace prefix (e.g., `svg:svg`). It does not
+/// construct a tree, so it is much faster than [`is_svg`](crate::is_svg()).
+/// This function also supports the [gzip-compressed] SVG image (`.svgz`).
+///
+/// Only the first 4 KiB of the data, or of the decompressed data for the
+/// gzip-compressed data, are examined, so that a large data does not make this
+/// slow. See [`looks_like_svg_within`] for changing this.
+///
+/// Since the rest of the data is not examined, this may return [`true`] for a
+/// data which [`is_svg`](crate::is_svg()) would reject, e.g., a truncated or
+/// malformed document. This returns [`false`] if the data ends before the
+/// first element.
+///
+/// # Examples
+///
+/// ```
+/// assert!(is_svg::looks_like_svg(include_str!(
+///     "../tests/data/w3/svg-logo-v.svg"
+/// )));
+/// assert!(!is_svg::looks_like_svg(include_bytes!(
+///     "../tests/data/w3/svg-logo-v.png"
+/// )));
+///
+/// # #[cfg(feature = "gzip")]
+/// assert!(is_svg::looks_like_svg(include_bytes!(
+///     "../tests/data/w3/svg-logo-v.svgz"
+/// )));
+///
+/// assert!(is_svg::looks_like_svg(include_str!(
+///     "../tests/data/resources/unclosed.svg"
+/// )));
+/// assert!(!is_svg::is_svg(include_str!(
+///     "../tests/data/resources/unclosed.svg"
+/// )));
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+#[must_use]
+#[inline]
+pub fn looks_like_svg(data: impl AsRef<[u8]>) -> bool {
+    looks_like_svg_within(data, DEFAULT_WINDOW)
+}
+
+/// Returns [`true`] if the first `window` bytes of `data` look like a [SVG]
+/// data, and [`false`] otherwise.
+///
+/// This is the same as [`looks_like_svg`], except that the name of the first
+/// element must end within the first `window` bytes, instead of the first 4
+/// KiB. The byte right after them is also examined for the end of the name.
+/// For the [gzip-compressed] data, this applies to the decompressed data, and
+/// at most `window + 1` bytes are decompressed. The whitespace, the comments
+/// and the other markup before the first element are also counted, so the
+/// data whose first element starts after `window` bytes does not look like a
+/// SVG data.
+///
+/// # Examples
+///
+/// ```
+/// let svg = include_str!("../tests/data/w3/svg-logo-v.svg");
+/// // The comments and the other markup before the first element.
+/// let pos = svg.find("<svg").unwrap();
+///
+/// assert!(is_svg::looks_like_svg_within(svg, pos + 4));
+/// assert!(!is_svg::looks_like_svg_within(svg, pos + 3));
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+#[must_use]
+#[inline]
+pub fn looks_like_svg_within(data: impl AsRef<[u8]>, window: usize) -> bool {
+    let inner = |data: &[u8]| -> bool {
+        // The byte after the window is also examined for the end of the name.
+        let len = window.saturating_add(1);
+        if !gzip::has_gzip_magic(data) {
+            return first_element_is_svg(&data[..data.len().min(len)], data.len() <= len);
+        }
+        // Without the `gzip` feature, the gzip-compressed data never looks like
+        // a SVG image, as well as `is_svg`.
+        #[cfg(feature = "gzip")]
+        {
+            let buf = gzip::peek(data, u64::try_from(len).unwrap_or(u64::MAX));
+            first_element_is_svg(&buf, buf.len() < len)
+        }
+        #[cfg(not(feature = "gzip"))]
+        false
+    };
+    inner(data.as_ref())
+}
+
+/// Returns [`true`] if `data` would be sniffed as a [SVG] image following the
+/// [WHATWG MIME Sniffing Standard] conventions, and [`false`] otherwise.
+///
+/// The standard does not define a signature for SVG, so this applies its
+/// pattern matching conventions to the `<svg` tag: only the first 1445 bytes
+/// (the resource header) are examined, the leading whitespace bytes, comments
+/// (`<!--` to `-->`), markup declarations (`<!` to `>`) and processing
+/// instructions (`<?` to `?>`) are skipped, and the tag is matched
+/// case-insensitively and must be followed by a space or `>`. This is useful
+/// for web-facing tools which need to match the browser behavior rather than
+/// whether the data can be rendered.
+///
+/// The differences with [`is_svg`](crate::is_svg()) are as follows:
+///
+/// | Data                                           | `is_svg`  | `is_svg_whatwg` |
+/// | ---------------------------------------------- | --------- | --------------- |
+/// | Well-formed SVG document                       | [`true`]  | [`true`]        |
+/// | Malformed document starting with `<svg>`       | [`false`] | [`true`]        |
+/// | Document starting with `<SVG>`                 | [`false`] | [`true`]        |
+/// | Root element with a namespace prefix           | [`true`]  | [`false`]       |
+/// | `<svg` followed by a newline                   | [`true`]  | [`false`]       |
+/// | Root element after more than 1445 bytes        | [`true`]  | [`false`]       |
+/// | Document starting with a byte order mark       | [`true`]  | [`false`]       |
+/// | Gzip-compressed SVG image (`.svgz`)            | [`true`]  | [`false`]       |
+/// | XML declaration without the root element       | [`false`] | [`false`]       |
+///
+/// # Examples
+///
+/// ```
+/// assert!(is_svg::is_svg_whatwg(include_str!(
+///     "../tests/data/w3/svg-logo-v.svg"
+/// )));
+/// assert!(!is_svg::is_svg_whatwg(include_bytes!(
+///     "../tests/data/w3/svg-logo-v.png"
+/// )));
+///
+/// assert!(!is_svg::is_svg_whatwg(include_bytes!(
+///     "../tests/data/w3/svg-logo-v.svgz"
+/// )));
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+/// [WHATWG MIME Sniffing Standard]: https://mimesniff.spec.whatwg.org/
+#[must_use]
+#[inline]
+pub fn is_svg_whatwg(data: impl AsRef<[u8]>) -> bool {
+    let inner = |data: &[u8]| -> bool {
+        let mut data = &data[..data.len().min(RESOURCE_HEADER_LEN)];
+        loop {
+            data = trim_start_whatwg(data);
+            let rest = if let Some(rest) = data.strip_prefix(b"<!--") {
+                skip_past(rest, b"-->")
+            } else if let Some(rest) = data.strip_prefix(b"<!") {
+                skip_past(rest, b">")
+            } else if let Some(rest) = data.strip_prefix(b"<?") {
+                skip_past(rest, b"?>")
+            } else {
+                return data.len() > 4
+                    && data[..4].eq_ignore_ascii_case(b"<svg")
+                    && matches!(data[4], b' ' | b'>');
+            };
+            match rest {
+                Some(rest) => data = rest,
+                None => return false,
+            }
+        }
+    };
+    inner(data.as_ref())
+}
+
+/// Returns [`true`] if `data` can never be a valid XML document, without
+/// parsing it.
+///
+/// The XML document always starts with `<` after the optional byte order mark
+/// and whitespace, unless it is UTF-16 encoded. For the [gzip-compressed] data,
+/// only the first 1 KiB of the decompressed data is examined, so the garbage in
+/// the gzip-compressed data is rejected without decompressing all of it. The
+/// whitespace of the non gzip-compressed data is not limited by a window, since
+/// the data which is not rejected by this is parsed anyway, which is much
+/// slower than skipping the whitespace. If `decompressor` is [`Some`] or the
+/// `gzip` feature is disabled, the gzip-compressed data is never rejected.
+///
+/// This is used to reject the binary data (e.g., PNG) and the plain text
+/// without validating the whole data as a UTF-8. Only the boolean results are
+/// short-circuited by this, since the error would be different from the one
+/// returned by the full parse.
+///
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+#[cfg_attr(not(feature = "gzip"), allow(unused_variables))]
+pub fn is_obviously_not_xml(data: &[u8], decompressor: Option<&dyn Decompressor>) -> bool {
+    if !gzip::has_gzip_magic(data) {
+        return !starts_with_markup(data, true);
+    }
+    #[cfg(feature = "gzip")]
+    if decompressor.is_none() {
+        let buf = gzip::peek(data, gzip::PEEK_LIMIT);
+        // The nested gzip-compressed data is left to the validation, which
+        // knows how deeply it may be nested.
+        if gzip::has_gzip_magic(&buf) {
+            return false;
+        }
+        // If the peeked data is only whitespace, the rest of the data is
+        // unknown.
+        let is_complete = u64::try_from(buf.len()).map_or(false, |len| len < gzip::PEEK_LIMIT);
+        return !starts_with_markup(&buf, is_complete);
+    }
+    false
+}
+
+/// Returns [`true`] if the first byte of `data` is `<`, skipping the byte order
+/// mark and whitespace, or if `data` is UTF-16 encoded.
+///
+/// If `data` is only whitespace, this returns [`false`] if `is_complete` is
+/// [`true`], and [`true`] otherwise.
+fn starts_with_markup(data: &[u8], is_complete: bool) -> bool {
+    if crate::utf16::detect(data).is_some() {
+        return true;
+    }
+    let data = data.strip_prefix(b"\xef\xbb\xbf").unwrap_or(data);
+    trim_start(data)
+        .first()
+        .map_or(!is_complete, |&b| b == b'<')
+}
+
+/// Returns [`true`] if the name of the first element of `data` is `svg`.
+///
+/// If `is_complete` is [`false`], `data` is the first part of the data, so the
+/// name which reaches the end of `data` is not `svg`.
+fn first_element_is_svg(data: &[u8], is_complete: bool) -> bool {
+    first_element(data).map_or(false, |rest| is_svg_name(rest, is_complete))
+}
+
+/// Returns the rest of `data` after the `<` of the first element, skipping the
+/// byte order mark, the XML declaration, the processing instructions, the
+/// comments, the document type declaration and whitespace.
+///
+/// Returns [`None`] if `data` ends before the first element, or has the other
+/// content before it.
+pub fn first_element(data: &[u8]) -> Option<&[u8]> {
+    let mut data = data.strip_prefix(b"\xef\xbb\xbf").unwrap_or(data);
+    loop {
+        data = trim_start(data);
+        data = if let Some(rest) = data.strip_prefix(b"<?") {
+            skip_past(rest, b"?>")?
+        } else if let Some(rest) = data.strip_prefix(b"<!--") {
+            skip_past(rest, b"-->")?
+        } else if let Some(rest) = data.strip_prefix(b"<!") {
+            skip_doctype(rest)?
+        } else {
+            return data.strip_prefix(b"<");
+        };
+    }
+}
+
+/// Returns [`true`] if `data` has only the byte order mark, the comments and
+/// whitespace.
+pub fn is_blank(data: &[u8]) -> bool {
+    let mut data = data.strip_prefix(b"\xef\xbb\xbf").unwrap_or(data);
+    loop {
+        data = trim_start(data);
+        if data.is_empty() {
+            return true;
+        }
+        match data
+            .strip_prefix(b"<!--")
+            .and_then(|rest| skip_past(rest, b"-->"))
+        {
+            Some(rest) => data = rest,
+            None => return false,
+        }
+    }
+}
+
+/// Skips the whitespace bytes defined in the XML specification.
+pub fn trim_start(data: &[u8]) -> &[u8] {
+    let start = data
+        .iter()
+        .position(|b| !matches!(b, b' ' | b'\t' | b'\r' | b'\n'))
+        .unwrap_or(data.len());
+    &data[start..]
+}
+
+/// Skips the whitespace bytes defined in the [WHATWG MIME Sniffing Standard].
+///
+/// [WHATWG MIME Sniffing Standard]: https://mimesniff.spec.whatwg.org/#whitespace-byte
+fn trim_start_whatwg(data: &[u8]) -> &[u8] {
+    let start = data
+        .iter()
+        .position(|b| !matches!(b, b'\t' | b'\n' | b'\x0c' | b'\r' | b' '))
+        .unwrap_or(data.len());
+    &data[start..]
+}
+
+/// Returns the rest of `data` after the first occurrence of `pattern`.
+pub fn skip_past<'a>(data: &'a [u8], pattern: &[u8]) -> Option<&'a [u8]> {
+    memmem::find(data, pattern).map(|pos| &data[pos + pattern.len()..])
+}
+
+/// Returns the rest of `data` after the end of the document type declaration,
+/// including the internal subset.
+fn skip_doctype(data: &[u8]) -> Option<&[u8]> {
+    let mut depth = 0_usize;
+    let mut quote = None;
+    for (pos, &b) in data.iter().enumerate() {
+        match (quote, b) {
+            (Some(q), _) if q == b => quote = None,
+            (None, b'"' | b'\'') => quote = Some(b),
+            (None, b'[') => depth += 1,
+            (None, b']') => depth = depth.saturating_sub(1),
+            (None, b'>') if depth == 0 => return Some(&data[pos + 1..]),
+            _ => (),
+        }
+    }
+    None
+}
+
+/// Returns [`true`] if `data` starts with the element name `svg`, optionally
+/// with a namespace prefix.
+fn is_svg_name(data: &[u8], is_complete: bool) -> bool {
+    let Some(end) = data
+        .iter()
+        .position(|b| matches!(b, b' ' | b'\t' | b'\r' | b'\n' | b'>' | b'/'))
+        .or_else(|| is_complete.then_some(data.len()))
+    else {
+        return false;
+    };
+    let name = &data[..end];
+    name.iter()
+        .rposition(|&b| b == b':')
+        .map_or(name == b"svg", |pos| pos > 0 && &name[pos + 1..] == b"svg")
+}
@@ -0,0 +1,64 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Precedence of the `href` attribute over the `xlink:href` attribute.
+//!
+//! SVG 2 deprecates the `xlink:href` attribute in favor of the `href`
+//! attribute, and the `href` attribute takes precedence if both are specified
+//! on the same element. `usvg` prefers the `xlink:href` attribute instead, so
+//! such an element would refer to the other element than the one which the
+//! user agents render (e.g., a `use` element which refers to nothing). The
+//! `xlink:href` attributes which are overridden are removed before converting
+//! the document to the tree.
+
+use std::ops::Range;
+
+use usvg::roxmltree::Document;
+
+use crate::XLINK_NAMESPACE;
+
+/// Returns the text of `doc` without the `xlink:href` attributes which are
+/// overridden by the `href` attributes, or [`None`] if there is no such
+/// attribute.
+///
+/// The `xlink:href` attribute whose value is the same as the `href` attribute
+/// is not overridden, since either refers to the same resource.
+pub fn remove_overridden(doc: &Document<'_>) -> Option<String> {
+    let text = doc.input_text();
+    let mut ranges = doc
+        .descendants()
+        .filter_map(|node| {
+            let href = node.attribute("href")?;
+            let xlink_href = node
+                .attributes()
+                .find(|attr| attr.namespace() == Some(XLINK_NAMESPACE) && attr.name() == "href")?;
+            // The attributes of the elements in the entity values do not have
+            // the ranges in the text.
+            let is_in_text = text
+                .get(xlink_href.range_qname())
+                .map_or(false, |qname| qname.ends_with(":href"));
+            (xlink_href.value() != href && is_in_text).then(|| xlink_href.range())
+        })
+        .collect::<Vec<_>>();
+    if ranges.is_empty() {
+        return None;
+    }
+    ranges.sort_unstable_by_key(|range| range.start);
+    Some(remove_ranges(text, &ranges))
+}
+
+/// Returns `text` without `ranges`, which are sorted.
+fn remove_ranges(text: &str, ranges: &[Range<usize>]) -> String {
+    let mut removed = String::with_capacity(text.len());
+    let mut pos = 0;
+    for range in ranges {
+        if range.start < pos {
+            continue;
+        }
+        removed.push_str(&text[pos..range.start]);
+        pos = range.end;
+    }
+    removed.push_str(&text[pos..]);
+    removed
+}
@@ -0,0 +1,191 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Validation of the resource fetched over HTTP.
+
+use std::time::Duration;
+
+use crate::{reader::DEFAULT_READ_LIMIT, Error, Limit};
+
+/// Options for fetching the resource over HTTP.
+///
+/// # Examples
+///
+/// ```
+/// # use std::time::Duration;
+/// #
+/// # use is_svg::HttpOptions;
+/// #
+/// let opt = HttpOptions::new()
+///     .max_size(1024 * 1024)
+///     .timeout(Duration::from_secs(5));
+/// ```
+#[derive(Clone, Debug)]
+pub struct HttpOptions {
+    max_size: u64,
+    timeout: Duration,
+}
+
+impl HttpOptions {
+    /// Creates a new `HttpOptions` with the default settings.
+    ///
+    /// By default, at most 64 MiB are fetched, and the request times out after
+    /// 30 seconds.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            max_size: DEFAULT_READ_LIMIT,
+            timeout: Duration::from_secs(30),
+        }
+    }
+
+    /// Sets the maximum size of the response body in bytes.
+    ///
+    /// If the response body is compressed with `Content-Encoding`, this limit
+    /// applies to the decoded body.
+    #[must_use]
+    #[inline]
+    pub const fn max_size(mut self, limit: u64) -> Self {
+        self.max_size = limit;
+        self
+    }
+
+    /// Sets the timeout for the whole request, including reading the response
+    /// body.
+    #[must_use]
+    #[inline]
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+impl Default for HttpOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns [`Ok(true)`](Ok) if the resource at `url` is a valid [SVG] image,
+/// and [`Ok(false)`](Ok) otherwise.
+///
+/// The resource is fetched with the default [`HttpOptions`]. The response body
+/// compressed with `Content-Encoding: gzip` is decoded transparently, and the
+/// decoded body may also be a [gzip-compressed] SVG image (`.svgz`).
+///
+/// # Errors
+///
+/// Returns [`Err`] if the resource could not be fetched, or the response has
+/// an error status code.
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+#[inline]
+pub async fn is_svg_url(url: &str) -> Result<bool, Error> {
+    to_bool(check_svg_url(url, &HttpOptions::new()).await)
+}
+
+/// Tests whether the resource at `url` is a valid [SVG] image with the given
+/// options.
+///
+/// See [`is_svg_url`] for more details.
+///
+/// # Errors
+///
+/// Returns [`Err`] if any of the following are true:
+///
+/// - The resource could not be fetched, or the response has an error status
+///   code.
+/// - The response body exceeds the maximum size.
+/// - The response body is not a valid SVG data. See
+///   [`check_svg`](crate::check_svg()) for more details.
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+pub async fn check_svg_url(url: &str, opt: &HttpOptions) -> Result<(), Error> {
+    let client = reqwest::Client::builder()
+        .timeout(opt.timeout)
+        .build()
+        .map_err(Error::Http)?;
+    let mut response = client
+        .get(url)
+        .send()
+        .await
+        .and_then(reqwest::Response::error_for_status)
+        .map_err(Error::Http)?;
+    check_content_length(response.content_length(), opt.max_size)?;
+    let mut buf = Vec::new();
+    while let Some(chunk) = response.chunk().await.map_err(Error::Http)? {
+        buf.extend_from_slice(&chunk);
+        check_content_length(u64::try_from(buf.len()).ok(), opt.max_size)?;
+    }
+    crate::check_svg(buf)
+}
+
+/// Returns [`Ok(true)`](Ok) if the resource at `url` is a valid [SVG] image,
+/// and [`Ok(false)`](Ok) otherwise.
+///
+/// This is the blocking version of [`is_svg_url`]. This function must not be
+/// called from the context of an asynchronous runtime.
+///
+/// # Errors
+///
+/// Returns [`Err`] if the resource could not be fetched, or the response has
+/// an error status code.
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+#[cfg(feature = "http-blocking")]
+#[inline]
+pub fn is_svg_url_blocking(url: &str) -> Result<bool, Error> {
+    to_bool(check_svg_url_blocking(url, &HttpOptions::new()))
+}
+
+/// Tests whether the resource at `url` is a valid [SVG] image with the given
+/// options.
+///
+/// This is the blocking version of [`check_svg_url`]. This function must not
+/// be called from the context of an asynchronous runtime.
+///
+/// # Errors
+///
+/// Returns [`Err`] if any of the following are true:
+///
+/// - The resource could not be fetched, or the response has an error status
+///   code.
+/// - The response body exceeds the maximum size.
+/// - The response body is not a valid SVG data. See
+///   [`check_svg`](crate::check_svg()) for more details.
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+#[cfg(feature = "http-blocking")]
+pub fn check_svg_url_blocking(url: &str, opt: &HttpOptions) -> Result<(), Error> {
+    let client = reqwest::blocking::Client::builder()
+        .timeout(opt.timeout)
+        .build()
+        .map_err(Error::Http)?;
+    let response = client
+        .get(url)
+        .send()
+        .and_then(reqwest::blocking::Response::error_for_status)
+        .map_err(Error::Http)?;
+    check_content_length(response.content_length(), opt.max_size)?;
+    let data = crate::reader::read_limited(response, opt.max_size)?;
+    crate::check_svg(data)
+}
+
+fn check_content_length(len: Option<u64>, limit: u64) -> Result<(), Error> {
+    if len.map_or(false, |len| len > limit) {
+        return Err(Error::LimitExceeded(Limit::InputSize(limit)));
+    }
+    Ok(())
+}
+
+fn to_bool(result: Result<(), Error>) -> Result<bool, Error> {
+    match result {
+        Ok(()) => Ok(true),
+        Err(err @ (Error::Io(_) | Error::Http(_))) => Err(err),
+        Err(_) => Ok(false),
+    }
+}
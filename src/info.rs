@@ -0,0 +1,260 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The information about the SVG image.
+
+use usvg::{roxmltree::Document, Size};
+
+use crate::{Error, SvgKind};
+
+/// The absolute units of the lengths which depend on the DPI, and how many of
+/// them make an inch.
+const UNITS: [(&str, f32); 5] = [
+    ("in", 1.0),
+    ("cm", 2.54),
+    ("mm", 25.4),
+    ("pt", 72.0),
+    ("pc", 6.0),
+];
+
+/// The `viewBox` attribute of the root element, which is returned by
+/// [`view_box`](crate::view_box()).
+///
+/// If the `serde` feature is enabled, this is serialized as a struct with the
+/// fields of the same name.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ViewBox {
+    /// The x coordinate of the top left corner.
+    pub min_x: f32,
+
+    /// The y coordinate of the top left corner.
+    pub min_y: f32,
+
+    /// The width, which is always positive.
+    pub width: f32,
+
+    /// The height, which is always positive.
+    pub height: f32,
+}
+
+/// The information about the [SVG] image, which is returned by
+/// [`info`](crate::info).
+///
+/// If the `serde` feature is enabled, this is serialized as a struct with the
+/// fields of the same name.
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[non_exhaustive]
+pub struct SvgInfo {
+    /// The kind of the SVG image.
+    pub kind: SvgKind,
+
+    /// The intrinsic width in pixels, which is converted at 96 DPI.
+    ///
+    /// This is [`None`] if the `width` attribute of the root element is not
+    /// specified or is not an absolute length (e.g., `100%`).
+    pub width: Option<f32>,
+
+    /// The intrinsic height in pixels, which is converted at 96 DPI.
+    ///
+    /// This is [`None`] if the `height` attribute of the root element is not
+    /// specified or is not an absolute length (e.g., `100%`).
+    pub height: Option<f32>,
+
+    /// The `viewBox` attribute of the root element.
+    ///
+    /// This is [`None`] if it is not specified or is not valid. Use
+    /// [`view_box`](crate::view_box()) for telling them apart.
+    pub view_box: Option<ViewBox>,
+
+    /// The `version` attribute of the root element.
+    pub version: Option<String>,
+
+    /// The `baseProfile` attribute of the root element.
+    pub base_profile: Option<String>,
+
+    /// The size of the data in bytes.
+    pub size: usize,
+
+    /// The size of the decompressed data in bytes, or [`None`] if the data is
+    /// not compressed.
+    pub decompressed_size: Option<usize>,
+}
+
+/// Returns the information about `doc`, which is decoded from the data of
+/// `size` bytes.
+///
+/// The lengths are converted at `dpi`. `decompressed_size` is ignored if
+/// `kind` is [`SvgKind::Svg`].
+pub fn of_doc(
+    doc: &Document<'_>,
+    dpi: f32,
+    kind: SvgKind,
+    size: usize,
+    decompressed_size: usize,
+) -> SvgInfo {
+    let root = doc.root_element();
+    let length = |name| {
+        root.attribute(name)
+            .and_then(|value| parse_length(value, dpi))
+    };
+    SvgInfo {
+        kind,
+        width: length("width"),
+        height: length("height"),
+        view_box: root.attribute("viewBox").and_then(parse_view_box),
+        version: root.attribute("version").map(str::to_owned),
+        base_profile: root.attribute("baseProfile").map(str::to_owned),
+        size,
+        decompressed_size: (kind != SvgKind::Svg).then_some(decompressed_size),
+    }
+}
+
+/// Returns the intrinsic width and height of `doc` in pixels, which are
+/// converted from the absolute units at `dpi`.
+///
+/// Returns [`None`] unless both the `width` and the `height` attributes of the
+/// root element are absolute lengths, in which case `usvg` converts them in
+/// the same way.
+pub fn dimensions(doc: &Document<'_>, dpi: f32) -> Option<(f32, f32)> {
+    let root = doc.root_element();
+    let width = parse_length(root.attribute("width")?, dpi)?;
+    let height = parse_length(root.attribute("height")?, dpi)?;
+    Some((width, height))
+}
+
+/// Returns the size of `doc` in pixels which is resolved by the [default
+/// sizing algorithm] of CSS, in which the default object size is `fallback`.
+///
+/// The absolute lengths of the `width` and the `height` attributes of the root
+/// element are the natural dimensions, which are converted at `dpi`, and the
+/// `viewBox` attribute gives the natural aspect ratio. The other lengths
+/// (e.g., `50%`) are indeterminate.
+///
+/// Returns [`Error::InvalidIntrinsicSize`] if the resolved size is not valid
+/// (e.g., overflows).
+///
+/// [default sizing algorithm]: https://www.w3.org/TR/css-images-3/#default-sizing
+pub fn intrinsic_size(doc: &Document<'_>, dpi: f32, fallback: Size) -> Result<Size, Error> {
+    let root = doc.root_element();
+    let length = |name| {
+        root.attribute(name)
+            .and_then(|value| parse_length(value, dpi))
+    };
+    let ratio = root
+        .attribute("viewBox")
+        .and_then(parse_view_box)
+        .map(|view_box| view_box.width / view_box.height);
+    let (width, height) = match (length("width"), length("height"), ratio) {
+        (Some(width), Some(height), _) => (width, height),
+        (Some(width), None, Some(ratio)) => (width, width / ratio),
+        (None, Some(height), Some(ratio)) => (height * ratio, height),
+        (Some(width), None, None) => (width, fallback.height()),
+        (None, Some(height), None) => (fallback.width(), height),
+        // The largest size which has the ratio and fits in the fallback size,
+        // which is the `contain` constraint.
+        (None, None, Some(ratio)) if fallback.width() / fallback.height() > ratio => {
+            (fallback.height() * ratio, fallback.height())
+        }
+        (None, None, Some(ratio)) => (fallback.width(), fallback.width() / ratio),
+        (None, None, None) => return Ok(fallback),
+    };
+    Size::from_wh(width, height).ok_or(Error::InvalidIntrinsicSize { width, height })
+}
+
+/// Parses `value` as an absolute length, and returns it in pixels which are
+/// converted at `dpi` in the same way as `usvg`.
+///
+/// As in `usvg`, the leading whitespace is allowed but the trailing whitespace
+/// is not. The lengths relative to the font size (e.g., `em`) are not
+/// absolute.
+fn parse_length(value: &str, dpi: f32) -> Option<f32> {
+    let value = value.trim_start_matches(is_wsp);
+    let unit = UNITS
+        .iter()
+        .find_map(|(unit, per_inch)| value.strip_suffix(unit).map(|number| (number, *per_inch)));
+    if let Some((number, per_inch)) = unit {
+        return parse_number(number).map(|number| number * dpi / per_inch);
+    }
+    parse_number(value.strip_suffix("px").unwrap_or(value))
+}
+
+/// Parses `value` as the list of the four numbers of the `viewBox`
+/// attribute, whose width and height must be positive.
+///
+/// Each number is followed by the whitespace and at most one comma, or by
+/// nothing if the next number starts with a sign (e.g., `0-8 16 16`), in the
+/// same way as the number list parser of `usvg`. So the separator after the
+/// last number is allowed (e.g., `0 0 16 16,`), but unlike `usvg`, the other
+/// data after it is not ignored.
+pub fn parse_view_box(value: &str) -> Option<ViewBox> {
+    let mut numbers = [0.0; 4];
+    let mut rest = value;
+    for number in &mut numbers {
+        (*number, rest) = split_number(rest.trim_start_matches(is_wsp))?;
+        rest = rest.trim_start_matches(is_wsp);
+        rest = rest.strip_prefix(',').unwrap_or(rest);
+    }
+    let [min_x, min_y, width, height] = numbers;
+    (rest.trim_start_matches(is_wsp).is_empty() && width > 0.0 && height > 0.0).then_some(ViewBox {
+        min_x,
+        min_y,
+        width,
+        height,
+    })
+}
+
+/// Parses `value` as a finite number.
+fn parse_number(value: &str) -> Option<f32> {
+    split_number(value)
+        .filter(|(_, rest)| rest.is_empty())
+        .map(|(number, _)| number)
+}
+
+/// Splits the finite number at the start of `value` off, and returns it and
+/// the rest of `value`.
+///
+/// The number is in the scientific notation of SVG (e.g., `-1.5e-3`). Unlike
+/// [`str::parse`], the keywords such as `NaN` and `inf` are not numbers. The
+/// exponent is not split off unless it has the digits, so `1em` is split into
+/// `1` and `em`.
+fn split_number(value: &str) -> Option<(f32, &str)> {
+    let bytes = value.as_bytes();
+    let digits = |start: usize| {
+        start
+            + bytes[start..]
+                .iter()
+                .take_while(|b| b.is_ascii_digit())
+                .count()
+    };
+    let mut end = usize::from(matches!(bytes.first(), Some(b'+' | b'-')));
+    let integer_end = digits(end);
+    let mut has_digits = integer_end > end;
+    end = integer_end;
+    if bytes.get(end) == Some(&b'.') {
+        let fraction_end = digits(end + 1);
+        has_digits |= fraction_end > end + 1;
+        end = fraction_end;
+    }
+    if !has_digits {
+        return None;
+    }
+    if matches!(bytes.get(end), Some(b'e' | b'E')) {
+        let sign = usize::from(matches!(bytes.get(end + 1), Some(b'+' | b'-')));
+        let exponent_end = digits(end + 1 + sign);
+        if exponent_end > end + 1 + sign {
+            end = exponent_end;
+        }
+    }
+    let number = value[..end].parse::<f32>().ok()?;
+    number.is_finite().then_some((number, &value[end..]))
+}
+
+/// Returns [`true`] if `c` is the whitespace in XML.
+const fn is_wsp(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\n' | '\r')
+}
@@ -0,0 +1,359 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The kind of the SVG image.
+
+use std::{error, fmt, str::FromStr};
+
+use crate::{CompressionKind, Error};
+
+/// The kind of the [SVG] image.
+///
+/// If the `serde` feature is enabled, this is serialized as the file extension
+/// (e.g., `"svg"`, `"svgz"` or `"svgz.gz"`), which is the same as
+/// [`Display`](fmt::Display).
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum SvgKind {
+    /// A non [gzip-compressed] SVG image (`.svg`).
+    ///
+    /// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+    Svg,
+
+    /// A [gzip-compressed] SVG image (`.svgz`).
+    ///
+    /// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+    Svgz,
+
+    /// A [gzip-compressed] SVG image which is gzip-compressed again
+    /// (`.svgz.gz`).
+    ///
+    /// This is produced by the servers and the exporters which compress the
+    /// data which is already compressed. Unlike [`SvgKind::Svgz`], this cannot
+    /// be rendered by the browsers without decompressing it once.
+    ///
+    /// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+    #[cfg_attr(feature = "serde", serde(rename = "svgz.gz"))]
+    NestedSvgz,
+
+    /// A [zlib-compressed] SVG image (`.svg.zz`).
+    ///
+    /// This is only detected by [`Validator`](crate::Validator) which accepts
+    /// [`CompressionKind::Zlib`].
+    ///
+    /// [zlib-compressed]: https://datatracker.ietf.org/doc/html/rfc1950
+    #[cfg_attr(feature = "serde", serde(rename = "svg.zz"))]
+    ZlibSvg,
+
+    /// A SVG image which is compressed in the raw [deflate] format
+    /// (`.svg.deflate`).
+    ///
+    /// This is only detected by [`Validator`](crate::Validator) which accepts
+    /// [`CompressionKind::Deflate`].
+    ///
+    /// [deflate]: https://datatracker.ietf.org/doc/html/rfc1951
+    #[cfg_attr(feature = "serde", serde(rename = "svg.deflate"))]
+    DeflateSvg,
+}
+
+impl SvgKind {
+    /// Returns the file extension of this kind.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::SvgKind;
+    /// #
+    /// assert_eq!(SvgKind::Svg.extension(), "svg");
+    /// assert_eq!(SvgKind::Svgz.extension(), "svgz");
+    /// assert_eq!(SvgKind::NestedSvgz.extension(), "svgz.gz");
+    /// assert_eq!(SvgKind::ZlibSvg.extension(), "svg.zz");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Svg => "svg",
+            Self::Svgz => "svgz",
+            Self::NestedSvgz => "svgz.gz",
+            Self::ZlibSvg => "svg.zz",
+            Self::DeflateSvg => "svg.deflate",
+        }
+    }
+
+    /// Returns the [IANA media type] of this kind.
+    ///
+    /// This always returns `image/svg+xml`, because the [gzip-compressed] SVG
+    /// image shares the media type with the non gzip-compressed SVG image.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::SvgKind;
+    /// #
+    /// assert_eq!(SvgKind::Svg.mime_type(), "image/svg+xml");
+    /// assert_eq!(SvgKind::Svgz.mime_type(), "image/svg+xml");
+    /// ```
+    ///
+    /// [IANA media type]: https://www.iana.org/assignments/media-types/image/svg+xml
+    /// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+    #[must_use]
+    #[inline]
+    pub const fn mime_type(self) -> &'static str {
+        "image/svg+xml"
+    }
+
+    /// Returns the compression format of this kind.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::{CompressionKind, SvgKind};
+    /// #
+    /// assert_eq!(SvgKind::Svg.compression(), CompressionKind::None);
+    /// assert_eq!(SvgKind::Svgz.compression(), CompressionKind::Gzip);
+    /// assert_eq!(SvgKind::NestedSvgz.compression(), CompressionKind::Gzip);
+    /// assert_eq!(SvgKind::ZlibSvg.compression(), CompressionKind::Zlib);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn compression(self) -> CompressionKind {
+        match self {
+            Self::Svg => CompressionKind::None,
+            Self::Svgz | Self::NestedSvgz => CompressionKind::Gzip,
+            Self::ZlibSvg => CompressionKind::Zlib,
+            Self::DeflateSvg => CompressionKind::Deflate,
+        }
+    }
+}
+
+impl fmt::Display for SvgKind {
+    /// Formats the kind as its file extension.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::SvgKind;
+    /// #
+    /// assert_eq!(SvgKind::Svg.to_string(), "svg");
+    /// assert_eq!(SvgKind::Svgz.to_string(), "svgz");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.extension())
+    }
+}
+
+impl FromStr for SvgKind {
+    type Err = ParseSvgKindError;
+
+    /// Parses a string `s` to return the kind.
+    ///
+    /// `s` is either the file extension (e.g., `svg`, `svgz` or `svgz.gz`) or
+    /// the [IANA media type] (`image/svg+xml`), and is matched
+    /// case-insensitively. The media type is parsed as [`SvgKind::Svg`],
+    /// since it does not distinguish the [gzip-compressed] SVG image.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `s` is not any of the above.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::SvgKind;
+    /// #
+    /// assert_eq!("svg".parse(), Ok(SvgKind::Svg));
+    /// assert_eq!("SVGZ".parse(), Ok(SvgKind::Svgz));
+    /// assert_eq!("svgz.gz".parse(), Ok(SvgKind::NestedSvgz));
+    /// assert_eq!("image/svg+xml".parse(), Ok(SvgKind::Svg));
+    ///
+    /// assert!("png".parse::<SvgKind>().is_err());
+    /// ```
+    ///
+    /// [IANA media type]: https://www.iana.org/assignments/media-types/image/svg+xml
+    /// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case(Self::Svg.extension())
+            || s.eq_ignore_ascii_case(Self::Svg.mime_type())
+        {
+            Ok(Self::Svg)
+        } else if s.eq_ignore_ascii_case(Self::Svgz.extension()) {
+            Ok(Self::Svgz)
+        } else if s.eq_ignore_ascii_case(Self::NestedSvgz.extension()) {
+            Ok(Self::NestedSvgz)
+        } else if s.eq_ignore_ascii_case(Self::ZlibSvg.extension()) {
+            Ok(Self::ZlibSvg)
+        } else if s.eq_ignore_ascii_case(Self::DeflateSvg.extension()) {
+            Ok(Self::DeflateSvg)
+        } else {
+            Err(ParseSvgKindError(()))
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for SvgKind {
+    type Error = Error;
+
+    /// Detects the kind of the SVG image from `data`.
+    ///
+    /// This is the same as [`detect`](crate::detect()), but returns the reason
+    /// why `data` is not a valid SVG data.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `data` is not a valid SVG data. See
+    /// [`check_svg`](crate::check_svg()) for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::SvgKind;
+    /// #
+    /// # #[cfg(feature = "gzip")]
+    /// assert_eq!(
+    ///     SvgKind::try_from(&include_bytes!("../tests/data/w3/svg-logo-v.svgz")[..]).unwrap(),
+    ///     SvgKind::Svgz
+    /// );
+    ///
+    /// assert!(SvgKind::try_from(&include_bytes!("../tests/data/w3/svg-logo-v.png")[..]).is_err());
+    /// ```
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        crate::validate(data, crate::default_options())
+    }
+}
+
+#[cfg(feature = "clap")]
+impl clap::ValueEnum for SvgKind {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            Self::Svg,
+            Self::Svgz,
+            Self::NestedSvgz,
+            Self::ZlibSvg,
+            Self::DeflateSvg,
+        ]
+    }
+
+    fn to_possible_value(&self) -> Option<clap::builder::PossibleValue> {
+        let value = clap::builder::PossibleValue::new(self.extension());
+        Some(match self {
+            Self::Svg => value.help("Non gzip-compressed SVG image (.svg)"),
+            Self::Svgz => value.help("Gzip-compressed SVG image (.svgz)"),
+            Self::NestedSvgz => value.help("Doubly gzip-compressed SVG image (.svgz.gz)"),
+            Self::ZlibSvg => value.help("Zlib-compressed SVG image (.svg.zz)"),
+            Self::DeflateSvg => value.help("Raw deflate-compressed SVG image (.svg.deflate)"),
+        })
+    }
+}
+
+/// An error which can be returned when parsing a [`SvgKind`].
+///
+/// This error is returned from [`SvgKind::from_str`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseSvgKindError(());
+
+impl fmt::Display for ParseSvgKindError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "string was not a valid SVG kind")
+    }
+}
+
+impl error::Error for ParseSvgKindError {}
+
+/// A filter for the kind of the [SVG] image.
+///
+/// This is useful for the command-line options such as `--type any|svg|svgz`.
+/// If the `clap` feature is enabled, this implements [`clap::ValueEnum`], as
+/// well as [`SvgKind`].
+///
+/// # Examples
+///
+/// ```
+/// # use is_svg::{KindFilter, SvgKind};
+/// #
+/// assert!(KindFilter::Any.matches(SvgKind::Svgz));
+/// assert!(KindFilter::Svg.matches(SvgKind::Svg));
+///
+/// assert!(!KindFilter::Svg.matches(SvgKind::Svgz));
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum KindFilter {
+    /// Any kind of the SVG image.
+    #[default]
+    Any,
+
+    /// Only the non [gzip-compressed] SVG image (`.svg`).
+    ///
+    /// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+    Svg,
+
+    /// Only the [gzip-compressed] SVG image (`.svgz`), including the one
+    /// which is gzip-compressed again.
+    ///
+    /// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+    Svgz,
+}
+
+impl KindFilter {
+    /// Returns [`true`] if `kind` passes this filter, and [`false`] otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::{KindFilter, SvgKind};
+    /// #
+    /// assert!(KindFilter::Svgz.matches(SvgKind::Svgz));
+    /// assert!(KindFilter::Svgz.matches(SvgKind::NestedSvgz));
+    ///
+    /// assert!(!KindFilter::Svgz.matches(SvgKind::Svg));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn matches(self, kind: SvgKind) -> bool {
+        matches!(
+            (self, kind),
+            (Self::Any, _)
+                | (Self::Svg, SvgKind::Svg)
+                | (Self::Svgz, SvgKind::Svgz | SvgKind::NestedSvgz)
+        )
+    }
+}
+
+impl From<SvgKind> for KindFilter {
+    /// Returns the narrowest filter which `kind` passes.
+    ///
+    /// [`SvgKind::ZlibSvg`] and [`SvgKind::DeflateSvg`] only pass
+    /// [`KindFilter::Any`].
+    #[inline]
+    fn from(kind: SvgKind) -> Self {
+        match kind {
+            SvgKind::Svg => Self::Svg,
+            SvgKind::Svgz | SvgKind::NestedSvgz => Self::Svgz,
+            SvgKind::ZlibSvg | SvgKind::DeflateSvg => Self::Any,
+        }
+    }
+}
+
+#[cfg(feature = "clap")]
+impl clap::ValueEnum for KindFilter {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[Self::Any, Self::Svg, Self::Svgz]
+    }
+
+    fn to_possible_value(&self) -> Option<clap::builder::PossibleValue> {
+        Some(match self {
+            Self::Any => clap::builder::PossibleValue::new("any").help("Any SVG image"),
+            Self::Svg => SvgKind::Svg.to_possible_value()?,
+            Self::Svgz => SvgKind::Svgz.to_possible_value()?,
+        })
+    }
+}
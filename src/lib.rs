@@ -8,7 +8,7 @@
 //! This crate assumes the given data to be a valid SVG image if
 //! [`usvg::Tree::from_data`] returns [`Ok`], and an invalid SVG image if it
 //! returns [`Err`]. It supports both a SVG string and a [gzip-compressed] SVG
-//! data.
+//! data. The latter requires the `gzip` feature, which is enabled by default.
 //!
 //! # Examples
 //!
@@ -21,28 +21,238 @@
 //! )));
 //!
 //! // `.svgz` is also supported.
+//! # #[cfg(feature = "gzip")]
 //! assert!(is_svg::is_svg(include_bytes!(
 //!     "../tests/data/w3/svg-logo-v.svgz"
 //! )));
 //! ```
 //!
+//! # Hostile input
+//!
+//! This crate is meant to be fed untrusted data. If [`usvg`] or the other
+//! dependencies panic while processing the data, the panic does not unwind out
+//! of this crate, and the data is treated as an invalid SVG image (e.g.,
+//! [`is_svg`] returns [`false`], and [`check_svg`] returns
+//! [`Error::Panicked`]). This requires the panic strategy to be `unwind`.
+//!
+//! Panics from the code provided by the caller, such as a reader passed to
+//! [`is_svg_reader`], are not contained and propagate as is. However, the
+//! resolvers in [`Options`] are called by `usvg` while parsing, so panics from
+//! them are contained as well.
+//!
+//! # Version of `usvg`
+//!
+//! This crate uses [`usvg`] for testing the data, and its types such as
+//! [`Options`] and [`usvg::Tree`] appear in the public API. This crate
+//! re-exports `usvg`, so use [`is_svg::usvg`](usvg) instead of depending on
+//! `usvg` directly to avoid the version mismatch. Updating `usvg` to an
+//! incompatible version is considered a breaking change of this crate.
+//!
+//! # `no_std` support
+//!
+//! This crate requires `std`, because `usvg` requires it. However, the gzip
+//! decompression does not have to use the built-in implementation, and can be
+//! replaced by implementing [`Decompressor`].
+//!
+//! # Feature flags
+//!
+//! - `clap`: Implements [`clap`](https://crates.io/crates/clap)'s `ValueEnum`
+//!   for [`SvgKind`] and [`KindFilter`].
+//! - `encoding`: Enables the support for the SVG documents in the legacy
+//!   encodings (e.g., ISO-8859-1) using
+//!   [`encoding_rs`](https://crates.io/crates/encoding_rs). The encoding is
+//!   sniffed from the XML declaration, and the data is transcoded to UTF-8
+//!   before validating it. If this is disabled, the data which is not a valid
+//!   UTF-8 is rejected, regardless of the declared encoding.
+//! - `ext`: Enables the `IsSvg`, `IsSvgFile` and `SvgIterExt` extension traits.
+//! - `gzip`: Enables the support for the [gzip-compressed] SVG image (`.svgz`)
+//!   using [`flate2`](https://crates.io/crates/flate2). This is enabled by
+//!   default. If this is disabled, the gzip-compressed data is not considered
+//!   to be a valid SVG data (e.g., `is_svg` and `is_svgz` return [`false`]),
+//!   unless a [`Decompressor`] is set to the [`Validator`]. The functions for
+//!   compressing and decompressing the data, such as `compress_svg` and
+//!   `decompress_svgz`, are also disabled. Note that `usvg` always depends on
+//!   `flate2`, so disabling this does not remove `flate2` from the dependency
+//!   tree, and does not make the binary much smaller.
+//! - `http`: Enables `is_svg_url` and `check_svg_url` for fetching the resource
+//!   using [`reqwest`](https://crates.io/crates/reqwest).
+//! - `http-blocking`: Enables the `http` feature, and also enables
+//!   `is_svg_url_blocking` and `check_svg_url_blocking`.
+//! - `light`: Enables `is_svg_light` and `check_svg_light` for testing whether
+//!   the data is a well-formed XML document whose root element is the `svg`
+//!   element, without constructing the tree.
+//! - `mmap`: Enables `is_svg_file_mmap` for testing the file by mapping it into
+//!   memory using [`memmap2`](https://crates.io/crates/memmap2).
+//! - `proptest`: Enables the `gzip` feature, and also enables the `strategy`
+//!   module which provides the [`proptest`](https://crates.io/crates/proptest)
+//!   strategies for generating the SVG and non-SVG data.
+//! - `rayon`: Enables the parallel validation for [`are_svgs`] and
+//!   [`check_all`] using [`rayon`](https://crates.io/crates/rayon).
+//! - `serde`: Enables the serialization of `SvgKind`, `Error`, `Limit`,
+//!   `Capabilities`, `SvgInfo`, `ViewBox`, `SvgEntry` and `ScanReport` using
+//!   [`serde`](https://crates.io/crates/serde). `SvgKind`, `Limit`, `SvgInfo`,
+//!   `ViewBox` and `SvgEntry` can also be deserialized. The format is part of
+//!   the public API, and is described in the documentation of each type.
+//! - `test-util`: Enables the `test_util` module which provides the sample data
+//!   for testing.
+//! - `text`: Enables the `text` feature of [`usvg`], which converts the text
+//!   elements using the fonts in `Options::fontdb`. This pulls in
+//!   [`fontdb`](https://crates.io/crates/fontdb) and the text shaping
+//!   dependencies. Since this crate does not load any fonts, the text is
+//!   dropped unless the fonts are loaded into the options, and whether a data
+//!   is a valid SVG data does not depend on this feature. If this is disabled,
+//!   the text elements are skipped without shaping. This also enables
+//!   `is_svg_with_fontdb` and `ValidatorBuilder::fontdb` for sharing the font
+//!   database, and re-exports `fontdb`.
+//! - `tokio`: Enables `is_svg_reader_async` and `check_svg_reader_async` for
+//!   reading the data from [`tokio`](https://crates.io/crates/tokio)'s
+//!   `AsyncRead`.
+//! - `tokio-rt`: Enables the `tokio` feature, and parses the data read by the
+//!   asynchronous functions on Tokio's blocking thread pool.
+//! - `walk`: Enables `scan_dir` and `DirScanner` for finding SVG files under a
+//!   directory tree.
+//! - `web`: Enables the `gzip` feature, and also enables the `SvgBody`
+//!   extractor for [`axum`](https://crates.io/crates/axum).
+//!
 //! [SVG]: https://www.w3.org/Graphics/SVG/
 //! [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
 
 #![doc(html_root_url = "https://docs.rs/is-svg/0.1.4/")]
+#![cfg_attr(docsrs, feature(doc_cfg))]
 // Lint levels of rustc.
 #![deny(missing_docs)]
+// Only the `mmap` module needs the unsafe code, so it is forbidden without
+// `mmap` feature.
+#![cfg_attr(not(feature = "mmap"), forbid(unsafe_code))]
+
+#[cfg(feature = "tokio")]
+mod async_reader;
+mod backend;
+mod batch;
+mod cache;
+mod checker;
+mod compression;
+mod content;
+mod data_uri;
+mod decompress;
+#[cfg(feature = "gzip")]
+mod deflate;
+mod depth;
+#[cfg(feature = "encoding")]
+mod encoding;
+mod entity;
+mod error;
+#[cfg(feature = "ext")]
+mod ext;
+mod fast;
+mod fs;
+mod gzip;
+mod heuristic;
+mod href;
+#[cfg(feature = "http")]
+mod http;
+mod info;
+mod kind;
+#[cfg(feature = "light")]
+mod light;
+mod limits;
+mod location;
+#[cfg(feature = "gzip")]
+mod member;
+#[cfg(feature = "mmap")]
+mod mmap;
+mod namespace;
+mod number;
+mod policy;
+mod reader;
+mod reference;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+mod svg2;
+#[cfg(feature = "test-util")]
+pub mod test_util;
+mod unwind;
+mod utf16;
+mod validator;
+#[cfg(feature = "walk")]
+mod walk;
+#[cfg(feature = "web")]
+mod web;
+
+#[cfg(feature = "text")]
+use std::sync::Arc;
+use std::{borrow::Cow, str};
 
-use usvg::{Options, Tree};
+use once_cell::sync::Lazy;
+#[cfg(feature = "text")]
+pub use usvg::fontdb;
+pub use usvg::{self, Options};
+use usvg::{roxmltree, Size, Tree};
 
-/// Magic number of gzip defined in [RFC 1952].
+#[cfg(feature = "tokio")]
+pub use crate::async_reader::{check_svg_reader_async, is_svg_reader_async};
+#[cfg(feature = "ext")]
+pub use crate::ext::{FilterDetect, FilterSvg, IsSvg, IsSvgFile, SvgIterExt};
+#[cfg(feature = "http")]
+pub use crate::http::{check_svg_url, is_svg_url, HttpOptions};
+#[cfg(feature = "http-blocking")]
+pub use crate::http::{check_svg_url_blocking, is_svg_url_blocking};
+#[cfg(feature = "light")]
+pub use crate::light::{check_svg_light, is_svg_light};
+use crate::limits::XmlLimits;
+#[cfg(feature = "mmap")]
+pub use crate::mmap::is_svg_file_mmap;
+#[cfg(feature = "walk")]
+pub use crate::walk::{scan_dir, DirScanner, ScanReport, SvgEntry};
+#[cfg(feature = "web")]
+pub use crate::web::{SvgBody, SvgBodyLimit, SvgBodyRejection};
+pub use crate::{
+    backend::{backend_version, capabilities, Capabilities},
+    batch::{are_svgs, check_all},
+    cache::CacheStats,
+    checker::SvgChecker,
+    compression::CompressionKind,
+    data_uri::{check_svg_data_uri, is_svg_data_uri},
+    decompress::Decompressor,
+    error::{Error, ErrorKind, Limit, Location},
+    fs::{detect_file, detect_file_limited, is_svg_file, is_svg_file_limited},
+    gzip::{has_gzip_magic, GZIP_MAGIC_NUMBER},
+    heuristic::{is_svg_whatwg, looks_like_svg, looks_like_svg_within},
+    info::{SvgInfo, ViewBox},
+    kind::{KindFilter, ParseSvgKindError, SvgKind},
+    reader::{check_svg_reader, is_svg_reader, ValidatingReader},
+    validator::{Validator, ValidatorBuilder},
+};
+#[cfg(feature = "gzip")]
+pub use crate::{
+    decompress::GzipDecompressor,
+    gzip::{compress_svg, compress_svg_with_level, decompress_svgz, looks_like_svgz, Compression},
+};
+
+/// The namespace name of the [SVG] elements.
 ///
-/// [RFC 1952]: https://datatracker.ietf.org/doc/html/rfc1952
-const GZIP_MAGIC_NUMBER: [u8; 2] = [0x1f, 0x8b];
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
+
+/// The namespace name of the `xlink:href` attribute.
+const XLINK_NAMESPACE: &str = "http://www.w3.org/1999/xlink";
+
+/// Returns the default options, which are shared by the free functions so
+/// that they are not rebuilt for each call.
+fn default_options() -> &'static Options<'static> {
+    // `std::sync::OnceLock` requires Rust 1.70.
+    static OPTIONS: Lazy<Options<'static>> = Lazy::new(Options::default);
+    &OPTIONS
+}
 
 /// Returns [`true`] if `data` is a valid [SVG] data, and [`false`] otherwise.
 ///
-/// This function also supports the [gzip-compressed] SVG image (`.svgz`).
+/// This function also supports the [gzip-compressed] SVG image (`.svgz`). If
+/// the `gzip` feature is disabled, the gzip-compressed data is not considered
+/// to be a valid SVG data.
+///
+/// If `data` is already a string, [`is_svg_str`] avoids validating it as a
+/// UTF-8 again.
 ///
 /// # Examples
 ///
@@ -54,6 +264,7 @@ const GZIP_MAGIC_NUMBER: [u8; 2] = [0x1f, 0x8b];
 ///     "../tests/data/w3/svg-logo-v.png"
 /// )));
 ///
+/// # #[cfg(feature = "gzip")]
 /// assert!(is_svg::is_svg(include_bytes!(
 ///     "../tests/data/w3/svg-logo-v.svgz"
 /// )));
@@ -64,17 +275,274 @@ const GZIP_MAGIC_NUMBER: [u8; 2] = [0x1f, 0x8b];
 #[inline]
 pub fn is_svg(data: impl AsRef<[u8]>) -> bool {
     let inner = |data: &[u8]| -> bool {
-        let opt = Options::default();
-        Tree::from_data(data, &opt).is_ok()
+        !heuristic::is_obviously_not_xml(data, None) && check_svg(data).is_ok()
+    };
+    inner(data.as_ref())
+}
+
+/// Tests whether `data` is a valid [SVG] data.
+///
+/// This function also supports the [gzip-compressed] SVG image (`.svgz`).
+///
+/// # Errors
+///
+/// Returns [`Err`] if any of the following are true:
+///
+/// - `data` is gzip-compressed, but could not be decompressed.
+/// - `data` is gzip-compressed, but does not match the CRC-32 or the size in
+///   the trailer.
+/// - `data` is gzip-compressed, but the decompressed data exceeds 64 MiB.
+/// - `data` is not a valid UTF-8, or is not a valid UTF-16 if it starts with
+///   the byte order mark of UTF-16.
+/// - The entities declared in `data` expand to more than 1 MiB in total.
+/// - The elements of `data` are nested deeper than 4096.
+/// - `data` is not a valid XML document.
+/// - `data` is not a valid SVG document.
+///
+/// # Examples
+///
+/// ```
+/// assert!(is_svg::check_svg(include_str!("../tests/data/w3/svg-logo-v.svg")).is_ok());
+/// assert!(matches!(
+///     is_svg::check_svg(include_bytes!("../tests/data/w3/svg-logo-v.png")),
+///     Err(is_svg::Error::NotUtf8(_))
+/// ));
+///
+/// # #[cfg(feature = "gzip")]
+/// assert!(is_svg::check_svg(include_bytes!("../tests/data/w3/svg-logo-v.svgz")).is_ok());
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+#[inline]
+pub fn check_svg(data: impl AsRef<[u8]>) -> Result<(), Error> {
+    check_svg_with_options(data, default_options())
+}
+
+/// Returns [`true`] if `data` is a valid [SVG] string, and [`false`]
+/// otherwise.
+///
+/// Unlike [`is_svg`], this function passes `data` to `usvg` as is, so the byte
+/// slice path and the check for the [gzip-compressed] data are skipped. This
+/// function never accepts the gzip-compressed SVG image (`.svgz`), because the
+/// gzip-compressed data is never a valid UTF-8.
+///
+/// # Examples
+///
+/// ```
+/// assert!(is_svg::is_svg_str(include_str!(
+///     "../tests/data/w3/svg-logo-v.svg"
+/// )));
+/// assert!(!is_svg::is_svg_str(include_str!(
+///     "../tests/data/resources/unclosed.svg"
+/// )));
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+#[must_use]
+#[inline]
+pub fn is_svg_str(data: &str) -> bool {
+    parse_str(data, default_options(), XmlLimits::default()).is_ok()
+}
+
+/// Returns [`true`] if `data` is a valid [SVG] data with the given options,
+/// and [`false`] otherwise.
+///
+/// This function also supports the [gzip-compressed] SVG image (`.svgz`). The
+/// given options are also used for the gzip-compressed SVG image.
+///
+/// Note that the options are passed to `usvg` as is, so custom options can
+/// change what is considered to be a valid SVG data. For example,
+/// [`Options::image_href_resolver`] is called while testing `data`.
+///
+/// # Examples
+///
+/// ```
+/// # use is_svg::Options;
+/// #
+/// let opt = Options {
+///     resources_dir: Some("tests/data/w3".into()),
+///     ..Default::default()
+/// };
+/// assert!(is_svg::is_svg_with_options(
+///     include_str!("../tests/data/w3/svg-logo-v.svg"),
+///     &opt
+/// ));
+/// assert!(!is_svg::is_svg_with_options(
+///     include_bytes!("../tests/data/w3/svg-logo-v.png"),
+///     &opt
+/// ));
+///
+/// # #[cfg(feature = "gzip")]
+/// assert!(is_svg::is_svg_with_options(
+///     include_bytes!("../tests/data/w3/svg-logo-v.svgz"),
+///     &opt
+/// ));
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+#[inline]
+pub fn is_svg_with_options(data: impl AsRef<[u8]>, opt: &Options<'_>) -> bool {
+    let inner = |data: &[u8]| -> bool {
+        !heuristic::is_obviously_not_xml(data, None) && check_svg_with_options(data, opt).is_ok()
+    };
+    inner(data.as_ref())
+}
+
+/// Tests whether `data` is a valid [SVG] data with the given options.
+///
+/// This function also supports the [gzip-compressed] SVG image (`.svgz`). The
+/// given options are also used for the gzip-compressed SVG image.
+///
+/// Note that the options are passed to `usvg` as is, so custom options can
+/// change what is considered to be a valid SVG data.
+///
+/// # Errors
+///
+/// Returns [`Err`] if `data` is not a valid SVG data. See [`check_svg`] for
+/// more details.
+///
+/// # Examples
+///
+/// ```
+/// # use is_svg::Options;
+/// #
+/// let opt = Options {
+///     resources_dir: Some("tests/data/w3".into()),
+///     ..Default::default()
+/// };
+/// assert!(
+///     is_svg::check_svg_with_options(include_str!("../tests/data/w3/svg-logo-v.svg"), &opt)
+///         .is_ok()
+/// );
+/// assert!(matches!(
+///     is_svg::check_svg_with_options(include_bytes!("../tests/data/w3/svg-logo-v.png"), &opt),
+///     Err(is_svg::Error::NotUtf8(_))
+/// ));
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+#[inline]
+pub fn check_svg_with_options(data: impl AsRef<[u8]>, opt: &Options<'_>) -> Result<(), Error> {
+    validate(data.as_ref(), opt).map(|_| ())
+}
+
+/// Returns [`true`] if `data` is a valid [SVG] data whose size is at most
+/// `limit` bytes, and [`false`] otherwise.
+///
+/// The size of `data` is tested before `data` is decompressed or parsed, so
+/// the large data is rejected without parsing it. For the [gzip-compressed]
+/// SVG image (`.svgz`), `limit` applies to the compressed size. To set the
+/// limit with the other options, use [`ValidatorBuilder::max_size`].
+///
+/// # Examples
+///
+/// ```
+/// let data = include_str!("../tests/data/w3/svg-logo-v.svg");
+/// assert!(is_svg::is_svg_limited(data, u64::MAX));
+/// assert!(!is_svg::is_svg_limited(data, 1024));
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+#[inline]
+pub fn is_svg_limited(data: impl AsRef<[u8]>, limit: u64) -> bool {
+    let inner = |data: &[u8]| -> bool {
+        !heuristic::is_obviously_not_xml(data, None) && check_svg_limited(data, limit).is_ok()
     };
     inner(data.as_ref())
 }
 
+/// Tests whether `data` is a valid [SVG] data whose size is at most `limit`
+/// bytes.
+///
+/// See [`is_svg_limited`] for more details.
+///
+/// # Errors
+///
+/// Returns [`Err`] if any of the following are true:
+///
+/// - The size of `data` exceeds `limit`.
+/// - `data` is not a valid SVG data. See [`check_svg`] for more details.
+///
+/// # Examples
+///
+/// ```
+/// # use is_svg::{ErrorKind, Limit};
+/// #
+/// let data = include_str!("../tests/data/w3/svg-logo-v.svg");
+/// assert!(is_svg::check_svg_limited(data, u64::MAX).is_ok());
+///
+/// let err = is_svg::check_svg_limited(data, 1024).unwrap_err();
+/// assert_eq!(err.kind(), ErrorKind::LimitExceeded);
+/// assert!(matches!(
+///     err,
+///     is_svg::Error::LimitExceeded(Limit::InputSize(1024))
+/// ));
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+#[inline]
+pub fn check_svg_limited(data: impl AsRef<[u8]>, limit: u64) -> Result<(), Error> {
+    let data = data.as_ref();
+    check_input_size(data, limit)?;
+    validate(data, default_options()).map(|_| ())
+}
+
+/// Returns [`true`] if `data` is a valid [SVG] data with the given font
+/// database, and [`false`] otherwise.
+///
+/// The text elements are converted using the fonts in `fontdb`, which is
+/// shared rather than copied, so the font database that is already loaded for
+/// rendering can be reused. The other options are the default. See
+/// [`is_svg_with_options`] for more details.
+///
+/// [`fontdb`] is re-exported from [`usvg`], and its version is always the one
+/// which `usvg` depends on. If the [`fontdb`](https://crates.io/crates/fontdb)
+/// crate is also a direct dependency, its version must be the same, otherwise
+/// its `Database` is a different type.
+///
+/// # Examples
+///
+/// ```
+/// # use std::sync::Arc;
+/// #
+/// # use is_svg::fontdb::Database;
+/// #
+/// // The font database which is also used for rendering.
+/// let fontdb = Arc::new(Database::new());
+///
+/// assert!(is_svg::is_svg_with_fontdb(
+///     include_str!("../tests/data/usvg/text-simple-case.svg"),
+///     &fontdb
+/// ));
+/// assert!(!is_svg::is_svg_with_fontdb(
+///     include_bytes!("../tests/data/w3/svg-logo-v.png"),
+///     &fontdb
+/// ));
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+#[cfg(feature = "text")]
+#[inline]
+pub fn is_svg_with_fontdb(data: impl AsRef<[u8]>, fontdb: &Arc<fontdb::Database>) -> bool {
+    let opt = Options {
+        fontdb: Arc::clone(fontdb),
+        ..Default::default()
+    };
+    is_svg_with_options(data, &opt)
+}
+
 /// Returns [`true`] if `data` is a valid non [gzip-compressed] [SVG] data
-/// (`.svg`), and [`false`] otherwise.
+/// (`.svg`) which is a valid UTF-8 as a whole, and [`false`] otherwise.
 ///
 /// This function returns [`false`] if `data` is a valid SVG data, but
-/// gzip-compressed (`.svgz`).
+/// gzip-compressed (`.svgz`), or is encoded in UTF-16 or the other encoding.
+/// So if this returns [`true`], `data` can always be converted to a string
+/// (e.g., by [`str::from_utf8`]).
 ///
 /// # Examples
 ///
@@ -89,13 +557,18 @@ pub fn is_svg(data: impl AsRef<[u8]>) -> bool {
 /// assert!(!is_svg::is_svg_string(include_bytes!(
 ///     "../tests/data/w3/svg-logo-v.svgz"
 /// )));
+/// assert!(!is_svg::is_svg_string(include_bytes!(
+///     "../tests/data/encoding/utf-16le.svg"
+/// )));
 /// ```
 ///
 /// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
 /// [SVG]: https://www.w3.org/Graphics/SVG/
 #[inline]
 pub fn is_svg_string(data: impl AsRef<[u8]>) -> bool {
-    let inner = |data: &[u8]| -> bool { is_svg(data) && !data.starts_with(&GZIP_MAGIC_NUMBER) };
+    // UTF-8 is tested first, so that the data in the other encodings and the
+    // gzip-compressed data are not decoded and parsed only to be rejected.
+    let inner = |data: &[u8]| -> bool { str::from_utf8(data).is_ok() && is_svg(data) };
     inner(data.as_ref())
 }
 
@@ -103,11 +576,13 @@ pub fn is_svg_string(data: impl AsRef<[u8]>) -> bool {
 /// (`.svgz`), and [`false`] otherwise.
 ///
 /// This function returns [`false`] if `data` is a valid SVG data, but non
-/// gzip-compressed (`.svg`).
+/// gzip-compressed (`.svg`). If the `gzip` feature is disabled, this function
+/// always returns [`false`].
 ///
 /// # Examples
 ///
 /// ```
+/// # #[cfg(feature = "gzip")]
 /// assert!(is_svg::is_svgz(include_bytes!(
 ///     "../tests/data/w3/svg-logo-v.svgz"
 /// )));
@@ -124,6 +599,821 @@ pub fn is_svg_string(data: impl AsRef<[u8]>) -> bool {
 /// [SVG]: https://www.w3.org/Graphics/SVG/
 #[inline]
 pub fn is_svgz(data: impl AsRef<[u8]>) -> bool {
-    let inner = |data: &[u8]| -> bool { is_svg(data) && data.starts_with(&GZIP_MAGIC_NUMBER) };
+    // The magic number is tested first, so that the non gzip-compressed data is
+    // not parsed only to be rejected.
+    let inner = |data: &[u8]| -> bool { has_gzip_magic(data) && is_svg(data) };
     inner(data.as_ref())
 }
+
+/// Detects the kind of the [SVG] image.
+///
+/// Returns [`None`] if `data` is not a valid SVG data. Unlike calling both
+/// [`is_svg_string`] and [`is_svgz`], this function parses `data` only once.
+///
+/// # Examples
+///
+/// ```
+/// # use is_svg::SvgKind;
+/// #
+/// assert_eq!(
+///     is_svg::detect(include_str!("../tests/data/w3/svg-logo-v.svg")),
+///     Some(SvgKind::Svg)
+/// );
+/// # #[cfg(feature = "gzip")]
+/// assert_eq!(
+///     is_svg::detect(include_bytes!("../tests/data/w3/svg-logo-v.svgz")),
+///     Some(SvgKind::Svgz)
+/// );
+///
+/// assert_eq!(
+///     is_svg::detect(include_bytes!("../tests/data/w3/svg-logo-v.png")),
+///     None
+/// );
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+#[inline]
+pub fn detect(data: impl AsRef<[u8]>) -> Option<SvgKind> {
+    let data = data.as_ref();
+    if heuristic::is_obviously_not_xml(data, None) {
+        return None;
+    }
+    validate(data, default_options()).ok()
+}
+
+/// Returns the canonical file extension of `data` if `data` is a valid [SVG]
+/// data.
+///
+/// Returns `Some("svg")` for the non [gzip-compressed] SVG image,
+/// `Some("svgz")` for the gzip-compressed SVG image, and [`None`] otherwise.
+/// `data` is parsed only once.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(
+///     is_svg::guess_extension(include_str!("../tests/data/w3/svg-logo-v.svg")),
+///     Some("svg")
+/// );
+/// # #[cfg(feature = "gzip")]
+/// assert_eq!(
+///     is_svg::guess_extension(include_bytes!("../tests/data/w3/svg-logo-v.svgz")),
+///     Some("svgz")
+/// );
+///
+/// assert_eq!(
+///     is_svg::guess_extension(include_bytes!("../tests/data/w3/svg-logo-v.png")),
+///     None
+/// );
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+#[inline]
+pub fn guess_extension(data: impl AsRef<[u8]>) -> Option<&'static str> {
+    detect(data).map(SvgKind::extension)
+}
+
+/// Returns the [IANA media type] of `data` if `data` is a valid [SVG] data.
+///
+/// Returns `Some("image/svg+xml")` for both the non [gzip-compressed] SVG
+/// image (`.svg`) and the gzip-compressed SVG image (`.svgz`), and [`None`]
+/// otherwise. `data` is parsed only once.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(
+///     is_svg::mime_type(include_str!("../tests/data/w3/svg-logo-v.svg")),
+///     Some("image/svg+xml")
+/// );
+/// # #[cfg(feature = "gzip")]
+/// assert_eq!(
+///     is_svg::mime_type(include_bytes!("../tests/data/w3/svg-logo-v.svgz")),
+///     Some("image/svg+xml")
+/// );
+///
+/// assert_eq!(
+///     is_svg::mime_type(include_bytes!("../tests/data/w3/svg-logo-v.png")),
+///     None
+/// );
+/// ```
+///
+/// [IANA media type]: https://www.iana.org/assignments/media-types/image/svg+xml
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+#[inline]
+pub fn mime_type(data: impl AsRef<[u8]>) -> Option<&'static str> {
+    detect(data).map(SvgKind::mime_type)
+}
+
+/// Parses `data` as a [SVG] data, and returns the constructed [`Tree`].
+///
+/// This function does exactly the same validation as [`is_svg`], including the
+/// transparent decompression of the [gzip-compressed] SVG image (`.svgz`), so
+/// the tree can be used without parsing `data` twice.
+///
+/// The returned tree is a type of the [`usvg`] crate which is re-exported by
+/// this crate. Using the re-exported crate avoids the version mismatch with
+/// the `usvg` which is used by this crate.
+///
+/// # Errors
+///
+/// Returns [`Err`] if `data` is not a valid SVG data. See [`check_svg`] for
+/// more details.
+///
+/// # Examples
+///
+/// ```
+/// let tree = is_svg::parse_svg(include_str!("../tests/data/w3/svg-logo-v.svg")).unwrap();
+/// assert_eq!(
+///     tree.size(),
+///     is_svg::usvg::Size::from_wh(300.0, 300.0).unwrap()
+/// );
+///
+/// # #[cfg(feature = "gzip")]
+/// let tree = is_svg::parse_svg(include_bytes!("../tests/data/w3/svg-logo-v.svgz")).unwrap();
+/// # #[cfg(feature = "gzip")]
+/// assert_eq!(
+///     tree.size(),
+///     is_svg::usvg::Size::from_wh(300.0, 300.0).unwrap()
+/// );
+///
+/// assert!(is_svg::parse_svg(include_bytes!("../tests/data/w3/svg-logo-v.png")).is_err());
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+#[inline]
+pub fn parse_svg(data: impl AsRef<[u8]>) -> Result<Tree, Error> {
+    parse(
+        data.as_ref(),
+        default_options(),
+        None,
+        gzip::DEFAULT_DECOMPRESS_LIMIT,
+        gzip::StreamPolicy::default(),
+        XmlLimits::default(),
+    )
+    .map(|(tree, _)| tree)
+}
+
+/// Returns [`true`] if `data` uses the features which are only defined in
+/// [SVG 2] and are not rendered by this crate.
+///
+/// The features are the paint servers such as the mesh gradients
+/// (`meshgradient`) and the hatches (`hatch`), which Inkscape 1.x can emit.
+/// The data which uses them is still a valid SVG data, but it is only
+/// partially rendered, so this can be used for warning about it. The unknown
+/// elements are not the features of SVG 2.
+///
+/// `data` can be a [gzip-compressed] SVG image (`.svgz`). This does not test
+/// whether `data` is a valid SVG data, and returns [`false`] if `data` is not
+/// even a valid XML document.
+///
+/// # Examples
+///
+/// ```
+/// assert!(is_svg::uses_svg2_only_features(include_str!(
+///     "../tests/data/svg2/meshgradient.svg"
+/// )));
+/// assert!(is_svg::is_svg(include_str!(
+///     "../tests/data/svg2/meshgradient.svg"
+/// )));
+///
+/// assert!(!is_svg::uses_svg2_only_features(include_str!(
+///     "../tests/data/w3/svg-logo-v.svg"
+/// )));
+/// ```
+///
+/// [SVG 2]: https://www.w3.org/TR/SVG2/
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+#[inline]
+pub fn uses_svg2_only_features(data: impl AsRef<[u8]>) -> bool {
+    decode(
+        data.as_ref(),
+        None,
+        gzip::DEFAULT_DECOMPRESS_LIMIT,
+        gzip::StreamPolicy::default(),
+        |text| {
+            parse_xml(text, XmlLimits::default(), |doc| {
+                Ok(svg2::has_svg2_only_elements(doc))
+            })
+        },
+    )
+    .map_or(false, |(uses, _)| uses)
+}
+
+/// Tests whether `data` is a valid [SVG] data, and returns the information
+/// about it, such as the kind, the size and the `viewBox` attribute.
+///
+/// `data` is parsed only once, and is validated in the same way as
+/// [`check_svg`].
+///
+/// # Errors
+///
+/// Returns [`Err`] if `data` is not a valid SVG data. See [`check_svg`] for
+/// more details.
+///
+/// # Examples
+///
+/// ```
+/// # use is_svg::{SvgKind, ViewBox};
+/// #
+/// let info = is_svg::info(include_str!("../tests/data/w3/svg-logo-v.svg")).unwrap();
+/// assert_eq!(info.kind, SvgKind::Svg);
+/// assert_eq!(info.width, None);
+/// assert_eq!(
+///     info.view_box,
+///     Some(ViewBox {
+///         min_x: 0.0,
+///         min_y: 0.0,
+///         width: 300.0,
+///         height: 300.0,
+///     })
+/// );
+///
+/// # #[cfg(feature = "gzip")]
+/// let info = is_svg::info(include_bytes!("../tests/data/w3/svg-logo-v.svgz")).unwrap();
+/// # #[cfg(feature = "gzip")]
+/// assert_eq!(info.kind, SvgKind::Svgz);
+/// # #[cfg(feature = "gzip")]
+/// assert_eq!(info.decompressed_size, Some(4273));
+///
+/// assert!(is_svg::info(include_bytes!("../tests/data/w3/svg-logo-v.png")).is_err());
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+#[inline]
+pub fn info(data: impl AsRef<[u8]>) -> Result<SvgInfo, Error> {
+    let data = data.as_ref();
+    let xml_limits = XmlLimits::default();
+    decode_sized(
+        data,
+        None,
+        gzip::DEFAULT_DECOMPRESS_LIMIT,
+        gzip::StreamPolicy::default(),
+        |text, kind, decompressed_size| {
+            let opt = default_options();
+            validate_str(text, opt, xml_limits, |doc| {
+                info::of_doc(doc, opt.dpi, kind, data.len(), decompressed_size)
+            })
+        },
+    )
+    .map(|(info, _)| info)
+}
+
+/// Tests whether `data` is a valid [SVG] data, and returns its size in pixels
+/// which is resolved in the same way as the browsers.
+///
+/// The size is resolved by the [default sizing algorithm] of CSS, in which
+/// the default object size is `fallback` (e.g., 300×150 for the `img`
+/// element):
+///
+/// - If both the `width` and the `height` attributes of the root element are
+///   absolute lengths, they are used as is.
+/// - If only one of them is an absolute length, the other is computed from the
+///   aspect ratio of the `viewBox` attribute, or is taken from `fallback` if
+///   the `viewBox` attribute is not specified.
+/// - If neither of them is an absolute length, the largest size which has the
+///   aspect ratio of the `viewBox` attribute and fits in `fallback` is used, or
+///   `fallback` is used as is if the `viewBox` attribute is not specified.
+///
+/// The absolute units are converted at 96 DPI, as in CSS. The percentages
+/// (e.g., `50%`) and the lengths relative to the font size are indeterminate
+/// without the context, so they are treated as if they were not specified.
+/// The `viewBox` attribute which is not valid is also ignored. See
+/// [`dimensions`] and [`view_box`] for more details.
+///
+/// # Errors
+///
+/// Returns [`Err`] if `data` is not a valid SVG data. See [`check_svg`] for
+/// more details.
+///
+/// Returns [`Error::InvalidIntrinsicSize`] if the size which is computed from
+/// the aspect ratio of the `viewBox` attribute overflows or underflows, which
+/// happens if only one of the `width` and the `height` attributes is specified
+/// and the aspect ratio is too large or too small for it (e.g., `width="1e30"`
+/// with `viewBox="0 0 1e-30 1e30"`), or if neither of them is specified and
+/// the aspect ratio is too large or too small for `fallback`.
+///
+/// # Examples
+///
+/// ```
+/// # use is_svg::usvg::Size;
+/// #
+/// let fallback = Size::from_wh(300.0, 150.0).unwrap();
+///
+/// let svg = r#"<svg xmlns="http://www.w3.org/2000/svg" width="64" viewBox="0 0 32 16"/>"#;
+/// assert_eq!(
+///     is_svg::intrinsic_size(svg, fallback).unwrap(),
+///     Size::from_wh(64.0, 32.0).unwrap()
+/// );
+///
+/// // `width="100%"` and `height="100%"` with `viewBox="0 0 300 300"`.
+/// assert_eq!(
+///     is_svg::intrinsic_size(include_str!("../tests/data/w3/svg-logo-v.svg"), fallback).unwrap(),
+///     Size::from_wh(150.0, 150.0).unwrap()
+/// );
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+/// [default sizing algorithm]: https://www.w3.org/TR/css-images-3/#default-sizing
+#[inline]
+pub fn intrinsic_size(data: impl AsRef<[u8]>, fallback: Size) -> Result<Size, Error> {
+    validate_with(
+        data.as_ref(),
+        default_options(),
+        None,
+        gzip::DEFAULT_DECOMPRESS_LIMIT,
+        gzip::StreamPolicy::default(),
+        XmlLimits::default(),
+        |doc| info::intrinsic_size(doc, 96.0, fallback),
+    )
+    .and_then(|(size, _)| size)
+}
+
+/// Tests whether `data` is a valid [SVG] data, and returns the `viewBox`
+/// attribute of its root element.
+///
+/// The attribute is parsed as a list of four numbers, which are separated by
+/// the whitespace and/or a comma, and can be in the scientific notation (e.g.,
+/// `0,0 1.6e1 16`). As in `usvg`, the last number can also be followed by a
+/// separator. Returns [`None`] if the attribute is not specified.
+///
+/// # Errors
+///
+/// Returns [`Err`] if `data` is not a valid SVG data. See [`check_svg`] for
+/// more details.
+///
+/// Returns [`Error::InvalidViewBox`] if the attribute does not have exactly
+/// four numbers, or if its width or height is not positive. `usvg` ignores
+/// such an attribute, so `data` is still a valid SVG data. Note that `usvg`
+/// uses the first four numbers if they are followed by more data (e.g.,
+/// `0 0 16 16 16`).
+///
+/// # Examples
+///
+/// ```
+/// # use is_svg::{Error, ViewBox};
+/// #
+/// assert_eq!(
+///     is_svg::view_box(include_str!("../tests/data/w3/svg-logo-v.svg")).unwrap(),
+///     Some(ViewBox {
+///         min_x: 0.0,
+///         min_y: 0.0,
+///         width: 300.0,
+///         height: 300.0,
+///     })
+/// );
+///
+/// let svg = r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" viewBox="0 0 16"/>"#;
+/// assert!(is_svg::is_svg(svg));
+/// assert!(matches!(
+///     is_svg::view_box(svg),
+///     Err(Error::InvalidViewBox { value }) if value == "0 0 16"
+/// ));
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+#[inline]
+pub fn view_box(data: impl AsRef<[u8]>) -> Result<Option<ViewBox>, Error> {
+    validate_with(
+        data.as_ref(),
+        default_options(),
+        None,
+        gzip::DEFAULT_DECOMPRESS_LIMIT,
+        gzip::StreamPolicy::default(),
+        XmlLimits::default(),
+        |doc| {
+            doc.root_element()
+                .attribute("viewBox")
+                .map(|value| {
+                    info::parse_view_box(value).ok_or_else(|| Error::InvalidViewBox {
+                        value: value.to_owned(),
+                    })
+                })
+                .transpose()
+        },
+    )
+    .and_then(|(view_box, _)| view_box)
+}
+
+/// Tests whether `data` is a valid [SVG] data, and returns its intrinsic width
+/// and height in pixels.
+///
+/// The `width` and the `height` attributes of the root element are parsed
+/// in the same way as `usvg`: the plain numbers and `px` are in pixels, and
+/// the absolute units (`mm`, `cm`, `in`, `pt` and `pc`) are converted at the
+/// DPI of [`Options`], which is 96 by default. Use [`Validator::dimensions`]
+/// for converting them at another DPI.
+///
+/// Returns [`None`] if either of them is not specified, or is not an absolute
+/// length (e.g., `100%` or `1em`), since the size then depends on the
+/// `viewBox` attribute or the font size. Note that `usvg` resolves the lengths
+/// relative to the font size against the `font-size` attribute, so `2em` is 24
+/// pixels by default.
+///
+/// # Errors
+///
+/// Returns [`Err`] if `data` is not a valid SVG data. See [`check_svg`] for
+/// more details.
+///
+/// # Examples
+///
+/// ```
+/// let svg = r#"<svg xmlns="http://www.w3.org/2000/svg" width="1in" height="48"/>"#;
+/// assert_eq!(is_svg::dimensions(svg).unwrap(), Some((96.0, 48.0)));
+///
+/// // `width="100%"` and `height="100%"` with the `viewBox` attribute.
+/// assert_eq!(
+///     is_svg::dimensions(include_str!("../tests/data/w3/svg-logo-v.svg")).unwrap(),
+///     None
+/// );
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+#[inline]
+pub fn dimensions(data: impl AsRef<[u8]>) -> Result<Option<(f32, f32)>, Error> {
+    let opt = default_options();
+    validate_with(
+        data.as_ref(),
+        opt,
+        None,
+        gzip::DEFAULT_DECOMPRESS_LIMIT,
+        gzip::StreamPolicy::default(),
+        XmlLimits::default(),
+        |doc| info::dimensions(doc, opt.dpi),
+    )
+    .map(|(dimensions, _)| dimensions)
+}
+
+/// Returns [`Err`] if the size of `data` exceeds `limit` bytes.
+fn check_input_size(data: &[u8], limit: u64) -> Result<(), Error> {
+    if u64::try_from(data.len()).map_or(true, |len| len > limit) {
+        return Err(Error::LimitExceeded(Limit::InputSize(limit)));
+    }
+    Ok(())
+}
+
+/// Tests whether `data` is a valid [SVG] data with the given options, and
+/// returns the kind of the SVG image.
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+fn validate(data: &[u8], opt: &Options<'_>) -> Result<SvgKind, Error> {
+    validate_with(
+        data,
+        opt,
+        None,
+        gzip::DEFAULT_DECOMPRESS_LIMIT,
+        gzip::StreamPolicy::default(),
+        XmlLimits::default(),
+        |_| (),
+    )
+    .map(|((), kind)| kind)
+}
+
+/// Tests whether `data` is a valid [SVG] data with the given options and the
+/// given decompressor, and returns the result of `inspect` and the kind of
+/// the SVG image.
+///
+/// If `decompressor` is [`None`], the built-in decompressor is used. See
+/// [`decode`] for `limit` and `policy`. `inspect` is called with the document
+/// only if it is valid.
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+fn validate_with<T: Send>(
+    data: &[u8],
+    opt: &Options<'_>,
+    decompressor: Option<&dyn Decompressor>,
+    limit: u64,
+    policy: gzip::StreamPolicy,
+    xml_limits: XmlLimits<'_>,
+    inspect: impl FnOnce(&roxmltree::Document<'_>) -> T + Send,
+) -> Result<(T, SvgKind), Error> {
+    decode(data, decompressor, limit, policy, |text| {
+        xml_limits.check_deadline()?;
+        validate_str(text, opt, xml_limits, inspect)
+    })
+}
+
+/// Decompresses `data` which is compressed in `format`
+/// ([`CompressionKind::Zlib`] or [`CompressionKind::Deflate`]), and tests
+/// whether the decompressed data is a valid [SVG] string with the given
+/// options.
+///
+/// The decompression stops as soon as the decompressed data exceeds `limit`
+/// bytes, and then the data is rejected. See [`validate_with`] for `inspect`.
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+#[cfg(feature = "gzip")]
+fn validate_deflated<T: Send>(
+    data: &[u8],
+    format: CompressionKind,
+    opt: &Options<'_>,
+    limit: u64,
+    xml_limits: XmlLimits<'_>,
+    inspect: impl FnOnce(&roxmltree::Document<'_>) -> T + Send,
+) -> Result<(T, SvgKind), Error> {
+    let kind = if format == CompressionKind::Zlib {
+        SvgKind::ZlibSvg
+    } else {
+        SvgKind::DeflateSvg
+    };
+    gzip::with_scratch(|buf| {
+        deflate::decompress(data, format, limit, buf)?;
+        let text = utf16::to_str(buf)?;
+        xml_limits.check_deadline()?;
+        let inspected =
+            validate_str(&text, opt, xml_limits, inspect).map_err(location::decompressed)?;
+        Ok((inspected, kind))
+    })
+}
+
+/// Parses `data` as a [SVG] data with the given options, and returns the tree
+/// and the kind of the SVG image.
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+fn parse(
+    data: &[u8],
+    opt: &Options<'_>,
+    decompressor: Option<&dyn Decompressor>,
+    limit: u64,
+    policy: gzip::StreamPolicy,
+    xml_limits: XmlLimits<'_>,
+) -> Result<(Tree, SvgKind), Error> {
+    decode(data, decompressor, limit, policy, |text| {
+        xml_limits.check_deadline()?;
+        parse_str(text, opt, xml_limits)
+    })
+}
+
+/// Decompresses `data` if it is [gzip-compressed], and calls `f` with the
+/// decoded string. Returns the result of `f` and the kind of the SVG image.
+///
+/// If `decompressor` is [`None`], the built-in decompressor is used. The
+/// decompression stops as soon as the decompressed data exceeds `limit` bytes,
+/// and then the data is rejected. The built-in decompressor rejects the data
+/// which has the gzip members or the trailing garbage against `policy`. The
+/// decompressed data which is gzip-compressed again is decompressed up to the
+/// nesting allowed by `policy`.
+///
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+fn decode<T>(
+    data: &[u8],
+    decompressor: Option<&dyn Decompressor>,
+    limit: u64,
+    policy: gzip::StreamPolicy,
+    f: impl FnOnce(&str) -> Result<T, Error>,
+) -> Result<(T, SvgKind), Error> {
+    decode_sized(data, decompressor, limit, policy, |text, _, _| f(text))
+}
+
+/// Same as [`decode`], but `f` is also called with the kind of the SVG image
+/// and the size of the decompressed data in bytes, which is the size of
+/// `data` if `data` is not compressed.
+///
+/// The size is the one before the data is decoded from UTF-16, so it can
+/// differ from the length of the string.
+fn decode_sized<T>(
+    data: &[u8],
+    decompressor: Option<&dyn Decompressor>,
+    limit: u64,
+    policy: gzip::StreamPolicy,
+    f: impl FnOnce(&str, SvgKind, usize) -> Result<T, Error>,
+) -> Result<(T, SvgKind), Error> {
+    // Without any decompressor, the data is tested as is, so it is rejected as
+    // not a valid UTF-8.
+    if !data.starts_with(&gzip::GZIP_MAGIC_NUMBER)
+        || (cfg!(not(feature = "gzip")) && decompressor.is_none())
+    {
+        let text = utf16::to_str(data)?;
+        return Ok((f(&text, SvgKind::Svg, data.len())?, SvgKind::Svg));
+    }
+    gzip::with_scratch(|buf| {
+        let nesting = gzip::decompress_nested(decompressor, data, limit, policy, buf)?;
+        let text = utf16::to_str(buf)?;
+        let kind = if nesting == 0 {
+            SvgKind::Svgz
+        } else {
+            SvgKind::NestedSvgz
+        };
+        Ok((
+            f(&text, kind, buf.len()).map_err(location::decompressed)?,
+            kind,
+        ))
+    })
+}
+
+/// Parses `text` as a [SVG] string with the given options.
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+fn parse_str(text: &str, opt: &Options<'_>, xml_limits: XmlLimits<'_>) -> Result<Tree, Error> {
+    parse_xml(text, xml_limits, |doc| {
+        check_doc(doc, xml_limits)?;
+        convert(doc, opt, xml_limits)
+    })
+}
+
+/// Tests whether `text` is a valid [SVG] string with the given options, and
+/// returns the result of `inspect`, which is called with the valid document.
+///
+/// If `xml_limits` allows, this does not convert the content of the document
+/// to the tree. See the `fast` module for more details.
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+fn validate_str<T: Send>(
+    text: &str,
+    opt: &Options<'_>,
+    xml_limits: XmlLimits<'_>,
+    inspect: impl FnOnce(&roxmltree::Document<'_>) -> T + Send,
+) -> Result<T, Error> {
+    parse_xml(text, xml_limits, |doc| {
+        check_doc(doc, xml_limits)?;
+        // The content is required for testing whether the tree is empty.
+        if xml_limits.fast && !xml_limits.reject_empty && !xml_limits.require_renderable_content {
+            if let Some(result) = fast::check(doc, opt) {
+                result.map_err(|err| parse_error(doc, err))?;
+                xml_limits.check_deadline()?;
+                return Ok(inspect(doc));
+            }
+        }
+        convert(doc, opt, xml_limits)?;
+        Ok(inspect(doc))
+    })
+}
+
+/// Returns [`Err`] if `doc` is rejected by `xml_limits`, or if the size of its
+/// root element overflows, before converting it to the tree.
+fn check_doc(doc: &roxmltree::Document<'_>, xml_limits: XmlLimits<'_>) -> Result<(), Error> {
+    if xml_limits.require_svg_namespace {
+        check_root(doc)?;
+    }
+    if let Some(policy) = xml_limits.element_policy {
+        policy.check(doc)?;
+    }
+    if !xml_limits.allow_external_references {
+        reference::check(doc)?;
+    }
+    number::check_root_size(doc).map_err(Error::Parse)
+}
+
+/// Converts `doc` to the tree with the given options, and returns [`Err`] if
+/// the tree is rejected by `xml_limits`.
+///
+/// The `xlink:href` attributes which are overridden by the `href` attributes
+/// are ignored. See the `href` module for more details.
+fn convert(
+    doc: &roxmltree::Document<'_>,
+    opt: &Options<'_>,
+    xml_limits: XmlLimits<'_>,
+) -> Result<Tree, Error> {
+    let tree = if let Some(text) = href::remove_overridden(doc) {
+        // The locations in the rewritten document differ from the original one.
+        let doc = roxmltree::Document::parse_with_options(&text, parsing_options(xml_limits))
+            .map_err(|source| Error::NotXml {
+                source,
+                location: None,
+            })?;
+        Tree::from_xmltree(&doc, opt)
+    } else {
+        Tree::from_xmltree(doc, opt)
+    }
+    .map_err(|err| parse_error(doc, err))?;
+    xml_limits.check_deadline()?;
+    if xml_limits.reject_empty && !content::has_content(tree.root()) {
+        return Err(Error::EmptyDocument);
+    }
+    if xml_limits.require_renderable_content && !content::is_renderable(doc, &tree) {
+        return Err(Error::NothingToRender);
+    }
+    Ok(tree)
+}
+
+/// Returns [`Err`] if the root element of `doc` is not the `svg` element in
+/// the [SVG] namespace.
+///
+/// The prefix of the root element is not significant.
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+fn check_root(doc: &roxmltree::Document<'_>) -> Result<(), Error> {
+    if doc.root_element().has_tag_name((SVG_NAMESPACE, "svg")) {
+        Ok(())
+    } else {
+        Err(Error::NotSvgRoot {
+            found: policy::qualified_name(doc.root_element()),
+        })
+    }
+}
+
+/// Returns the error for `err`, which `roxmltree` returned while parsing
+/// `text`.
+///
+/// The data which has no root element is distinguished from the other XML
+/// documents if it has only whitespace and comments. The location of the
+/// error is computed from `text`.
+fn xml_error(text: &str, err: roxmltree::Error) -> Error {
+    match err {
+        roxmltree::Error::NoRootNode if heuristic::is_blank(text.as_bytes()) => Error::Empty,
+        err => Error::NotXml {
+            location: location::of_xml_error(text, &err),
+            source: err,
+        },
+    }
+}
+
+/// Returns the error for `err`, which `usvg` returned while converting `doc`.
+///
+/// `usvg` returns [`roxmltree::Error::NoRootNode`] if the root element is not
+/// the `svg` element in the SVG namespace, which is the same as the error of
+/// [`check_root`].
+fn parse_error(doc: &roxmltree::Document<'_>, err: usvg::Error) -> Error {
+    match err {
+        usvg::Error::ParsingFailed(roxmltree::Error::NoRootNode) => {
+            check_root(doc).err().unwrap_or(Error::Parse(err))
+        }
+        err => Error::Parse(err),
+    }
+}
+
+fn parsing_options(xml_limits: XmlLimits<'_>) -> roxmltree::ParsingOptions {
+    roxmltree::ParsingOptions {
+        allow_dtd: xml_limits.allow_dtd,
+        ..Default::default()
+    }
+}
+
+/// Parses `text` as a XML document, and calls `f` with the document.
+///
+/// The deeply nested document is parsed on a thread with a large stack, or
+/// rejected if the thread cannot be spawned, and the document which is nested
+/// deeper than `xml_limits` is rejected before parsing. See the `depth` module
+/// for more details. The document which has more elements than `xml_limits`, or
+/// whose entities expand beyond `xml_limits` is also rejected before parsing.
+/// See the `entity` module for more details.
+///
+/// If `xml_limits` allows, the missing namespace declaration is added to the
+/// root element before parsing, but the locations of the errors still refer
+/// to `text`.
+fn parse_xml<T: Send>(
+    text: &str,
+    xml_limits: XmlLimits<'_>,
+    f: impl FnOnce(&roxmltree::Document<'_>) -> Result<T, Error> + Send,
+) -> Result<T, Error> {
+    if xml_limits.allow_missing_namespace {
+        if let Cow::Owned(added) = namespace::add_missing(text) {
+            return parse_xml_as_is(&added, xml_limits, f).map_err(|err| {
+                location::map(err, |location| {
+                    location::at(
+                        text,
+                        namespace::original_offset(text, &added, location.offset),
+                    )
+                })
+            });
+        }
+    }
+    parse_xml_as_is(text, xml_limits, f)
+}
+
+/// Parses `text` as a XML document without adding the missing namespace
+/// declaration, and calls `f` with the document.
+fn parse_xml_as_is<T: Send>(
+    text: &str,
+    xml_limits: XmlLimits<'_>,
+    f: impl FnOnce(&roxmltree::Document<'_>) -> Result<T, Error> + Send,
+) -> Result<T, Error> {
+    // The document type declaration is rejected by the XML parser before the
+    // entities are expanded.
+    if xml_limits.allow_dtd {
+        entity::check(text, xml_limits)?;
+    }
+    let xml_opt = parsing_options(xml_limits);
+    let parse = || {
+        unwind::contain(|| {
+            let doc = roxmltree::Document::parse_with_options(text, xml_opt)
+                .map_err(|err| xml_error(text, err))?;
+            xml_limits.check_deadline()?;
+            f(&doc)
+        })
+    };
+    let estimate = depth::estimate(text);
+    if estimate.depth > xml_limits.depth {
+        return Err(Error::LimitExceeded(Limit::Depth(xml_limits.depth)));
+    }
+    if estimate.elements > xml_limits.elements {
+        return Err(Error::LimitExceeded(Limit::Elements(xml_limits.elements)));
+    }
+    if estimate.attribute_len > xml_limits.attribute_len {
+        return Err(Error::LimitExceeded(Limit::AttributeLength(
+            xml_limits.attribute_len,
+        )));
+    }
+    xml_limits.check_deadline()?;
+    if estimate.depth <= depth::INLINE_LIMIT {
+        parse()
+    } else {
+        // Parsing it on the current thread may overflow the stack, so it is
+        // rejected if the thread cannot be spawned.
+        depth::with_large_stack(parse)
+            .unwrap_or(Err(Error::LimitExceeded(Limit::Depth(depth::INLINE_LIMIT))))
+    }
+}
@@ -0,0 +1,91 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Lightweight validation which does not construct the tree.
+
+use crate::{limits::XmlLimits, Error};
+
+/// Returns [`true`] if `data` is a well-formed XML document whose root element
+/// is the `svg` element in the [SVG] namespace, and [`false`] otherwise.
+///
+/// This function only parses `data` with [`roxmltree`](usvg::roxmltree), and
+/// does not construct the [`Tree`](usvg::Tree), so it is faster than
+/// [`is_svg`](crate::is_svg()). The data which `is_svg` accepts is always
+/// accepted by this function, but not vice versa. Unlike `is_svg`, this
+/// function accepts:
+///
+/// - the document whose size is invalid (e.g., `width="0"`) or too large.
+/// - the document whose SVG elements are nested deeper than 1024.
+/// - the document whose `use` elements expand to too many elements.
+///
+/// Neither function rejects the invalid attribute values such as the invalid
+/// path data, or the references to the non-existent elements, since `usvg`
+/// ignores them. This function does not call the resolvers in
+/// [`Options`](crate::Options), since the resources are not loaded. This
+/// function also supports the [gzip-compressed] SVG image (`.svgz`) in the
+/// same way as `is_svg`.
+///
+/// # Examples
+///
+/// ```
+/// assert!(is_svg::is_svg_light(include_str!(
+///     "../tests/data/w3/svg-logo-v.svg"
+/// )));
+/// assert!(!is_svg::is_svg_light(include_bytes!(
+///     "../tests/data/w3/svg-logo-v.png"
+/// )));
+///
+/// // The size is not checked.
+/// let svg = r#"<svg xmlns="http://www.w3.org/2000/svg" width="0" height="0"/>"#;
+/// assert!(is_svg::is_svg_light(svg));
+/// assert!(!is_svg::is_svg(svg));
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+#[inline]
+pub fn is_svg_light(data: impl AsRef<[u8]>) -> bool {
+    check_svg_light(data).is_ok()
+}
+
+/// Tests whether `data` is a well-formed XML document whose root element is
+/// the `svg` element in the [SVG] namespace.
+///
+/// See [`is_svg_light`] for the differences from
+/// [`check_svg`](crate::check_svg()).
+///
+/// # Errors
+///
+/// Returns [`Err`] if any of the following are true:
+///
+/// - `data` is gzip-compressed, but could not be decompressed.
+/// - `data` is gzip-compressed, but the decompressed data exceeds 64 MiB.
+/// - `data` is not a valid UTF-8.
+/// - The entities declared in `data` expand to more than 1 MiB in total.
+/// - The elements of `data` are nested deeper than 4096.
+/// - `data` is not a valid XML document.
+/// - The root element of `data` is not the `svg` element in the SVG namespace.
+///
+/// # Examples
+///
+/// ```
+/// assert!(is_svg::check_svg_light(include_str!("../tests/data/w3/svg-logo-v.svg")).is_ok());
+/// assert!(matches!(
+///     is_svg::check_svg_light(r#"<html xmlns="http://www.w3.org/1999/xhtml"/>"#),
+///     Err(is_svg::Error::NotSvgRoot { found }) if found == "html"
+/// ));
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+#[inline]
+pub fn check_svg_light(data: impl AsRef<[u8]>) -> Result<(), Error> {
+    crate::decode(
+        data.as_ref(),
+        None,
+        crate::gzip::DEFAULT_DECOMPRESS_LIMIT,
+        crate::gzip::StreamPolicy::default(),
+        |text| crate::parse_xml(text, XmlLimits::default(), crate::check_root),
+    )
+    .map(|_| ())
+}
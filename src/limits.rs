@@ -0,0 +1,98 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Limits on the XML document.
+
+use std::time::Instant;
+
+use crate::{depth, entity, policy::ElementPolicy, Error};
+
+/// The limits on the XML document.
+///
+/// The flags are the independent options of
+/// [`ValidatorBuilder`](crate::ValidatorBuilder), so they are not merged.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct XmlLimits<'a> {
+    /// The maximum nesting depth of the elements.
+    pub depth: usize,
+
+    /// The maximum number of the elements.
+    pub elements: usize,
+
+    /// The maximum length of an attribute value in bytes.
+    pub attribute_len: usize,
+
+    /// The maximum depth of the nested entity references.
+    pub entity_depth: u32,
+
+    /// The maximum total size of the text which is expanded from the entity
+    /// references in bytes.
+    pub entity_expansion: u64,
+
+    /// The time by which the validation must finish.
+    pub deadline: Option<Instant>,
+
+    /// Whether the document type declaration is allowed.
+    pub allow_dtd: bool,
+
+    /// Whether the root element is required to be the `svg` element in the SVG
+    /// namespace before converting the document to the tree.
+    pub require_svg_namespace: bool,
+
+    /// Whether the SVG namespace is declared on the root element if it is
+    /// missing.
+    pub allow_missing_namespace: bool,
+
+    /// Whether the tree which has no renderable nodes is rejected.
+    pub reject_empty: bool,
+
+    /// Whether the tree which has no visible nodes is rejected.
+    pub require_renderable_content: bool,
+
+    /// The elements which are allowed, or [`None`] if all the elements are
+    /// allowed.
+    pub element_policy: Option<&'a ElementPolicy>,
+
+    /// Whether the references to the external resources are allowed.
+    pub allow_external_references: bool,
+
+    /// Whether the document is validated without converting its content to the
+    /// tree if possible.
+    pub fast: bool,
+}
+
+impl Default for XmlLimits<'_> {
+    fn default() -> Self {
+        Self {
+            depth: depth::MAX_DEPTH,
+            elements: usize::MAX,
+            attribute_len: usize::MAX,
+            entity_depth: entity::MAX_DEPTH,
+            entity_expansion: entity::DEFAULT_MAX_SIZE,
+            deadline: Option::default(),
+            allow_dtd: true,
+            require_svg_namespace: false,
+            allow_missing_namespace: false,
+            reject_empty: false,
+            require_renderable_content: false,
+            element_policy: Option::default(),
+            allow_external_references: true,
+            fast: false,
+        }
+    }
+}
+
+impl XmlLimits<'_> {
+    /// Returns [`Err`] if the deadline has passed.
+    ///
+    /// This is called between the stages of the validation, since the stages
+    /// themselves cannot be interrupted.
+    pub fn check_deadline(&self) -> Result<(), Error> {
+        match self.deadline {
+            Some(deadline) if Instant::now() >= deadline => Err(Error::Timeout),
+            _ => Ok(()),
+        }
+    }
+}
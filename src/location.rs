@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Locations of the errors in the document.
+//!
+//! `roxmltree` only reports the line and the column of an error, so the byte
+//! offset is computed from them while the document is still available.
+
+use usvg::roxmltree::{self, TextPos};
+
+use crate::{Error, Location};
+
+/// Returns the location at `offset` in `text`.
+///
+/// The line and the column are counted in the same way as `roxmltree`.
+pub fn at(text: &str, offset: usize) -> Location {
+    let before = &text[..offset];
+    let line_start = before.rfind('\n').map_or(0, |pos| pos + 1);
+    let row = before.bytes().filter(|&b| b == b'\n').count() + 1;
+    let col = before[line_start..].chars().count() + 1;
+    Location {
+        position: TextPos::new(
+            u32::try_from(row).unwrap_or(u32::MAX),
+            u32::try_from(col).unwrap_or(u32::MAX),
+        ),
+        offset,
+        decompressed: false,
+    }
+}
+
+/// Returns the location of `err` in `text`, or [`None`] if `err` does not
+/// refer to a location.
+///
+/// The root element which is not closed is located at the end of `text`.
+pub fn of_xml_error(text: &str, err: &roxmltree::Error) -> Option<Location> {
+    match err {
+        roxmltree::Error::UnclosedRootNode | roxmltree::Error::UnexpectedEndOfStream => {
+            Some(at(text, text.len()))
+        }
+        // `roxmltree` reports the start of the document for these.
+        roxmltree::Error::NoRootNode
+        | roxmltree::Error::DtdDetected
+        | roxmltree::Error::NodesLimitReached
+        | roxmltree::Error::AttributesLimitReached
+        | roxmltree::Error::NamespacesLimitReached => None,
+        err => Some(at(text, offset_of(text, err.pos()))),
+    }
+}
+
+/// Returns the byte offset of `pos` in `text`.
+fn offset_of(text: &str, pos: TextPos) -> usize {
+    let line_start = match pos.row {
+        0 | 1 => 0,
+        row => text
+            .match_indices('\n')
+            .nth(usize::try_from(row - 2).unwrap_or(usize::MAX))
+            .map_or(text.len(), |(pos, _)| pos + 1),
+    };
+    let col = usize::try_from(pos.col.saturating_sub(1)).unwrap_or(usize::MAX);
+    text[line_start..]
+        .char_indices()
+        .nth(col)
+        .map_or(text.len(), |(pos, _)| line_start + pos)
+}
+
+/// Returns `err` whose location, if any, is replaced with the result of `f`.
+pub fn map(err: Error, f: impl FnOnce(Location) -> Location) -> Error {
+    match err {
+        Error::NotXml { source, location } => Error::NotXml {
+            source,
+            location: location.map(f),
+        },
+        Error::DisallowedElement { name, location } => Error::DisallowedElement {
+            name,
+            location: f(location),
+        },
+        Error::ExternalReference { href, location } => Error::ExternalReference {
+            href,
+            location: f(location),
+        },
+        err => err,
+    }
+}
+
+/// Returns `err` whose location, if any, refers to the decompressed document.
+pub fn decompressed(err: Error) -> Error {
+    map(err, |location| Location {
+        decompressed: true,
+        ..location
+    })
+}
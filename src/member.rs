@@ -0,0 +1,331 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Decoding of the gzip members.
+//!
+//! The decoders of `flate2` report the mismatch of the CRC-32 or the size in
+//! the trailer only as an [`io::Error`] which is not told from the other errors
+//! except by its message. So the header and the trailer of each member are
+//! read here, and only the deflate stream is decompressed by `flate2`.
+
+use std::io;
+
+use flate2::{Crc, Decompress, FlushDecompress, Status};
+
+use crate::{
+    gzip::{StreamPolicy, GZIP_MAGIC_NUMBER},
+    Error,
+};
+
+const FHCRC: u8 = 1 << 1;
+const FEXTRA: u8 = 1 << 2;
+const FNAME: u8 = 1 << 3;
+const FCOMMENT: u8 = 1 << 4;
+const FRESERVED: u8 = 0xe0;
+
+/// The size of the fixed fields of the header.
+const HEADER_LEN: usize = 10;
+
+/// The size of the trailer, which is the CRC-32 and the size.
+const TRAILER_LEN: usize = 8;
+
+/// The maximum length of the file name and the comment, which is the same as
+/// `flate2`.
+const MAX_FIELD_LEN: usize = 65535;
+
+/// The maximum number of bytes which are decompressed at once.
+const CHUNK_LEN: usize = 32 * 1024;
+
+/// The part of the gzip-compressed data which is read next.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum State {
+    /// The fixed fields of the header.
+    Header,
+
+    /// The length of the extra field.
+    ExtraLen,
+
+    /// The extra field, with the number of the remaining bytes.
+    Extra(usize),
+
+    /// The file name, with the number of the bytes which have been read.
+    Name(usize),
+
+    /// The comment, with the number of the bytes which have been read.
+    Comment(usize),
+
+    /// The CRC-16 of the header.
+    HeaderCrc,
+
+    /// The deflate stream.
+    Body,
+
+    /// The trailer.
+    Trailer,
+
+    /// The data after a member.
+    End,
+
+    /// The data after the last member which is ignored.
+    Garbage,
+}
+
+/// The decoder of the gzip members, which is fed the data in chunks.
+///
+/// The CRC-16 of the header, and the CRC-32 and the size in the trailer are
+/// verified, and the mismatch is reported as [`Error::GzipIntegrity`]. The
+/// data after a member is handled as `policy` of [`Decoder::new`] says, as
+/// well as [`gzip::decompress`](crate::gzip::decompress).
+#[derive(Debug)]
+pub struct Decoder {
+    policy: StreamPolicy,
+    state: State,
+    flags: u8,
+
+    /// The bytes of the fixed-size field which have been read.
+    field: Vec<u8>,
+
+    /// The CRC-32 of the header, or of the decompressed data of the member.
+    crc: Crc,
+
+    inflater: Decompress,
+}
+
+impl Decoder {
+    /// Creates a new `Decoder` which handles the gzip members as `policy`
+    /// says.
+    pub fn new(policy: StreamPolicy) -> Self {
+        Self {
+            policy,
+            state: State::Header,
+            flags: u8::default(),
+            field: Vec::with_capacity(HEADER_LEN),
+            crc: Crc::new(),
+            inflater: Decompress::new(false),
+        }
+    }
+
+    /// Decodes `input`, and appends the decompressed data to `buf`.
+    ///
+    /// This stops decompressing once `buf` exceeds `limit` bytes, so the
+    /// caller must check the size of `buf` rather than calling
+    /// [`finish`](Self::finish) then.
+    pub fn write(&mut self, mut input: &[u8], buf: &mut Vec<u8>, limit: u64) -> Result<(), Error> {
+        let limit = usize::try_from(limit).unwrap_or(usize::MAX);
+        while buf.len() <= limit {
+            let progressed = match self.state {
+                State::Body => self.inflate(&mut input, buf, limit)?,
+                State::Trailer => self.read_trailer(&mut input)?,
+                State::End => self.read_end(&mut input)?,
+                State::Garbage => false,
+                _ => self.read_header(&mut input)?,
+            };
+            if !progressed {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads a field of the header from `input`, and returns [`false`] if more
+    /// data is needed.
+    fn read_header(&mut self, input: &mut &[u8]) -> Result<bool, Error> {
+        match self.state {
+            State::Header => {
+                if !self.fill(input, HEADER_LEN) {
+                    return Ok(false);
+                }
+                let header = &self.field;
+                if !header.starts_with(&GZIP_MAGIC_NUMBER)
+                    || header[2] != 8
+                    || header[3] & FRESERVED != 0
+                {
+                    return Err(Error::GzipDecode(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        "invalid gzip header",
+                    )));
+                }
+                self.flags = header[3];
+                self.crc.reset();
+                self.crc.update(header);
+                self.state = self.next_field(0);
+            }
+            State::ExtraLen => {
+                if !self.fill(input, 2) {
+                    return Ok(false);
+                }
+                self.crc.update(&self.field);
+                let len = u16::from_le_bytes([self.field[0], self.field[1]]);
+                self.state = State::Extra(len.into());
+            }
+            State::Extra(0) => self.state = self.next_field(1),
+            State::Extra(remaining) => {
+                let (extra, rest) = input.split_at(remaining.min(input.len()));
+                self.crc.update(extra);
+                *input = rest;
+                self.state = State::Extra(remaining - extra.len());
+                return Ok(!input.is_empty());
+            }
+            State::Name(len) | State::Comment(len) => {
+                let end = input.iter().position(|&b| b == 0);
+                let (field, rest) = input.split_at(end.map_or(input.len(), |end| end + 1));
+                let len = len + field.len() - usize::from(end.is_some());
+                if len > MAX_FIELD_LEN {
+                    return Err(Error::GzipDecode(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        "gzip header field too long",
+                    )));
+                }
+                self.crc.update(field);
+                *input = rest;
+                self.state = match (self.state, end) {
+                    (State::Name(_), Some(_)) => self.next_field(2),
+                    (State::Name(_), None) => State::Name(len),
+                    (_, Some(_)) => self.next_field(3),
+                    (_, None) => State::Comment(len),
+                };
+                return Ok(end.is_some());
+            }
+            State::HeaderCrc => {
+                if !self.fill(input, 2) {
+                    return Ok(false);
+                }
+                let hcrc = u16::from_le_bytes([self.field[0], self.field[1]]);
+                if u32::from(hcrc) != self.crc.sum() & 0xffff {
+                    return Err(Error::GzipIntegrity);
+                }
+                self.state = self.next_field(4);
+            }
+            _ => unreachable!("{:?} is not a field of the header", self.state),
+        }
+        Ok(true)
+    }
+
+    /// Decompresses at most [`CHUNK_LEN`] bytes from `input`, and returns
+    /// [`false`] if more data is needed.
+    fn inflate(
+        &mut self,
+        input: &mut &[u8],
+        buf: &mut Vec<u8>,
+        limit: usize,
+    ) -> Result<bool, Error> {
+        let start = buf.len();
+        buf.resize(start + CHUNK_LEN.min((limit - start).saturating_add(1)), 0);
+        let total_in = self.inflater.total_in();
+        let total_out = self.inflater.total_out();
+        let status = self
+            .inflater
+            .decompress(input, &mut buf[start..], FlushDecompress::None)
+            .map_err(|err| Error::GzipDecode(io::Error::new(io::ErrorKind::InvalidInput, err)));
+        let consumed = usize::try_from(self.inflater.total_in() - total_in)
+            .expect("consumed bytes should not exceed the input");
+        let produced = usize::try_from(self.inflater.total_out() - total_out)
+            .expect("produced bytes should not exceed the output");
+        buf.truncate(start + produced);
+        self.crc.update(&buf[start..]);
+        *input = &input[consumed..];
+        if status? == Status::StreamEnd {
+            self.state = State::Trailer;
+            return Ok(true);
+        }
+        Ok(consumed > 0 || produced > 0)
+    }
+
+    /// Reads the trailer from `input`, and returns [`false`] if more data is
+    /// needed.
+    fn read_trailer(&mut self, input: &mut &[u8]) -> Result<bool, Error> {
+        if !self.fill(input, TRAILER_LEN) {
+            return Ok(false);
+        }
+        let (crc, size) = self.field.split_at(4);
+        if crc != self.crc.sum().to_le_bytes() || size != self.crc.amount().to_le_bytes() {
+            return Err(Error::GzipIntegrity);
+        }
+        self.field.clear();
+        self.state = State::End;
+        Ok(true)
+    }
+
+    /// Tests whether the data after a member in `input` is the next member,
+    /// and returns [`false`] if more data is needed.
+    fn read_end(&mut self, input: &mut &[u8]) -> Result<bool, Error> {
+        let magic = input.len().min(GZIP_MAGIC_NUMBER.len() - self.field.len());
+        self.field.extend_from_slice(&input[..magic]);
+        if !GZIP_MAGIC_NUMBER.starts_with(&self.field) {
+            self.state = self.garbage()?;
+        } else if self.field.len() < GZIP_MAGIC_NUMBER.len() {
+            return Ok(false);
+        } else if self.policy.single_member {
+            return Err(Error::GzipDecode(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "data had multiple gzip members",
+            )));
+        } else {
+            // The magic number is the start of the header of the next member.
+            *input = &input[magic..];
+            self.state = State::Header;
+        }
+        Ok(true)
+    }
+
+    /// Finishes decoding the data.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the data is truncated, or the data after the last
+    /// member is not allowed.
+    pub fn finish(self) -> Result<(), Error> {
+        match self.state {
+            State::End if self.field.is_empty() => Ok(()),
+            State::End => self.garbage().map(|_| ()),
+            State::Garbage => Ok(()),
+            _ => Err(Error::GzipDecode(io::ErrorKind::UnexpectedEof.into())),
+        }
+    }
+
+    /// Moves the bytes from `input` to the fixed-size field of `len` bytes, and
+    /// returns [`true`] if the field is complete.
+    fn fill(&mut self, input: &mut &[u8], len: usize) -> bool {
+        let (field, rest) = input.split_at(input.len().min(len - self.field.len()));
+        self.field.extend_from_slice(field);
+        *input = rest;
+        self.field.len() == len
+    }
+
+    /// Returns the state of the first optional field of the header which is
+    /// present, skipping the first `skip` fields, or of the deflate stream if
+    /// none of them is present.
+    fn next_field(&mut self, skip: usize) -> State {
+        const FIELDS: [(u8, State); 4] = [
+            (FEXTRA, State::ExtraLen),
+            (FNAME, State::Name(0)),
+            (FCOMMENT, State::Comment(0)),
+            (FHCRC, State::HeaderCrc),
+        ];
+
+        self.field.clear();
+        if let Some(&(_, state)) = FIELDS[skip..]
+            .iter()
+            .find(|(flag, _)| self.flags & flag != 0)
+        {
+            return state;
+        }
+        self.crc.reset();
+        self.inflater.reset(false);
+        State::Body
+    }
+
+    /// Returns the state after the data which is not a gzip member, or [`Err`]
+    /// if it is not allowed.
+    fn garbage(&self) -> Result<State, Error> {
+        if self.policy.allow_trailing_garbage {
+            Ok(State::Garbage)
+        } else {
+            Err(Error::GzipDecode(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "data after the end of the gzip members was not a gzip member",
+            )))
+        }
+    }
+}
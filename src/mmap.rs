@@ -0,0 +1,67 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Validation of the memory-mapped file.
+
+use std::{
+    fs::{self, File},
+    io,
+    path::Path,
+};
+
+use memmap2::Mmap;
+
+/// Returns [`Ok(true)`](Ok) if the file at `path` is a valid [SVG] image, and
+/// [`Ok(false)`](Ok) otherwise.
+///
+/// Unlike [`is_svg_file`](crate::is_svg_file()), the file is mapped into
+/// memory read-only instead of being read into a buffer, so that the peak
+/// memory usage does not double for a very large file. For the same reason,
+/// the size of the file is not limited. If the file is empty or could not be
+/// mapped, it is read into a buffer instead. This function also supports the
+/// [gzip-compressed] SVG image (`.svgz`).
+///
+/// # Safety considerations
+///
+/// Although this function is safe to call, the mapped file must not be
+/// modified or truncated by this or any other process while it is being
+/// tested. Modifying it may make the result meaningless, and truncating it
+/// may cause the process to crash (e.g., with `SIGBUS` on Unix). Use
+/// [`is_svg_file`](crate::is_svg_file()) for the files which may change.
+///
+/// # Errors
+///
+/// Returns [`Err`] if the file could not be opened or read.
+///
+/// # Examples
+///
+/// ```
+/// assert!(is_svg::is_svg_file_mmap("tests/data/w3/svg-logo-v.svg").unwrap());
+/// assert!(!is_svg::is_svg_file_mmap("tests/data/w3/svg-logo-v.png").unwrap());
+///
+/// # #[cfg(feature = "gzip")]
+/// assert!(is_svg::is_svg_file_mmap("tests/data/w3/svg-logo-v.svgz").unwrap());
+///
+/// assert!(is_svg::is_svg_file_mmap("tests/data/w3/non-existent.svg").is_err());
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+#[inline]
+pub fn is_svg_file_mmap(path: impl AsRef<Path>) -> io::Result<bool> {
+    let inner = |path: &Path| -> io::Result<bool> {
+        let file = File::open(path)?;
+        if file.metadata()?.len() > 0 {
+            // SAFETY: The mapping is read-only and is only alive in this
+            // function. The caller is responsible for not modifying the file
+            // while it is mapped, as documented above.
+            #[allow(unsafe_code)]
+            if let Ok(mmap) = unsafe { Mmap::map(&file) } {
+                return Ok(crate::is_svg(&mmap[..]));
+            }
+        }
+        fs::read(path).map(crate::is_svg)
+    };
+    inner(path.as_ref())
+}
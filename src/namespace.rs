@@ -0,0 +1,99 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Completion of the missing namespace declaration of the root element.
+//!
+//! The SVG snippets which are copied from the design tools often omit the
+//! namespace declaration (e.g., `<svg viewBox="0 0 24 24">`), so the root
+//! element is not in the SVG namespace. The declaration is inserted before
+//! parsing, so that such a document is parsed as if it were declared.
+
+use std::borrow::Cow;
+
+use crate::{heuristic, SVG_NAMESPACE};
+
+/// Returns `text` with the default namespace declaration of the SVG namespace
+/// added to the root element, if the root element is the unprefixed `svg`
+/// element which does not declare the default namespace.
+///
+/// Otherwise, including the case where the root element declares the other
+/// default namespace, `text` is returned unchanged. This does not validate
+/// `text`, and the malformed root element is also returned unchanged.
+pub fn add_missing(text: &str) -> Cow<'_, str> {
+    let data = text.as_bytes();
+    let Some(rest) = heuristic::first_element(data) else {
+        return Cow::Borrowed(text);
+    };
+    let name_end = name_len(rest);
+    if &rest[..name_end] != b"svg" || declares_default_namespace(&rest[name_end..]) != Some(false) {
+        return Cow::Borrowed(text);
+    }
+
+    // The declaration is inserted right after the element name, so that it is
+    // not affected by the end of the tag (e.g., `/>`).
+    let pos = data.len() - rest.len() + name_end;
+    let mut added = String::with_capacity(text.len() + SVG_NAMESPACE.len() + 9);
+    added.push_str(&text[..pos]);
+    added.push_str(" xmlns=\"");
+    added.push_str(SVG_NAMESPACE);
+    added.push('"');
+    added.push_str(&text[pos..]);
+    Cow::Owned(added)
+}
+
+/// Returns the byte offset in `text` which corresponds to `offset` in `added`,
+/// which is returned by [`add_missing`] for `text`.
+///
+/// The offset in the added declaration corresponds to the position where it
+/// is inserted.
+pub fn original_offset(text: &str, added: &str, offset: usize) -> usize {
+    let inserted = added.len() - text.len();
+    let pos = text
+        .bytes()
+        .zip(added.bytes())
+        .position(|(a, b)| a != b)
+        .unwrap_or(text.len());
+    if offset < pos {
+        offset
+    } else {
+        offset.saturating_sub(inserted).max(pos)
+    }
+}
+
+/// Returns the length of the name at the start of `data`.
+fn name_len(data: &[u8]) -> usize {
+    data.iter()
+        .position(|&b| b.is_ascii_whitespace() || matches!(b, b'=' | b'>' | b'/'))
+        .unwrap_or(data.len())
+}
+
+fn trim_start(data: &[u8]) -> &[u8] {
+    let start = data
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .unwrap_or(data.len());
+    &data[start..]
+}
+
+/// Returns whether the attributes at the start of `data` declare the default
+/// namespace, or [`None`] if the attributes are malformed.
+fn declares_default_namespace(mut data: &[u8]) -> Option<bool> {
+    loop {
+        data = trim_start(data);
+        match data.first()? {
+            b'>' | b'/' => return Some(false),
+            _ => (),
+        }
+        let len = name_len(data);
+        let name = &data[..len];
+        data = trim_start(&data[len..]).strip_prefix(b"=")?;
+        data = trim_start(data);
+        let quote = *data.first().filter(|&&b| matches!(b, b'"' | b'\''))?;
+        let value_len = data[1..].iter().position(|&b| b == quote)?;
+        if name == b"xmlns" {
+            return Some(true);
+        }
+        data = &data[value_len + 2..];
+    }
+}
@@ -0,0 +1,98 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Early rejection of the size of the root element which overflows.
+//!
+//! `usvg` rejects the root element whose size is too large for [`f32`] (e.g.,
+//! `width="1e39"`), but ignores the size which is too large even for [`f64`]
+//! (e.g., `width="1e999999"`), as if it were not specified. So the verdict on
+//! the latter is made consistent with the former by rejecting it before
+//! converting the document. The other attributes are left to `usvg`, which
+//! ignores or clamps the numbers which overflow.
+
+use usvg::roxmltree::Document;
+
+/// The attributes of the root element which determine the size of the tree.
+const SIZE_ATTRIBUTES: [&str; 3] = ["width", "height", "viewBox"];
+
+/// Returns [`Err`] if the size of the root element of `doc` has a number which
+/// is too large for [`f32`].
+///
+/// The keywords such as `NaN` and `Infinity` are not numbers in SVG, so these
+/// are left to `usvg`.
+pub fn check_root_size(doc: &Document<'_>) -> Result<(), usvg::Error> {
+    let root = doc.root_element();
+    if root.tag_name().name() != "svg" {
+        return Ok(());
+    }
+    let overflows = SIZE_ATTRIBUTES
+        .iter()
+        .filter_map(|name| root.attribute(*name))
+        .any(has_overflowing_number);
+    if overflows {
+        return Err(usvg::Error::InvalidSize);
+    }
+    Ok(())
+}
+
+/// Returns [`true`] if `value` has a number which is too large for [`f32`].
+fn has_overflowing_number(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    let mut pos = 0;
+    while pos < bytes.len() {
+        let end = number_end(bytes, pos);
+        if end == pos {
+            pos += 1;
+            continue;
+        }
+        if value[pos..end]
+            .parse::<f32>()
+            .map_or(false, f32::is_infinite)
+        {
+            return true;
+        }
+        pos = end;
+    }
+    false
+}
+
+/// Returns the end of the number which starts at `start` of `bytes`, or
+/// `start` if there is no number.
+///
+/// The number is a sign, an integer part and a fractional part, either of
+/// which can be omitted, and an exponent.
+fn number_end(bytes: &[u8], start: usize) -> usize {
+    let digits_end = |pos: usize| {
+        pos + bytes[pos..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count()
+    };
+    let mut pos = start;
+    if matches!(bytes.get(pos), Some(b'+' | b'-')) {
+        pos += 1;
+    }
+    let int_end = digits_end(pos);
+    let mut end = int_end;
+    if bytes.get(int_end) == Some(&b'.') {
+        let frac_end = digits_end(int_end + 1);
+        if frac_end > int_end + 1 || int_end > pos {
+            end = frac_end;
+        }
+    }
+    if end == pos {
+        return start;
+    }
+    if matches!(bytes.get(end), Some(b'e' | b'E')) {
+        let mut exp = end + 1;
+        if matches!(bytes.get(exp), Some(b'+' | b'-')) {
+            exp += 1;
+        }
+        let exp_end = digits_end(exp);
+        if exp_end > exp {
+            end = exp_end;
+        }
+    }
+    end
+}
@@ -0,0 +1,131 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A policy on the elements of the XML document.
+//!
+//! The elements are tested on the XML document before converting it to the
+//! tree, since `usvg` ignores or flattens the elements such as `script`,
+//! `foreignObject` and `animate`.
+
+use std::collections::HashSet;
+
+use usvg::roxmltree::{Document, ExpandedName, Node};
+
+use crate::{location, Error, SVG_NAMESPACE};
+
+/// The elements which are allowed in the XML document.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ElementPolicy {
+    /// The local names of the allowed elements in the SVG namespace, or
+    /// [`None`] if all the elements are allowed.
+    pub allowed: Option<HashSet<Box<str>>>,
+
+    /// Whether the elements which are not in the SVG namespace are allowed if
+    /// `allowed` is set.
+    pub allow_foreign: bool,
+
+    /// The patterns of the denied elements, which take precedence over
+    /// `allowed`.
+    pub denied: Vec<NamePattern>,
+}
+
+impl ElementPolicy {
+    /// Returns [`true`] if any element can be rejected by this policy.
+    pub fn is_restrictive(&self) -> bool {
+        self.allowed.is_some() || !self.denied.is_empty()
+    }
+
+    /// Returns [`Err`] if `doc` has any element which is not allowed.
+    ///
+    /// The elements are tested in the document order, and the first element
+    /// which is not allowed is returned. The elements in the SVG namespace are
+    /// tested even if they are the descendants of the allowed foreign
+    /// elements.
+    pub fn check(&self, doc: &Document<'_>) -> Result<(), Error> {
+        let disallowed = doc
+            .descendants()
+            .filter(Node::is_element)
+            .find(|node| !self.allows(node.tag_name()));
+        disallowed.map_or(Ok(()), |node| {
+            Err(Error::DisallowedElement {
+                name: qualified_name(node),
+                location: location::at(doc.input_text(), node.range().start),
+            })
+        })
+    }
+
+    fn allows(&self, name: ExpandedName<'_, '_>) -> bool {
+        if self.denied.iter().any(|pattern| pattern.matches(name)) {
+            return false;
+        }
+        self.allowed.as_ref().map_or(true, |allowed| {
+            if name.namespace() == Some(SVG_NAMESPACE) {
+                allowed.contains(name.name())
+            } else {
+                self.allow_foreign
+            }
+        })
+    }
+}
+
+/// A pattern which matches the names of the elements.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NamePattern {
+    /// The namespace name, or [`None`] if any namespace matches.
+    namespace: Option<Box<str>>,
+
+    /// The local name, or its prefix if `is_prefix` is [`true`].
+    name: Box<str>,
+
+    is_prefix: bool,
+}
+
+impl NamePattern {
+    /// Parses `pattern` which is the local name (e.g., `script`), optionally
+    /// preceded by the namespace name in braces (e.g.,
+    /// `{http://www.w3.org/2000/svg}script`).
+    ///
+    /// The trailing `*` matches any sequence of characters (e.g., `animate*`).
+    pub fn new(pattern: &str) -> Self {
+        let (namespace, name) = pattern
+            .strip_prefix('{')
+            .and_then(|rest| rest.split_once('}'))
+            .map_or((None, pattern), |(namespace, name)| {
+                (Some(namespace.into()), name)
+            });
+        let (name, is_prefix) = name
+            .strip_suffix('*')
+            .map_or((name, false), |prefix| (prefix, true));
+        Self {
+            namespace,
+            name: name.into(),
+            is_prefix,
+        }
+    }
+
+    fn matches(&self, name: ExpandedName<'_, '_>) -> bool {
+        let namespace_matches = self
+            .namespace
+            .as_deref()
+            .map_or(true, |namespace| name.namespace() == Some(namespace));
+        let name_matches = if self.is_prefix {
+            name.name().starts_with(&*self.name)
+        } else {
+            name.name() == &*self.name
+        };
+        namespace_matches && name_matches
+    }
+}
+
+/// Returns the name of `node` with the prefix, if any.
+pub fn qualified_name(node: Node<'_, '_>) -> String {
+    let name = node.tag_name();
+    match name
+        .namespace()
+        .and_then(|namespace| node.lookup_prefix(namespace))
+    {
+        Some(prefix) if !prefix.is_empty() => format!("{prefix}:{}", name.name()),
+        _ => name.name().to_owned(),
+    }
+}
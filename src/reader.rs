@@ -0,0 +1,225 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Validation of the data read from a reader.
+
+use std::io::{self, Read};
+
+use crate::{Error, Limit, SvgChecker, SvgKind};
+
+/// The maximum number of bytes to read from a reader.
+pub const DEFAULT_READ_LIMIT: u64 = 64 * 1024 * 1024;
+
+/// Returns [`Ok(true)`](Ok) if the data read from `reader` is a valid [SVG]
+/// data, and [`Ok(false)`](Ok) otherwise.
+///
+/// This function also supports the [gzip-compressed] SVG image (`.svgz`).
+///
+/// At most 64 MiB are read from `reader`. If `reader` has more data than
+/// this, the data is not considered to be a valid SVG data.
+///
+/// # Errors
+///
+/// Returns [`Err`] if an error occurred while reading from `reader`.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::Cursor;
+/// #
+/// assert!(
+///     is_svg::is_svg_reader(Cursor::new(include_str!("../tests/data/w3/svg-logo-v.svg")))
+///         .unwrap()
+/// );
+/// assert!(!is_svg::is_svg_reader(Cursor::new(include_bytes!(
+///     "../tests/data/w3/svg-logo-v.png"
+/// )))
+/// .unwrap());
+///
+/// # #[cfg(feature = "gzip")]
+/// assert!(is_svg::is_svg_reader(Cursor::new(include_bytes!(
+///     "../tests/data/w3/svg-logo-v.svgz"
+/// )))
+/// .unwrap());
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+#[inline]
+pub fn is_svg_reader<R: Read>(reader: R) -> io::Result<bool> {
+    match check_svg_reader(reader) {
+        Ok(()) => Ok(true),
+        Err(Error::Io(err)) => Err(err),
+        Err(_) => Ok(false),
+    }
+}
+
+/// Tests whether the data read from `reader` is a valid [SVG] data.
+///
+/// This function also supports the [gzip-compressed] SVG image (`.svgz`).
+///
+/// At most 64 MiB are read from `reader`.
+///
+/// # Errors
+///
+/// Returns [`Err`] if any of the following are true:
+///
+/// - An error occurred while reading from `reader`.
+/// - `reader` has more than 64 MiB of data.
+/// - The data read from `reader` is not a valid SVG data. See [`check_svg`] for
+///   more details.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::Cursor;
+/// #
+/// assert!(
+///     is_svg::check_svg_reader(Cursor::new(include_str!("../tests/data/w3/svg-logo-v.svg")))
+///         .is_ok()
+/// );
+/// assert!(matches!(
+///     is_svg::check_svg_reader(Cursor::new(include_bytes!(
+///         "../tests/data/w3/svg-logo-v.png"
+///     ))),
+///     Err(is_svg::Error::NotUtf8(_))
+/// ));
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+/// [`check_svg`]: crate::check_svg
+#[inline]
+pub fn check_svg_reader<R: Read>(reader: R) -> Result<(), Error> {
+    let data = read_limited(reader, DEFAULT_READ_LIMIT)?;
+    crate::check_svg(data)
+}
+
+/// A reader which tests whether the data read through it is a [SVG] data.
+///
+/// Everything read from the inner reader is also fed into a [`SvgChecker`],
+/// so the data can be validated while it is being copied, without reading it
+/// twice. This also supports the [gzip-compressed] SVG image (`.svgz`), and
+/// any size of reads.
+///
+/// The data is buffered internally for the validation, but at most 64 MiB by
+/// default. If the data is larger than this, the data is not considered to be
+/// a valid SVG data, but it can still be read.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Cursor};
+/// #
+/// # use is_svg::{SvgKind, ValidatingReader};
+/// #
+/// # #[cfg(feature = "gzip")]
+/// # {
+/// let data = include_bytes!("../tests/data/w3/svg-logo-v.svgz");
+/// let mut reader = ValidatingReader::new(Cursor::new(data));
+/// let mut buf = Vec::new();
+/// io::copy(&mut reader, &mut buf).unwrap();
+/// assert_eq!(buf, data);
+/// assert_eq!(reader.verdict().unwrap(), SvgKind::Svgz);
+/// # }
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+#[derive(Debug)]
+pub struct ValidatingReader<R> {
+    inner: R,
+    checker: SvgChecker,
+}
+
+impl<R: Read> ValidatingReader<R> {
+    /// Creates a new `ValidatingReader` which reads from `inner`.
+    #[inline]
+    pub const fn new(inner: R) -> Self {
+        Self {
+            inner,
+            checker: SvgChecker::new(),
+        }
+    }
+
+    /// Sets the maximum size of the data in bytes to buffer for the
+    /// validation.
+    ///
+    /// See [`SvgChecker::max_size`] for more details. The default is 64 MiB.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// #
+    /// # use is_svg::{Error, Limit, ValidatingReader};
+    /// #
+    /// let mut reader =
+    ///     ValidatingReader::new(Cursor::new(include_str!("../tests/data/w3/svg-logo-v.svg")))
+    ///         .max_size(1024);
+    /// io::copy(&mut reader, &mut io::sink()).unwrap();
+    /// assert!(matches!(
+    ///     reader.verdict(),
+    ///     Err(Error::LimitExceeded(Limit::InputSize(1024)))
+    /// ));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn max_size(mut self, limit: u64) -> Self {
+        self.checker = self.checker.max_size(limit);
+        self
+    }
+
+    /// Returns a reference to the inner reader.
+    #[inline]
+    pub const fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the inner reader.
+    ///
+    /// Reading directly from the inner reader bypasses the validation.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the kind of the [SVG] image of the data read so far.
+    ///
+    /// This should be called after the inner reader has been read to the end,
+    /// e.g. by [`io::copy`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the data read so far exceeds the maximum size, or is
+    /// not a valid SVG data. See [`check_svg`](crate::check_svg()) for more
+    /// details.
+    ///
+    /// [SVG]: https://www.w3.org/Graphics/SVG/
+    #[inline]
+    pub fn verdict(self) -> Result<SvgKind, Error> {
+        self.checker.finish()
+    }
+}
+
+impl<R: Read> Read for ValidatingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.checker.push(&buf[..n]);
+        Ok(n)
+    }
+}
+
+/// Reads all bytes from `reader`, but at most `limit` bytes.
+pub fn read_limited(reader: impl Read, limit: u64) -> Result<Vec<u8>, Error> {
+    let mut buf = Vec::new();
+    reader
+        .take(limit.saturating_add(1))
+        .read_to_end(&mut buf)
+        .map_err(Error::Io)?;
+    if u64::try_from(buf.len()).map_or(true, |len| len > limit) {
+        return Err(Error::LimitExceeded(Limit::InputSize(limit)));
+    }
+    Ok(buf)
+}
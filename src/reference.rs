@@ -0,0 +1,170 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Detection of the references to the external resources.
+//!
+//! The references are tested on the XML document before converting it to the
+//! tree, since `usvg` ignores the references which cannot be resolved, such as
+//! the `use` element which refers to the other file.
+
+use usvg::roxmltree::{Document, Node};
+
+use crate::{location, Error, XLINK_NAMESPACE};
+
+/// The presentation attributes whose values can have the CSS `url()`
+/// functions.
+const URL_ATTRIBUTES: [&str; 9] = [
+    "clip-path",
+    "cursor",
+    "fill",
+    "filter",
+    "marker-end",
+    "marker-mid",
+    "marker-start",
+    "mask",
+    "stroke",
+];
+
+/// Returns [`Err`] if `doc` has any reference to the external resource.
+///
+/// The references are the `href` and the `xlink:href` attributes, the `href`
+/// pseudo-attributes of the `xml-stylesheet` processing instructions, and the
+/// CSS `url()` functions and `@import` rules in the `style` attributes, the
+/// `style` elements and the presentation attributes. The reference which is
+/// only the fragment (e.g., `#gradient`) or the `data:` URI is not external.
+/// The references are tested in the document order, and the first external
+/// reference is returned.
+pub fn check(doc: &Document<'_>) -> Result<(), Error> {
+    for node in doc.descendants() {
+        let external = if node.is_element() {
+            node.attributes().find_map(|attr| {
+                let name = attr.name();
+                match attr.namespace() {
+                    None | Some(XLINK_NAMESPACE) if name == "href" => external_href(attr.value()),
+                    None if name == "style" || URL_ATTRIBUTES.contains(&name) => {
+                        external_css(attr.value())
+                    }
+                    _ => None,
+                }
+            })
+        } else if node.is_text() && is_style_element(node.parent()) {
+            node.text().and_then(external_css)
+        } else if let Some(pi) = node.pi().filter(|pi| pi.target == "xml-stylesheet") {
+            pi.value
+                .and_then(|value| pseudo_attribute(value, "href"))
+                .and_then(external_href)
+        } else {
+            None
+        };
+        if let Some(href) = external {
+            let element = if node.is_text() {
+                node.parent().unwrap_or(node)
+            } else {
+                node
+            };
+            return Err(Error::ExternalReference {
+                href: href.to_owned(),
+                location: location::at(doc.input_text(), element.range().start),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn is_style_element(node: Option<Node<'_, '_>>) -> bool {
+    node.map_or(false, |node| {
+        node.is_element() && node.tag_name().name() == "style"
+    })
+}
+
+/// Returns `href` trimmed if it refers to the external resource.
+fn external_href(href: &str) -> Option<&str> {
+    let href = href.trim_matches(|c: char| c.is_ascii_whitespace());
+    let is_local = href.is_empty()
+        || href.starts_with('#')
+        || href
+            .get(..5)
+            .map_or(false, |scheme| scheme.eq_ignore_ascii_case("data:"));
+    (!is_local).then_some(href)
+}
+
+/// Returns the value of the pseudo-attribute `name` in the content of the
+/// processing instruction.
+///
+/// Returns [`None`] if the pseudo-attributes before `name` are malformed.
+fn pseudo_attribute<'a>(mut content: &'a str, name: &str) -> Option<&'a str> {
+    loop {
+        content = trim_start(content);
+        let name_end = content.find(|c: char| c.is_ascii_whitespace() || c == '=')?;
+        let (attr_name, rest) = content.split_at(name_end);
+        content = trim_start(trim_start(rest).strip_prefix('=')?);
+        if !content.starts_with(['"', '\'']) {
+            return None;
+        }
+        let (value, rest) = string(content);
+        if attr_name == name {
+            return Some(value);
+        }
+        content = rest;
+    }
+}
+
+/// Returns the first reference to the external resource in `css`.
+///
+/// This does not parse `css`, so the references in the comments and the
+/// strings are also returned.
+fn external_css(css: &str) -> Option<&str> {
+    let mut rest = css;
+    while let Some(pos) = rest.find(['u', 'U', '@']) {
+        let (is_url, prefix_len) = if starts_with_ignore_case(&rest[pos..], "url(") {
+            (true, 4)
+        } else if starts_with_ignore_case(&rest[pos..], "@import") {
+            (false, 7)
+        } else {
+            rest = &rest[pos + 1..];
+            continue;
+        };
+        rest = trim_start(&rest[pos + prefix_len..]);
+        let (href, remainder) = if is_url {
+            url_argument(rest)
+        } else if rest.starts_with(['"', '\'']) {
+            string(rest)
+        } else {
+            // `@import url(...)` is tested by the next iteration.
+            continue;
+        };
+        if let Some(href) = external_href(href) {
+            return Some(href);
+        }
+        rest = remainder;
+    }
+    None
+}
+
+/// Splits the argument of the `url()` function at the start of `css` and the
+/// rest.
+fn url_argument(css: &str) -> (&str, &str) {
+    if css.starts_with(['"', '\'']) {
+        return string(css);
+    }
+    css.find(')')
+        .map_or((css, ""), |end| (&css[..end], &css[end + 1..]))
+}
+
+/// Splits the quoted string at the start of `css` and the rest.
+fn string(css: &str) -> (&str, &str) {
+    let quote = css.as_bytes()[0] as char;
+    let css = &css[1..];
+    css.find(quote)
+        .map_or((css, ""), |end| (&css[..end], &css[end + 1..]))
+}
+
+fn starts_with_ignore_case(s: &str, prefix: &str) -> bool {
+    s.get(..prefix.len())
+        .map_or(false, |s| s.eq_ignore_ascii_case(prefix))
+}
+
+fn trim_start(s: &str) -> &str {
+    s.trim_start_matches(|c: char| c.is_ascii_whitespace())
+}
@@ -0,0 +1,137 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! [`proptest`] strategies for generating the SVG and non-SVG data.
+//!
+//! This module is enabled by the `proptest` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use is_svg::strategy;
+//! use proptest::{prop_assert, proptest};
+//!
+//! proptest!(|(svg in strategy::arb_valid_svg())| {
+//!     prop_assert!(is_svg::is_svg_string(&svg));
+//! });
+//! ```
+
+use std::{fmt::Write as _, io::Write};
+
+use flate2::{write::GzEncoder, Compression};
+use proptest::{
+    collection, option, prop_oneof,
+    strategy::{Just, Strategy},
+};
+
+/// Returns a strategy which generates a small valid [SVG] document.
+///
+/// The generated document has a random size and zero or more random shapes
+/// (`rect`, `circle`, `ellipse`, `line` and `path`) with random presentation
+/// attributes. The generated document is always accepted by
+/// [`is_svg_string`](crate::is_svg_string()).
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+pub fn arb_valid_svg() -> impl Strategy<Value = String> {
+    (1_u32..=1000, 1_u32..=1000, collection::vec(arb_shape(), 0..8)).prop_map(
+        |(width, height, shapes)| {
+            format!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">{}</svg>"#,
+                shapes.concat()
+            )
+        },
+    )
+}
+
+/// Returns a strategy which generates a [gzip-compressed] SVG image
+/// (`.svgz`).
+///
+/// The generated data is the document generated by [`arb_valid_svg`] which is
+/// compressed with gzip, so it is always accepted by
+/// [`is_svgz`](crate::is_svgz()).
+///
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+pub fn arb_svgz() -> impl Strategy<Value = Vec<u8>> {
+    arb_valid_svg().prop_map(|svg| compress(svg.as_bytes()))
+}
+
+/// Returns a strategy which generates a data which is not a valid [SVG] data.
+///
+/// The generated data is one of the random bytes, a truncated SVG document, a
+/// SVG document with an unknown root element, and the random bytes following
+/// the magic number of other image formats. The generated data is never
+/// accepted by [`is_svg`](crate::is_svg()).
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+pub fn arb_non_svg() -> impl Strategy<Value = Vec<u8>> {
+    prop_oneof![
+        collection::vec(proptest::num::u8::ANY, 0..256)
+            .prop_filter("must not be a valid SVG data", |data| !crate::is_svg(data)),
+        (arb_valid_svg(), proptest::num::usize::ANY).prop_map(|(svg, len)| {
+            let mut data = svg.into_bytes();
+            data.truncate(len % data.len());
+            data
+        }),
+        arb_valid_svg().prop_map(|svg| svg.replace("svg", "html").into_bytes()),
+        (
+            prop_oneof![
+                Just(&b"\x89PNG\r\n\x1a\n"[..]),
+                Just(&b"GIF89a"[..]),
+                Just(&b"\xff\xd8\xff"[..]),
+                Just(&b"RIFF\0\0\0\0WEBP"[..]),
+                Just(&b"\xff\x0a"[..]),
+            ],
+            collection::vec(proptest::num::u8::ANY, 0..256)
+        )
+            .prop_map(|(magic, rest)| [magic, &rest].concat()),
+    ]
+}
+
+fn arb_shape() -> impl Strategy<Value = String> {
+    let coord = || 0_u32..1000;
+    let len = || 1_u32..1000;
+    let shape = prop_oneof![
+        (coord(), coord(), len(), len())
+            .prop_map(|(x, y, w, h)| format!(r#"<rect x="{x}" y="{y}" width="{w}" height="{h}""#)),
+        (coord(), coord(), len())
+            .prop_map(|(cx, cy, r)| format!(r#"<circle cx="{cx}" cy="{cy}" r="{r}""#)),
+        (coord(), coord(), len(), len()).prop_map(|(cx, cy, rx, ry)| format!(
+            r#"<ellipse cx="{cx}" cy="{cy}" rx="{rx}" ry="{ry}""#
+        )),
+        (coord(), coord(), coord(), coord()).prop_map(|(x1, y1, x2, y2)| format!(
+            r#"<line x1="{x1}" y1="{y1}" x2="{x2}" y2="{y2}" stroke="black""#
+        )),
+        collection::vec((coord(), coord()), 1..8).prop_map(|points| {
+            let data: Vec<_> = points.iter().map(|(x, y)| format!("{x} {y}")).collect();
+            format!(r#"<path d="M {} Z""#, data.join(" L "))
+        }),
+    ];
+    let fill = option::of(prop_oneof![
+        Just(String::from("none")),
+        Just(String::from("red")),
+        Just(String::from("currentColor")),
+        proptest::num::u32::ANY.prop_map(|color| format!("#{:06x}", color & 0x00ff_ffff)),
+    ]);
+    let opacity = option::of(0_u8..=100);
+    (shape, fill, opacity).prop_map(|(shape, fill, opacity)| {
+        let mut shape = shape;
+        if let Some(fill) = fill {
+            write!(shape, r#" fill="{fill}""#).expect("writing to `String` should never fail");
+        }
+        if let Some(opacity) = opacity {
+            write!(shape, r#" opacity="{}""#, f32::from(opacity) / 100.0)
+                .expect("writing to `String` should never fail");
+        }
+        shape.push_str("/>");
+        shape
+    })
+}
+
+fn compress(data: &[u8]) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(data)
+        .and_then(|()| encoder.finish())
+        .expect("writing to `Vec` should never fail")
+}
@@ -0,0 +1,37 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Detection of the elements which are only defined in SVG 2.
+//!
+//! SVG 2 adds the paint servers such as the mesh gradients and the hatches,
+//! which Inkscape 1.x can emit, but `usvg` does not render them. They are
+//! ignored like the unknown elements, and the shapes which are painted with
+//! them fall back to their fallback colors or are not painted, so such a
+//! document is still a valid SVG data, which is only partially rendered.
+
+use usvg::roxmltree::Document;
+
+use crate::SVG_NAMESPACE;
+
+/// The elements which are only defined in SVG 2 and are not rendered by
+/// `usvg`, in the alphabetical order.
+const SVG2_ONLY_ELEMENTS: [&str; 6] = [
+    "hatch",
+    "hatchpath",
+    "meshgradient",
+    "meshpatch",
+    "meshrow",
+    "solidcolor",
+];
+
+/// Returns [`true`] if `doc` has any of [`SVG2_ONLY_ELEMENTS`] in the SVG
+/// namespace.
+pub fn has_svg2_only_elements(doc: &Document<'_>) -> bool {
+    doc.descendants().any(|node| {
+        let name = node.tag_name();
+        node.is_element()
+            && name.namespace() == Some(SVG_NAMESPACE)
+            && SVG2_ONLY_ELEMENTS.binary_search(&name.name()).is_ok()
+    })
+}
@@ -0,0 +1,90 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Sample data for testing the code which uses this crate.
+//!
+//! This module is enabled by the `test-util` feature, and is intended to be
+//! used from tests of the downstream crates.
+//!
+//! # Examples
+//!
+//! ```
+//! use is_svg::test_util;
+//!
+//! assert!(is_svg::is_svg_string(test_util::SAMPLE_SVG));
+//! # #[cfg(feature = "gzip")]
+//! assert!(is_svg::is_svgz(test_util::SAMPLE_SVGZ));
+//! assert!(!is_svg::is_svg(test_util::SAMPLE_PNG));
+//!
+//! assert!(is_svg::is_svg(test_util::minimal_svg(100, 50)));
+//! ```
+
+/// A small valid [SVG] image.
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+pub const SAMPLE_SVG: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 16 16"><rect width="16" height="16"/></svg>
+"#;
+
+/// [`SAMPLE_SVG`] compressed as a [gzip-compressed] SVG image (`.svgz`).
+///
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+pub const SAMPLE_SVGZ: &[u8] = &[
+    0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xb3, 0x29, 0x2e, 0x4b, 0x57, 0xa8,
+    0xc8, 0xcd, 0xc9, 0x2b, 0xb6, 0x55, 0xca, 0x28, 0x29, 0x29, 0xb0, 0xd2, 0xd7, 0x2f, 0x2f, 0x2f,
+    0xd7, 0x2b, 0x37, 0xd6, 0xcb, 0x2f, 0x4a, 0xd7, 0x37, 0x32, 0x30, 0x30, 0xd0, 0x07, 0xaa, 0x50,
+    0x52, 0x28, 0xcb, 0x4c, 0x2d, 0x77, 0xca, 0xaf, 0xb0, 0x55, 0x32, 0x50, 0x30, 0x50, 0x30, 0x34,
+    0x03, 0x22, 0x25, 0x3b, 0x9b, 0xa2, 0xd4, 0xe4, 0x12, 0x85, 0xf2, 0xcc, 0x94, 0x92, 0x0c, 0x5b,
+    0x25, 0xa0, 0x80, 0x42, 0x46, 0x6a, 0x66, 0x7a, 0x46, 0x09, 0x98, 0xad, 0x6f, 0x67, 0x03, 0xd2,
+    0x68, 0xc7, 0x05, 0x00, 0x1d, 0x27, 0x42, 0x44, 0x61, 0x00, 0x00, 0x00,
+];
+
+/// A 1x1 transparent [PNG] image.
+///
+/// [PNG]: https://www.w3.org/TR/png-3/
+pub const SAMPLE_PNG: &[u8] = &[
+    0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x06, 0x00, 0x00, 0x00, 0x1f, 0x15, 0xc4,
+    0x89, 0x00, 0x00, 0x00, 0x0b, 0x49, 0x44, 0x41, 0x54, 0x78, 0x9c, 0x63, 0x60, 0x00, 0x02, 0x00,
+    0x00, 0x05, 0x00, 0x01, 0x7a, 0x5e, 0xab, 0x3f, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4e, 0x44,
+    0xae, 0x42, 0x60, 0x82,
+];
+
+/// An invalid SVG data whose root element is not closed.
+pub const UNCLOSED_SVG: &str = r#"<svg version="1.1" viewBox="-4 -4 8 8" xmlns="http://www.w3.org/2000/svg">
+"#;
+
+/// An invalid SVG data which only has the document type declaration.
+pub const DOCTYPE_ONLY_SVG: &str = r#"<!DOCTYPE svg PUBLIC "-//W3C//DTD SVG 1.1//EN" "http://www.w3.org/Graphics/SVG/1.1/DTD/svg11.dtd">
+"#;
+
+/// A [gzip-compressed] empty data.
+///
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+pub const EMPTY_GZIP: &[u8] = &[
+    0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00,
+];
+
+/// Returns a minimal valid [SVG] image of the given size.
+///
+/// The returned image has a `width` by `height` `viewBox` and no content. If
+/// either `width` or `height` is zero, the image is not a valid SVG image.
+///
+/// # Examples
+///
+/// ```
+/// # use is_svg::{test_util, usvg::Size};
+/// #
+/// let tree = is_svg::parse_svg(test_util::minimal_svg(100, 50)).unwrap();
+/// assert_eq!(tree.size(), Size::from_wh(100.0, 50.0).unwrap());
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+#[must_use]
+#[inline]
+pub fn minimal_svg(width: u32, height: u32) -> String {
+    format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}"/>"#
+    )
+}
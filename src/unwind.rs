@@ -0,0 +1,22 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Containment of the panics from the dependencies.
+
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::Error;
+
+/// Calls `f`, and returns [`Error::Panicked`] if it panics.
+///
+/// This is used for every call into [`usvg`], [`roxmltree`](usvg::roxmltree)
+/// and [`flate2`], so that a bug in them cannot unwind out of the public API
+/// on hostile input. `f` must not call any code which is provided by the
+/// caller of the public API, such as a reader, since a panic from it has to be
+/// propagated as is.
+pub fn contain<T>(f: impl FnOnce() -> Result<T, Error>) -> Result<T, Error> {
+    // The state which `f` may leave inconsistent is discarded, or is not used
+    // again once `Err` is returned.
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or(Err(Error::Panicked))
+}
@@ -0,0 +1,78 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Transcoding of the UTF-16 encoded data.
+//!
+//! The encoding is detected as described in [Appendix F] of the XML
+//! specification: from the byte order mark, or from the `<?` which the XML
+//! declaration starts with if there is no byte order mark. The other data is
+//! treated as UTF-8.
+//!
+//! [Appendix F]: https://www.w3.org/TR/xml/#sec-guessing-no-ext-info
+
+use std::{borrow::Cow, str};
+
+use crate::Error;
+
+/// The byte order of the UTF-16 encoded data.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Endian {
+    Little,
+    Big,
+}
+
+impl Endian {
+    const fn unit(self, bytes: [u8; 2]) -> u16 {
+        match self {
+            Self::Little => u16::from_le_bytes(bytes),
+            Self::Big => u16::from_be_bytes(bytes),
+        }
+    }
+}
+
+/// Returns the byte order and the length of the byte order mark if `data` is
+/// UTF-16 encoded, and [`None`] otherwise.
+pub fn detect(data: &[u8]) -> Option<(Endian, usize)> {
+    match data {
+        [0xff, 0xfe, ..] => Some((Endian::Little, 2)),
+        [0xfe, 0xff, ..] => Some((Endian::Big, 2)),
+        [b'<', 0x00, b'?', 0x00, ..] => Some((Endian::Little, 0)),
+        [0x00, b'<', 0x00, b'?', ..] => Some((Endian::Big, 0)),
+        _ => None,
+    }
+}
+
+/// Returns [`true`] if `data` is too short to tell whether it is UTF-16
+/// encoded.
+pub fn is_undecided(data: &[u8]) -> bool {
+    let markers: [&[u8]; 4] = [b"\xff\xfe", b"\xfe\xff", b"<\0?\0", b"\0<\0?"];
+    data.len() < 4 && markers.iter().any(|marker| marker.starts_with(data))
+}
+
+/// Converts `data` to a string, transcoding it if it is UTF-16 encoded, or if
+/// the `encoding` feature is enabled and it declares the other encoding.
+///
+/// The byte order mark of the UTF-16 encoded data is removed.
+pub fn to_str(data: &[u8]) -> Result<Cow<'_, str>, Error> {
+    let Some((endian, bom_len)) = detect(data) else {
+        #[cfg(feature = "encoding")]
+        if let Some(text) = crate::encoding::transcode(data)? {
+            return Ok(text);
+        }
+        return str::from_utf8(data)
+            .map(Cow::Borrowed)
+            .map_err(Error::NotUtf8);
+    };
+    let data = &data[bom_len..];
+    if data.len() % 2 != 0 {
+        return Err(Error::NotUtf16);
+    }
+    let units = data
+        .chunks_exact(2)
+        .map(|unit| endian.unit([unit[0], unit[1]]));
+    char::decode_utf16(units)
+        .collect::<Result<String, _>>()
+        .map(Cow::Owned)
+        .map_err(|_| Error::NotUtf16)
+}
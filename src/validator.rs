@@ -0,0 +1,1730 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A reusable validator.
+
+#[cfg(feature = "text")]
+use std::mem;
+use std::{
+    num::NonZeroUsize,
+    str,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+#[cfg(feature = "text")]
+use once_cell::sync::Lazy;
+use usvg::roxmltree;
+#[cfg(feature = "text")]
+use usvg::FontResolver;
+
+use crate::{
+    cache::{Cache, CacheStats},
+    depth, entity,
+    gzip::StreamPolicy,
+    limits::XmlLimits,
+    policy::{ElementPolicy, NamePattern},
+    CompressionKind, Decompressor, Error, Options, SvgKind,
+};
+
+/// A reusable validator for testing whether a given data is a [SVG] image.
+///
+/// Unlike the free functions of this crate, this holds the pre-built
+/// [`Options`], so it is not rebuilt for each call. This type is [`Send`] and
+/// [`Sync`], so one instance can be shared across threads. The settings are
+/// behind an [`Arc`], so cloning this is cheap, and the clones share the same
+/// [`Options`] and the same font database.
+///
+/// # Examples
+///
+/// ```
+/// # use is_svg::Validator;
+/// #
+/// let validator = Validator::builder().max_size(1024 * 1024).build();
+///
+/// assert!(validator.is_svg(include_str!("../tests/data/w3/svg-logo-v.svg")));
+/// assert!(!validator.is_svg(include_bytes!("../tests/data/w3/svg-logo-v.png")));
+///
+/// # #[cfg(feature = "gzip")]
+/// assert!(validator.is_svg(include_bytes!("../tests/data/w3/svg-logo-v.svgz")));
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+#[derive(Clone, Debug)]
+pub struct Validator {
+    inner: Arc<Inner>,
+}
+
+/// The settings of [`Validator`], which are shared between the clones.
+#[derive(Debug)]
+struct Inner {
+    options: Options<'static>,
+    max_size: Option<u64>,
+    max_decompressed_size: u64,
+    gzip_policy: StreamPolicy,
+    accepted_compressions: Vec<CompressionKind>,
+    xml_limits: XmlLimits<'static>,
+    timeout: Option<Duration>,
+    element_policy: Option<ElementPolicy>,
+    decompressor: Option<Box<dyn Decompressor>>,
+    cache: Option<Cache>,
+}
+
+impl Validator {
+    /// Creates a new `Validator` with the default settings.
+    ///
+    /// This is the same as `Validator::builder().build()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::Validator;
+    /// #
+    /// let validator = Validator::new();
+    /// assert!(validator.is_svg(include_str!("../tests/data/w3/svg-logo-v.svg")));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`ValidatorBuilder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::Validator;
+    /// #
+    /// let validator = Validator::builder().max_size(1024 * 1024).build();
+    /// assert!(validator.is_svg(include_str!("../tests/data/w3/svg-logo-v.svg")));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn builder() -> ValidatorBuilder {
+        ValidatorBuilder::new()
+    }
+
+    /// Creates a new `Validator` with the strict settings.
+    ///
+    /// This is the same as `ValidatorBuilder::strict().build()`. See
+    /// [`ValidatorBuilder::strict`] for the settings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::Validator;
+    /// #
+    /// let validator = Validator::strict();
+    /// assert!(validator.is_svg(include_str!("../tests/data/w3/svg-logo-v.svg")));
+    /// assert!(!validator.is_svg(include_str!("../tests/data/resources/dtd.svg")));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn strict() -> Self {
+        ValidatorBuilder::strict().build()
+    }
+
+    /// Creates a new `Validator` with the lenient settings.
+    ///
+    /// This is the same as `ValidatorBuilder::lenient().build()`. See
+    /// [`ValidatorBuilder::lenient`] for the settings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::Validator;
+    /// #
+    /// let validator = Validator::lenient();
+    /// assert!(validator.is_svg(include_str!("../tests/data/w3/svg-logo-v.svg")));
+    /// assert!(validator.is_svg(r#"<svg width="24" height="24"/>"#));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn lenient() -> Self {
+        ValidatorBuilder::lenient().build()
+    }
+
+    /// Returns [`true`] if `data` is a valid [SVG] data, and [`false`]
+    /// otherwise.
+    ///
+    /// See [`is_svg`](crate::is_svg()) for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::Validator;
+    /// #
+    /// let validator = Validator::new();
+    ///
+    /// assert!(validator.is_svg(include_str!("../tests/data/w3/svg-logo-v.svg")));
+    /// assert!(!validator.is_svg(include_bytes!("../tests/data/w3/svg-logo-v.png")));
+    ///
+    /// # #[cfg(feature = "gzip")]
+    /// assert!(validator.is_svg(include_bytes!("../tests/data/w3/svg-logo-v.svgz")));
+    /// ```
+    ///
+    /// [SVG]: https://www.w3.org/Graphics/SVG/
+    #[inline]
+    pub fn is_svg(&self, data: impl AsRef<[u8]>) -> bool {
+        self.detect(data).is_some()
+    }
+
+    /// Returns [`true`] if `data` is a valid non [gzip-compressed] [SVG] data
+    /// (`.svg`) which is a valid UTF-8 as a whole, and [`false`] otherwise.
+    ///
+    /// See [`is_svg_string`](crate::is_svg_string()) for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::Validator;
+    /// #
+    /// let validator = Validator::new();
+    ///
+    /// assert!(validator.is_svg_string(include_str!("../tests/data/w3/svg-logo-v.svg")));
+    /// assert!(!validator.is_svg_string(include_bytes!("../tests/data/w3/svg-logo-v.png")));
+    ///
+    /// assert!(!validator.is_svg_string(include_bytes!("../tests/data/w3/svg-logo-v.svgz")));
+    /// ```
+    ///
+    /// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+    /// [SVG]: https://www.w3.org/Graphics/SVG/
+    #[inline]
+    pub fn is_svg_string(&self, data: impl AsRef<[u8]>) -> bool {
+        let data = data.as_ref();
+        str::from_utf8(data).is_ok() && self.detect(data) == Some(SvgKind::Svg)
+    }
+
+    /// Returns [`true`] if `data` is a valid [gzip-compressed] [SVG] data
+    /// (`.svgz`), and [`false`] otherwise.
+    ///
+    /// See [`is_svgz`](crate::is_svgz()) for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::Validator;
+    /// #
+    /// let validator = Validator::new();
+    ///
+    /// # #[cfg(feature = "gzip")]
+    /// assert!(validator.is_svgz(include_bytes!("../tests/data/w3/svg-logo-v.svgz")));
+    /// assert!(!validator.is_svgz(include_bytes!("../tests/data/w3/svg-logo-v.png")));
+    ///
+    /// assert!(!validator.is_svgz(include_str!("../tests/data/w3/svg-logo-v.svg")));
+    /// ```
+    ///
+    /// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+    /// [SVG]: https://www.w3.org/Graphics/SVG/
+    #[inline]
+    pub fn is_svgz(&self, data: impl AsRef<[u8]>) -> bool {
+        let data = data.as_ref();
+        crate::has_gzip_magic(data) && self.is_svg(data)
+    }
+
+    /// Detects the kind of the [SVG] image.
+    ///
+    /// Returns [`None`] if `data` is not a valid SVG data. The compression
+    /// format which was found is returned by [`SvgKind::compression`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::{SvgKind, Validator};
+    /// #
+    /// let validator = Validator::new();
+    ///
+    /// assert_eq!(
+    ///     validator.detect(include_str!("../tests/data/w3/svg-logo-v.svg")),
+    ///     Some(SvgKind::Svg)
+    /// );
+    /// # #[cfg(feature = "gzip")]
+    /// assert_eq!(
+    ///     validator.detect(include_bytes!("../tests/data/w3/svg-logo-v.svgz")),
+    ///     Some(SvgKind::Svgz)
+    /// );
+    ///
+    /// assert_eq!(
+    ///     validator.detect(include_bytes!("../tests/data/w3/svg-logo-v.png")),
+    ///     None
+    /// );
+    /// ```
+    ///
+    /// [SVG]: https://www.w3.org/Graphics/SVG/
+    #[inline]
+    pub fn detect(&self, data: impl AsRef<[u8]>) -> Option<SvgKind> {
+        let data = data.as_ref();
+        if !self.may_be_deflated(data)
+            && crate::heuristic::is_obviously_not_xml(data, self.inner.decompressor.as_deref())
+        {
+            return None;
+        }
+        let Some(cache) = &self.inner.cache else {
+            return self.validate(data).ok();
+        };
+        cache
+            .get(data)
+            .map_or_else(|| self.validate_and_cache(cache, data).ok(), Result::ok)
+    }
+
+    /// Tests whether `data` is a valid [SVG] data.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if any of the following are true:
+    ///
+    /// - The size of `data` exceeds the maximum size.
+    /// - `data` exceeds the other limits, or is rejected by the other options
+    ///   of this validator (e.g., [`ValidatorBuilder::reject_empty`]).
+    /// - `data` is not a valid SVG data. See [`check_svg`](crate::check_svg())
+    ///   for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::{Error, Limit, Validator};
+    /// #
+    /// let validator = Validator::builder().max_size(1024).build();
+    ///
+    /// assert!(validator
+    ///     .check_svg(include_str!("../tests/data/usvg/path-simple-case.svg"))
+    ///     .is_ok());
+    /// assert!(matches!(
+    ///     validator.check_svg(include_str!("../tests/data/w3/svg-logo-v.svg")),
+    ///     Err(Error::LimitExceeded(Limit::InputSize(1024)))
+    /// ));
+    /// ```
+    ///
+    /// [SVG]: https://www.w3.org/Graphics/SVG/
+    #[inline]
+    pub fn check_svg(&self, data: impl AsRef<[u8]>) -> Result<(), Error> {
+        let data = data.as_ref();
+        let Some(cache) = &self.inner.cache else {
+            return self.validate(data).map(|_| ());
+        };
+        match cache.get(data) {
+            Some(Ok(_)) => Ok(()),
+            // The details of the error are not cached, so the data is validated
+            // again.
+            Some(Err(_)) => self.validate(data).map(|_| ()),
+            None => self.validate_and_cache(cache, data).map(|_| ()),
+        }
+    }
+
+    /// Tests whether `data` is a valid [SVG] data, and returns its intrinsic
+    /// width and height in pixels.
+    ///
+    /// The absolute units are converted at the DPI of this validator, which is
+    /// set by [`ValidatorBuilder::dpi`]. See
+    /// [`dimensions`](crate::dimensions()) for more details. The cache of this
+    /// validator is not used.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `data` is not a valid SVG data. See
+    /// [`check_svg`](Self::check_svg) for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::Validator;
+    /// #
+    /// let svg = r#"<svg xmlns="http://www.w3.org/2000/svg" width="1in" height="72pt"/>"#;
+    ///
+    /// assert_eq!(
+    ///     Validator::new().dimensions(svg).unwrap(),
+    ///     Some((96.0, 96.0))
+    /// );
+    /// assert_eq!(
+    ///     Validator::builder()
+    ///         .dpi(300.0)
+    ///         .build()
+    ///         .dimensions(svg)
+    ///         .unwrap(),
+    ///     Some((300.0, 300.0))
+    /// );
+    /// ```
+    ///
+    /// [SVG]: https://www.w3.org/Graphics/SVG/
+    #[inline]
+    pub fn dimensions(&self, data: impl AsRef<[u8]>) -> Result<Option<(f32, f32)>, Error> {
+        let dpi = self.inner.options.dpi;
+        self.validate_with(data.as_ref(), |doc| crate::info::dimensions(doc, dpi))
+            .map(|(dimensions, _)| dimensions)
+    }
+
+    /// Returns the statistics of the cache of this validator, or [`None`] if
+    /// the cache is not enabled.
+    ///
+    /// The clones of this validator share the same cache.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::num::NonZeroUsize;
+    /// #
+    /// # use is_svg::Validator;
+    /// #
+    /// let validator = Validator::builder().cache(NonZeroUsize::new(1024)).build();
+    /// let svg = include_str!("../tests/data/w3/svg-logo-v.svg");
+    ///
+    /// assert!(validator.is_svg(svg));
+    /// assert!(validator.is_svg(svg));
+    ///
+    /// let stats = validator.cache_stats().unwrap();
+    /// assert_eq!(stats.hits, 1);
+    /// assert_eq!(stats.misses, 1);
+    ///
+    /// assert!(Validator::new().cache_stats().is_none());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn cache_stats(&self) -> Option<CacheStats> {
+        self.inner.cache.as_ref().map(Cache::stats)
+    }
+
+    /// Returns [`true`] if `data` may be the zlib-compressed or the raw deflate
+    /// data which is accepted, which the heuristics cannot tell.
+    fn may_be_deflated(&self, data: &[u8]) -> bool {
+        let accepted = &self.inner.accepted_compressions;
+        accepted.contains(&CompressionKind::Deflate)
+            || (accepted.contains(&CompressionKind::Zlib)
+                && CompressionKind::from_magic(data) == CompressionKind::Zlib)
+    }
+
+    fn validate_and_cache(&self, cache: &Cache, data: &[u8]) -> Result<SvgKind, Error> {
+        let result = self.validate(data);
+        cache.insert(data, result.as_ref().copied().map_err(Error::kind));
+        result
+    }
+
+    fn validate(&self, data: &[u8]) -> Result<SvgKind, Error> {
+        self.validate_with(data, |_| ()).map(|((), kind)| kind)
+    }
+
+    /// Tests whether `data` is a valid [SVG] data, and returns the result of
+    /// `inspect`, which is called with the valid document, and the kind of
+    /// the SVG image.
+    ///
+    /// [SVG]: https://www.w3.org/Graphics/SVG/
+    fn validate_with<T: Send>(
+        &self,
+        data: &[u8],
+        inspect: impl Fn(&roxmltree::Document<'_>) -> T + Sync,
+    ) -> Result<(T, SvgKind), Error> {
+        let inner = &*self.inner;
+        if let Some(limit) = inner.max_size {
+            crate::check_input_size(data, limit)?;
+        }
+        let compression = CompressionKind::from_magic(data);
+        if !compression.is_supported() || !inner.accepted_compressions.contains(&compression) {
+            return Err(Error::UnsupportedCompression(compression));
+        }
+        let xml_limits = XmlLimits {
+            deadline: inner
+                .timeout
+                .and_then(|timeout| Instant::now().checked_add(timeout)),
+            element_policy: inner.element_policy.as_ref(),
+            ..inner.xml_limits
+        };
+        #[cfg(feature = "gzip")]
+        if compression == CompressionKind::Zlib {
+            return crate::validate_deflated(
+                data,
+                compression,
+                &inner.options,
+                inner.max_decompressed_size,
+                xml_limits,
+                &inspect,
+            );
+        }
+        let result = crate::validate_with(
+            data,
+            &inner.options,
+            inner.decompressor.as_deref(),
+            inner.max_decompressed_size,
+            inner.gzip_policy,
+            xml_limits,
+            &inspect,
+        );
+        // The raw deflate data has no magic number, so it is only tried if the
+        // data is not a valid SVG data as is. The error of the latter is kept,
+        // since most of such data is not compressed at all.
+        #[cfg(feature = "gzip")]
+        if result.is_err()
+            && compression == CompressionKind::None
+            && inner
+                .accepted_compressions
+                .contains(&CompressionKind::Deflate)
+        {
+            if let Ok(inspected) = crate::validate_deflated(
+                data,
+                CompressionKind::Deflate,
+                &inner.options,
+                inner.max_decompressed_size,
+                xml_limits,
+                &inspect,
+            ) {
+                return Ok(inspected);
+            }
+        }
+        result
+    }
+}
+
+impl Default for Validator {
+    #[inline]
+    fn default() -> Self {
+        ValidatorBuilder::new().build()
+    }
+}
+
+/// A builder for [`Validator`].
+///
+/// # Examples
+///
+/// ```
+/// # use is_svg::{Options, ValidatorBuilder};
+/// #
+/// let validator = ValidatorBuilder::new()
+///     .options(Options {
+///         resources_dir: Some("tests/data/w3".into()),
+///         ..Default::default()
+///     })
+///     .max_size(1024 * 1024)
+///     .build();
+/// assert!(validator.is_svg(include_str!("../tests/data/w3/svg-logo-v.svg")));
+/// ```
+#[derive(Debug)]
+pub struct ValidatorBuilder {
+    options: Options<'static>,
+    max_size: Option<u64>,
+    max_decompressed_size: u64,
+    gzip_policy: StreamPolicy,
+    accepted_compressions: Vec<CompressionKind>,
+    xml_limits: XmlLimits<'static>,
+    timeout: Option<Duration>,
+    element_policy: ElementPolicy,
+    decompressor: Option<Box<dyn Decompressor>>,
+    cache: Option<NonZeroUsize>,
+}
+
+impl ValidatorBuilder {
+    /// Creates a new `ValidatorBuilder` with the default settings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::ValidatorBuilder;
+    /// #
+    /// let validator = ValidatorBuilder::new().build();
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new `ValidatorBuilder` with the strict settings.
+    ///
+    /// This is for the untrusted data such as the uploaded images, and rejects
+    /// the data which would otherwise be accepted if it is unusual or can be
+    /// harmful when it is rendered by a browser. The settings are:
+    ///
+    /// | Setting                                                        | Value                        |
+    /// | -------------------------------------------------------------- | ---------------------------- |
+    /// | [`max_size`](Self::max_size)                                   | 1 MiB                        |
+    /// | [`max_decompressed_size`](Self::max_decompressed_size)         | 4 MiB                        |
+    /// | [`single_member_only`](Self::single_member_only)               | [`true`]                     |
+    /// | [`max_gzip_nesting`](Self::max_gzip_nesting)                   | 0                            |
+    /// | [`max_depth`](Self::max_depth)                                 | 256                          |
+    /// | [`max_elements`](Self::max_elements)                           | 100000                       |
+    /// | [`allow_dtd`](Self::allow_dtd)                                 | [`false`]                    |
+    /// | [`require_svg_namespace`](Self::require_svg_namespace)         | [`true`]                     |
+    /// | [`allow_missing_namespace`](Self::allow_missing_namespace)     | [`false`]                    |
+    /// | [`deny_elements`](Self::deny_elements)                         | `script` and `foreignObject` |
+    /// | [`allow_external_references`](Self::allow_external_references) | [`false`]                    |
+    ///
+    /// The other settings are the same as [`new`](Self::new), and can be
+    /// changed after this.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::ValidatorBuilder;
+    /// #
+    /// let validator = ValidatorBuilder::strict().build();
+    ///
+    /// assert!(validator.is_svg(include_str!("../tests/data/w3/svg-logo-v.svg")));
+    /// assert!(!validator.is_svg(include_str!(
+    ///     "../tests/data/wikipedia/SVG_animation_using_ES.svg"
+    /// )));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn strict() -> Self {
+        Self::new()
+            .max_size(1024 * 1024)
+            .max_decompressed_size(4 * 1024 * 1024)
+            .single_member_only(true)
+            .max_gzip_nesting(0)
+            .max_depth(256)
+            .max_elements(100_000)
+            .allow_dtd(false)
+            .require_svg_namespace(true)
+            .allow_missing_namespace(false)
+            .deny_elements(&["script", "foreignObject"])
+            .allow_external_references(false)
+    }
+
+    /// Creates a new `ValidatorBuilder` with the lenient settings.
+    ///
+    /// This accepts as many data as possible which can be rendered, such as
+    /// the SVG snippets which are copied from the design tools. The settings
+    /// are:
+    ///
+    /// | Setting                                                     | Value      |
+    /// | ----------------------------------------------------------- | ---------- |
+    /// | [`allow_dtd`](Self::allow_dtd)                              | [`true`]   |
+    /// | [`require_svg_namespace`](Self::require_svg_namespace)      | [`false`]  |
+    /// | [`allow_missing_namespace`](Self::allow_missing_namespace)  | [`true`]   |
+    /// | [`allow_trailing_garbage`](Self::allow_trailing_garbage)    | [`true`]   |
+    ///
+    /// The other settings, including the limits against the hostile data, are
+    /// the same as [`new`](Self::new), and can be changed after this.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::ValidatorBuilder;
+    /// #
+    /// let validator = ValidatorBuilder::lenient().build();
+    ///
+    /// assert!(validator.is_svg(r#"<svg width="24" height="24" viewBox="0 0 24 24"/>"#));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn lenient() -> Self {
+        Self::new()
+            .allow_dtd(true)
+            .require_svg_namespace(false)
+            .allow_missing_namespace(true)
+            .allow_trailing_garbage(true)
+    }
+
+    /// Sets the options which are passed to `usvg`.
+    ///
+    /// Note that custom options can change what is considered to be a valid
+    /// SVG data. See [`is_svg_with_options`](crate::is_svg_with_options()) for
+    /// more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::{Options, ValidatorBuilder};
+    /// #
+    /// let validator = ValidatorBuilder::new()
+    ///     .options(Options {
+    ///         resources_dir: Some("tests/data/w3".into()),
+    ///         ..Default::default()
+    ///     })
+    ///     .build();
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn options(mut self, options: Options<'static>) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Sets the languages which are used for evaluating the `systemLanguage`
+    /// attributes.
+    ///
+    /// Only one child of the `switch` element is converted, and the children
+    /// whose conditions are not met are ignored, so the verdict on the
+    /// document which has the `switch` elements does not depend on the
+    /// languages, unless the rendered content is tested (e.g.,
+    /// [`require_renderable_content`](Self::require_renderable_content)). This
+    /// overrides `Options::languages`, so this should be called after
+    /// [`options`](Self::options). By default, this is `["en"]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::ValidatorBuilder;
+    /// #
+    /// let validator = ValidatorBuilder::new()
+    ///     .languages(vec!["fr".into(), "en".into()])
+    ///     .build();
+    /// assert!(validator.is_svg(include_str!("../tests/data/switch/multilingual.svg")));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn languages(mut self, languages: Vec<String>) -> Self {
+        self.options.languages = languages;
+        self
+    }
+
+    /// Sets the DPI which is used for converting the absolute units (e.g.,
+    /// `mm`) to pixels.
+    ///
+    /// This affects the sizes returned by
+    /// [`Validator::dimensions`], and the sizes used by `usvg`, but not
+    /// whether a data is a valid SVG data. This overrides `Options::dpi`, so
+    /// this should be called after [`options`](Self::options). By default, this
+    /// is 96.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::ValidatorBuilder;
+    /// #
+    /// let validator = ValidatorBuilder::new().dpi(300.0).build();
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn dpi(mut self, dpi: f32) -> Self {
+        self.options.dpi = dpi;
+        self
+    }
+
+    /// Sets the font database which is used for converting the text elements.
+    ///
+    /// `fontdb` is shared rather than copied, so the font database that is
+    /// already loaded for rendering can be reused. This overrides
+    /// `Options::fontdb`, so this should be called after
+    /// [`options`](Self::options). See
+    /// [`is_svg_with_fontdb`](crate::is_svg_with_fontdb()) for the version of
+    /// [`fontdb`](crate::fontdb).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// #
+    /// # use is_svg::{fontdb::Database, ValidatorBuilder};
+    /// #
+    /// // The font database which is also used for rendering.
+    /// let fontdb = Arc::new(Database::new());
+    /// let validator = ValidatorBuilder::new().fontdb(fontdb).build();
+    /// assert!(validator.is_svg(include_str!("../tests/data/usvg/text-simple-case.svg")));
+    /// ```
+    #[cfg(feature = "text")]
+    #[must_use]
+    #[inline]
+    pub fn fontdb(mut self, fontdb: Arc<crate::fontdb::Database>) -> Self {
+        self.options.fontdb = fontdb;
+        self
+    }
+
+    /// Sets the function which builds the font database which is used for
+    /// converting the text elements.
+    ///
+    /// Unlike [`fontdb`](Self::fontdb), `init` is not called until the first
+    /// data which has any text element is validated, and then the built font
+    /// database is shared by all the following calls and the clones of the
+    /// [`Validator`]. So the cost of loading the fonts (e.g., the system fonts)
+    /// is not paid if only the data without the text elements is validated.
+    ///
+    /// This wraps `Options::font_resolver`, and the font database replaces
+    /// `Options::fontdb` while converting the text elements, so this should be
+    /// called after [`options`](Self::options) and [`fontdb`](Self::fontdb).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::{fontdb::Database, ValidatorBuilder};
+    /// #
+    /// let validator = ValidatorBuilder::new()
+    ///     .fontdb_with(|| {
+    ///         let fontdb = Database::new();
+    ///         // Load the fonts here.
+    ///         fontdb
+    ///     })
+    ///     .build();
+    /// // The fonts are not loaded for this.
+    /// assert!(validator.is_svg(include_str!("../tests/data/w3/svg-logo-v.svg")));
+    /// // The fonts are loaded for this.
+    /// assert!(validator.is_svg(include_str!("../tests/data/usvg/text-simple-case.svg")));
+    /// ```
+    #[cfg(feature = "text")]
+    #[must_use]
+    pub fn fontdb_with(
+        mut self,
+        init: impl FnOnce() -> crate::fontdb::Database + Send + 'static,
+    ) -> Self {
+        type Init = Box<dyn FnOnce() -> Arc<crate::fontdb::Database> + Send>;
+
+        /// Replaces `fontdb` with the built font database, unless it already
+        /// is.
+        fn load(
+            lazy: &Lazy<Arc<crate::fontdb::Database>, Init>,
+            fontdb: &mut Arc<crate::fontdb::Database>,
+        ) {
+            if !Arc::ptr_eq(fontdb, lazy) {
+                *fontdb = Arc::clone(lazy);
+            }
+        }
+
+        let lazy: Arc<Lazy<_, Init>> = Arc::new(Lazy::new(Box::new(move || Arc::new(init()))));
+        let FontResolver {
+            select_font,
+            select_fallback,
+        } = mem::take(&mut self.options.font_resolver);
+        let fallback_lazy = Arc::clone(&lazy);
+        self.options.font_resolver = FontResolver {
+            select_font: Box::new(move |font, fontdb| {
+                load(&lazy, fontdb);
+                select_font(font, fontdb)
+            }),
+            select_fallback: Box::new(move |c, exclude_fonts, fontdb| {
+                load(&fallback_lazy, fontdb);
+                select_fallback(c, exclude_fonts, fontdb)
+            }),
+        };
+        self
+    }
+
+    /// Sets the maximum size of the data in bytes.
+    ///
+    /// The data larger than this is not considered to be a valid SVG data. The
+    /// size is tested before the data is decompressed or parsed, so for the
+    /// gzip-compressed SVG image (`.svgz`), this applies to the compressed
+    /// size. By default, the size of the data is unlimited.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::ValidatorBuilder;
+    /// #
+    /// let validator = ValidatorBuilder::new().max_size(1024).build();
+    ///
+    /// assert!(validator.is_svg(include_str!("../tests/data/usvg/path-simple-case.svg")));
+    /// assert!(!validator.is_svg(include_str!("../tests/data/w3/svg-logo-v.svg")));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn max_size(mut self, max_size: u64) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// Sets the maximum size of the decompressed data in bytes for the
+    /// [gzip-compressed] SVG image (`.svgz`).
+    ///
+    /// The decompression stops as soon as the decompressed data exceeds this,
+    /// so a small data which decompresses to a huge data (a decompression
+    /// bomb) is rejected without decompressing all of it. This also applies to
+    /// the [`Decompressor`] set by [`decompressor`](Self::decompressor). The
+    /// default is 64 MiB. To disable the limit, set this to [`u64::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::ValidatorBuilder;
+    /// #
+    /// let validator = ValidatorBuilder::new().max_decompressed_size(1024).build();
+    ///
+    /// assert!(validator.is_svg(include_str!("../tests/data/w3/svg-logo-v.svg")));
+    /// # #[cfg(feature = "gzip")]
+    /// assert!(!validator.is_svg(include_bytes!("../tests/data/w3/svg-logo-v.svgz")));
+    /// ```
+    ///
+    /// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+    #[must_use]
+    #[inline]
+    pub const fn max_decompressed_size(mut self, limit: u64) -> Self {
+        self.max_decompressed_size = limit;
+        self
+    }
+
+    /// Sets whether the [gzip-compressed] SVG image (`.svgz`) is required to
+    /// consist of only one gzip member.
+    ///
+    /// A gzip file can consist of multiple members, such as the files which
+    /// are concatenated by `cat`. By default, this is [`false`], and all the
+    /// members are decompressed and the concatenation of them is validated. If
+    /// this is set to [`true`], the data which has another member after the
+    /// end of the first member is rejected with
+    /// [`Error::GzipDecode`](crate::Error::GzipDecode). The data after the
+    /// first member which is not a gzip member is handled by
+    /// [`allow_trailing_garbage`](Self::allow_trailing_garbage). This has no
+    /// effect on the [`Decompressor`] set by
+    /// [`decompressor`](Self::decompressor).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::ValidatorBuilder;
+    /// #
+    /// let svgz = [
+    ///     &include_bytes!("../tests/data/w3/svg-logo-v.svgz")[..],
+    ///     include_bytes!("../tests/data/resources/empty.gz"),
+    /// ]
+    /// .concat();
+    ///
+    /// # #[cfg(feature = "gzip")]
+    /// assert!(ValidatorBuilder::new().build().is_svgz(&svgz));
+    /// assert!(!ValidatorBuilder::new()
+    ///     .single_member_only(true)
+    ///     .build()
+    ///     .is_svgz(&svgz));
+    /// ```
+    ///
+    /// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+    #[must_use]
+    #[inline]
+    pub const fn single_member_only(mut self, single_member: bool) -> Self {
+        self.gzip_policy.single_member = single_member;
+        self
+    }
+
+    /// Sets whether the garbage after the end of the [gzip-compressed] SVG
+    /// image (`.svgz`) is allowed.
+    ///
+    /// The data after the end of the last gzip member is a gzip member if it
+    /// starts with the gzip magic number, and otherwise it is garbage, such as
+    /// the padding of a truncated file or another file which is appended by
+    /// mistake. By default, this is [`false`], and the data which has such
+    /// garbage is rejected with
+    /// [`Error::GzipDecode`](crate::Error::GzipDecode). If this is set to
+    /// [`true`], the garbage is ignored, and only the gzip members before
+    /// it are validated. This has no effect on the [`Decompressor`] set by
+    /// [`decompressor`](Self::decompressor).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::ValidatorBuilder;
+    /// #
+    /// let mut svgz = include_bytes!("../tests/data/w3/svg-logo-v.svgz").to_vec();
+    /// svgz.extend_from_slice(&[0; 1024]);
+    ///
+    /// assert!(!ValidatorBuilder::new().build().is_svgz(&svgz));
+    /// # #[cfg(feature = "gzip")]
+    /// assert!(ValidatorBuilder::new()
+    ///     .allow_trailing_garbage(true)
+    ///     .build()
+    ///     .is_svgz(&svgz));
+    /// ```
+    ///
+    /// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+    #[must_use]
+    #[inline]
+    pub const fn allow_trailing_garbage(mut self, allow: bool) -> Self {
+        self.gzip_policy.allow_trailing_garbage = allow;
+        self
+    }
+
+    /// Sets the maximum number of the [gzip-compressed] layers inside the
+    /// outermost one which are decompressed.
+    ///
+    /// Some servers and exporters compress the SVG image which is already
+    /// gzip-compressed (`.svgz`), so the decompressed data is gzip-compressed
+    /// again. By default, this is 1, and such data is decompressed once more
+    /// and detected as [`SvgKind::NestedSvgz`]. The data which is nested more
+    /// deeply than this is rejected with
+    /// [`Limit::GzipNesting`](crate::Limit::GzipNesting). The limit of the
+    /// decompressed size applies to each layer. This also applies to the
+    /// [`Decompressor`] set by [`decompressor`](Self::decompressor).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::{Error, Limit, SvgKind, ValidatorBuilder};
+    /// #
+    /// # #[cfg(feature = "gzip")]
+    /// # {
+    /// let svgz = include_bytes!("../tests/data/hostile/nested.svgz");
+    ///
+    /// assert_eq!(
+    ///     ValidatorBuilder::new().build().detect(svgz),
+    ///     Some(SvgKind::NestedSvgz)
+    /// );
+    /// assert!(matches!(
+    ///     ValidatorBuilder::new()
+    ///         .max_gzip_nesting(0)
+    ///         .build()
+    ///         .check_svg(svgz),
+    ///     Err(Error::LimitExceeded(Limit::GzipNesting(0)))
+    /// ));
+    /// # }
+    /// ```
+    ///
+    /// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+    #[must_use]
+    #[inline]
+    pub const fn max_gzip_nesting(mut self, nesting: u8) -> Self {
+        self.gzip_policy.max_nesting = nesting;
+        self
+    }
+
+    /// Sets the compression formats which are accepted.
+    ///
+    /// The format of the data is detected from its magic number by
+    /// [`CompressionKind::from_magic`], and the data in the format which is
+    /// not in `kinds` is rejected with
+    /// [`Error::UnsupportedCompression`](crate::Error::UnsupportedCompression)
+    /// before decompressing, even if the format is supported. The data in the
+    /// format which is not supported (see [`CompressionKind::is_supported`]) is
+    /// always rejected with the same error. By default,
+    /// [`CompressionKind::None`] and [`CompressionKind::Gzip`] are accepted.
+    ///
+    /// [`CompressionKind::Zlib`] and [`CompressionKind::Deflate`] are only
+    /// accepted if they are in `kinds`. Since the raw deflate data has no
+    /// magic number, it is only decompressed if the data is not a valid SVG
+    /// data as is, and the error of the latter is returned if both fail. This
+    /// does not change [`Validator::is_svgz`], which only accepts the
+    /// gzip-compressed data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::{CompressionKind, Error, SvgKind, ValidatorBuilder};
+    /// #
+    /// let validator = ValidatorBuilder::new()
+    ///     .accepted_compressions(&[CompressionKind::None])
+    ///     .build();
+    ///
+    /// assert!(validator.is_svg(include_str!("../tests/data/w3/svg-logo-v.svg")));
+    /// assert!(matches!(
+    ///     validator.check_svg(include_bytes!("../tests/data/w3/svg-logo-v.svgz")),
+    ///     Err(Error::UnsupportedCompression(CompressionKind::Gzip))
+    /// ));
+    ///
+    /// # #[cfg(feature = "gzip")]
+    /// # {
+    /// let validator = ValidatorBuilder::new()
+    ///     .accepted_compressions(&[
+    ///         CompressionKind::None,
+    ///         CompressionKind::Gzip,
+    ///         CompressionKind::Zlib,
+    ///         CompressionKind::Deflate,
+    ///     ])
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     validator.detect(include_bytes!("../tests/data/deflate/svg-logo-v.svg.zz")),
+    ///     Some(SvgKind::ZlibSvg)
+    /// );
+    /// assert_eq!(
+    ///     validator.detect(include_bytes!(
+    ///         "../tests/data/deflate/svg-logo-v.svg.deflate"
+    ///     )),
+    ///     Some(SvgKind::DeflateSvg)
+    /// );
+    /// # }
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn accepted_compressions(mut self, kinds: &[CompressionKind]) -> Self {
+        self.accepted_compressions = kinds.to_vec();
+        self
+    }
+
+    /// Sets the maximum nesting depth of the elements.
+    ///
+    /// The data whose elements are nested deeper than this is rejected before
+    /// parsing, so the deeply nested data does not take the time to parse. The
+    /// depth is estimated, and the elements expanded from the entities are
+    /// counted as if each entity reference were nested 10 levels deep. The
+    /// default and the maximum is 4096, which is deeper than `usvg` accepts,
+    /// so a larger value is the same as 4096.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::{Error, Limit, ValidatorBuilder};
+    /// #
+    /// let validator = ValidatorBuilder::new().max_depth(2).build();
+    ///
+    /// assert!(validator
+    ///     .is_svg(r#"<svg xmlns="http://www.w3.org/2000/svg" width="1" height="1"><g/></svg>"#));
+    /// assert!(matches!(
+    ///     validator.check_svg(
+    ///         r#"<svg xmlns="http://www.w3.org/2000/svg" width="1" height="1"><g><g/></g></svg>"#
+    ///     ),
+    ///     Err(Error::LimitExceeded(Limit::Depth(2)))
+    /// ));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn max_depth(mut self, depth: usize) -> Self {
+        self.xml_limits.depth = if depth < depth::MAX_DEPTH {
+            depth
+        } else {
+            depth::MAX_DEPTH
+        };
+        self
+    }
+
+    /// Sets the maximum number of the elements.
+    ///
+    /// The data which has more elements than this is rejected before parsing,
+    /// so the data which has too many elements to render does not take the
+    /// time to parse, even if it is a valid SVG data. The elements in the
+    /// source document are counted, so the elements which are expanded from
+    /// the entities, or are instantiated by the `use` elements, are not
+    /// counted. By default, the number of the elements is unlimited.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::{Error, Limit, ValidatorBuilder};
+    /// #
+    /// let validator = ValidatorBuilder::new().max_elements(2).build();
+    ///
+    /// assert!(validator
+    ///     .is_svg(r#"<svg xmlns="http://www.w3.org/2000/svg" width="1" height="1"><g/></svg>"#));
+    /// assert!(matches!(
+    ///     validator.check_svg(
+    ///         r#"<svg xmlns="http://www.w3.org/2000/svg" width="1" height="1"><g/><g/></svg>"#
+    ///     ),
+    ///     Err(Error::LimitExceeded(Limit::Elements(2)))
+    /// ));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn max_elements(mut self, elements: usize) -> Self {
+        self.xml_limits.elements = elements;
+        self
+    }
+
+    /// Sets the maximum length of an attribute value in bytes.
+    ///
+    /// The data which has a longer attribute value than this, such as the
+    /// gigantic path data, is rejected before parsing. The length is measured
+    /// as the value is written in the source document, so the entity
+    /// references and the character references are not expanded. By default,
+    /// the length of an attribute value is unlimited.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::{Error, Limit, ValidatorBuilder};
+    /// #
+    /// let validator = ValidatorBuilder::new().max_attribute_len(32).build();
+    ///
+    /// assert!(validator.is_svg(
+    ///     r#"<svg xmlns="http://www.w3.org/2000/svg" width="1" height="1"><path d="M0 0H1V1Z"/></svg>"#
+    /// ));
+    /// assert!(matches!(
+    ///     validator.check_svg(
+    ///         r#"<svg xmlns="http://www.w3.org/2000/svg" width="1" height="1"><path d="M0 0H1V1H0V0H1V1H0V0H1V1H0V0H1V1H0V0Z"/></svg>"#
+    ///     ),
+    ///     Err(Error::LimitExceeded(Limit::AttributeLength(32)))
+    /// ));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn max_attribute_len(mut self, len: usize) -> Self {
+        self.xml_limits.attribute_len = len;
+        self
+    }
+
+    /// Sets the maximum depth of the nested entity references.
+    ///
+    /// The entities declared in the DTD can reference the other entities. The
+    /// data whose entity references are nested deeper than this is not
+    /// considered to be a valid SVG data. The default and the maximum is 10,
+    /// which is the depth that the XML parser supports, so a larger value is
+    /// the same as 10.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::{ErrorKind, ValidatorBuilder};
+    /// #
+    /// let svg = r#"<!DOCTYPE svg [<!ENTITY a "1"><!ENTITY b "&a;">]><svg xmlns="http://www.w3.org/2000/svg" width="&b;" height="1"/>"#;
+    /// assert!(ValidatorBuilder::new().build().is_svg(svg));
+    ///
+    /// let validator = ValidatorBuilder::new().max_entity_depth(1).build();
+    /// assert_eq!(
+    ///     validator.check_svg(svg).unwrap_err().kind(),
+    ///     ErrorKind::EntityExpansionLimit
+    /// );
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn max_entity_depth(mut self, depth: u32) -> Self {
+        self.xml_limits.entity_depth = if depth < entity::MAX_DEPTH {
+            depth
+        } else {
+            entity::MAX_DEPTH
+        };
+        self
+    }
+
+    /// Sets the maximum total size of the text which is expanded from the
+    /// entity references in bytes.
+    ///
+    /// The size is computed from the entity declarations before parsing, so a
+    /// small data whose entities expand to a huge text (e.g., [billion laughs])
+    /// is rejected without expanding them. The default is 1 MiB. To disable
+    /// the limit, set this to [`u64::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::{ErrorKind, ValidatorBuilder};
+    /// #
+    /// let svg = r#"<!DOCTYPE svg [<!ENTITY size "1024">]><svg xmlns="http://www.w3.org/2000/svg" width="&size;" height="&size;"/>"#;
+    /// assert!(ValidatorBuilder::new().build().is_svg(svg));
+    ///
+    /// let validator = ValidatorBuilder::new().max_entity_expansion(4).build();
+    /// assert_eq!(
+    ///     validator.check_svg(svg).unwrap_err().kind(),
+    ///     ErrorKind::EntityExpansionLimit
+    /// );
+    /// ```
+    ///
+    /// [billion laughs]: https://en.wikipedia.org/wiki/Billion_laughs_attack
+    #[must_use]
+    #[inline]
+    pub const fn max_entity_expansion(mut self, limit: u64) -> Self {
+        self.xml_limits.entity_expansion = limit;
+        self
+    }
+
+    /// Sets whether the data which has the document type declaration
+    /// (`<!DOCTYPE>`) is considered to be a valid SVG data.
+    ///
+    /// Many SVG images which were exported by the older editors have the
+    /// document type declaration of SVG 1.1, so this is allowed by default.
+    /// Both the external identifier and the internal subset are allowed, but
+    /// the external DTD and the external entities are never loaded. The
+    /// entities declared in the internal subset are expanded within the limits
+    /// set by [`max_entity_depth`](Self::max_entity_depth) and
+    /// [`max_entity_expansion`](Self::max_entity_expansion), so the namespaces
+    /// which are declared with the entities, as the Adobe Illustrator does, are
+    /// also resolved. The data which references the external entity is
+    /// rejected with [`Error::NotXml`](crate::Error::NotXml), since the entity
+    /// cannot be expanded without loading it. If this is set to
+    /// [`false`], the data which has the document type declaration is rejected
+    /// with [`Error::NotXml`](crate::Error::NotXml) before the entities are
+    /// expanded.
+    ///
+    /// Note that the data which only has the document type declaration is not
+    /// a valid SVG data in either case, since it does not have the root
+    /// element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::{usvg::roxmltree, Error, ValidatorBuilder};
+    /// #
+    /// let svg = r#"<!DOCTYPE svg PUBLIC "-//W3C//DTD SVG 1.1//EN" "http://www.w3.org/Graphics/SVG/1.1/DTD/svg11.dtd"><svg xmlns="http://www.w3.org/2000/svg" width="1" height="1"/>"#;
+    /// assert!(ValidatorBuilder::new().allow_dtd(true).build().is_svg(svg));
+    ///
+    /// let validator = ValidatorBuilder::new().allow_dtd(false).build();
+    /// assert!(matches!(
+    ///     validator.check_svg(svg),
+    ///     Err(Error::NotXml { source: roxmltree::Error::DtdDetected, .. })
+    /// ));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn allow_dtd(mut self, allow_dtd: bool) -> Self {
+        self.xml_limits.allow_dtd = allow_dtd;
+        self
+    }
+
+    /// Sets whether the root element is required to be the `svg` element in
+    /// the [SVG] namespace (`http://www.w3.org/2000/svg`).
+    ///
+    /// If this is set to [`true`], the name of the root element is tested
+    /// before converting the document to the tree, and the data whose root
+    /// element does not have the SVG namespace, or has the other namespace is
+    /// rejected with [`Error::NotSvgRoot`](crate::Error::NotSvgRoot)
+    /// regardless of whether `usvg` accepts it. The prefix is not significant,
+    /// so the root element such as `<s:svg xmlns:s="http://www.w3.org/2000/svg">`
+    /// is accepted. By default, this is [`false`], and such data is rejected
+    /// only if `usvg` rejects it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::{Error, ValidatorBuilder};
+    /// #
+    /// let validator = ValidatorBuilder::new().require_svg_namespace(true).build();
+    ///
+    /// assert!(
+    ///     validator.is_svg(r#"<s:svg xmlns:s="http://www.w3.org/2000/svg" width="1" height="1"/>"#)
+    /// );
+    /// assert!(matches!(
+    ///     validator.check_svg(r#"<svg width="1" height="1"/>"#),
+    ///     Err(Error::NotSvgRoot { .. })
+    /// ));
+    /// ```
+    ///
+    /// [SVG]: https://www.w3.org/Graphics/SVG/
+    #[must_use]
+    #[inline]
+    pub const fn require_svg_namespace(mut self, require: bool) -> Self {
+        self.xml_limits.require_svg_namespace = require;
+        self
+    }
+
+    /// Sets whether the data whose root element does not declare the
+    /// namespace is considered to be in the [SVG] namespace.
+    ///
+    /// The SVG snippets which are copied from the design tools often omit the
+    /// namespace declaration (e.g., `<svg viewBox="0 0 24 24">`). If this is
+    /// set to [`true`], the default namespace declaration of the SVG namespace
+    /// is added to such a root element before parsing, so the descendants are
+    /// also in the SVG namespace. This only applies to the root element whose
+    /// name is `svg` without the prefix, and which does not declare the default
+    /// namespace. The data whose root element declares the other default
+    /// namespace (e.g., `<svg xmlns="http://example.com/">`) is not changed,
+    /// and is still rejected. By default, this is [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::ValidatorBuilder;
+    /// #
+    /// let svg = r#"<svg width="24" height="24" viewBox="0 0 24 24"><path d="M12 8v4"/></svg>"#;
+    /// assert!(!ValidatorBuilder::new().build().is_svg(svg));
+    ///
+    /// let validator = ValidatorBuilder::new()
+    ///     .allow_missing_namespace(true)
+    ///     .build();
+    /// assert!(validator.is_svg(svg));
+    /// assert!(!validator.is_svg(
+    ///     r#"<svg xmlns="http://example.com/" width="24" height="24" viewBox="0 0 24 24"/>"#
+    /// ));
+    /// ```
+    ///
+    /// [SVG]: https://www.w3.org/Graphics/SVG/
+    #[must_use]
+    #[inline]
+    pub const fn allow_missing_namespace(mut self, allow: bool) -> Self {
+        self.xml_limits.allow_missing_namespace = allow;
+        self
+    }
+
+    /// Sets whether the SVG data which has no renderable content is
+    /// considered to be invalid.
+    ///
+    /// If this is set to [`true`], the data is rejected with
+    /// [`Error::EmptyDocument`](crate::Error::EmptyDocument) if the converted
+    /// tree has no nodes to render, such as the shapes, the text or the images.
+    /// So the data whose root element only has the `defs` element, the
+    /// comments, the whitespace, or the elements which are not rendered (e.g.,
+    /// `<rect width="0"/>`) is also rejected. Note that the text elements are
+    /// not rendered if the `text` feature is disabled, so such data is also
+    /// rejected. By default, this is [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::{ErrorKind, ValidatorBuilder};
+    /// #
+    /// let validator = ValidatorBuilder::new().reject_empty(true).build();
+    ///
+    /// assert!(validator.is_svg(include_str!("../tests/data/w3/svg-logo-v.svg")));
+    /// assert_eq!(
+    ///     validator
+    ///         .check_svg(r#"<svg xmlns="http://www.w3.org/2000/svg" width="1" height="1"/>"#)
+    ///         .unwrap_err()
+    ///         .kind(),
+    ///     ErrorKind::EmptyDocument
+    /// );
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn reject_empty(mut self, reject: bool) -> Self {
+        self.xml_limits.reject_empty = reject;
+        self
+    }
+
+    /// Sets whether the SVG data which renders to nothing is considered to be
+    /// invalid.
+    ///
+    /// If this is set to [`true`], the converted tree is inspected using the
+    /// bounding boxes of the nodes, and the data is rejected with
+    /// [`Error::NothingToRender`](crate::Error::NothingToRender) if no node is
+    /// visible on the canvas. For example, the node which is transparent
+    /// (e.g., in the group with `opacity="0"`), hidden, outside the canvas, or
+    /// clipped away entirely is not visible, and the root element whose
+    /// `viewBox` has zero width or height renders nothing. This is stricter
+    /// than [`reject_empty`](Self::reject_empty), but is still an estimation,
+    /// so the data which is invisible for the other reasons (e.g., masked
+    /// away) may be accepted. By default, this is [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::{ErrorKind, ValidatorBuilder};
+    /// #
+    /// let validator = ValidatorBuilder::new()
+    ///     .require_renderable_content(true)
+    ///     .build();
+    ///
+    /// assert!(validator.is_svg(include_str!("../tests/data/w3/svg-logo-v.svg")));
+    /// assert_eq!(
+    ///     validator
+    ///         .check_svg(
+    ///             r#"<svg xmlns="http://www.w3.org/2000/svg" width="1" height="1"><rect x="2" width="1" height="1"/></svg>"#
+    ///         )
+    ///         .unwrap_err()
+    ///         .kind(),
+    ///     ErrorKind::NothingToRender
+    /// );
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn require_renderable_content(mut self, require: bool) -> Self {
+        self.xml_limits.require_renderable_content = require;
+        self
+    }
+
+    /// Sets the elements which are allowed in the SVG data.
+    ///
+    /// `names` are the local names of the elements in the [SVG] namespace
+    /// (e.g., `"path"`), which must include the root `svg` element. If this is
+    /// set, the data which has any other element is rejected with
+    /// [`Error::DisallowedElement`](crate::Error::DisallowedElement). The
+    /// elements are tested on the XML document before converting it to the
+    /// tree, so the elements which `usvg` ignores (e.g., `script` and
+    /// `foreignObject`) are also rejected, even if they are in the `defs`
+    /// element. The elements which are not in the SVG namespace (e.g., the
+    /// metadata of Inkscape) are rejected unless
+    /// [`allow_foreign_elements`](Self::allow_foreign_elements) is set to
+    /// [`true`]. By default, all the elements are allowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::{Error, ValidatorBuilder};
+    /// #
+    /// let validator = ValidatorBuilder::new()
+    ///     .allowed_elements(&["svg", "g", "path", "rect", "circle"])
+    ///     .build();
+    ///
+    /// assert!(validator.is_svg(
+    ///     r#"<svg xmlns="http://www.w3.org/2000/svg" width="1" height="1"><rect width="1" height="1"/></svg>"#
+    /// ));
+    /// assert!(matches!(
+    ///     validator.check_svg(
+    ///         r#"<svg xmlns="http://www.w3.org/2000/svg" width="1" height="1"><script>alert(1)</script></svg>"#
+    ///     ),
+    ///     Err(Error::DisallowedElement { name, .. }) if name == "script"
+    /// ));
+    /// ```
+    ///
+    /// [SVG]: https://www.w3.org/Graphics/SVG/
+    #[must_use]
+    #[inline]
+    pub fn allowed_elements(mut self, names: &[&str]) -> Self {
+        self.element_policy.allowed = Some(names.iter().map(|&name| name.into()).collect());
+        self
+    }
+
+    /// Sets whether the elements which are not in the [SVG] namespace are
+    /// allowed if [`allowed_elements`](Self::allowed_elements) is set.
+    ///
+    /// The SVG images which are saved by the editors often have the elements
+    /// in their own namespaces (e.g., `sodipodi:namedview` of Inkscape) and the
+    /// metadata in RDF. If this is set to [`true`], these elements are allowed
+    /// regardless of their names, but the elements in the SVG namespace in them
+    /// are still tested. By default, this is [`false`], and these elements are
+    /// rejected. This has no effect unless `allowed_elements` is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::ValidatorBuilder;
+    /// #
+    /// let svg = r#"<svg xmlns="http://www.w3.org/2000/svg" xmlns:sodipodi="http://sodipodi.sourceforge.net/DTD/sodipodi-0.dtd" width="1" height="1"><sodipodi:namedview/><rect width="1" height="1"/></svg>"#;
+    ///
+    /// let builder = ValidatorBuilder::new().allowed_elements(&["svg", "rect"]);
+    /// assert!(!builder.build().is_svg(svg));
+    ///
+    /// let builder = ValidatorBuilder::new()
+    ///     .allowed_elements(&["svg", "rect"])
+    ///     .allow_foreign_elements(true);
+    /// assert!(builder.build().is_svg(svg));
+    /// ```
+    ///
+    /// [SVG]: https://www.w3.org/Graphics/SVG/
+    #[must_use]
+    #[inline]
+    pub const fn allow_foreign_elements(mut self, allow: bool) -> Self {
+        self.element_policy.allow_foreign = allow;
+        self
+    }
+
+    /// Sets the elements which are denied in the SVG data.
+    ///
+    /// `names` are the local names of the elements (e.g., `"script"`), which
+    /// match the elements in any namespace. To match only the elements in a
+    /// namespace, precede the local name with the namespace name in braces
+    /// (e.g., `"{http://www.w3.org/2000/svg}script"`). The trailing `*`
+    /// matches any sequence of characters (e.g., `"animate*"` matches
+    /// `animate`, `animateMotion` and `animateTransform`). The data which has
+    /// any of these elements is rejected with
+    /// [`Error::DisallowedElement`](crate::Error::DisallowedElement) in the
+    /// same way as [`allowed_elements`](Self::allowed_elements), including the
+    /// elements in the `defs` and `symbol` elements. If both are set, the
+    /// element which is both allowed and denied is rejected. By default, no
+    /// elements are denied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::{Error, ValidatorBuilder};
+    /// #
+    /// let validator = ValidatorBuilder::new()
+    ///     .deny_elements(&["script", "foreignObject", "animate*"])
+    ///     .build();
+    ///
+    /// assert!(validator.is_svg(include_str!(
+    ///     "../tests/data/wikipedia/SVG_animation_using_CSS.svg"
+    /// )));
+    /// assert!(matches!(
+    ///     validator.check_svg(include_str!(
+    ///         "../tests/data/wikipedia/SVG_animation_using_ES.svg"
+    ///     )),
+    ///     Err(Error::DisallowedElement { name, .. }) if name == "script"
+    /// ));
+    /// assert!(matches!(
+    ///     validator.check_svg(include_str!(
+    ///         "../tests/data/wikipedia/SVG_animation_using_SMIL.svg"
+    ///     )),
+    ///     Err(Error::DisallowedElement { name, .. }) if name == "animateTransform"
+    /// ));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn deny_elements(mut self, names: &[&str]) -> Self {
+        self.element_policy.denied = names.iter().map(|name| NamePattern::new(name)).collect();
+        self
+    }
+
+    /// Sets whether the references to the external resources are allowed in
+    /// the SVG data.
+    ///
+    /// The SVG data which refers to the other files or the remote resources
+    /// (e.g., `<image href="../image.png">` or `<use
+    /// href="https://example.com/icons.svg#user">`) is a valid SVG data, but
+    /// the references can leak the information when it is rendered. If this is
+    /// set to [`false`], the data which has such a reference is rejected with
+    /// [`Error::ExternalReference`](crate::Error::ExternalReference). The
+    /// references are the `href` and the `xlink:href` attributes, the
+    /// `xml-stylesheet` processing instructions, and the CSS
+    /// `url()` functions and `@import` rules in the `style` attributes, the
+    /// `style` elements and the presentation attributes such as `fill`. The
+    /// references to the fragments in the same document (e.g., `#gradient`)
+    /// and the `data:` URIs are not external, so they are still allowed. The
+    /// CSS is not parsed, so the references in the CSS comments are also
+    /// rejected. The `xlink:href` attribute which is overridden by the `href`
+    /// attribute is also tested, since the user agents which only support SVG
+    /// 1.1 refer to it. By default, this is [`true`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::{Error, ValidatorBuilder};
+    /// #
+    /// let validator = ValidatorBuilder::new()
+    ///     .allow_external_references(false)
+    ///     .build();
+    ///
+    /// assert!(validator.is_svg(include_str!(
+    ///     "../tests/data/reference/fragment-only.svg"
+    /// )));
+    /// assert!(matches!(
+    ///     validator.check_svg(include_str!(
+    ///         "../tests/data/reference/file-relative.svg"
+    ///     )),
+    ///     Err(Error::ExternalReference { href, .. }) if href == "../../etc/passwd"
+    /// ));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn allow_external_references(mut self, allow: bool) -> Self {
+        self.xml_limits.allow_external_references = allow;
+        self
+    }
+
+    /// Sets whether to validate the data without converting its content to the
+    /// tree if possible.
+    ///
+    /// `usvg` builds the whole tree, such as the gradients, the instances of
+    /// the `use` elements and the outlines of the text, but most of it is not
+    /// required for testing whether the data is a valid SVG data. If this is
+    /// [`true`], only the root element is converted if the rest of the
+    /// document cannot be rejected, which gives the same result with much
+    /// less work for the large data. The data which has the `use`, the `tref`
+    /// or the `style` elements, or which is rejected by
+    /// [`reject_empty`](Self::reject_empty) or
+    /// [`require_renderable_content`](Self::require_renderable_content), is
+    /// still converted as a whole. By default, the whole document is
+    /// converted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::ValidatorBuilder;
+    /// #
+    /// let validator = ValidatorBuilder::new().fast(true).build();
+    ///
+    /// assert!(validator.is_svg(include_str!("../tests/data/w3/svg-logo-v.svg")));
+    /// assert!(!validator.is_svg(r#"<svg xmlns="http://www.w3.org/2000/svg" width="0" height="16"/>"#));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn fast(mut self, fast: bool) -> Self {
+        self.xml_limits.fast = fast;
+        self
+    }
+
+    /// Sets the maximum time which the validation of each data takes.
+    ///
+    /// The data which is not validated within this is not considered to be a
+    /// valid SVG data. The timeout is tested between the stages of the
+    /// validation (decompressing the data, parsing the XML document, and
+    /// converting the document to the tree), since the stages themselves
+    /// cannot be interrupted. So the validation can still take longer than
+    /// this by the time of one stage, and the slow data is rejected only
+    /// after the slow stage has finished. The other limits bound the time of
+    /// each stage. By default, the time is unlimited.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// #
+    /// # use is_svg::{Error, ValidatorBuilder};
+    /// #
+    /// let validator = ValidatorBuilder::new()
+    ///     .timeout(Duration::from_secs(10))
+    ///     .build();
+    /// assert!(validator.is_svg(include_str!("../tests/data/w3/svg-logo-v.svg")));
+    ///
+    /// let validator = ValidatorBuilder::new().timeout(Duration::ZERO).build();
+    /// assert!(matches!(
+    ///     validator.check_svg(include_str!("../tests/data/w3/svg-logo-v.svg")),
+    ///     Err(Error::Timeout)
+    /// ));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the decompressor for the [gzip-compressed] SVG image (`.svgz`).
+    ///
+    /// By default, the built-in `GzipDecompressor` is used if the `gzip`
+    /// feature is enabled. Otherwise, the gzip-compressed data is not
+    /// considered to be a valid SVG data unless this is set. See
+    /// [`Decompressor`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "gzip")]
+    /// # {
+    /// # use is_svg::{GzipDecompressor, ValidatorBuilder};
+    /// #
+    /// let validator = ValidatorBuilder::new()
+    ///     .decompressor(GzipDecompressor)
+    ///     .build();
+    /// assert!(validator.is_svgz(include_bytes!("../tests/data/w3/svg-logo-v.svgz")));
+    /// # }
+    /// ```
+    ///
+    /// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+    #[must_use]
+    #[inline]
+    pub fn decompressor(mut self, decompressor: impl Decompressor + 'static) -> Self {
+        self.decompressor = Some(Box::new(decompressor));
+        self
+    }
+
+    /// Sets the number of the verdicts which are cached, or [`None`] to disable
+    /// the cache.
+    ///
+    /// If this is set, the verdicts on the data are cached, and the same data
+    /// is not validated again until its verdict is evicted as the least
+    /// recently used one. The cached data is compared with the given data, so
+    /// the hash collision never changes the verdict. The data larger than
+    /// 64 KiB, and the data which is rejected by the
+    /// [`timeout`](Self::timeout), are not cached. The details of the errors
+    /// are not cached, so [`Validator::check_svg`] validates the invalid data
+    /// again. By default, the cache is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::num::NonZeroUsize;
+    /// #
+    /// # use is_svg::ValidatorBuilder;
+    /// #
+    /// let validator = ValidatorBuilder::new()
+    ///     .cache(NonZeroUsize::new(1024))
+    ///     .build();
+    /// assert!(validator.is_svg(include_str!("../tests/data/w3/svg-logo-v.svg")));
+    /// assert!(validator.is_svg(include_str!("../tests/data/w3/svg-logo-v.svg")));
+    /// assert_eq!(validator.cache_stats().unwrap().hits, 1);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn cache(mut self, capacity: Option<NonZeroUsize>) -> Self {
+        self.cache = capacity;
+        self
+    }
+
+    /// Builds a [`Validator`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::ValidatorBuilder;
+    /// #
+    /// let validator = ValidatorBuilder::new().build();
+    /// assert!(validator.is_svg(include_str!("../tests/data/w3/svg-logo-v.svg")));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn build(self) -> Validator {
+        let inner = Inner {
+            options: self.options,
+            max_size: self.max_size,
+            max_decompressed_size: self.max_decompressed_size,
+            gzip_policy: self.gzip_policy,
+            accepted_compressions: self.accepted_compressions,
+            xml_limits: self.xml_limits,
+            timeout: self.timeout,
+            element_policy: Some(self.element_policy).filter(ElementPolicy::is_restrictive),
+            decompressor: self.decompressor,
+            cache: self.cache.map(Cache::new),
+        };
+        Validator {
+            inner: Arc::new(inner),
+        }
+    }
+}
+
+impl Default for ValidatorBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            options: Options::default(),
+            max_size: Option::default(),
+            max_decompressed_size: crate::gzip::DEFAULT_DECOMPRESS_LIMIT,
+            gzip_policy: StreamPolicy::default(),
+            accepted_compressions: vec![CompressionKind::None, CompressionKind::Gzip],
+            xml_limits: XmlLimits::default(),
+            timeout: Option::default(),
+            element_policy: ElementPolicy::default(),
+            decompressor: Option::default(),
+            cache: Option::default(),
+        }
+    }
+}
@@ -0,0 +1,288 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Finding SVG files under a directory tree.
+
+use std::{
+    ffi::OsString,
+    fs::{self, File},
+    io,
+    path::{Path, PathBuf},
+};
+
+use walkdir::WalkDir;
+
+use crate::{
+    reader::{self, DEFAULT_READ_LIMIT},
+    Error, SvgKind,
+};
+
+/// Finds valid [SVG] files under `root`.
+///
+/// This is the same as `DirScanner::new(root).scan()`, but only returns the
+/// found files. Files which could not be read are skipped. See
+/// [`DirScanner`] for more details.
+///
+/// # Errors
+///
+/// Returns [`Err`] if `root` could not be read as a directory.
+///
+/// # Examples
+///
+/// ```
+/// let entries = is_svg::scan_dir("tests/data/w3").unwrap();
+/// # #[cfg(feature = "gzip")]
+/// assert_eq!(entries.len(), 2);
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+#[inline]
+pub fn scan_dir(root: impl AsRef<Path>) -> io::Result<Vec<SvgEntry>> {
+    DirScanner::new(root).scan().map(|report| report.entries)
+}
+
+/// A valid [SVG] file found by [`scan_dir`] or [`DirScanner`].
+///
+/// If the `serde` feature is enabled, this is serialized as a struct with the
+/// `path`, `kind` and `size` fields, which correspond to the methods of the
+/// same name. Serializing fails if the path is not a valid UTF-8.
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct SvgEntry {
+    path: PathBuf,
+    kind: SvgKind,
+    size: u64,
+}
+
+impl SvgEntry {
+    /// Returns the path of the file.
+    #[must_use]
+    #[inline]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the kind of the SVG image.
+    #[must_use]
+    #[inline]
+    pub const fn kind(&self) -> SvgKind {
+        self.kind
+    }
+
+    /// Returns the size of the file in bytes.
+    #[must_use]
+    #[inline]
+    pub const fn size(&self) -> u64 {
+        self.size
+    }
+}
+
+/// A result of [`DirScanner::scan`].
+///
+/// If the `serde` feature is enabled, this is serialized as a struct with the
+/// following fields:
+///
+/// - `entries`: The found valid SVG files.
+/// - `errors`: The paths which could not be read, as structs with the `path`
+///   field and the `message` field which is the message of the error.
+///
+/// This cannot be deserialized, since the errors cannot be reconstructed from
+/// their messages.
+#[derive(Debug)]
+pub struct ScanReport {
+    entries: Vec<SvgEntry>,
+    errors: Vec<(PathBuf, io::Error)>,
+}
+
+impl ScanReport {
+    /// Returns the found valid SVG files.
+    #[must_use]
+    #[inline]
+    pub fn entries(&self) -> &[SvgEntry] {
+        &self.entries
+    }
+
+    /// Returns the paths which could not be read and the errors.
+    #[must_use]
+    #[inline]
+    pub fn errors(&self) -> &[(PathBuf, io::Error)] {
+        &self.errors
+    }
+
+    /// Consumes the report, returning the found valid SVG files.
+    #[must_use]
+    #[inline]
+    pub fn into_entries(self) -> Vec<SvgEntry> {
+        self.entries
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ScanReport {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        #[derive(serde::Serialize)]
+        struct ScanError<'a> {
+            path: &'a Path,
+            message: String,
+        }
+
+        let errors: Vec<_> = self
+            .errors
+            .iter()
+            .map(|(path, err)| ScanError {
+                path,
+                message: err.to_string(),
+            })
+            .collect();
+        let mut state = serializer.serialize_struct("ScanReport", 2)?;
+        state.serialize_field("entries", &self.entries)?;
+        state.serialize_field("errors", &errors)?;
+        state.end()
+    }
+}
+
+/// A scanner for finding valid [SVG] files under a directory tree.
+///
+/// Symbolic links are not followed, so the scanner never loops. Files which
+/// could not be read do not abort the walk, and are collected as errors
+/// instead. Files larger than 64 MiB are not considered to be valid SVG files.
+///
+/// # Examples
+///
+/// ```
+/// # use is_svg::{DirScanner, SvgKind};
+/// #
+/// # #[cfg(feature = "gzip")]
+/// # {
+/// let report = DirScanner::new("tests/data/w3")
+///     .extensions(&["svgz"])
+///     .scan()
+///     .unwrap();
+/// assert_eq!(report.entries().len(), 1);
+/// assert_eq!(report.entries()[0].kind(), SvgKind::Svgz);
+/// assert!(report.errors().is_empty());
+/// # }
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+#[derive(Clone, Debug)]
+pub struct DirScanner {
+    root: PathBuf,
+    extensions: Option<Vec<OsString>>,
+}
+
+impl DirScanner {
+    /// Creates a new `DirScanner` which scans under `root`.
+    #[must_use]
+    #[inline]
+    pub fn new(root: impl AsRef<Path>) -> Self {
+        Self {
+            root: root.as_ref().to_path_buf(),
+            extensions: None,
+        }
+    }
+
+    /// Restricts the files to be tested by the file extensions.
+    ///
+    /// The file extensions are compared case-insensitively. Files which do not
+    /// have any of `extensions` are skipped before reading their content. By
+    /// default, all files are tested.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::DirScanner;
+    /// #
+    /// let report = DirScanner::new("tests/data/w3")
+    ///     .extensions(&["svg", "svgz"])
+    ///     .scan()
+    ///     .unwrap();
+    /// # #[cfg(feature = "gzip")]
+    /// assert_eq!(report.entries().len(), 2);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn extensions(mut self, extensions: &[&str]) -> Self {
+        self.extensions = Some(extensions.iter().map(OsString::from).collect());
+        self
+    }
+
+    /// Scans the directory tree.
+    ///
+    /// The found files are sorted by the file name in each directory.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the root could not be read as a directory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use is_svg::DirScanner;
+    /// #
+    /// let report = DirScanner::new("tests/data/w3").scan().unwrap();
+    /// # #[cfg(feature = "gzip")]
+    /// assert_eq!(report.entries().len(), 2);
+    /// ```
+    pub fn scan(&self) -> io::Result<ScanReport> {
+        if !fs::metadata(&self.root)?.is_dir() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{} is not a directory", self.root.display()),
+            ));
+        }
+
+        let mut report = ScanReport {
+            entries: Vec::new(),
+            errors: Vec::new(),
+        };
+        for entry in WalkDir::new(&self.root).sort_by_file_name() {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(err) => {
+                    let path = err.path().unwrap_or(&self.root).to_path_buf();
+                    report.errors.push((path, err.into()));
+                    continue;
+                }
+            };
+            if !entry.file_type().is_file() || !self.matches_extension(entry.path()) {
+                continue;
+            }
+            match test_file(entry.path()) {
+                Ok(Some((kind, size))) => report.entries.push(SvgEntry {
+                    path: entry.into_path(),
+                    kind,
+                    size,
+                }),
+                Ok(None) => (),
+                Err(err) => report.errors.push((entry.into_path(), err)),
+            }
+        }
+        Ok(report)
+    }
+
+    fn matches_extension(&self, path: &Path) -> bool {
+        self.extensions.as_ref().map_or(true, |extensions| {
+            path.extension().map_or(false, |ext| {
+                extensions
+                    .iter()
+                    .any(|extension| ext.eq_ignore_ascii_case(extension))
+            })
+        })
+    }
+}
+
+fn test_file(path: &Path) -> io::Result<Option<(SvgKind, u64)>> {
+    let file = File::open(path)?;
+    let size = file.metadata()?.len();
+    match reader::read_limited(file, DEFAULT_READ_LIMIT) {
+        Ok(data) => Ok(crate::detect(data).map(|kind| (kind, size))),
+        Err(Error::Io(err)) => Err(err),
+        Err(_) => Ok(None),
+    }
+}
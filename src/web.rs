@@ -0,0 +1,237 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Extractor for the web frameworks.
+
+use std::{error, fmt};
+
+use axum_core::{
+    extract::{FromRequest, Request},
+    response::{IntoResponse, Response},
+};
+use bytes::Bytes;
+use http::{header, StatusCode};
+use http_body_util::{BodyExt, LengthLimitError, Limited};
+
+use crate::{gzip, reader::DEFAULT_READ_LIMIT, Error, SvgKind};
+
+/// An extractor for the request body which is a valid [SVG] image.
+///
+/// The request body is buffered up to the limit, and tested whether it is a
+/// valid SVG data. The request body compressed with `Content-Encoding: gzip`
+/// is decoded transparently, and the decoded body may also be a
+/// [gzip-compressed] SVG image (`.svgz`).
+///
+/// By default, at most 64 MiB are buffered. The limit can be changed by
+/// adding [`SvgBodyLimit`] to the request extensions, e.g. with the
+/// `Extension` layer of `axum`. This limit applies to both the request body
+/// and the decoded body.
+///
+/// If the extraction fails, the request is rejected with [`SvgBodyRejection`].
+///
+/// # Examples
+///
+/// ```
+/// use axum::{routing::post, Extension, Router};
+/// use is_svg::{SvgBody, SvgBodyLimit};
+///
+/// async fn upload(body: SvgBody) -> String {
+///     format!("{} bytes of {:?}", body.data().len(), body.kind())
+/// }
+///
+/// let app: Router = Router::new()
+///     .route("/upload", post(upload))
+///     .layer(Extension(SvgBodyLimit::new(1024 * 1024)));
+/// ```
+///
+/// [SVG]: https://www.w3.org/Graphics/SVG/
+/// [gzip-compressed]: https://datatracker.ietf.org/doc/html/rfc1952
+#[derive(Clone, Debug)]
+pub struct SvgBody {
+    data: Bytes,
+    kind: SvgKind,
+}
+
+impl SvgBody {
+    /// Returns the validated data.
+    ///
+    /// If the request body was compressed with `Content-Encoding: gzip`, this
+    /// is the decoded body.
+    #[must_use]
+    #[inline]
+    pub const fn data(&self) -> &Bytes {
+        &self.data
+    }
+
+    /// Returns the kind of the SVG image.
+    #[must_use]
+    #[inline]
+    pub const fn kind(&self) -> SvgKind {
+        self.kind
+    }
+
+    /// Consumes the `SvgBody`, returning the validated data.
+    #[must_use]
+    #[inline]
+    pub fn into_data(self) -> Bytes {
+        self.data
+    }
+}
+
+impl<S: Send + Sync> FromRequest<S> for SvgBody {
+    type Rejection = SvgBodyRejection;
+
+    async fn from_request(req: Request, _: &S) -> Result<Self, Self::Rejection> {
+        let limit = req
+            .extensions()
+            .get::<SvgBodyLimit>()
+            .copied()
+            .unwrap_or_default()
+            .0;
+        let gzip_encoded = match req.headers().get(header::CONTENT_ENCODING) {
+            None => false,
+            Some(value) => match value.to_str().map(str::trim) {
+                Ok(encoding) if encoding.eq_ignore_ascii_case("identity") => false,
+                Ok(encoding)
+                    if encoding.eq_ignore_ascii_case("gzip")
+                        || encoding.eq_ignore_ascii_case("x-gzip") =>
+                {
+                    true
+                }
+                _ => return Err(SvgBodyRejection::UnsupportedEncoding),
+            },
+        };
+
+        let body = Limited::new(
+            req.into_body(),
+            usize::try_from(limit).unwrap_or(usize::MAX),
+        );
+        let mut data = match body.collect().await {
+            Ok(collected) => collected.to_bytes(),
+            Err(err) if err.is::<LengthLimitError>() => {
+                return Err(SvgBodyRejection::PayloadTooLarge)
+            }
+            Err(_) => return Err(SvgBodyRejection::InvalidBody),
+        };
+        if gzip_encoded {
+            if !data.starts_with(&gzip::GZIP_MAGIC_NUMBER) {
+                return Err(SvgBodyRejection::InvalidBody);
+            }
+            let mut decoded = Vec::new();
+            data = match gzip::decompress(
+                &data,
+                Some(limit),
+                gzip::StreamPolicy::default(),
+                &mut decoded,
+            ) {
+                Ok(()) => decoded.into(),
+                Err(Error::LimitExceeded(_)) => return Err(SvgBodyRejection::PayloadTooLarge),
+                Err(_) => return Err(SvgBodyRejection::InvalidBody),
+            };
+        }
+        let kind =
+            crate::validate(&data, crate::default_options()).map_err(SvgBodyRejection::NotSvg)?;
+        Ok(Self { data, kind })
+    }
+}
+
+/// The maximum size of the request body in bytes for [`SvgBody`].
+///
+/// # Examples
+///
+/// ```
+/// # use is_svg::SvgBodyLimit;
+/// #
+/// assert_eq!(SvgBodyLimit::default(), SvgBodyLimit::new(64 * 1024 * 1024));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct SvgBodyLimit(u64);
+
+impl SvgBodyLimit {
+    /// Creates a new `SvgBodyLimit`.
+    #[must_use]
+    #[inline]
+    pub const fn new(limit: u64) -> Self {
+        Self(limit)
+    }
+
+    /// Returns the limit in bytes.
+    #[must_use]
+    #[inline]
+    pub const fn get(self) -> u64 {
+        self.0
+    }
+}
+
+impl Default for SvgBodyLimit {
+    #[inline]
+    fn default() -> Self {
+        Self(DEFAULT_READ_LIMIT)
+    }
+}
+
+/// The rejection type for [`SvgBody`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SvgBodyRejection {
+    /// The request body exceeded the limit.
+    ///
+    /// This is responded with `413 Payload Too Large`.
+    PayloadTooLarge,
+
+    /// The request body could not be read or decoded.
+    ///
+    /// This is responded with `400 Bad Request`.
+    InvalidBody,
+
+    /// The request body was compressed with an unsupported
+    /// `Content-Encoding`.
+    ///
+    /// This is responded with `415 Unsupported Media Type`.
+    UnsupportedEncoding,
+
+    /// The request body was not a valid SVG data.
+    ///
+    /// This is responded with `415 Unsupported Media Type`.
+    NotSvg(Error),
+}
+
+impl SvgBodyRejection {
+    /// Returns the status code of the response for this rejection.
+    #[must_use]
+    #[inline]
+    pub const fn status(&self) -> StatusCode {
+        match self {
+            Self::PayloadTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+            Self::InvalidBody => StatusCode::BAD_REQUEST,
+            Self::UnsupportedEncoding | Self::NotSvg(_) => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+        }
+    }
+}
+
+impl fmt::Display for SvgBodyRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PayloadTooLarge => write!(f, "request body exceeded the limit"),
+            Self::InvalidBody => write!(f, "could not read the request body"),
+            Self::UnsupportedEncoding => write!(f, "unsupported content encoding"),
+            Self::NotSvg(err) => write!(f, "request body was not a valid SVG image: {err}"),
+        }
+    }
+}
+
+impl error::Error for SvgBodyRejection {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::NotSvg(err) => Some(err),
+            Self::PayloadTooLarge | Self::InvalidBody | Self::UnsupportedEncoding => None,
+        }
+    }
+}
+
+impl IntoResponse for SvgBodyRejection {
+    fn into_response(self) -> Response {
+        (self.status(), self.to_string()).into_response()
+    }
+}
@@ -0,0 +1,72 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The allocations are counted by the global allocator, so this file has only
+//! one test to avoid counting the other tests.
+
+// The global allocator can only be implemented with `unsafe`.
+#![allow(unsafe_code)]
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    io::Write,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use flate2::{write::GzEncoder, Compression};
+
+/// Counts the allocations and the reallocations.
+struct Counting;
+
+static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        COUNT.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        COUNT.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// Returns the number of the allocations made by `f`.
+fn count(f: impl FnOnce()) -> usize {
+    let base = COUNT.load(Ordering::Relaxed);
+    f();
+    COUNT.load(Ordering::Relaxed) - base
+}
+
+#[test]
+fn allocations_are_bounded() {
+    let svg = include_str!("data/usvg/path-simple-case.svg");
+    let svgz = {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(svg.as_bytes()).unwrap();
+        encoder.finish().unwrap()
+    };
+
+    // The first calls initialize the shared options and the scratch buffer.
+    assert!(is_svg::is_svg(svg));
+    assert!(is_svg::is_svg(&svgz));
+
+    // Most of the allocations are made by `roxmltree` and `usvg`, so these
+    // bounds have some margin for their updates.
+    let plain = count(|| assert!(is_svg::is_svg(svg)));
+    assert!(plain <= 64, "{plain}");
+
+    // The decompressed data is written to the reused buffer, so the rest of
+    // the allocations are made by the decoder.
+    let gzipped = count(|| assert!(is_svg::is_svg(&svgz)));
+    assert!(gzipped <= plain + 8, "{gzipped}");
+}
@@ -0,0 +1,113 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+use std::time::{Duration, Instant};
+
+use is_svg::{Error, SvgKind, Validator, ValidatorBuilder};
+
+const SMIL_STRESS: &str = include_str!("data/animation/smil-stress.svg");
+const SMIL: &str = include_str!("data/wikipedia/SVG_animation_using_SMIL.svg");
+
+/// The bound which only catches the pathological regressions, since the
+/// validation of [`SMIL_STRESS`] takes less than a second even in the debug
+/// build.
+const TIME_LIMIT: Duration = Duration::from_secs(10);
+
+/// Returns `text` without the lines which have the animation elements.
+fn without_animations(text: &str) -> String {
+    text.lines()
+        .filter(|line| !line.contains("<animate") && !line.contains("<set"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn validators() -> [Validator; 6] {
+    [
+        Validator::lenient(),
+        Validator::new(),
+        Validator::strict(),
+        Validator::builder().fast(true).build(),
+        ValidatorBuilder::new().reject_empty(true).build(),
+        ValidatorBuilder::new()
+            .require_renderable_content(true)
+            .build(),
+    ]
+}
+
+#[test]
+fn stress_fixture() {
+    assert_eq!(SMIL_STRESS.matches("<animate").count(), 1000);
+    assert_eq!(SMIL_STRESS.matches("<animateTransform").count(), 200);
+    assert!(SMIL_STRESS.contains(r#"begin="0s; a999.end""#));
+}
+
+#[test]
+fn is_svg_with_many_animations() {
+    let start = Instant::now();
+    assert!(is_svg::is_svg(SMIL_STRESS));
+    assert!(is_svg::check_svg(SMIL_STRESS).is_ok());
+    assert!(is_svg::check_svg_reader(SMIL_STRESS.as_bytes()).is_ok());
+    for validator in validators() {
+        assert!(validator.check_svg(SMIL_STRESS).is_ok());
+    }
+    for chunk_size in [61, 4096, SMIL_STRESS.len()] {
+        assert!(matches!(
+            common::check_chunked(SMIL_STRESS.as_bytes(), chunk_size),
+            Ok(SvgKind::Svg)
+        ));
+    }
+    assert!(start.elapsed() < TIME_LIMIT);
+}
+
+#[test]
+fn animations_do_not_affect_verdict() {
+    for text in [SMIL_STRESS, SMIL] {
+        let stripped = without_animations(text);
+        assert_ne!(stripped, text);
+        for validator in validators() {
+            assert_eq!(
+                validator.check_svg(text).is_ok(),
+                validator.check_svg(&stripped).is_ok()
+            );
+        }
+    }
+}
+
+#[test]
+fn repeated_validation_is_stable() {
+    let validator = Validator::new();
+    let start = Instant::now();
+    for _ in 0..10 {
+        assert!(validator.is_svg(SMIL_STRESS));
+    }
+    assert!(start.elapsed() < TIME_LIMIT);
+}
+
+#[test]
+fn deny_animations() {
+    let validator = ValidatorBuilder::new().deny_elements(&["animate*"]).build();
+    assert!(matches!(
+        validator.check_svg(SMIL_STRESS),
+        Err(Error::DisallowedElement { .. })
+    ));
+    assert!(validator.is_svg(without_animations(SMIL_STRESS)));
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn is_svgz_with_many_animations() {
+    let data = is_svg::compress_svg(SMIL_STRESS).unwrap();
+    let start = Instant::now();
+    assert_eq!(is_svg::detect(&data), Some(SvgKind::Svgz));
+    for validator in validators() {
+        assert!(validator.check_svg(&data).is_ok());
+    }
+    assert!(matches!(
+        common::check_chunked(&data, 64),
+        Ok(SvgKind::Svgz)
+    ));
+    assert!(start.elapsed() < TIME_LIMIT);
+}
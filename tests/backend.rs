@@ -0,0 +1,26 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[test]
+fn backend_version() {
+    let manifest = include_str!("../Cargo.toml");
+    let requirement = manifest
+        .lines()
+        .find_map(|line| line.strip_prefix("usvg = { version = \""))
+        .and_then(|rest| rest.split('"').next())
+        .unwrap();
+    let version = is_svg::backend_version();
+    assert!(
+        requirement == version || requirement.starts_with(&format!("{version}.")),
+        "{requirement} does not match {version}"
+    );
+}
+
+#[test]
+fn capabilities() {
+    let capabilities = is_svg::capabilities();
+    assert_eq!(capabilities.gzip, cfg!(feature = "gzip"));
+    assert_eq!(capabilities.text, cfg!(feature = "text"));
+    assert_eq!(capabilities.light, cfg!(feature = "light"));
+}
@@ -0,0 +1,79 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use is_svg::{Error, SvgKind};
+
+const ITEMS: [&[u8]; 6] = [
+    include_bytes!("data/ferris/corro.svg"),
+    include_bytes!("data/ferris/corro.svgz"),
+    include_bytes!("data/resources/unclosed.svg"),
+    include_bytes!("data/w3/svg-logo-v.png"),
+    include_bytes!("data/w3/svg-logo-v.svg"),
+    include_bytes!("data/w3/svg-logo-v.svgz"),
+];
+
+#[test]
+fn are_svgs() {
+    assert_eq!(
+        is_svg::are_svgs(ITEMS),
+        [
+            true,
+            cfg!(feature = "gzip"),
+            false,
+            false,
+            true,
+            cfg!(feature = "gzip")
+        ]
+    );
+    assert_eq!(
+        is_svg::are_svgs(ITEMS.iter().map(|item| item.to_vec())),
+        [
+            true,
+            cfg!(feature = "gzip"),
+            false,
+            false,
+            true,
+            cfg!(feature = "gzip")
+        ]
+    );
+}
+
+#[test]
+fn are_svgs_keeps_order() {
+    let items = ITEMS.repeat(50);
+    let expected = items.iter().map(is_svg::is_svg).collect::<Vec<_>>();
+    assert_eq!(is_svg::are_svgs(items), expected);
+}
+
+#[test]
+fn are_svgs_from_empty() {
+    assert!(is_svg::are_svgs(Vec::<&[u8]>::new()).is_empty());
+}
+
+#[test]
+fn check_all() {
+    let results = is_svg::check_all(ITEMS);
+    assert_eq!(results.len(), ITEMS.len());
+    assert!(matches!(results[0], Ok(SvgKind::Svg)));
+    #[cfg(feature = "gzip")]
+    assert!(matches!(results[1], Ok(SvgKind::Svgz)));
+    assert!(matches!(results[2], Err(Error::NotXml { .. })));
+    assert!(matches!(results[3], Err(Error::NotUtf8(_))));
+    assert!(matches!(results[4], Ok(SvgKind::Svg)));
+    #[cfg(feature = "gzip")]
+    assert!(matches!(results[5], Ok(SvgKind::Svgz)));
+}
+
+#[test]
+fn check_all_keeps_order() {
+    let items = ITEMS.repeat(50);
+    let expected = items.iter().map(is_svg::detect).collect::<Vec<_>>();
+    assert_eq!(
+        is_svg::check_all(items)
+            .into_iter()
+            .map(Result::ok)
+            .collect::<Vec<_>>(),
+        expected
+    );
+}
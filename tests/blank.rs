@@ -0,0 +1,147 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+use is_svg::{Error, ErrorKind, Validator, ValidatorBuilder};
+
+const ZERO_BYTES: &[u8] = include_bytes!("data/blank/zero-bytes.svg");
+const WHITESPACE: &[u8] = include_bytes!("data/blank/whitespace.svg");
+const COMMENTS: &[u8] = include_bytes!("data/blank/comments.svg");
+
+const FIXTURES: [&[u8]; 3] = [ZERO_BYTES, WHITESPACE, COMMENTS];
+
+#[cfg(feature = "gzip")]
+const COMPRESSED_FIXTURES: [&[u8]; 3] = [
+    include_bytes!("data/blank/zero-bytes.svgz"),
+    include_bytes!("data/blank/whitespace.svgz"),
+    include_bytes!("data/blank/comments.svgz"),
+];
+
+fn validators() -> [Validator; 6] {
+    [
+        Validator::lenient(),
+        Validator::new(),
+        Validator::strict(),
+        Validator::builder().fast(true).build(),
+        ValidatorBuilder::new().reject_empty(true).build(),
+        ValidatorBuilder::new().allow_dtd(false).build(),
+    ]
+}
+
+#[test]
+fn fixtures() {
+    assert!(ZERO_BYTES.is_empty());
+    assert_eq!(WHITESPACE.len(), 1024);
+    assert!(WHITESPACE.iter().all(u8::is_ascii_whitespace));
+    assert!(COMMENTS.starts_with(b"<!--"));
+}
+
+#[test]
+fn check_svg_from_blank() {
+    for data in FIXTURES {
+        assert!(!is_svg::is_svg(data));
+        assert!(matches!(is_svg::check_svg(data), Err(Error::Empty)));
+        assert!(matches!(is_svg::check_svg_reader(data), Err(Error::Empty)));
+        for validator in validators() {
+            assert_eq!(
+                validator.check_svg(data).unwrap_err().kind(),
+                ErrorKind::Empty
+            );
+        }
+        for chunk_size in [1, 64, data.len().max(1)] {
+            assert!(matches!(
+                common::check_chunked(data, chunk_size),
+                Err(Error::Empty)
+            ));
+        }
+    }
+}
+
+#[test]
+fn check_svg_from_blank_with_bom() {
+    for data in FIXTURES {
+        let data = [b"\xef\xbb\xbf", data].concat();
+        assert!(matches!(is_svg::check_svg(data), Err(Error::Empty)));
+    }
+    assert!(matches!(is_svg::check_svg(b"\xff\xfe"), Err(Error::Empty)));
+    assert!(matches!(
+        is_svg::check_svg(b"\xfe\xff\0 \0\n"),
+        Err(Error::Empty)
+    ));
+}
+
+#[test]
+fn check_svg_from_not_blank() {
+    for text in [
+        "<?xml version=\"1.0\"?>",
+        "<!DOCTYPE svg>",
+        "<?xml-stylesheet href=\"style.css\"?>",
+        "<!-- unclosed --",
+        "<!-- -->\n<",
+        "Lorem ipsum",
+    ] {
+        let kind = is_svg::check_svg(text).unwrap_err().kind();
+        assert_eq!(kind, ErrorKind::NotXml, "{text}");
+    }
+    assert_eq!(
+        is_svg::check_svg(include_bytes!("data/resources/xml_declaration.svg"))
+            .unwrap_err()
+            .kind(),
+        ErrorKind::NotXml
+    );
+    // The SVG document without the content is not empty data.
+    assert!(is_svg::is_svg(include_bytes!(
+        "data/empty/comments-only.svg"
+    )));
+    assert_eq!(
+        ValidatorBuilder::new()
+            .reject_empty(true)
+            .build()
+            .check_svg(include_bytes!("data/empty/comments-only.svg"))
+            .unwrap_err()
+            .kind(),
+        ErrorKind::EmptyDocument
+    );
+}
+
+#[test]
+fn display() {
+    assert_eq!(
+        is_svg::check_svg(ZERO_BYTES).unwrap_err().to_string(),
+        "data was empty"
+    );
+}
+
+#[cfg(feature = "light")]
+#[test]
+fn check_svg_light_from_blank() {
+    for data in FIXTURES {
+        assert!(matches!(is_svg::check_svg_light(data), Err(Error::Empty)));
+    }
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn check_svgz_from_blank() {
+    for data in COMPRESSED_FIXTURES {
+        assert!(is_svg::has_gzip_magic(data));
+        assert!(!is_svg::is_svgz(data));
+        assert!(matches!(is_svg::check_svg(data), Err(Error::Empty)));
+        assert_eq!(
+            Validator::strict().check_svg(data).unwrap_err().kind(),
+            ErrorKind::Empty
+        );
+        for chunk_size in [1, data.len()] {
+            assert!(matches!(
+                common::check_chunked(data, chunk_size),
+                Err(Error::Empty)
+            ));
+        }
+    }
+    assert!(matches!(
+        is_svg::check_svg(include_bytes!("data/resources/empty.gz")),
+        Err(Error::Empty)
+    ));
+}
@@ -0,0 +1,112 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::{num::NonZeroUsize, thread, time::Duration};
+
+use is_svg::{CacheStats, Error, Validator};
+
+fn svg(width: usize) -> String {
+    format!(r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="16"/>"#)
+}
+
+fn stats(validator: &Validator) -> (u64, u64) {
+    let CacheStats { hits, misses, .. } = validator.cache_stats().unwrap();
+    (hits, misses)
+}
+
+#[test]
+fn cache_hits_repeated_data() {
+    let validator = Validator::builder().cache(NonZeroUsize::new(16)).build();
+    assert_eq!(stats(&validator), (0, 0));
+    for _ in 0..3 {
+        assert!(validator.is_svg(svg(16)));
+    }
+    assert_eq!(stats(&validator), (2, 1));
+    assert!(validator.is_svg(svg(8)));
+    assert_eq!(stats(&validator), (2, 2));
+    assert!(validator.check_svg(svg(8)).is_ok());
+    assert_eq!(stats(&validator), (3, 2));
+}
+
+#[test]
+fn cache_keeps_verdict() {
+    let validator = Validator::builder().cache(NonZeroUsize::new(16)).build();
+    let svgz = include_bytes!("data/w3/svg-logo-v.svgz");
+    for _ in 0..2 {
+        assert_eq!(validator.detect(svgz), is_svg::detect(svgz));
+        assert!(!validator.is_svg(svg(0)));
+        assert!(matches!(validator.check_svg(svg(0)), Err(Error::Parse(_))));
+        assert!(validator.is_svg_string(svg(16)));
+        assert!(!validator.is_svgz(svg(16)));
+    }
+    assert_eq!(stats(&validator), (5, 3));
+}
+
+#[test]
+fn cache_is_bounded() {
+    let validator = Validator::builder().cache(NonZeroUsize::new(2)).build();
+    for width in [1, 2, 1, 3] {
+        assert!(validator.is_svg(svg(width)));
+    }
+    assert_eq!(stats(&validator), (1, 3));
+    // The least recently used one is evicted.
+    assert!(validator.is_svg(svg(1)));
+    assert_eq!(stats(&validator), (2, 3));
+    assert!(validator.is_svg(svg(2)));
+    assert_eq!(stats(&validator), (2, 4));
+}
+
+#[test]
+fn cache_skips_large_data() {
+    let large = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16"><!--{}--></svg>"#,
+        " ".repeat(128 * 1024)
+    );
+    let validator = Validator::builder().cache(NonZeroUsize::new(16)).build();
+    assert!(validator.is_svg(&large));
+    assert!(validator.is_svg(&large));
+    assert_eq!(stats(&validator), (0, 2));
+}
+
+#[test]
+fn cache_skips_timeout() {
+    let validator = Validator::builder()
+        .cache(NonZeroUsize::new(16))
+        .timeout(Duration::ZERO)
+        .build();
+    assert!(matches!(validator.check_svg(svg(16)), Err(Error::Timeout)));
+    assert!(matches!(validator.check_svg(svg(16)), Err(Error::Timeout)));
+    assert_eq!(stats(&validator), (0, 2));
+}
+
+#[test]
+fn cache_is_shared() {
+    let validator = Validator::builder().cache(NonZeroUsize::new(16)).build();
+    let handles = (0..8)
+        .map(|_| {
+            let validator = validator.clone();
+            thread::spawn(move || {
+                for width in 1..=4 {
+                    assert!(validator.is_svg(svg(width)));
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    let (hits, misses) = stats(&validator);
+    assert_eq!(hits + misses, 32);
+    assert!(misses >= 4);
+}
+
+#[test]
+fn cache_is_disabled_by_default() {
+    assert!(Validator::new().cache_stats().is_none());
+    assert!(Validator::builder()
+        .cache(None)
+        .build()
+        .cache_stats()
+        .is_none());
+}
@@ -0,0 +1,209 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+use std::io::Cursor;
+
+use is_svg::{Error, ErrorKind, SvgKind, Validator};
+
+const STYLE_BRACKETS: &str = include_str!("data/cdata/style-brackets.svg");
+const STYLE_END_TAG: &str = include_str!("data/cdata/style-end-tag.svg");
+const SCRIPT: &str = include_str!("data/cdata/script.svg");
+const TEXT: &str = include_str!("data/cdata/text.svg");
+
+const FIXTURES: [&str; 4] = [STYLE_BRACKETS, STYLE_END_TAG, SCRIPT, TEXT];
+
+/// Returns `text` with every `CDATA` section replaced by its content as the
+/// character data.
+fn strip_cdata(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("<![CDATA[") {
+        stripped.push_str(&rest[..start]);
+        rest = &rest[start + 9..];
+        let end = rest.find("]]>").unwrap();
+        for c in rest[..end].chars() {
+            match c {
+                '<' => stripped.push_str("&lt;"),
+                '>' => stripped.push_str("&gt;"),
+                '&' => stripped.push_str("&amp;"),
+                c => stripped.push(c),
+            }
+        }
+        rest = &rest[end + 3..];
+    }
+    stripped.push_str(rest);
+    stripped
+}
+
+fn presets() -> [Validator; 4] {
+    [
+        Validator::lenient(),
+        Validator::new(),
+        Validator::strict(),
+        Validator::builder().fast(true).build(),
+    ]
+}
+
+fn verdicts(data: &[u8]) -> Vec<Option<ErrorKind>> {
+    presets()
+        .iter()
+        .map(|validator| validator.check_svg(data).err().map(|err| err.kind()))
+        .collect()
+}
+
+#[test]
+fn strip_cdata_sections() {
+    assert_eq!(
+        strip_cdata("<a><![CDATA[<b> & ]]]]><![CDATA[>]]></a>"),
+        "<a>&lt;b&gt; &amp; ]]&gt;</a>"
+    );
+    for text in FIXTURES {
+        assert!(!strip_cdata(text).contains("<![CDATA["));
+    }
+}
+
+#[test]
+fn is_svg_with_cdata() {
+    for text in FIXTURES {
+        let stripped = strip_cdata(text);
+        assert!(is_svg::is_svg(text), "{text}");
+        assert!(is_svg::is_svg(&stripped), "{stripped}");
+        assert!(is_svg::is_svg_string(text), "{text}");
+        assert!(is_svg::check_svg(text).is_ok(), "{text}");
+        assert!(
+            is_svg::check_svg_reader(Cursor::new(text)).is_ok(),
+            "{text}"
+        );
+        assert!(is_svg::looks_like_svg(text), "{text}");
+        assert_eq!(is_svg::detect(text), Some(SvgKind::Svg), "{text}");
+    }
+}
+
+#[test]
+fn presets_with_cdata() {
+    for text in FIXTURES {
+        assert_eq!(
+            verdicts(text.as_bytes()),
+            verdicts(strip_cdata(text).as_bytes()),
+            "{text}"
+        );
+    }
+    for text in [STYLE_BRACKETS, STYLE_END_TAG, TEXT] {
+        assert!(presets().iter().all(|validator| validator.is_svg(text)));
+    }
+    // The script is only rejected by the strict preset, as without the `CDATA`
+    // section.
+    assert!(matches!(
+        Validator::strict().check_svg(SCRIPT),
+        Err(Error::DisallowedElement { .. })
+    ));
+}
+
+#[test]
+fn svg_checker_with_cdata() {
+    for text in FIXTURES {
+        let data = text.as_bytes();
+        for chunk_size in [1, 7, 64, data.len()] {
+            assert!(
+                matches!(common::check_chunked(data, chunk_size), Ok(SvgKind::Svg)),
+                "{text}"
+            );
+        }
+    }
+}
+
+#[test]
+fn parse_svg_with_cdata() {
+    // The style sheets in the `CDATA` sections are applied as without them.
+    for text in [STYLE_BRACKETS, STYLE_END_TAG] {
+        let tree = is_svg::parse_svg(text).unwrap();
+        let stripped = is_svg::parse_svg(strip_cdata(text)).unwrap();
+        assert_eq!(
+            tree.root().children().len(),
+            stripped.root().children().len()
+        );
+        let is_styled = |tree: &is_svg::usvg::Tree| match &tree.root().children()[0] {
+            is_svg::usvg::Node::Path(path) => path.fill().map_or(false, |fill| {
+                matches!(
+                    fill.paint(),
+                    is_svg::usvg::Paint::Color(color)
+                        if *color == is_svg::usvg::Color::new_rgb(0x2a, 0x7a, 0xe2)
+                )
+            }),
+            _ => false,
+        };
+        assert!(is_styled(&tree), "{text}");
+        assert!(is_styled(&stripped), "{text}");
+    }
+}
+
+#[test]
+fn end_tag_in_cdata() {
+    // Without the `CDATA` section, the `</style>` in it ends the element.
+    let start = STYLE_END_TAG.find("<![CDATA[").unwrap();
+    let end = STYLE_END_TAG.find("]]>").unwrap();
+    let unwrapped = format!(
+        "{}{}{}",
+        &STYLE_END_TAG[..start],
+        &STYLE_END_TAG[start + 9..end],
+        &STYLE_END_TAG[end + 3..]
+    );
+    assert!(!is_svg::is_svg(&unwrapped));
+    assert!(matches!(
+        is_svg::check_svg(&unwrapped),
+        Err(Error::NotXml { .. })
+    ));
+    assert!(is_svg::is_svg(STYLE_END_TAG));
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn is_svgz_with_cdata() {
+    use std::io::Write;
+
+    use flate2::{write::GzEncoder, Compression};
+
+    for (svg, svgz) in [
+        (
+            STYLE_BRACKETS,
+            &include_bytes!("data/cdata/style-brackets.svgz")[..],
+        ),
+        (
+            STYLE_END_TAG,
+            include_bytes!("data/cdata/style-end-tag.svgz"),
+        ),
+    ] {
+        assert_eq!(is_svg::decompress_svgz(svgz).unwrap(), svg);
+        assert!(is_svg::is_svgz(svgz), "{svg}");
+        assert!(is_svg::looks_like_svgz(svgz), "{svg}");
+    }
+    for text in FIXTURES {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(text.as_bytes()).unwrap();
+        let data = encoder.finish().unwrap();
+        assert_eq!(is_svg::detect(&data), Some(SvgKind::Svgz), "{text}");
+        assert_eq!(verdicts(&data), verdicts(text.as_bytes()), "{text}");
+        for chunk_size in [1, 64, data.len()] {
+            assert!(
+                matches!(common::check_chunked(&data, chunk_size), Ok(SvgKind::Svgz)),
+                "{text}"
+            );
+        }
+    }
+}
+
+#[cfg(feature = "light")]
+#[test]
+fn is_svg_light_with_cdata() {
+    for text in FIXTURES {
+        assert!(is_svg::is_svg_light(text), "{text}");
+        assert_eq!(
+            is_svg::check_svg_light(text).is_ok(),
+            is_svg::check_svg_light(strip_cdata(text)).is_ok(),
+            "{text}"
+        );
+    }
+}
@@ -0,0 +1,235 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use usvg::roxmltree;
+
+#[test]
+#[allow(clippy::cognitive_complexity, clippy::too_many_lines)]
+fn check_svg_from_svg() {
+    assert!(is_svg::check_svg(include_str!("data/ferris/corro.svg")).is_ok());
+    assert!(is_svg::check_svg(include_str!("data/ferris/cuddlyferris.svg")).is_ok());
+    assert!(is_svg::check_svg(include_str!("data/ferris/rustacean-flat-gesture.svg")).is_ok());
+    assert!(is_svg::check_svg(include_str!("data/ferris/rustacean-flat-happy.svg")).is_ok());
+    assert!(is_svg::check_svg(include_str!("data/ferris/rustacean-flat-noshadow.svg")).is_ok());
+    assert!(is_svg::check_svg(include_str!("data/ferris/rustacean-orig-noshadow.svg")).is_ok());
+    assert!(is_svg::check_svg(include_str!("data/usvg/clip-path-with-complex-text.svg")).is_ok());
+    assert!(is_svg::check_svg(include_str!(
+        "data/usvg/clip-path-with-object-units-multi-use.svg"
+    ))
+    .is_ok());
+    assert!(is_svg::check_svg(include_str!("data/usvg/clip-path-with-text.svg")).is_ok());
+    assert!(is_svg::check_svg(include_str!("data/usvg/ellipse-simple-case.svg")).is_ok());
+    assert!(is_svg::check_svg(include_str!("data/usvg/filter-id-with-prefix.svg")).is_ok());
+    assert!(is_svg::check_svg(include_str!(
+        "data/usvg/filter-with-object-units-multi-use.svg"
+    ))
+    .is_ok());
+    assert!(is_svg::check_svg(include_str!(
+        "data/usvg/generate-id-clip-path-for-symbol.svg"
+    ))
+    .is_ok());
+    assert!(
+        is_svg::check_svg(include_str!("data/usvg/generate-id-filter-function-v1.svg")).is_ok()
+    );
+    assert!(
+        is_svg::check_svg(include_str!("data/usvg/generate-id-filter-function-v2.svg")).is_ok()
+    );
+    assert!(is_svg::check_svg(include_str!(
+        "data/usvg/mask-with-object-units-multi-use.svg"
+    ))
+    .is_ok());
+    assert!(is_svg::check_svg(include_str!("data/usvg/path-simple-case.svg")).is_ok());
+    assert!(is_svg::check_svg(include_str!("data/usvg/preserve-id-clip-path-v1.svg")).is_ok());
+    assert!(is_svg::check_svg(include_str!("data/usvg/preserve-id-clip-path-v2.svg")).is_ok());
+    assert!(is_svg::check_svg(include_str!(
+        "data/usvg/preserve-id-fe-image-with-opacity.svg"
+    ))
+    .is_ok());
+    assert!(is_svg::check_svg(include_str!("data/usvg/preserve-id-fe-image.svg")).is_ok());
+    assert!(is_svg::check_svg(include_str!("data/usvg/preserve-id-filter.svg")).is_ok());
+    assert!(is_svg::check_svg(include_str!(
+        "data/usvg/preserve-id-for-clip-path-in-pattern.svg"
+    ))
+    .is_ok());
+    assert!(is_svg::check_svg(include_str!("data/usvg/preserve-text-in-clip-path.svg")).is_ok());
+    assert!(is_svg::check_svg(include_str!("data/usvg/preserve-text-in-mask.svg")).is_ok());
+    assert!(is_svg::check_svg(include_str!("data/usvg/preserve-text-in-pattern.svg")).is_ok());
+    assert!(is_svg::check_svg(include_str!(
+        "data/usvg/preserve-text-multiple-font-families.svg"
+    ))
+    .is_ok());
+    assert!(is_svg::check_svg(include_str!("data/usvg/preserve-text-on-path.svg")).is_ok());
+    assert!(is_svg::check_svg(include_str!("data/usvg/preserve-text-simple-case.svg")).is_ok());
+    assert!(is_svg::check_svg(include_str!(
+        "data/usvg/preserve-text-with-complex-text-decoration.svg"
+    ))
+    .is_ok());
+    assert!(is_svg::check_svg(include_str!("data/usvg/preserve-text-with-dx-and-dy.svg")).is_ok());
+    assert!(is_svg::check_svg(include_str!(
+        "data/usvg/preserve-text-with-nested-baseline-shift.svg"
+    ))
+    .is_ok());
+    assert!(is_svg::check_svg(include_str!("data/usvg/preserve-text-with-rotate.svg")).is_ok());
+    assert!(is_svg::check_svg(include_str!("data/usvg/text-simple-case.svg")).is_ok());
+    assert!(is_svg::check_svg(include_str!("data/usvg/text-with-generated-gradients.svg")).is_ok());
+    assert!(is_svg::check_svg(include_str!("data/w3/svg-logo-v.svg")).is_ok());
+    assert!(is_svg::check_svg(include_str!("data/wikipedia/SVG_animation_using_CSS.svg")).is_ok());
+    assert!(is_svg::check_svg(include_str!("data/wikipedia/SVG_animation_using_ES.svg")).is_ok());
+    assert!(is_svg::check_svg(include_str!("data/wikipedia/SVG_animation_using_SMIL.svg")).is_ok());
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn check_svg_from_svgz() {
+    assert!(is_svg::check_svg(include_bytes!("data/ferris/corro.svgz")).is_ok());
+    assert!(is_svg::check_svg(include_bytes!("data/ferris/cuddlyferris.svgz")).is_ok());
+    assert!(is_svg::check_svg(include_bytes!("data/ferris/rustacean-flat-gesture.svgz")).is_ok());
+    assert!(is_svg::check_svg(include_bytes!("data/ferris/rustacean-flat-happy.svgz")).is_ok());
+    assert!(is_svg::check_svg(include_bytes!("data/ferris/rustacean-flat-noshadow.svgz")).is_ok());
+    assert!(is_svg::check_svg(include_bytes!("data/ferris/rustacean-orig-noshadow.svgz")).is_ok());
+    assert!(is_svg::check_svg(include_bytes!("data/w3/svg-logo-v.svgz")).is_ok());
+    assert!(is_svg::check_svg(include_bytes!(
+        "data/wikipedia/SVG_animation_using_CSS.svgz"
+    ))
+    .is_ok());
+    assert!(
+        is_svg::check_svg(include_bytes!("data/wikipedia/SVG_animation_using_ES.svgz")).is_ok()
+    );
+    assert!(is_svg::check_svg(include_bytes!(
+        "data/wikipedia/SVG_animation_using_SMIL.svgz"
+    ))
+    .is_ok());
+}
+
+#[test]
+fn check_svg_from_invalid_svg() {
+    assert!(matches!(
+        is_svg::check_svg(include_str!("data/resources/dtd.svg")),
+        Err(is_svg::Error::NotXml {
+            source: roxmltree::Error::NoRootNode,
+            ..
+        })
+    ));
+    assert!(matches!(
+        is_svg::check_svg(include_str!("data/resources/unclosed.svg")),
+        Err(is_svg::Error::NotXml {
+            source: roxmltree::Error::UnclosedRootNode,
+            ..
+        })
+    ));
+    assert!(matches!(
+        is_svg::check_svg(include_str!("data/resources/xml_declaration.svg")),
+        Err(is_svg::Error::NotXml {
+            source: roxmltree::Error::NoRootNode,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn check_svg_from_non_svg() {
+    assert!(matches!(
+        is_svg::check_svg(include_bytes!("data/w3/svg-logo-v.jxl")),
+        Err(is_svg::Error::NotUtf8(_))
+    ));
+    assert!(matches!(
+        is_svg::check_svg(include_bytes!("data/w3/svg-logo-v.png")),
+        Err(is_svg::Error::NotUtf8(_))
+    ));
+    assert!(matches!(
+        is_svg::check_svg(include_bytes!("data/w3/svg-logo-v.webp")),
+        Err(is_svg::Error::NotUtf8(_))
+    ));
+}
+
+#[test]
+fn check_svg_from_empty() {
+    assert!(matches!(is_svg::check_svg([]), Err(is_svg::Error::Empty)));
+    #[cfg(feature = "gzip")]
+    assert!(matches!(
+        is_svg::check_svg(include_bytes!("data/resources/empty.gz")),
+        Err(is_svg::Error::Empty)
+    ));
+}
+
+#[test]
+fn check_svg_from_mathml() {
+    assert!(matches!(
+        is_svg::check_svg(include_str!("data/resources/quadratic_formula.mml")),
+        Err(is_svg::Error::NotXml {
+            source: roxmltree::Error::UnknownEntityReference(..),
+            ..
+        })
+    ));
+}
+
+#[test]
+fn check_svg_from_html() {
+    assert!(matches!(
+        is_svg::check_svg(include_str!("data/resources/index.html")),
+        Err(is_svg::Error::NotXml {
+            source: roxmltree::Error::InvalidName(_),
+            ..
+        })
+    ));
+}
+
+#[test]
+fn check_svg_from_compressed_svg() {
+    assert!(matches!(
+        is_svg::check_svg(include_bytes!("data/w3/svg-logo-v.svg.lz")),
+        Err(is_svg::Error::NotUtf8(_))
+    ));
+    assert!(matches!(
+        is_svg::check_svg(include_bytes!("data/w3/svg-logo-v.svg.zst")),
+        Err(is_svg::Error::NotUtf8(_))
+    ));
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn check_svg_from_corrupted_svgz() {
+    let mut data = include_bytes!("data/w3/svg-logo-v.svgz").to_vec();
+    data.truncate(data.len() / 2);
+    assert!(matches!(
+        is_svg::check_svg(data),
+        Err(is_svg::Error::GzipDecode(_))
+    ));
+}
+
+#[test]
+fn display_error() {
+    assert_eq!(
+        format!(
+            "{}",
+            is_svg::check_svg(include_bytes!("data/w3/svg-logo-v.png")).unwrap_err()
+        ),
+        "data was not a valid UTF-8"
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            is_svg::check_svg(include_str!("data/resources/unclosed.svg")).unwrap_err()
+        ),
+        "data was not a valid XML document at 2:1"
+    );
+}
+
+#[test]
+fn source_error() {
+    use std::error::Error;
+
+    assert!(is_svg::check_svg(include_bytes!("data/w3/svg-logo-v.png"))
+        .unwrap_err()
+        .source()
+        .unwrap()
+        .is::<std::str::Utf8Error>());
+    assert!(
+        is_svg::check_svg(include_str!("data/resources/unclosed.svg"))
+            .unwrap_err()
+            .source()
+            .unwrap()
+            .is::<roxmltree::Error>()
+    );
+}
@@ -0,0 +1,44 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::io::{self, Cursor};
+
+use is_svg::{Error, Limit};
+
+#[test]
+fn check_svg_reader_from_svg() {
+    assert!(is_svg::check_svg_reader(Cursor::new(include_str!("data/w3/svg-logo-v.svg"))).is_ok());
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn check_svg_reader_from_svgz() {
+    assert!(
+        is_svg::check_svg_reader(Cursor::new(include_bytes!("data/w3/svg-logo-v.svgz"))).is_ok()
+    );
+}
+
+#[test]
+fn check_svg_reader_from_invalid_svg() {
+    assert!(matches!(
+        is_svg::check_svg_reader(Cursor::new(include_str!("data/resources/unclosed.svg"))),
+        Err(Error::NotXml { .. })
+    ));
+}
+
+#[test]
+fn check_svg_reader_from_non_svg() {
+    assert!(matches!(
+        is_svg::check_svg_reader(Cursor::new(include_bytes!("data/w3/svg-logo-v.png"))),
+        Err(Error::NotUtf8(_))
+    ));
+}
+
+#[test]
+fn check_svg_reader_from_endless_reader() {
+    assert!(matches!(
+        is_svg::check_svg_reader(io::repeat(b' ')),
+        Err(Error::LimitExceeded(Limit::InputSize(67_108_864)))
+    ));
+}
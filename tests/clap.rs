@@ -0,0 +1,124 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use clap::{builder::EnumValueParser, Arg, Command, ValueEnum};
+use is_svg::{KindFilter, SvgKind};
+
+fn command() -> Command {
+    Command::new("test")
+        .arg(
+            Arg::new("kind")
+                .long("kind")
+                .value_parser(EnumValueParser::<SvgKind>::new()),
+        )
+        .arg(
+            Arg::new("type")
+                .long("type")
+                .value_parser(EnumValueParser::<KindFilter>::new())
+                .default_value("any"),
+        )
+}
+
+#[test]
+fn svg_kind_value_variants() {
+    assert_eq!(
+        SvgKind::value_variants(),
+        [
+            SvgKind::Svg,
+            SvgKind::Svgz,
+            SvgKind::NestedSvgz,
+            SvgKind::ZlibSvg,
+            SvgKind::DeflateSvg
+        ]
+    );
+}
+
+#[test]
+fn svg_kind_possible_values() {
+    let names: Vec<_> = SvgKind::value_variants()
+        .iter()
+        .filter_map(ValueEnum::to_possible_value)
+        .map(|value| value.get_name().to_owned())
+        .collect();
+    assert_eq!(names, ["svg", "svgz", "svgz.gz", "svg.zz", "svg.deflate"]);
+    for kind in SvgKind::value_variants() {
+        assert_eq!(
+            kind.to_possible_value().unwrap().get_name(),
+            kind.to_string()
+        );
+    }
+}
+
+#[test]
+fn svg_kind_from_str() {
+    assert_eq!(SvgKind::from_str("svg", false), Ok(SvgKind::Svg));
+    assert_eq!(SvgKind::from_str("svgz", false), Ok(SvgKind::Svgz));
+    assert_eq!(SvgKind::from_str("SVGZ", true), Ok(SvgKind::Svgz));
+    assert_eq!(SvgKind::from_str("svgz.gz", false), Ok(SvgKind::NestedSvgz));
+    assert!(SvgKind::from_str("SVGZ", false).is_err());
+    assert!(SvgKind::from_str("any", false).is_err());
+}
+
+#[test]
+fn kind_filter_value_variants() {
+    assert_eq!(
+        KindFilter::value_variants(),
+        [KindFilter::Any, KindFilter::Svg, KindFilter::Svgz]
+    );
+}
+
+#[test]
+fn kind_filter_possible_values() {
+    let names: Vec<_> = KindFilter::value_variants()
+        .iter()
+        .filter_map(ValueEnum::to_possible_value)
+        .map(|value| value.get_name().to_owned())
+        .collect();
+    assert_eq!(names, ["any", "svg", "svgz"]);
+}
+
+#[test]
+fn kind_filter_from_str() {
+    assert_eq!(KindFilter::from_str("any", false), Ok(KindFilter::Any));
+    assert_eq!(KindFilter::from_str("svg", false), Ok(KindFilter::Svg));
+    assert_eq!(KindFilter::from_str("svgz", false), Ok(KindFilter::Svgz));
+    assert!(KindFilter::from_str("png", false).is_err());
+}
+
+#[test]
+fn parse_arguments() {
+    let matches = command()
+        .try_get_matches_from(["test", "--kind", "svgz", "--type", "svg"])
+        .unwrap();
+    assert_eq!(matches.get_one::<SvgKind>("kind"), Some(&SvgKind::Svgz));
+    assert_eq!(
+        matches.get_one::<KindFilter>("type"),
+        Some(&KindFilter::Svg)
+    );
+
+    let matches = command().try_get_matches_from(["test"]).unwrap();
+    assert_eq!(matches.get_one::<SvgKind>("kind"), None);
+    assert_eq!(
+        matches.get_one::<KindFilter>("type"),
+        Some(&KindFilter::Any)
+    );
+}
+
+#[test]
+fn parse_invalid_arguments() {
+    assert!(command()
+        .try_get_matches_from(["test", "--kind", "any"])
+        .is_err());
+    assert!(command()
+        .try_get_matches_from(["test", "--type", "png"])
+        .is_err());
+}
+
+#[test]
+fn help_contains_possible_values() {
+    let help = command().render_long_help().to_string();
+    assert!(help.contains("Non gzip-compressed SVG image (.svg)"));
+    assert!(help.contains("Gzip-compressed SVG image (.svgz)"));
+    assert!(help.contains("Any SVG image"));
+}
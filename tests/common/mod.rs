@@ -0,0 +1,78 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The helpers which are shared by the tests.
+
+// Each test uses only some of them.
+#![allow(dead_code)]
+
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use flate2::{write::GzEncoder, Compression};
+use is_svg::{Error, SvgChecker, SvgKind};
+
+/// The directory of the fixtures.
+pub const DATA_DIR: &str = "tests/data";
+
+/// The directories of the SVG images which are taken from the real world.
+const IMAGE_DIRS: [&str; 4] = ["ferris", "usvg", "w3", "wikipedia"];
+
+/// Returns the sorted paths of the files in `dir` and its subdirectories,
+/// other than the license files.
+pub fn fixtures(dir: impl AsRef<Path>) -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+    walk(dir.as_ref(), &mut paths);
+    paths.sort();
+    paths
+}
+
+/// Returns the paths of the fixtures in `dirs` of [`DATA_DIR`], other than
+/// the documentation files.
+pub fn fixtures_in(dirs: &[&str]) -> Vec<PathBuf> {
+    dirs.iter()
+        .flat_map(|dir| fixtures(Path::new(DATA_DIR).join(dir)))
+        .filter(|path| path.extension().map_or(false, |ext| ext != "adoc"))
+        .collect()
+}
+
+/// Returns the paths of the images with `extension` which are taken from the
+/// real world.
+pub fn images(extension: &str) -> Vec<PathBuf> {
+    fixtures_in(&IMAGE_DIRS)
+        .into_iter()
+        .filter(|path| path.extension().map_or(false, |ext| ext == extension))
+        .collect()
+}
+
+/// Checks `data` by pushing each chunk of `chunk_size` bytes to
+/// [`SvgChecker`].
+pub fn check_chunked(data: &[u8], chunk_size: usize) -> Result<SvgKind, Error> {
+    let mut checker = SvgChecker::new();
+    for chunk in data.chunks(chunk_size) {
+        checker.push(chunk);
+    }
+    checker.finish()
+}
+
+/// Compresses `data` as a gzip member.
+pub fn compress(data: &[u8]) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap()
+}
+
+fn walk(dir: &Path, paths: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            walk(&path, paths);
+        } else if path.extension().map_or(true, |ext| ext != "license") {
+            paths.push(path);
+        }
+    }
+}
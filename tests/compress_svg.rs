@@ -0,0 +1,67 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::{fs, path::Path};
+
+use is_svg::{Compression, Error};
+
+fn svg_fixtures() -> Vec<std::path::PathBuf> {
+    let mut paths = Vec::new();
+    for dir in ["ferris", "usvg", "w3", "wikipedia"] {
+        for entry in fs::read_dir(Path::new("tests/data").join(dir)).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().map_or(false, |ext| ext == "svg") {
+                paths.push(path);
+            }
+        }
+    }
+    paths
+}
+
+#[test]
+fn compress_svg_round_trip() {
+    let paths = svg_fixtures();
+    assert!(!paths.is_empty());
+    for path in paths {
+        let svg = fs::read_to_string(&path).unwrap();
+        let svgz = is_svg::compress_svg(&svg).unwrap();
+        assert!(is_svg::is_svgz(&svgz), "{}", path.display());
+        assert_eq!(is_svg::decompress_svgz(svgz).unwrap(), svg);
+    }
+}
+
+#[test]
+fn compress_svg_with_level_round_trip() {
+    let svg = include_str!("data/w3/svg-logo-v.svg");
+    for level in 0..=9 {
+        let svgz = is_svg::compress_svg_with_level(svg, Compression::new(level)).unwrap();
+        assert!(is_svg::is_svgz(&svgz));
+        assert_eq!(is_svg::decompress_svgz(svgz).unwrap(), svg);
+    }
+}
+
+#[test]
+fn compress_svg_from_svgz() {
+    assert!(matches!(
+        is_svg::compress_svg(include_bytes!("data/w3/svg-logo-v.svgz")),
+        Err(Error::AlreadyCompressed)
+    ));
+    assert!(matches!(
+        is_svg::compress_svg(include_bytes!("data/resources/empty.gz")),
+        Err(Error::AlreadyCompressed)
+    ));
+}
+
+#[test]
+fn compress_svg_from_non_svg() {
+    assert!(matches!(
+        is_svg::compress_svg(include_str!("data/resources/unclosed.svg")),
+        Err(Error::NotXml { .. })
+    ));
+    assert!(matches!(
+        is_svg::compress_svg(include_bytes!("data/w3/svg-logo-v.png")),
+        Err(Error::NotUtf8(_))
+    ));
+    assert!(matches!(is_svg::compress_svg([]), Err(Error::Empty)));
+}
@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use is_svg::CompressionKind;
+
+#[test]
+fn from_magic() {
+    assert_eq!(
+        CompressionKind::from_magic(include_str!("data/w3/svg-logo-v.svg")),
+        CompressionKind::None
+    );
+    assert_eq!(
+        CompressionKind::from_magic(include_bytes!("data/w3/svg-logo-v.png")),
+        CompressionKind::None
+    );
+    assert_eq!(
+        CompressionKind::from_magic(include_bytes!("data/w3/svg-logo-v.svgz")),
+        CompressionKind::Gzip
+    );
+    assert_eq!(
+        CompressionKind::from_magic(include_bytes!("data/resources/empty.gz")),
+        CompressionKind::Gzip
+    );
+    assert_eq!(
+        CompressionKind::from_magic(include_bytes!("data/w3/svg-logo-v.svg.zst")),
+        CompressionKind::Zstd
+    );
+    assert_eq!(
+        CompressionKind::from_magic(include_bytes!("data/w3/svg-logo-v.svg.lz")),
+        CompressionKind::Lzip
+    );
+    assert_eq!(
+        CompressionKind::from_magic(include_bytes!("data/deflate/svg-logo-v.svg.zz")),
+        CompressionKind::Zlib
+    );
+    assert_eq!(
+        CompressionKind::from_magic(b"\xfd7zXZ\x00\x00\x04"),
+        CompressionKind::Xz
+    );
+}
+
+#[test]
+fn from_magic_with_truncated_magic() {
+    assert_eq!(CompressionKind::from_magic([]), CompressionKind::None);
+    assert_eq!(CompressionKind::from_magic([0x1f]), CompressionKind::None);
+    assert_eq!(
+        CompressionKind::from_magic([0x28, 0xb5, 0x2f]),
+        CompressionKind::None
+    );
+    assert_eq!(
+        CompressionKind::from_magic(b"\xfd7zXZ"),
+        CompressionKind::None
+    );
+    assert_eq!(CompressionKind::from_magic(b"LZI"), CompressionKind::None);
+}
+
+#[test]
+fn name() {
+    assert_eq!(CompressionKind::None.name(), "none");
+    assert_eq!(CompressionKind::Gzip.name(), "gzip");
+    assert_eq!(CompressionKind::Zlib.name(), "zlib");
+    assert_eq!(CompressionKind::Deflate.name(), "deflate");
+    assert_eq!(CompressionKind::Zstd.name(), "zstd");
+    assert_eq!(CompressionKind::Xz.name(), "xz");
+    assert_eq!(CompressionKind::Lzip.name(), "lzip");
+}
+
+#[test]
+fn display() {
+    assert_eq!(CompressionKind::None.to_string(), "none");
+    assert_eq!(CompressionKind::Gzip.to_string(), "gzip");
+    assert_eq!(CompressionKind::Zlib.to_string(), "zlib");
+    assert_eq!(CompressionKind::Deflate.to_string(), "deflate");
+    assert_eq!(CompressionKind::Zstd.to_string(), "zstd");
+    assert_eq!(CompressionKind::Xz.to_string(), "xz");
+    assert_eq!(CompressionKind::Lzip.to_string(), "lzip");
+}
+
+#[test]
+fn is_supported() {
+    assert!(CompressionKind::None.is_supported());
+    assert!(CompressionKind::Gzip.is_supported());
+    assert_eq!(CompressionKind::Zlib.is_supported(), cfg!(feature = "gzip"));
+    assert_eq!(
+        CompressionKind::Deflate.is_supported(),
+        cfg!(feature = "gzip")
+    );
+    assert!(!CompressionKind::Zstd.is_supported());
+    assert!(!CompressionKind::Xz.is_supported());
+    assert!(!CompressionKind::Lzip.is_supported());
+}
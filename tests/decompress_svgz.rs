@@ -0,0 +1,81 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+use is_svg::{Error, Limit};
+
+#[test]
+fn decompress_svgz_from_svgz() {
+    assert_eq!(
+        is_svg::decompress_svgz(include_bytes!("data/ferris/corro.svgz")).unwrap(),
+        include_str!("data/ferris/corro.svg")
+    );
+    assert_eq!(
+        is_svg::decompress_svgz(include_bytes!("data/w3/svg-logo-v.svgz")).unwrap(),
+        include_str!("data/w3/svg-logo-v.svg")
+    );
+    assert_eq!(
+        is_svg::decompress_svgz(include_bytes!(
+            "data/wikipedia/SVG_animation_using_SMIL.svgz"
+        ))
+        .unwrap(),
+        include_str!("data/wikipedia/SVG_animation_using_SMIL.svg")
+    );
+}
+
+#[test]
+fn decompress_svgz_from_svg() {
+    assert!(matches!(
+        is_svg::decompress_svgz(include_str!("data/w3/svg-logo-v.svg")),
+        Err(Error::NotGzip)
+    ));
+}
+
+#[test]
+fn decompress_svgz_from_non_svg() {
+    assert!(matches!(
+        is_svg::decompress_svgz(include_bytes!("data/w3/svg-logo-v.png")),
+        Err(Error::NotGzip)
+    ));
+    assert!(matches!(
+        is_svg::decompress_svgz(common::compress(include_bytes!(
+            "data/resources/unclosed.svg"
+        ))),
+        Err(Error::NotXml { .. })
+    ));
+    assert!(matches!(
+        is_svg::decompress_svgz(common::compress(include_bytes!("data/w3/svg-logo-v.png"))),
+        Err(Error::NotUtf8(_))
+    ));
+}
+
+#[test]
+fn decompress_svgz_from_empty() {
+    assert!(matches!(is_svg::decompress_svgz([]), Err(Error::NotGzip)));
+    assert!(matches!(
+        is_svg::decompress_svgz(include_bytes!("data/resources/empty.gz")),
+        Err(Error::Empty)
+    ));
+}
+
+#[test]
+fn decompress_svgz_from_corrupted_svgz() {
+    let data = include_bytes!("data/w3/svg-logo-v.svgz");
+    assert!(matches!(
+        is_svg::decompress_svgz(&data[..data.len() / 2]),
+        Err(Error::GzipDecode(_))
+    ));
+}
+
+#[test]
+fn decompress_svgz_from_too_large_svgz() {
+    let mut svg = String::from(r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1 1">"#);
+    svg.push_str(&" ".repeat(64 * 1024 * 1024));
+    svg.push_str("</svg>");
+    assert!(matches!(
+        is_svg::decompress_svgz(common::compress(svg.as_bytes())),
+        Err(Error::LimitExceeded(Limit::DecompressedSize(67_108_864)))
+    ));
+}
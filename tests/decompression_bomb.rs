@@ -0,0 +1,99 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::{
+    io::{self, Write},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use flate2::{write::GzEncoder, Compression};
+use is_svg::{Decompressor, Error, ErrorKind, GzipDecompressor, Limit, Validator};
+
+const MIB: u64 = 1024 * 1024;
+
+/// Records the largest size of the decompressed data.
+#[derive(Debug)]
+struct Recording(Arc<AtomicUsize>);
+
+impl Decompressor for Recording {
+    fn decompress(&self, data: &[u8], limit: Option<u64>, buf: &mut Vec<u8>) -> io::Result<()> {
+        let result = GzipDecompressor.decompress(data, limit, buf);
+        self.0.fetch_max(buf.len(), Ordering::Relaxed);
+        result
+    }
+}
+
+/// Returns a gzip-compressed SVG data which decompresses to more than `size`
+/// bytes.
+fn svgz(size: u64) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::fast());
+    encoder
+        .write_all(br#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16"><!--"#)
+        .unwrap();
+    let chunk = vec![b' '; 64 * 1024];
+    for _ in 0..size / chunk.len() as u64 {
+        encoder.write_all(&chunk).unwrap();
+    }
+    encoder.write_all(b"--></svg>").unwrap();
+    encoder.finish().unwrap()
+}
+
+#[test]
+fn reject_decompression_bomb() {
+    let data = svgz(65 * MIB);
+    assert!((data.len() as u64) < MIB);
+
+    assert!(!is_svg::is_svg(&data));
+    assert!(!is_svg::is_svgz(&data));
+    let err = is_svg::check_svg(&data).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::LimitExceeded);
+    assert!(matches!(
+        err,
+        Error::LimitExceeded(Limit::DecompressedSize(limit)) if limit == 64 * MIB
+    ));
+    assert!(matches!(
+        Validator::new().check_svg(&data),
+        Err(Error::LimitExceeded(Limit::DecompressedSize(_)))
+    ));
+}
+
+#[test]
+fn decompression_stops_at_limit() {
+    let data = svgz(8 * MIB);
+    let len = Arc::default();
+    let validator = Validator::builder()
+        .max_decompressed_size(MIB)
+        .decompressor(Recording(Arc::clone(&len)))
+        .build();
+    assert!(matches!(
+        validator.check_svg(&data),
+        Err(Error::LimitExceeded(Limit::DecompressedSize(limit))) if limit == MIB
+    ));
+    assert_eq!(len.load(Ordering::Relaxed) as u64, MIB + 1);
+}
+
+#[test]
+fn accept_large_svgz_under_limit() {
+    let data = svgz(16 * MIB);
+    assert!(is_svg::is_svg(&data));
+    assert!(is_svg::is_svgz(&data));
+}
+
+#[test]
+fn max_decompressed_size() {
+    let data = svgz(2 * MIB);
+    assert!(Validator::new().is_svg(&data));
+
+    let validator = Validator::builder().max_decompressed_size(MIB).build();
+    assert!(!validator.is_svg(&data));
+
+    let validator = Validator::builder()
+        .max_decompressed_size(MIB)
+        .max_decompressed_size(u64::MAX)
+        .build();
+    assert!(validator.is_svg(&data));
+}
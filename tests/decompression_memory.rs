@@ -0,0 +1,115 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The peak memory usage is measured by the global allocator, so this file has
+//! only one test to avoid measuring the other tests.
+
+// The global allocator can only be implemented with `unsafe`.
+#![allow(unsafe_code)]
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    io::Write,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use flate2::{write::GzEncoder, Compression};
+use is_svg::{Error, Limit, Validator};
+
+const MIB: usize = 1024 * 1024;
+
+/// Records the current and the peak number of the allocated bytes.
+struct Counting;
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            let current = CURRENT.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+            PEAK.fetch_max(current, Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// Returns the peak number of the bytes allocated by `f` in addition to the
+/// bytes allocated before calling it.
+fn peak_while(f: impl FnOnce()) -> usize {
+    let base = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(base, Ordering::Relaxed);
+    f();
+    PEAK.load(Ordering::Relaxed) - base
+}
+
+/// Returns the gzip-compressed data which decompresses to `prefix` followed
+/// by `size` spaces.
+fn gzip(prefix: &[u8], size: usize) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::fast());
+    encoder.write_all(prefix).unwrap();
+    let chunk = vec![b' '; MIB];
+    for _ in 0..size / chunk.len() {
+        encoder.write_all(&chunk).unwrap();
+    }
+    encoder.finish().unwrap()
+}
+
+#[test]
+fn memory_is_bounded() {
+    let bomb = gzip(
+        br#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16"><!--"#,
+        500 * MIB,
+    );
+    assert!(bomb.len() < 4 * MIB);
+
+    // The decompression stops at the limit, so the buffer never grows much
+    // larger than it.
+    let peak = peak_while(|| {
+        assert!(matches!(
+            is_svg::check_svg(&bomb),
+            Err(Error::LimitExceeded(Limit::DecompressedSize(_)))
+        ));
+    });
+    assert!(peak < 256 * MIB, "{peak}");
+
+    let validator = Validator::builder()
+        .max_decompressed_size(MIB as u64)
+        .build();
+    let peak = peak_while(|| assert!(!validator.is_svg(&bomb)));
+    assert!(peak < 8 * MIB, "{peak}");
+
+    // The size is checked while decompressing a chunk, even if the whole data
+    // is pushed at once.
+    let peak = peak_while(|| {
+        let mut checker = is_svg::SvgChecker::new();
+        checker.push(&bomb);
+        assert!(checker.is_rejected());
+        assert!(matches!(
+            checker.finish(),
+            Err(Error::LimitExceeded(Limit::DecompressedSize(_)))
+        ));
+    });
+    assert!(peak < 256 * MIB, "{peak}");
+
+    // The data which does not start with `<` is rejected after decompressing
+    // only the first 1 KiB of it.
+    let garbage = gzip(b"garbage", 500 * MIB);
+    let peak = peak_while(|| {
+        assert!(!is_svg::is_svg(&garbage));
+        assert!(!is_svg::is_svgz(&garbage));
+        assert!(is_svg::detect(&garbage).is_none());
+        assert!(!Validator::new().is_svg(&garbage));
+    });
+    assert!(peak < MIB, "{peak}");
+}
@@ -0,0 +1,332 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::{
+    io::{self, Write},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use flate2::{write::GzEncoder, Compression};
+use is_svg::{Decompressor, Error, ErrorKind, GzipDecompressor, Limit, SvgKind, Validator};
+
+/// Compresses each of `parts` into a gzip member, and concatenates them.
+fn multi_member(parts: &[&[u8]]) -> Vec<u8> {
+    parts
+        .iter()
+        .flat_map(|part| {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(part).unwrap();
+            encoder.finish().unwrap()
+        })
+        .collect()
+}
+
+#[derive(Debug, Default)]
+struct Counting(AtomicUsize);
+
+impl Decompressor for Counting {
+    fn decompress(&self, data: &[u8], limit: Option<u64>, buf: &mut Vec<u8>) -> io::Result<()> {
+        self.0.fetch_add(1, Ordering::Relaxed);
+        GzipDecompressor.decompress(data, limit, buf)
+    }
+}
+
+#[derive(Debug)]
+struct Failing;
+
+impl Decompressor for Failing {
+    fn decompress(&self, _: &[u8], _: Option<u64>, _: &mut Vec<u8>) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::Unsupported, "unsupported"))
+    }
+}
+
+#[derive(Debug)]
+struct Fixed(&'static [u8]);
+
+impl Decompressor for Fixed {
+    fn decompress(&self, _: &[u8], _: Option<u64>, buf: &mut Vec<u8>) -> io::Result<()> {
+        buf.extend_from_slice(self.0);
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct Panicking;
+
+impl Decompressor for Panicking {
+    fn decompress(&self, _: &[u8], _: Option<u64>, _: &mut Vec<u8>) -> io::Result<()> {
+        panic!("decompressor panicked")
+    }
+}
+
+#[test]
+fn gzip_decompressor() {
+    let mut buf = Vec::new();
+    GzipDecompressor
+        .decompress(include_bytes!("data/w3/svg-logo-v.svgz"), None, &mut buf)
+        .unwrap();
+    assert_eq!(buf, include_bytes!("data/w3/svg-logo-v.svg"));
+}
+
+#[test]
+fn gzip_decompressor_appends_to_buf() {
+    let mut buf = b"prefix".to_vec();
+    GzipDecompressor
+        .decompress(include_bytes!("data/w3/svg-logo-v.svgz"), None, &mut buf)
+        .unwrap();
+    assert!(buf.starts_with(b"prefix"));
+    assert_eq!(&buf[6..], include_bytes!("data/w3/svg-logo-v.svg"));
+}
+
+#[test]
+fn gzip_decompressor_with_limit() {
+    let mut buf = Vec::new();
+    GzipDecompressor
+        .decompress(
+            include_bytes!("data/w3/svg-logo-v.svgz"),
+            Some(64),
+            &mut buf,
+        )
+        .unwrap();
+    assert_eq!(buf.len(), 65);
+}
+
+#[test]
+fn gzip_decompressor_with_invalid_data() {
+    let mut buf = Vec::new();
+    assert!(GzipDecompressor
+        .decompress(
+            include_bytes!("data/hostile/truncated.svgz"),
+            None,
+            &mut buf
+        )
+        .is_err());
+}
+
+#[test]
+fn gzip_decompressor_with_multiple_members() {
+    let svg = include_bytes!("data/w3/svg-logo-v.svg");
+    let (first, second) = svg.split_at(svg.len() / 2);
+    let mut buf = Vec::new();
+    GzipDecompressor
+        .decompress(&multi_member(&[first, second]), None, &mut buf)
+        .unwrap();
+    assert_eq!(buf, svg);
+
+    // The limit applies to the concatenation of the members.
+    let mut buf = Vec::new();
+    GzipDecompressor
+        .decompress(&multi_member(&[first, second]), Some(64), &mut buf)
+        .unwrap();
+    assert_eq!(buf.len(), 65);
+}
+
+#[test]
+fn validator_with_multiple_members() {
+    let svg = include_bytes!("data/w3/svg-logo-v.svg");
+    let (first, second) = svg.split_at(svg.len() / 2);
+    let halves = multi_member(&[first, second]);
+    let twice = multi_member(&[svg, svg]);
+    let single = multi_member(&[svg]);
+    let mut trailing = single.clone();
+    trailing.extend_from_slice(&[0; 16]);
+
+    // All the members are decompressed by default.
+    let validator = Validator::new();
+    assert_eq!(validator.detect(&halves), Some(SvgKind::Svgz));
+    assert_eq!(validator.detect(&single), Some(SvgKind::Svgz));
+    assert_eq!(
+        is_svg::decompress_svgz(&halves).unwrap().as_bytes(),
+        &svg[..]
+    );
+    assert!(is_svg::looks_like_svgz(multi_member(&[b"", svg])));
+    // The concatenation is not a valid XML document, so the first member is
+    // not validated alone.
+    assert_eq!(
+        validator.check_svg(&twice).unwrap_err().kind(),
+        ErrorKind::NotXml
+    );
+    assert_eq!(
+        validator
+            .check_svg(multi_member(&[first]))
+            .unwrap_err()
+            .kind(),
+        ErrorKind::NotXml
+    );
+    assert_eq!(
+        validator.check_svg(&trailing).unwrap_err().kind(),
+        ErrorKind::Gzip
+    );
+
+    for validator in [
+        Validator::builder().single_member_only(true).build(),
+        Validator::strict(),
+    ] {
+        assert_eq!(validator.detect(&single), Some(SvgKind::Svgz));
+        assert!(validator.is_svgz(include_bytes!("data/w3/svg-logo-v.svgz")));
+        for data in [&halves, &twice, &trailing] {
+            assert!(matches!(
+                validator.check_svg(data),
+                Err(Error::GzipDecode(err)) if err.kind() == io::ErrorKind::InvalidData
+            ));
+        }
+    }
+
+    // The limit applies before the rest of the data is tested.
+    let validator = Validator::builder()
+        .single_member_only(true)
+        .max_decompressed_size(64)
+        .build();
+    assert!(matches!(
+        validator.check_svg(&halves),
+        Err(Error::LimitExceeded(Limit::DecompressedSize(64)))
+    ));
+
+    // This has no effect on the custom decompressor.
+    let validator = Validator::builder()
+        .single_member_only(true)
+        .decompressor(Counting::default())
+        .build();
+    assert!(validator.is_svgz(&halves));
+}
+
+#[test]
+fn validator_with_trailing_garbage() {
+    let svgz = include_bytes!("data/w3/svg-logo-v.svgz");
+    let with = |garbage: &[u8]| [&svgz[..], garbage].concat();
+    let one_byte = with(b"\n");
+    let zeros = with(&[0; 1024]);
+    let png = with(include_bytes!("data/w3/svg-logo-v.png"));
+    let garbage = [&one_byte, &zeros, &png];
+
+    // The garbage is rejected by default.
+    for validator in [Validator::new(), Validator::strict()] {
+        assert!(validator.is_svgz(svgz));
+        for data in garbage {
+            assert!(matches!(
+                validator.check_svg(data),
+                Err(Error::GzipDecode(err)) if err.kind() == io::ErrorKind::InvalidData
+            ));
+        }
+    }
+    assert_eq!(
+        is_svg::check_svg(&zeros).unwrap_err().kind(),
+        ErrorKind::Gzip
+    );
+
+    for validator in [
+        Validator::builder().allow_trailing_garbage(true).build(),
+        Validator::lenient(),
+    ] {
+        for data in garbage {
+            assert_eq!(validator.detect(data), Some(SvgKind::Svgz));
+        }
+        // The garbage after the multiple members.
+        let svg = include_bytes!("data/w3/svg-logo-v.svg");
+        let (first, second) = svg.split_at(svg.len() / 2);
+        let mut halves = multi_member(&[first, second]);
+        halves.extend_from_slice(&[0; 1024]);
+        assert!(validator.is_svgz(&halves));
+        // The data which starts with the gzip magic number is not garbage.
+        assert_eq!(
+            validator
+                .check_svg(with(&[0x1f, 0x8b, 0x00]))
+                .unwrap_err()
+                .kind(),
+            ErrorKind::Gzip
+        );
+    }
+
+    // The garbage is still allowed if only one member is allowed.
+    let validator = Validator::builder()
+        .single_member_only(true)
+        .allow_trailing_garbage(true)
+        .build();
+    assert!(validator.is_svgz(&png));
+    assert!(!validator.is_svgz(multi_member(&[include_bytes!("data/w3/svg-logo-v.svg")]).repeat(2)));
+}
+
+#[test]
+fn validator_with_custom_decompressor() {
+    let validator = Validator::builder()
+        .decompressor(Counting::default())
+        .build();
+    assert!(validator.is_svgz(include_bytes!("data/w3/svg-logo-v.svgz")));
+    assert!(validator.is_svgz(include_bytes!("data/ferris/corro.svgz")));
+    assert!(validator.is_svg_string(include_str!("data/w3/svg-logo-v.svg")));
+    assert!(!validator.is_svg(include_bytes!("data/w3/svg-logo-v.png")));
+}
+
+#[test]
+fn validator_calls_decompressor_only_for_gzip() {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Debug)]
+    struct Static;
+
+    impl Decompressor for Static {
+        fn decompress(&self, data: &[u8], limit: Option<u64>, buf: &mut Vec<u8>) -> io::Result<()> {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+            GzipDecompressor.decompress(data, limit, buf)
+        }
+    }
+
+    let validator = Validator::builder().decompressor(Static).build();
+    assert!(validator.is_svg(include_str!("data/w3/svg-logo-v.svg")));
+    assert_eq!(COUNT.load(Ordering::Relaxed), 0);
+    assert!(validator.is_svg(include_bytes!("data/w3/svg-logo-v.svgz")));
+    assert_eq!(COUNT.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn validator_with_failing_decompressor() {
+    let validator = Validator::builder().decompressor(Failing).build();
+    assert!(matches!(
+        validator.check_svg(include_bytes!("data/w3/svg-logo-v.svgz")),
+        Err(Error::GzipDecode(err)) if err.kind() == io::ErrorKind::Unsupported
+    ));
+    assert!(validator.is_svg(include_str!("data/w3/svg-logo-v.svg")));
+}
+
+#[test]
+fn validator_uses_decompressed_data() {
+    let validator = Validator::builder()
+        .decompressor(Fixed(include_bytes!("data/w3/svg-logo-v.svg")))
+        .build();
+    assert!(validator.is_svgz(include_bytes!("data/resources/empty.gz")));
+
+    let validator = Validator::builder()
+        .decompressor(Fixed(include_bytes!("data/w3/svg-logo-v.png")))
+        .build();
+    assert!(matches!(
+        validator.check_svg(include_bytes!("data/w3/svg-logo-v.svgz")),
+        Err(Error::NotUtf8(_))
+    ));
+}
+
+#[test]
+fn validator_max_size_applies_before_decompression() {
+    let validator = Validator::builder()
+        .max_size(16)
+        .decompressor(Panicking)
+        .build();
+    assert!(matches!(
+        validator.check_svg(include_bytes!("data/w3/svg-logo-v.svgz")),
+        Err(Error::LimitExceeded(Limit::InputSize(16)))
+    ));
+}
+
+#[test]
+#[should_panic(expected = "decompressor panicked")]
+fn panic_from_decompressor_is_propagated() {
+    let validator = Validator::builder().decompressor(Panicking).build();
+    let _ = validator.is_svgz(include_bytes!("data/w3/svg-logo-v.svgz"));
+}
+
+#[test]
+fn validator_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<Validator>();
+}
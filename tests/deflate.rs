@@ -0,0 +1,191 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::io::Write;
+
+use flate2::{
+    write::{DeflateEncoder, ZlibEncoder},
+    Compression,
+};
+use is_svg::{CompressionKind, Error, ErrorKind, Limit, SvgKind, Validator, ValidatorBuilder};
+
+const SVG: &[u8] = include_bytes!("data/w3/svg-logo-v.svg");
+const ZLIB: &[u8] = include_bytes!("data/deflate/svg-logo-v.svg.zz");
+const DEFLATE: &[u8] = include_bytes!("data/deflate/svg-logo-v.svg.deflate");
+
+fn accepting(kinds: &[CompressionKind]) -> Validator {
+    ValidatorBuilder::new().accepted_compressions(kinds).build()
+}
+
+fn with_all() -> Validator {
+    accepting(&[
+        CompressionKind::None,
+        CompressionKind::Gzip,
+        CompressionKind::Zlib,
+        CompressionKind::Deflate,
+    ])
+}
+
+fn zlib(data: &[u8], level: Compression) -> Vec<u8> {
+    let mut encoder = ZlibEncoder::new(Vec::new(), level);
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap()
+}
+
+fn deflate(data: &[u8]) -> Vec<u8> {
+    let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap()
+}
+
+#[test]
+fn zlib_header() {
+    assert_eq!(CompressionKind::from_magic(ZLIB), CompressionKind::Zlib);
+    for level in [0, 1, 6, 9] {
+        assert_eq!(
+            CompressionKind::from_magic(zlib(SVG, Compression::new(level))),
+            CompressionKind::Zlib
+        );
+    }
+    // The check bits do not match.
+    assert_eq!(
+        CompressionKind::from_magic([0x78, 0x9d]),
+        CompressionKind::None
+    );
+    // The preset dictionary is required.
+    assert_eq!(
+        CompressionKind::from_magic([0x78, 0xbb]),
+        CompressionKind::None
+    );
+    assert_eq!(CompressionKind::from_magic([0x78]), CompressionKind::None);
+    assert_eq!(CompressionKind::from_magic(DEFLATE), CompressionKind::None);
+    assert_eq!(CompressionKind::from_magic(SVG), CompressionKind::None);
+}
+
+#[test]
+fn zlib_is_rejected_by_default() {
+    for validator in [Validator::lenient(), Validator::new(), Validator::strict()] {
+        assert!(matches!(
+            validator.check_svg(ZLIB),
+            Err(Error::UnsupportedCompression(CompressionKind::Zlib))
+        ));
+    }
+    assert!(!is_svg::is_svg(ZLIB));
+    assert_eq!(is_svg::detect(ZLIB), None);
+}
+
+#[test]
+fn deflate_is_rejected_by_default() {
+    for validator in [Validator::lenient(), Validator::new(), Validator::strict()] {
+        assert!(!validator.is_svg(DEFLATE));
+    }
+    assert!(!is_svg::is_svg(DEFLATE));
+    assert_eq!(is_svg::detect(DEFLATE), None);
+    // Only listing the zlib does not try the raw deflate.
+    assert!(!accepting(&[CompressionKind::None, CompressionKind::Zlib]).is_svg(DEFLATE));
+}
+
+#[test]
+fn detect_zlib() {
+    let validator = with_all();
+    assert_eq!(validator.detect(ZLIB), Some(SvgKind::ZlibSvg));
+    assert!(validator.is_svg(ZLIB));
+    assert!(!validator.is_svgz(ZLIB));
+    assert_eq!(
+        validator.detect(zlib(SVG, Compression::best())),
+        Some(SvgKind::ZlibSvg)
+    );
+    assert!(accepting(&[CompressionKind::Zlib]).is_svg(ZLIB));
+}
+
+#[test]
+fn detect_deflate() {
+    let validator = with_all();
+    assert_eq!(validator.detect(DEFLATE), Some(SvgKind::DeflateSvg));
+    assert!(validator.is_svg(DEFLATE));
+    assert!(!validator.is_svgz(DEFLATE));
+    assert_eq!(validator.detect(deflate(SVG)), Some(SvgKind::DeflateSvg));
+
+    // The other formats are still detected as usual.
+    assert_eq!(validator.detect(SVG), Some(SvgKind::Svg));
+    assert_eq!(
+        validator.detect(include_bytes!("data/w3/svg-logo-v.svgz")),
+        Some(SvgKind::Svgz)
+    );
+}
+
+#[test]
+fn deflate_keeps_error() {
+    let validator = with_all();
+    for data in [
+        &include_bytes!("data/w3/svg-logo-v.png")[..],
+        include_bytes!("data/resources/index.html"),
+        b"",
+    ] {
+        assert_eq!(
+            validator.check_svg(data).unwrap_err().kind(),
+            Validator::new().check_svg(data).unwrap_err().kind()
+        );
+    }
+    // The decompressed data which is not a SVG string is rejected with the
+    // error of the data as is.
+    let data = deflate(b"<html></html>");
+    assert_eq!(
+        validator.check_svg(&data).unwrap_err().kind(),
+        Validator::new().check_svg(&data).unwrap_err().kind()
+    );
+}
+
+#[test]
+fn corrupt_zlib() {
+    let validator = with_all();
+    let mut data = ZLIB.to_vec();
+    // The Adler-32 checksum in the trailer.
+    *data.last_mut().unwrap() ^= 1;
+    assert_eq!(
+        validator.check_svg(&data).unwrap_err().kind(),
+        ErrorKind::Gzip
+    );
+    let truncated = &ZLIB[..ZLIB.len() / 2];
+    assert!(matches!(
+        validator.check_svg(truncated),
+        Err(Error::GzipDecode(_))
+    ));
+    assert_eq!(
+        validator
+            .check_svg(zlib(b"<html></html>", Compression::default()))
+            .unwrap_err()
+            .kind(),
+        ErrorKind::NotSvgRoot
+    );
+}
+
+#[test]
+fn decompressed_size_limit() {
+    let data = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16"><!--{}--></svg>"#,
+        " ".repeat(1024 * 1024)
+    );
+    for (compressed, kind) in [
+        (
+            zlib(data.as_bytes(), Compression::default()),
+            SvgKind::ZlibSvg,
+        ),
+        (deflate(data.as_bytes()), SvgKind::DeflateSvg),
+    ] {
+        assert_eq!(with_all().detect(&compressed), Some(kind));
+        let validator = ValidatorBuilder::new()
+            .accepted_compressions(&[CompressionKind::Zlib, CompressionKind::Deflate])
+            .max_decompressed_size(64 * 1024)
+            .build();
+        if kind == SvgKind::ZlibSvg {
+            assert!(matches!(
+                validator.check_svg(&compressed),
+                Err(Error::LimitExceeded(Limit::DecompressedSize(65536)))
+            ));
+        } else {
+            assert!(!validator.is_svg(&compressed));
+        }
+    }
+}
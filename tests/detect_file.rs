@@ -0,0 +1,80 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::{fs, io};
+
+use is_svg::SvgKind;
+
+#[test]
+fn detect_file_from_svg() {
+    assert_eq!(
+        is_svg::detect_file("tests/data/ferris/corro.svg").unwrap(),
+        Some(SvgKind::Svg)
+    );
+    assert_eq!(
+        is_svg::detect_file("tests/data/w3/svg-logo-v.svg").unwrap(),
+        Some(SvgKind::Svg)
+    );
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn detect_file_from_svgz() {
+    assert_eq!(
+        is_svg::detect_file("tests/data/ferris/corro.svgz").unwrap(),
+        Some(SvgKind::Svgz)
+    );
+    assert_eq!(
+        is_svg::detect_file("tests/data/w3/svg-logo-v.svgz").unwrap(),
+        Some(SvgKind::Svgz)
+    );
+}
+
+#[test]
+fn detect_file_from_non_svg() {
+    assert_eq!(
+        is_svg::detect_file("tests/data/resources/unclosed.svg").unwrap(),
+        None
+    );
+    assert_eq!(
+        is_svg::detect_file("tests/data/w3/svg-logo-v.png").unwrap(),
+        None
+    );
+}
+
+#[test]
+fn detect_file_from_non_existent_file() {
+    assert_eq!(
+        is_svg::detect_file("tests/data/w3/non-existent.svg")
+            .unwrap_err()
+            .kind(),
+        io::ErrorKind::NotFound
+    );
+}
+
+#[test]
+fn detect_file_limited_under_and_over_limit() {
+    let path = "tests/data/w3/svg-logo-v.svg";
+    let size = fs::metadata(path).unwrap().len();
+    assert_eq!(
+        is_svg::detect_file_limited(path, size).unwrap(),
+        Some(SvgKind::Svg)
+    );
+    assert_eq!(is_svg::detect_file_limited(path, size - 1).unwrap(), None);
+    // Within the limit, the result is the same as `detect_file`.
+    assert_eq!(
+        is_svg::detect_file_limited(path, size).unwrap(),
+        is_svg::detect_file(path).unwrap()
+    );
+}
+
+#[test]
+fn detect_file_limited_from_non_existent_file() {
+    assert_eq!(
+        is_svg::detect_file_limited("tests/data/w3/non-existent.svg", 0)
+            .unwrap_err()
+            .kind(),
+        io::ErrorKind::NotFound
+    );
+}
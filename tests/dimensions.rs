@@ -0,0 +1,179 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use is_svg::{usvg::Tree, Error, Options, Validator, ValidatorBuilder};
+
+const A4: &str = include_str!("data/dimensions/a4.svg");
+const PERCENTAGE: &str = include_str!("data/dimensions/percentage.svg");
+const MISSING: &str = include_str!("data/dimensions/missing.svg");
+const PIXELS: &str = include_str!("data/dimensions/pixels.svg");
+
+/// The tolerance of the sizes which are converted from the absolute units.
+const EPSILON: f32 = 1e-3;
+
+fn assert_dimensions(actual: Option<(f32, f32)>, expected: (f32, f32)) {
+    let (width, height) = actual.unwrap();
+    assert!((width - expected.0).abs() < EPSILON, "{width}");
+    assert!((height - expected.1).abs() < EPSILON, "{height}");
+}
+
+#[test]
+fn dimensions_in_mm() {
+    assert_dimensions(is_svg::dimensions(A4).unwrap(), (793.700_8, 1_122.519_7));
+    assert_dimensions(
+        Validator::new().dimensions(A4).unwrap(),
+        (793.700_8, 1_122.519_7),
+    );
+}
+
+#[test]
+fn dimensions_in_pixels() {
+    assert_eq!(is_svg::dimensions(PIXELS).unwrap(), Some((640.0, 480.0)));
+    // The pixels do not depend on the DPI.
+    assert_eq!(
+        ValidatorBuilder::new()
+            .dpi(300.0)
+            .build()
+            .dimensions(PIXELS)
+            .unwrap(),
+        Some((640.0, 480.0))
+    );
+}
+
+#[test]
+fn dimensions_without_absolute_size() {
+    for text in [PERCENTAGE, MISSING] {
+        assert!(is_svg::is_svg(text));
+        assert_eq!(is_svg::dimensions(text).unwrap(), None);
+        assert_eq!(Validator::new().dimensions(text).unwrap(), None);
+    }
+    for (width, height) in [("16", "100%"), ("100%", "16"), ("1em", "16"), ("16", "2ex")] {
+        let text = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 16 16"/>"#
+        );
+        assert_eq!(is_svg::dimensions(text).unwrap(), None, "{width} {height}");
+    }
+    let text = r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" viewBox="0 0 16 16"/>"#;
+    assert_eq!(is_svg::dimensions(text).unwrap(), None);
+}
+
+#[test]
+fn dimensions_with_units() {
+    for (width, height, expected) in [
+        ("1in", "2in", (96.0, 192.0)),
+        ("2.54cm", "25.4mm", (96.0, 96.0)),
+        ("72pt", "6pc", (96.0, 96.0)),
+        (" 16px", "1.6e1", (16.0, 16.0)),
+    ] {
+        let text = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}"/>"#
+        );
+        assert_dimensions(is_svg::dimensions(text).unwrap(), expected);
+    }
+}
+
+#[test]
+fn dimensions_with_dpi() {
+    let validator = ValidatorBuilder::new().dpi(300.0).build();
+    assert_dimensions(validator.dimensions(A4).unwrap(), (2_480.315, 3_507.874));
+    let text = r#"<svg xmlns="http://www.w3.org/2000/svg" width="1in" height="72pt"/>"#;
+    assert_eq!(validator.dimensions(text).unwrap(), Some((300.0, 300.0)));
+
+    // `dpi` overrides the options which are set before it.
+    let validator = ValidatorBuilder::new()
+        .dpi(300.0)
+        .options(Options::default())
+        .build();
+    assert_eq!(validator.dimensions(text).unwrap(), Some((96.0, 96.0)));
+}
+
+#[test]
+fn dimensions_are_same_as_usvg() {
+    for dpi in [72.0, 96.0, 300.0] {
+        let opt = Options {
+            dpi,
+            ..Default::default()
+        };
+        let size = Tree::from_str(A4, &opt).unwrap().size();
+        assert_dimensions(
+            ValidatorBuilder::new()
+                .dpi(dpi)
+                .build()
+                .dimensions(A4)
+                .unwrap(),
+            (size.width(), size.height()),
+        );
+    }
+    let size = is_svg::parse_svg(PIXELS).unwrap().size();
+    assert_eq!(
+        is_svg::dimensions(PIXELS).unwrap(),
+        Some((size.width(), size.height()))
+    );
+}
+
+#[test]
+fn dimensions_are_same_as_usvg_for_lengths() {
+    for (width, height, expected) in [
+        (" 10", "\t10px", Some((10.0, 10.0))),
+        ("1in", "72pt", Some((96.0, 96.0))),
+        ("2.54cm", "6pc", Some((96.0, 96.0))),
+        // `usvg` does not allow the trailing whitespace, so it uses the default
+        // width.
+        (" 10 ", "10", None),
+        ("10px ", "10", None),
+        ("1in ", "10", None),
+        // `usvg` does not allow the uppercase units.
+        ("10PX", "10", None),
+        // `usvg` resolves them against the font size, which is 12 by default.
+        ("2em", "10", None),
+        ("10", "2ex", None),
+    ] {
+        let text = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}"/>"#
+        );
+        let dimensions = is_svg::dimensions(&text).unwrap();
+        assert_eq!(dimensions, expected, "{width:?} {height:?}");
+        let size = is_svg::parse_svg(&text).unwrap().size();
+        match dimensions {
+            Some(dimensions) => assert_dimensions(Some(dimensions), (size.width(), size.height())),
+            None => assert_ne!(
+                (size.width(), size.height()),
+                (10.0, 10.0),
+                "{width:?} {height:?}"
+            ),
+        }
+    }
+    let size =
+        is_svg::parse_svg(r#"<svg xmlns="http://www.w3.org/2000/svg" width="2em" height="10"/>"#)
+            .unwrap()
+            .size();
+    assert_eq!((size.width(), size.height()), (24.0, 10.0));
+}
+
+#[test]
+fn dimensions_from_invalid_data() {
+    assert!(matches!(
+        is_svg::dimensions(include_bytes!("data/w3/svg-logo-v.png")),
+        Err(Error::NotUtf8(_))
+    ));
+    assert!(matches!(
+        is_svg::dimensions(r#"<svg xmlns="http://www.w3.org/2000/svg" width="0" height="16"/>"#),
+        Err(Error::Parse(_))
+    ));
+    assert!(matches!(
+        Validator::strict().dimensions(include_str!("data/policy/script.svg")),
+        Err(Error::DisallowedElement { .. })
+    ));
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn dimensions_from_svgz() {
+    let data = is_svg::compress_svg(A4).unwrap();
+    assert_dimensions(is_svg::dimensions(&data).unwrap(), (793.700_8, 1_122.519_7));
+    assert_eq!(
+        is_svg::dimensions(include_bytes!("data/w3/svg-logo-v.svgz")).unwrap(),
+        None
+    );
+}
@@ -0,0 +1,114 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::{io, net::TcpListener};
+
+use is_svg::{
+    usvg::{self, roxmltree},
+    Error, Limit, Validator, ValidatorBuilder,
+};
+
+const ILLUSTRATOR: &str = include_str!("data/dtd/illustrator.svg");
+
+#[test]
+fn internal_subset_from_illustrator() {
+    assert!(is_svg::is_svg(ILLUSTRATOR));
+    assert!(Validator::new().is_svg(ILLUSTRATOR));
+    assert!(Validator::lenient().is_svg(ILLUSTRATOR));
+    assert!(ValidatorBuilder::new()
+        .fast(true)
+        .build()
+        .is_svg(ILLUSTRATOR));
+    // The namespace of the root element is declared with the entity, and the
+    // element policy is applied after expanding it.
+    assert!(matches!(
+        ValidatorBuilder::strict()
+            .allow_dtd(true)
+            .build()
+            .check_svg(ILLUSTRATOR),
+        Err(Error::DisallowedElement { name, .. }) if name == "foreignObject"
+    ));
+    assert!(matches!(
+        Validator::strict().check_svg(ILLUSTRATOR),
+        Err(Error::NotXml {
+            source: roxmltree::Error::DtdDetected,
+            ..
+        })
+    ));
+
+    let tree = is_svg::parse_svg(ILLUSTRATOR).unwrap();
+    assert_eq!(tree.size(), usvg::Size::from_wh(64.0, 64.0).unwrap());
+    assert!(tree.root().has_children());
+}
+
+#[test]
+fn internal_subset_within_limits() {
+    assert!(ValidatorBuilder::new()
+        .max_entity_expansion(1024)
+        .build()
+        .is_svg(ILLUSTRATOR));
+    assert!(matches!(
+        ValidatorBuilder::new()
+            .max_entity_expansion(64)
+            .build()
+            .check_svg(ILLUSTRATOR),
+        Err(Error::LimitExceeded(Limit::EntityExpansion(64)))
+    ));
+}
+
+#[test]
+fn external_entity_is_not_loaded() {
+    assert!(matches!(
+        is_svg::check_svg(include_str!("data/hostile/external-entity.svg")),
+        Err(Error::NotXml { source: roxmltree::Error::UnknownEntityReference(name, _), .. }) if name == "xxe"
+    ));
+    assert!(!Validator::lenient().is_svg(include_str!("data/hostile/external-entity.svg")));
+}
+
+#[test]
+fn external_declarations_do_not_trigger_io() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    listener.set_nonblocking(true).unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let svg = r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16"><text>&ext;</text></svg>"#;
+    let empty = r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16"/>"#;
+
+    for (data, expected) in [
+        (
+            format!(r#"<!DOCTYPE svg SYSTEM "{url}/svg.dtd">{empty}"#),
+            true,
+        ),
+        (
+            format!(r#"<!DOCTYPE svg PUBLIC "-//W3C//DTD SVG 1.1//EN" "{url}/svg.dtd">{empty}"#),
+            true,
+        ),
+        (
+            format!(r#"<!DOCTYPE svg [<!ENTITY ext SYSTEM "{url}/entity">]>{empty}"#),
+            true,
+        ),
+        (
+            format!(r#"<!DOCTYPE svg [<!ENTITY ext SYSTEM "{url}/entity">]>{svg}"#),
+            false,
+        ),
+        (
+            format!(
+                r#"<!DOCTYPE svg [<!ENTITY ext PUBLIC "-//EXAMPLE//ENTITY" "{url}/entity">]>{svg}"#
+            ),
+            false,
+        ),
+        (
+            format!(r#"<!DOCTYPE svg [<!ENTITY % dtd SYSTEM "{url}/svg.dtd"> %dtd;]>{svg}"#),
+            false,
+        ),
+    ] {
+        for validator in [Validator::new(), Validator::lenient()] {
+            assert_eq!(validator.is_svg(&data), expected, "{data}");
+        }
+        assert_eq!(is_svg::is_svg(&data), expected, "{data}");
+    }
+    assert_eq!(
+        listener.accept().unwrap_err().kind(),
+        io::ErrorKind::WouldBlock
+    );
+}
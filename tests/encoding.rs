@@ -0,0 +1,99 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use is_svg::{usvg::Node, Error, ErrorKind, Validator};
+
+fn svg(encoding: &str, id: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="{encoding}"?><svg xmlns="http://www.w3.org/2000/svg" width="16" height="16"><rect id="{id}" width="8" height="8"/></svg>"#
+    )
+}
+
+/// Encodes `text` in windows-1252, which is a superset of ISO-8859-1 except the
+/// C1 controls.
+fn encode_windows_1252(text: &str) -> Vec<u8> {
+    text.chars()
+        .map(|c| match c {
+            '€' => 0x80,
+            '“' => 0x93,
+            '”' => 0x94,
+            c => u8::try_from(u32::from(c)).unwrap(),
+        })
+        .collect()
+}
+
+fn id_of_first_node(data: &[u8]) -> String {
+    let tree = is_svg::parse_svg(data).unwrap();
+    let node = tree.root().children().first().unwrap();
+    match node {
+        Node::Group(group) => group.id(),
+        Node::Path(path) => path.id(),
+        Node::Image(image) => image.id(),
+        Node::Text(text) => text.id(),
+    }
+    .to_owned()
+}
+
+#[test]
+fn encoding_from_fixtures() {
+    for data in [
+        &include_bytes!("data/encoding/iso-8859-1.svg")[..],
+        include_bytes!("data/encoding/windows-1252.svg"),
+    ] {
+        assert!(std::str::from_utf8(data).is_err());
+        assert!(is_svg::is_svg(data));
+        assert!(is_svg::check_svg(data).is_ok());
+        assert!(Validator::lenient().is_svg(data));
+        assert!(Validator::strict().is_svg(data));
+        assert!(is_svg::check_svg_reader(data).is_ok());
+    }
+}
+
+#[test]
+fn encoding_round_trip() {
+    for (encoding, id) in [
+        ("ISO-8859-1", "café"),
+        ("iso-8859-1", "Señor"),
+        ("latin1", "Müller"),
+        ("windows-1252", "“5 €”"),
+        ("cp1252", "crème"),
+    ] {
+        let data = encode_windows_1252(&svg(encoding, id));
+        assert_eq!(id_of_first_node(&data), id, "{encoding}");
+    }
+    // The same document in UTF-8.
+    assert_eq!(id_of_first_node(svg("UTF-8", "café").as_bytes()), "café");
+}
+
+#[test]
+fn encoding_with_utf8() {
+    // The byte order mark takes precedence over the declaration.
+    let mut data = b"\xef\xbb\xbf".to_vec();
+    data.extend(encode_windows_1252(&svg("ISO-8859-1", "café")));
+    assert!(matches!(is_svg::check_svg(&data), Err(Error::NotUtf8(_))));
+
+    let data = encode_windows_1252(&svg("UTF-8", "café"));
+    assert!(matches!(is_svg::check_svg(data), Err(Error::NotUtf8(_))));
+
+    // This is not encoded in UTF-16, so it is treated as UTF-8.
+    assert!(is_svg::is_svg(svg("UTF-16", "café")));
+}
+
+#[test]
+fn encoding_unsupported() {
+    for encoding in ["EBCDIC-US", "ISO-2022-KR", "unknown"] {
+        let err = is_svg::check_svg(svg(encoding, "id")).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnsupportedEncoding);
+        assert!(matches!(err, Error::UnsupportedEncoding(label) if label == encoding));
+    }
+}
+
+#[test]
+fn encoding_invalid() {
+    let mut data = svg("Shift_JIS", "id").into_bytes();
+    data.extend_from_slice(b"\x81\x20");
+    let err = is_svg::check_svg(data).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidEncoding);
+    assert!(matches!(err, Error::InvalidEncoding("Shift_JIS")));
+}
@@ -0,0 +1,236 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::{error::Error as _, io};
+
+use is_svg::{usvg::roxmltree::TextPos, CompressionKind, Error, ErrorKind, Limit, Location};
+
+const LOCATION: Location = Location {
+    position: TextPos { row: 1, col: 1 },
+    offset: 0,
+    decompressed: false,
+};
+
+#[test]
+fn kind_from_resources() {
+    for (data, kind) in [
+        (
+            &include_bytes!("data/resources/dtd.svg")[..],
+            ErrorKind::NotXml,
+        ),
+        (
+            include_bytes!("data/resources/index.html"),
+            ErrorKind::NotXml,
+        ),
+        (
+            include_bytes!("data/resources/quadratic_formula.mml"),
+            ErrorKind::NotXml,
+        ),
+        (
+            include_bytes!("data/resources/unclosed.svg"),
+            ErrorKind::NotXml,
+        ),
+        (
+            include_bytes!("data/resources/xml_declaration.svg"),
+            ErrorKind::NotXml,
+        ),
+    ] {
+        assert_eq!(is_svg::check_svg(data).unwrap_err().kind(), kind);
+    }
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn kind_from_empty_gzip() {
+    assert_eq!(
+        is_svg::check_svg(include_bytes!("data/resources/empty.gz"))
+            .unwrap_err()
+            .kind(),
+        ErrorKind::Empty
+    );
+}
+
+#[test]
+fn kind_from_entity_expansion() {
+    assert_eq!(
+        is_svg::check_svg(include_bytes!("data/hostile/billion-laughs.svg"))
+            .unwrap_err()
+            .kind(),
+        ErrorKind::EntityExpansionLimit
+    );
+}
+
+#[test]
+fn kind_from_deeply_nested() {
+    assert_eq!(
+        is_svg::check_svg(include_bytes!("data/hostile/deeply-nested.svg"))
+            .unwrap_err()
+            .kind(),
+        ErrorKind::LimitExceeded
+    );
+}
+
+#[test]
+fn kind_from_non_utf8() {
+    assert_eq!(
+        is_svg::check_svg(include_bytes!("data/w3/svg-logo-v.png"))
+            .unwrap_err()
+            .kind(),
+        ErrorKind::NotUtf8
+    );
+}
+
+#[test]
+fn kind_from_non_svg_root() {
+    assert_eq!(
+        is_svg::check_svg(r#"<svg width="16" height="16"/>"#)
+            .unwrap_err()
+            .kind(),
+        ErrorKind::NotSvgRoot
+    );
+    assert_eq!(
+        is_svg::check_svg(r#"<html xmlns="http://www.w3.org/1999/xhtml"/>"#)
+            .unwrap_err()
+            .kind(),
+        ErrorKind::NotSvgRoot
+    );
+}
+
+#[test]
+fn kind_from_invalid_svg() {
+    assert_eq!(
+        is_svg::check_svg(r#"<svg xmlns="http://www.w3.org/2000/svg" width="0" height="16"/>"#)
+            .unwrap_err()
+            .kind(),
+        ErrorKind::Parse
+    );
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn kind_from_invalid_gzip() {
+    let data = include_bytes!("data/w3/svg-logo-v.svgz");
+    assert_eq!(
+        is_svg::check_svg(&data[..data.len() / 2])
+            .unwrap_err()
+            .kind(),
+        ErrorKind::Gzip
+    );
+    assert_eq!(
+        is_svg::decompress_svgz(include_str!("data/w3/svg-logo-v.svg"))
+            .unwrap_err()
+            .kind(),
+        ErrorKind::Gzip
+    );
+    assert_eq!(
+        is_svg::check_svg(include_bytes!("data/integrity/crc-mismatch.svgz"))
+            .unwrap_err()
+            .kind(),
+        ErrorKind::GzipIntegrity
+    );
+}
+
+#[test]
+fn kind_from_other_errors() {
+    assert_eq!(
+        Error::from(io::Error::from(io::ErrorKind::NotFound)).kind(),
+        ErrorKind::Io
+    );
+    assert_eq!(
+        Error::LimitExceeded(Limit::InputSize(1024)).kind(),
+        ErrorKind::LimitExceeded
+    );
+    assert_eq!(
+        Error::LimitExceeded(Limit::EntityDepth(10)).kind(),
+        ErrorKind::EntityExpansionLimit
+    );
+    assert_eq!(
+        Error::LimitExceeded(Limit::EntityExpansion(1024)).kind(),
+        ErrorKind::EntityExpansionLimit
+    );
+    assert_eq!(
+        Error::UnsupportedCompression(CompressionKind::Zstd).kind(),
+        ErrorKind::UnsupportedCompression
+    );
+    assert_eq!(Error::GzipIntegrity.kind(), ErrorKind::GzipIntegrity);
+    assert_eq!(Error::Timeout.kind(), ErrorKind::Timeout);
+    assert_eq!(Error::EmptyDocument.kind(), ErrorKind::EmptyDocument);
+    assert_eq!(Error::NothingToRender.kind(), ErrorKind::NothingToRender);
+    assert_eq!(
+        Error::DisallowedElement {
+            name: "script".to_owned(),
+            location: LOCATION,
+        }
+        .kind(),
+        ErrorKind::DisallowedElement
+    );
+    assert_eq!(
+        Error::ExternalReference {
+            href: "image.png".to_owned(),
+            location: LOCATION,
+        }
+        .kind(),
+        ErrorKind::ExternalReference
+    );
+    assert_eq!(
+        Error::InvalidViewBox {
+            value: "0 0 16".to_owned(),
+        }
+        .kind(),
+        ErrorKind::InvalidViewBox
+    );
+    assert_eq!(
+        Error::InvalidIntrinsicSize {
+            width: 1e30,
+            height: f32::INFINITY,
+        }
+        .kind(),
+        ErrorKind::InvalidIntrinsicSize
+    );
+    assert_eq!(Error::InvalidDataUri.kind(), ErrorKind::DataUri);
+    assert_eq!(Error::NotSvgMediaType.kind(), ErrorKind::DataUri);
+    assert_eq!(Error::Panicked.kind(), ErrorKind::Panicked);
+}
+
+#[test]
+fn source() {
+    let err = is_svg::check_svg(include_str!("data/resources/unclosed.svg")).unwrap_err();
+    assert!(err
+        .source()
+        .unwrap()
+        .downcast_ref::<is_svg::usvg::roxmltree::Error>()
+        .is_some());
+
+    let err =
+        is_svg::check_svg(r#"<svg xmlns="http://www.w3.org/2000/svg" width="0" height="16"/>"#)
+            .unwrap_err();
+    assert!(err
+        .source()
+        .unwrap()
+        .downcast_ref::<is_svg::usvg::Error>()
+        .is_some());
+
+    assert!(Error::LimitExceeded(Limit::InputSize(1024))
+        .source()
+        .is_none());
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn source_from_invalid_gzip() {
+    let data = include_bytes!("data/w3/svg-logo-v.svgz");
+    let err = is_svg::check_svg(&data[..data.len() / 2]).unwrap_err();
+    assert!(err.source().unwrap().downcast_ref::<io::Error>().is_some());
+}
+
+#[cfg(feature = "light")]
+#[test]
+fn kind_from_light() {
+    assert_eq!(
+        is_svg::check_svg_light(r#"<svg width="16" height="16"/>"#)
+            .unwrap_err()
+            .kind(),
+        ErrorKind::NotSvgRoot
+    );
+}
@@ -0,0 +1,167 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::path::Path;
+
+use is_svg::{IsSvg, IsSvgFile, SvgIterExt, SvgKind};
+
+#[test]
+fn is_svg() {
+    assert!(include_str!("data/w3/svg-logo-v.svg").is_svg());
+    assert!(include_bytes!("data/w3/svg-logo-v.svg")[..].is_svg());
+    assert!(include_bytes!("data/w3/svg-logo-v.svgz")[..].is_svg());
+    assert!(include_bytes!("data/w3/svg-logo-v.svgz").to_vec().is_svg());
+    assert!(String::from(include_str!("data/w3/svg-logo-v.svg")).is_svg());
+    assert!(!include_str!("data/resources/unclosed.svg").is_svg());
+    assert!(!include_bytes!("data/w3/svg-logo-v.png").to_vec().is_svg());
+}
+
+#[test]
+fn is_svg_string() {
+    assert!(include_str!("data/w3/svg-logo-v.svg").is_svg_string());
+    assert!(String::from(include_str!("data/w3/svg-logo-v.svg")).is_svg_string());
+    assert!(!include_bytes!("data/w3/svg-logo-v.svgz")[..].is_svg_string());
+    assert!(!include_bytes!("data/w3/svg-logo-v.png")[..].is_svg_string());
+}
+
+#[test]
+fn is_svgz() {
+    assert!(!include_str!("data/w3/svg-logo-v.svg").is_svgz());
+    assert!(include_bytes!("data/w3/svg-logo-v.svgz")[..].is_svgz());
+    assert!(include_bytes!("data/w3/svg-logo-v.svgz").to_vec().is_svgz());
+    assert!(!include_bytes!("data/w3/svg-logo-v.png")[..].is_svgz());
+}
+
+#[test]
+fn detect() {
+    assert_eq!(
+        include_str!("data/w3/svg-logo-v.svg").detect(),
+        Some(SvgKind::Svg)
+    );
+    assert_eq!(
+        include_bytes!("data/w3/svg-logo-v.svgz").to_vec().detect(),
+        Some(SvgKind::Svgz)
+    );
+    assert_eq!(include_bytes!("data/w3/svg-logo-v.png")[..].detect(), None);
+}
+
+#[test]
+fn is_svg_file() {
+    assert!(Path::new("tests/data/w3/svg-logo-v.svg")
+        .is_svg_file()
+        .unwrap());
+    assert!(Path::new("tests/data/w3/svg-logo-v.svgz")
+        .is_svg_file()
+        .unwrap());
+    assert!(!Path::new("tests/data/w3/svg-logo-v.png")
+        .is_svg_file()
+        .unwrap());
+    assert!(Path::new("tests/data/w3/non-existent.svg")
+        .is_svg_file()
+        .is_err());
+}
+
+#[test]
+fn detect_file() {
+    assert_eq!(
+        Path::new("tests/data/w3/svg-logo-v.svg")
+            .detect_file()
+            .unwrap(),
+        Some(SvgKind::Svg)
+    );
+    assert_eq!(
+        Path::new("tests/data/w3/svg-logo-v.svgz")
+            .detect_file()
+            .unwrap(),
+        Some(SvgKind::Svgz)
+    );
+    assert_eq!(
+        Path::new("tests/data/w3/svg-logo-v.png")
+            .detect_file()
+            .unwrap(),
+        None
+    );
+}
+
+fn mixed_fixtures() -> Vec<Vec<u8>> {
+    vec![
+        include_bytes!("data/w3/svg-logo-v.svg").to_vec(),
+        include_bytes!("data/w3/svg-logo-v.png").to_vec(),
+        include_bytes!("data/w3/svg-logo-v.svgz").to_vec(),
+        include_bytes!("data/resources/unclosed.svg").to_vec(),
+        include_bytes!("data/ferris/corro.svg").to_vec(),
+        Vec::new(),
+    ]
+}
+
+#[test]
+fn filter_svg() {
+    let svgs: Vec<_> = mixed_fixtures().into_iter().filter_svg().collect();
+    assert_eq!(
+        svgs,
+        [
+            include_bytes!("data/w3/svg-logo-v.svg").to_vec(),
+            include_bytes!("data/w3/svg-logo-v.svgz").to_vec(),
+            include_bytes!("data/ferris/corro.svg").to_vec()
+        ]
+    );
+    assert_eq!(
+        mixed_fixtures()
+            .into_iter()
+            .filter_svg()
+            .rev()
+            .collect::<Vec<_>>(),
+        svgs.into_iter().rev().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        mixed_fixtures().iter().filter_svg().size_hint(),
+        (0, Some(6))
+    );
+}
+
+#[test]
+fn filter_svg_is_lazy() {
+    let mut count = 0;
+    let mut svgs = mixed_fixtures()
+        .into_iter()
+        .inspect(|_| count += 1)
+        .filter_svg();
+    assert!(svgs.next().is_some());
+    drop(svgs);
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn filter_detect() {
+    let kinds: Vec<_> = mixed_fixtures()
+        .iter()
+        .filter_detect()
+        .map(|(_, kind)| kind)
+        .collect();
+    assert_eq!(kinds, [SvgKind::Svg, SvgKind::Svgz, SvgKind::Svg]);
+
+    let mut svgs = mixed_fixtures().into_iter().filter_detect();
+    assert_eq!(
+        svgs.next_back(),
+        Some((
+            include_bytes!("data/ferris/corro.svg").to_vec(),
+            SvgKind::Svg
+        ))
+    );
+    assert_eq!(
+        svgs.next(),
+        Some((
+            include_bytes!("data/w3/svg-logo-v.svg").to_vec(),
+            SvgKind::Svg
+        ))
+    );
+    assert_eq!(
+        svgs.next(),
+        Some((
+            include_bytes!("data/w3/svg-logo-v.svgz").to_vec(),
+            SvgKind::Svgz
+        ))
+    );
+    assert_eq!(svgs.next(), None);
+}
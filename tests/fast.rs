@@ -0,0 +1,109 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+use std::{fmt::Write, fs};
+
+use is_svg::{ErrorKind, Validator, ValidatorBuilder};
+
+type Preset = (&'static str, fn() -> ValidatorBuilder);
+
+fn builders() -> [Preset; 4] {
+    [
+        ("new", ValidatorBuilder::new),
+        ("lenient", ValidatorBuilder::lenient),
+        ("strict", ValidatorBuilder::strict),
+        ("reject_empty", || {
+            ValidatorBuilder::new().reject_empty(true)
+        }),
+    ]
+}
+
+fn assert_same(data: &[u8], name: &str) {
+    for (preset, builder) in builders() {
+        let full = builder().build().check_svg(data).map_err(|err| err.kind());
+        let fast = builder()
+            .fast(true)
+            .build()
+            .check_svg(data)
+            .map_err(|err| err.kind());
+        assert_eq!(fast, full, "{name} ({preset})");
+    }
+}
+
+#[test]
+fn fast_same_as_full_from_fixtures() {
+    let paths = common::fixtures(common::DATA_DIR);
+    assert!(!paths.is_empty());
+    for path in paths {
+        assert_same(&fs::read(&path).unwrap(), &path.display().to_string());
+    }
+}
+
+#[test]
+fn fast_same_as_full_from_size() {
+    for svg in [
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16"><rect width="8" height="8"/></svg>"#,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="0" height="16"><rect width="8" height="8"/></svg>"#,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="-1" height="16"/>"#,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="100%" height="16"/>"#,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 0 16"/>"#,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="1em" height="1em" font-size="0"/>"#,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="1em" height="1em" style="font-size:0"/>"#,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" style="width:0"/>"#,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="1em" height="1em"><style>svg { font-size: 0 }</style></svg>"#,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="1em" height="1em"><style>svg { font-size: 16px }</style></svg>"#,
+        r#"<!DOCTYPE svg [<!ENTITY zero "0">]><svg xmlns="http://www.w3.org/2000/svg" width="&zero;" height="16"/>"#,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" display="none"/>"#,
+        r#"<svg:svg xmlns:svg="http://www.w3.org/2000/svg" width="16" height="16"/>"#,
+        r#"<svg width="16" height="16"/>"#,
+    ] {
+        assert_same(svg.as_bytes(), svg);
+    }
+}
+
+#[test]
+fn fast_same_as_full_from_nodes_limit() {
+    let nested = |depth| {
+        format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16">{}{}</svg>"#,
+            "<g>".repeat(depth),
+            "</g>".repeat(depth)
+        )
+    };
+    for depth in [1000, 1022, 1023, 1024, 1025, 2000] {
+        assert_same(nested(depth).as_bytes(), &format!("depth {depth}"));
+    }
+
+    let mut svg = String::from(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink" width="16" height="16"><g id="g0"><rect width="1" height="1"/></g>"#,
+    );
+    for i in 1..24 {
+        write!(
+            svg,
+            r##"<g id="g{i}"><use xlink:href="#g{0}"/><use xlink:href="#g{0}"/></g>"##,
+            i - 1
+        )
+        .unwrap();
+    }
+    svg.push_str("</svg>");
+    assert_same(svg.as_bytes(), "use");
+}
+
+#[test]
+fn fast_is_svg() {
+    let validator = Validator::builder().fast(true).build();
+    assert!(validator.is_svg(include_str!("data/w3/svg-logo-v.svg")));
+    assert!(validator.is_svg(include_bytes!("data/w3/svg-logo-v.svgz")));
+    assert!(!validator
+        .is_svg(r#"<svg xmlns="http://www.w3.org/2000/svg" width="0" height="16"><g/></svg>"#));
+    assert_eq!(
+        validator
+            .check_svg(r#"<svg xmlns="http://www.w3.org/2000/svg" width="0" height="16"/>"#)
+            .unwrap_err()
+            .kind(),
+        ErrorKind::Parse
+    );
+}
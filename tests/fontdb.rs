@@ -0,0 +1,101 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use is_svg::{fontdb::Database, Options, Validator};
+
+#[test]
+fn is_svg_with_fontdb() {
+    let fontdb = Arc::new(Database::new());
+    assert!(is_svg::is_svg_with_fontdb(
+        include_str!("data/usvg/text-simple-case.svg"),
+        &fontdb
+    ));
+    assert!(is_svg::is_svg_with_fontdb(
+        include_str!("data/w3/svg-logo-v.svg"),
+        &fontdb
+    ));
+    assert!(!is_svg::is_svg_with_fontdb(
+        include_bytes!("data/w3/svg-logo-v.png"),
+        &fontdb
+    ));
+    assert_eq!(Arc::strong_count(&fontdb), 1);
+}
+
+#[test]
+fn validator_shares_fontdb() {
+    let fontdb = Arc::new(Database::new());
+    let validator = Validator::builder().fontdb(Arc::clone(&fontdb)).build();
+    assert_eq!(Arc::strong_count(&fontdb), 2);
+    assert!(validator.is_svg(include_str!("data/usvg/text-simple-case.svg")));
+    assert!(!validator.is_svg(include_bytes!("data/w3/svg-logo-v.png")));
+    drop(validator);
+    assert_eq!(Arc::strong_count(&fontdb), 1);
+}
+
+#[test]
+fn fontdb_overrides_options() {
+    let fontdb = Arc::new(Database::new());
+    let validator = Validator::builder()
+        .options(Options::default())
+        .fontdb(Arc::clone(&fontdb))
+        .build();
+    assert_eq!(Arc::strong_count(&fontdb), 2);
+    drop(validator);
+
+    let validator = Validator::builder()
+        .fontdb(Arc::clone(&fontdb))
+        .options(Options::default())
+        .build();
+    assert_eq!(Arc::strong_count(&fontdb), 1);
+    drop(validator);
+}
+
+#[test]
+fn fontdb_with_is_lazy() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let validator = {
+        let calls = Arc::clone(&calls);
+        Validator::builder()
+            .fontdb_with(move || {
+                calls.fetch_add(1, Ordering::Relaxed);
+                Database::new()
+            })
+            .build()
+    };
+    assert!(validator.is_svg(include_str!("data/usvg/path-simple-case.svg")));
+    assert!(validator.is_svg(include_str!("data/w3/svg-logo-v.svg")));
+    assert!(!validator.is_svg(include_bytes!("data/w3/svg-logo-v.png")));
+    assert_eq!(calls.load(Ordering::Relaxed), 0);
+
+    assert!(validator.is_svg(include_str!("data/usvg/preserve-text-simple-case.svg")));
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+    let cloned = validator.clone();
+    assert!(cloned.is_svg(include_str!("data/usvg/text-simple-case.svg")));
+    assert!(validator.is_svg(include_str!("data/usvg/text-simple-case.svg")));
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn fontdb_with_same_as_fontdb() {
+    let eager = Validator::builder()
+        .fontdb(Arc::new(Database::new()))
+        .build();
+    let lazy = Validator::builder().fontdb_with(Database::new).build();
+    for data in [
+        include_str!("data/usvg/path-simple-case.svg"),
+        include_str!("data/usvg/preserve-text-simple-case.svg"),
+        include_str!("data/usvg/text-simple-case.svg"),
+        include_str!("data/usvg/clip-path-with-text.svg"),
+        include_str!("data/w3/svg-logo-v.svg"),
+        include_str!("data/resources/unclosed.svg"),
+    ] {
+        assert_eq!(lazy.detect(data), eager.detect(data));
+        assert_eq!(lazy.detect(data), Validator::new().detect(data));
+    }
+}
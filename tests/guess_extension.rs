@@ -0,0 +1,300 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[test]
+#[allow(clippy::cognitive_complexity, clippy::too_many_lines)]
+fn guess_extension_from_svg() {
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/ferris/corro.svg")),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/ferris/cuddlyferris.svg")),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/ferris/rustacean-flat-gesture.svg")),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/ferris/rustacean-flat-happy.svg")),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/ferris/rustacean-flat-noshadow.svg")),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/ferris/rustacean-orig-noshadow.svg")),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/usvg/clip-path-with-complex-text.svg")),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!(
+            "data/usvg/clip-path-with-object-units-multi-use.svg"
+        )),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/usvg/clip-path-with-text.svg")),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/usvg/ellipse-simple-case.svg")),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/usvg/filter-id-with-prefix.svg")),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!(
+            "data/usvg/filter-with-object-units-multi-use.svg"
+        )),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!(
+            "data/usvg/generate-id-clip-path-for-symbol.svg"
+        )),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/usvg/generate-id-filter-function-v1.svg")),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/usvg/generate-id-filter-function-v2.svg")),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!(
+            "data/usvg/mask-with-object-units-multi-use.svg"
+        )),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/usvg/path-simple-case.svg")),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/usvg/preserve-id-clip-path-v1.svg")),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/usvg/preserve-id-clip-path-v2.svg")),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!(
+            "data/usvg/preserve-id-fe-image-with-opacity.svg"
+        )),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/usvg/preserve-id-fe-image.svg")),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/usvg/preserve-id-filter.svg")),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!(
+            "data/usvg/preserve-id-for-clip-path-in-pattern.svg"
+        )),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/usvg/preserve-text-in-clip-path.svg")),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/usvg/preserve-text-in-mask.svg")),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/usvg/preserve-text-in-pattern.svg")),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!(
+            "data/usvg/preserve-text-multiple-font-families.svg"
+        )),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/usvg/preserve-text-on-path.svg")),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/usvg/preserve-text-simple-case.svg")),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!(
+            "data/usvg/preserve-text-with-complex-text-decoration.svg"
+        )),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/usvg/preserve-text-with-dx-and-dy.svg")),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!(
+            "data/usvg/preserve-text-with-nested-baseline-shift.svg"
+        )),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/usvg/preserve-text-with-rotate.svg")),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/usvg/text-simple-case.svg")),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/usvg/text-with-generated-gradients.svg")),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/w3/svg-logo-v.svg")),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/wikipedia/SVG_animation_using_CSS.svg")),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/wikipedia/SVG_animation_using_ES.svg")),
+        Some("svg")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/wikipedia/SVG_animation_using_SMIL.svg")),
+        Some("svg")
+    );
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn guess_extension_from_svgz() {
+    assert_eq!(
+        is_svg::guess_extension(include_bytes!("data/ferris/corro.svgz")),
+        Some("svgz")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_bytes!("data/ferris/cuddlyferris.svgz")),
+        Some("svgz")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_bytes!("data/ferris/rustacean-flat-gesture.svgz")),
+        Some("svgz")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_bytes!("data/ferris/rustacean-flat-happy.svgz")),
+        Some("svgz")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_bytes!("data/ferris/rustacean-flat-noshadow.svgz")),
+        Some("svgz")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_bytes!("data/ferris/rustacean-orig-noshadow.svgz")),
+        Some("svgz")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_bytes!("data/w3/svg-logo-v.svgz")),
+        Some("svgz")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_bytes!(
+            "data/wikipedia/SVG_animation_using_CSS.svgz"
+        )),
+        Some("svgz")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_bytes!("data/wikipedia/SVG_animation_using_ES.svgz")),
+        Some("svgz")
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_bytes!(
+            "data/wikipedia/SVG_animation_using_SMIL.svgz"
+        )),
+        Some("svgz")
+    );
+}
+
+#[test]
+fn guess_extension_from_invalid_svg() {
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/resources/dtd.svg")),
+        None
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/resources/unclosed.svg")),
+        None
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/resources/xml_declaration.svg")),
+        None
+    );
+}
+
+#[test]
+fn guess_extension_from_non_svg() {
+    assert_eq!(
+        is_svg::guess_extension(include_bytes!("data/w3/svg-logo-v.jxl")),
+        None
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_bytes!("data/w3/svg-logo-v.png")),
+        None
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_bytes!("data/w3/svg-logo-v.webp")),
+        None
+    );
+}
+
+#[test]
+fn guess_extension_from_empty() {
+    assert_eq!(is_svg::guess_extension([]), None);
+    assert_eq!(
+        is_svg::guess_extension(include_bytes!("data/resources/empty.gz")),
+        None
+    );
+}
+
+#[test]
+fn guess_extension_from_mathml() {
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/resources/quadratic_formula.mml")),
+        None
+    );
+}
+
+#[test]
+fn guess_extension_from_html() {
+    assert_eq!(
+        is_svg::guess_extension(include_str!("data/resources/index.html")),
+        None
+    );
+}
+
+#[test]
+fn guess_extension_from_compressed_svg() {
+    assert_eq!(
+        is_svg::guess_extension(include_bytes!("data/w3/svg-logo-v.svg.lz")),
+        None
+    );
+    assert_eq!(
+        is_svg::guess_extension(include_bytes!("data/w3/svg-logo-v.svg.zst")),
+        None
+    );
+}
@@ -0,0 +1,191 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+use std::io::{Cursor, Write};
+
+use flate2::{write::DeflateEncoder, Compression, Crc};
+use is_svg::{Error, SvgKind, Validator};
+
+const SVG: &[u8] = include_bytes!("data/w3/svg-logo-v.svg");
+
+const FTEXT: u8 = 1 << 0;
+const FHCRC: u8 = 1 << 1;
+const FEXTRA: u8 = 1 << 2;
+const FNAME: u8 = 1 << 3;
+const FCOMMENT: u8 = 1 << 4;
+
+/// The file name which is not a valid UTF-8.
+const NAME: &[u8] = b"svg-logo-v \xe9\xff\xfe.svg";
+
+/// The comment which is not a valid UTF-8, including a lone surrogate encoded
+/// as WTF-8.
+const COMMENT: &[u8] = b"Caf\xe9 \xed\xa0\x80 \x80\xbf";
+
+/// Returns the extra field of the maximal length, which has a subfield.
+fn extra() -> Vec<u8> {
+    let len = u16::MAX - 4;
+    let mut extra = b"AP".to_vec();
+    extra.extend(len.to_le_bytes());
+    extra.extend((0..len).map(|i| u8::try_from(i % 251).unwrap()));
+    extra
+}
+
+/// Returns [`SVG`] gzip-compressed with the header which has the fields of
+/// `flags`.
+///
+/// If `bad_hcrc` is [`true`], the CRC-16 of the header does not match.
+fn svgz(flags: u8, bad_hcrc: bool) -> Vec<u8> {
+    let mut data = vec![0x1f, 0x8b, 0x08, flags, 0, 0, 0, 0, 0x02, 0x03];
+    if flags & FEXTRA != 0 {
+        let extra = extra();
+        data.extend(u16::try_from(extra.len()).unwrap().to_le_bytes());
+        data.extend(extra);
+    }
+    if flags & FNAME != 0 {
+        data.extend(NAME);
+        data.push(0);
+    }
+    if flags & FCOMMENT != 0 {
+        data.extend(COMMENT);
+        data.push(0);
+    }
+    if flags & FHCRC != 0 {
+        let mut crc = Crc::new();
+        crc.update(&data);
+        let hcrc = u16::try_from(crc.sum() & 0xffff).unwrap() ^ u16::from(bad_hcrc);
+        data.extend(hcrc.to_le_bytes());
+    }
+    let mut encoder = DeflateEncoder::new(data, Compression::best());
+    encoder.write_all(SVG).unwrap();
+    let mut data = encoder.finish().unwrap();
+    let mut crc = Crc::new();
+    crc.update(SVG);
+    data.extend(crc.sum().to_le_bytes());
+    data.extend(u32::try_from(SVG.len()).unwrap().to_le_bytes());
+    data
+}
+
+/// Returns the chunk sizes to feed `data` to [`SvgChecker`].
+///
+/// The chunks of 1 byte split every field of the header, but take too long to
+/// feed the extra field of the maximal length.
+const fn chunk_sizes(data: &[u8]) -> [usize; 3] {
+    let small = if data.len() > 4096 { 61 } else { 1 };
+    [small, 4096, data.len()]
+}
+
+fn assert_svgz(data: &[u8], name: &str) {
+    assert!(is_svg::is_svg(data), "{name}");
+    assert!(is_svg::is_svgz(data), "{name}");
+    assert!(is_svg::looks_like_svgz(data), "{name}");
+    assert_eq!(is_svg::detect(data), Some(SvgKind::Svgz), "{name}");
+    assert!(is_svg::check_svg(data).is_ok(), "{name}");
+    assert_eq!(
+        is_svg::decompress_svgz(data).unwrap().as_bytes(),
+        SVG,
+        "{name}"
+    );
+    assert!(
+        is_svg::check_svg_reader(Cursor::new(data)).is_ok(),
+        "{name}"
+    );
+    for validator in [Validator::lenient(), Validator::new(), Validator::strict()] {
+        assert!(validator.check_svg(data).is_ok(), "{name}");
+    }
+    for chunk_size in chunk_sizes(data) {
+        assert!(
+            matches!(common::check_chunked(data, chunk_size), Ok(SvgKind::Svgz)),
+            "{name}"
+        );
+    }
+}
+
+fn assert_integrity_error(data: &[u8], name: &str) {
+    assert!(!is_svg::is_svg(data), "{name}");
+    assert!(!is_svg::is_svgz(data), "{name}");
+    assert_eq!(is_svg::detect(data), None, "{name}");
+    assert!(
+        matches!(is_svg::check_svg(data), Err(Error::GzipIntegrity)),
+        "{name}"
+    );
+    assert!(
+        matches!(is_svg::decompress_svgz(data), Err(Error::GzipIntegrity)),
+        "{name}"
+    );
+    assert!(
+        matches!(
+            is_svg::check_svg_reader(Cursor::new(data)),
+            Err(Error::GzipIntegrity)
+        ),
+        "{name}"
+    );
+    for validator in [Validator::lenient(), Validator::new(), Validator::strict()] {
+        assert!(
+            matches!(validator.check_svg(data), Err(Error::GzipIntegrity)),
+            "{name}"
+        );
+    }
+    for chunk_size in chunk_sizes(data) {
+        assert!(
+            matches!(
+                common::check_chunked(data, chunk_size),
+                Err(Error::GzipIntegrity)
+            ),
+            "{name}"
+        );
+    }
+}
+
+#[test]
+fn header_fields() {
+    assert_svgz(include_bytes!("data/gzip-header/fextra.svgz"), "fextra");
+    assert_svgz(include_bytes!("data/gzip-header/fname.svgz"), "fname");
+    assert_svgz(include_bytes!("data/gzip-header/fcomment.svgz"), "fcomment");
+    assert_svgz(include_bytes!("data/gzip-header/fhcrc.svgz"), "fhcrc");
+    assert_svgz(
+        include_bytes!("data/gzip-header/all-flags.svgz"),
+        "all-flags",
+    );
+}
+
+#[test]
+fn bad_header_crc() {
+    assert_integrity_error(
+        include_bytes!("data/gzip-header/bad-fhcrc.svgz"),
+        "bad-fhcrc",
+    );
+}
+
+#[test]
+fn each_flag_combination() {
+    for flags in 0..(FCOMMENT << 1) {
+        let name = format!("flags {flags:#04x}");
+        assert_svgz(&svgz(flags, false), &name);
+        if flags & FHCRC != 0 {
+            assert_integrity_error(&svgz(flags, true), &name);
+        }
+    }
+    let all = FTEXT | FHCRC | FEXTRA | FNAME | FCOMMENT;
+    assert_svgz(&svgz(all, false), "all flags");
+}
+
+#[test]
+fn truncated_header() {
+    let data = svgz(FHCRC | FEXTRA | FNAME | FCOMMENT, false);
+    let header_len = data.len() - svgz(0, false).len() + 10;
+    for len in [11, 12, 1024, header_len - 2, header_len] {
+        let data = &data[..len];
+        assert!(!is_svg::is_svg(data), "{len}");
+        assert!(
+            matches!(is_svg::check_svg(data), Err(Error::GzipDecode(_))),
+            "{len}"
+        );
+        assert!(
+            matches!(common::check_chunked(data, 64), Err(Error::GzipDecode(_))),
+            "{len}"
+        );
+    }
+}
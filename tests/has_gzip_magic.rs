@@ -0,0 +1,50 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[test]
+fn has_gzip_magic_from_svgz() {
+    assert!(is_svg::has_gzip_magic(include_bytes!(
+        "data/ferris/corro.svgz"
+    )));
+    assert!(is_svg::has_gzip_magic(include_bytes!(
+        "data/w3/svg-logo-v.svgz"
+    )));
+}
+
+#[test]
+fn has_gzip_magic_from_empty_gz() {
+    assert!(is_svg::has_gzip_magic(include_bytes!(
+        "data/resources/empty.gz"
+    )));
+}
+
+#[test]
+fn has_gzip_magic_from_non_gzip() {
+    assert!(!is_svg::has_gzip_magic(include_str!(
+        "data/w3/svg-logo-v.svg"
+    )));
+    assert!(!is_svg::has_gzip_magic(include_bytes!(
+        "data/w3/svg-logo-v.png"
+    )));
+    assert!(!is_svg::has_gzip_magic(include_bytes!(
+        "data/w3/svg-logo-v.svg.zst"
+    )));
+}
+
+#[test]
+fn has_gzip_magic_from_empty() {
+    assert!(!is_svg::has_gzip_magic([]));
+}
+
+#[test]
+fn has_gzip_magic_from_one_byte() {
+    assert!(!is_svg::has_gzip_magic([0x1f]));
+    assert!(!is_svg::has_gzip_magic([0x8b]));
+}
+
+#[test]
+fn has_gzip_magic_from_magic_number() {
+    assert!(is_svg::has_gzip_magic(is_svg::GZIP_MAGIC_NUMBER));
+    assert!(!is_svg::is_svgz(is_svg::GZIP_MAGIC_NUMBER));
+}
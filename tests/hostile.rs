@@ -0,0 +1,454 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::{
+    fmt::Write,
+    fs,
+    io::{self, Read, Write as _},
+    path::PathBuf,
+    thread,
+    time::{Duration, Instant},
+};
+
+use flate2::{write::GzEncoder, Compression};
+use is_svg::{Error, Limit, Options, Validator};
+use usvg::{roxmltree, ImageHrefResolver};
+
+/// Runs `f` on a thread with the default stack size of the spawned threads, so
+/// that a regression which overflows the stack is detected regardless of the
+/// stack size of the test harness.
+fn on_small_stack<T: Send>(f: impl FnOnce() -> T + Send) -> T {
+    thread::scope(|scope| {
+        thread::Builder::new()
+            .stack_size(2 * 1024 * 1024)
+            .spawn_scoped(scope, f)
+            .unwrap()
+            .join()
+            .unwrap()
+    })
+}
+
+fn assert_not_svg(data: &[u8]) {
+    on_small_stack(|| {
+        assert!(!is_svg::is_svg(data));
+        assert!(!is_svg::is_svg_string(data));
+        assert!(!is_svg::is_svgz(data));
+    });
+}
+
+/// Returns the paths and the contents of the hostile inputs in
+/// `tests/data/hostile`.
+///
+/// These are also copied to the seed corpora of the fuzz targets by
+/// `just fuzz-corpus`.
+fn corpus() -> Vec<(PathBuf, Vec<u8>)> {
+    let mut corpus = fs::read_dir("tests/data/hostile")
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().map_or(true, |ext| ext != "license"))
+        .map(|path| {
+            let data = fs::read(&path).unwrap();
+            (path, data)
+        })
+        .collect::<Vec<_>>();
+    corpus.sort();
+    corpus
+}
+
+/// Tests `data` with all the entry points, and asserts that none of them
+/// panics, and that the verdicts agree with each other.
+fn assert_no_panic(data: &[u8], name: &str) {
+    on_small_stack(|| {
+        for validator in [Validator::lenient(), Validator::new(), Validator::strict()] {
+            let result = validator.check_svg(data);
+            assert!(!matches!(result, Err(Error::Panicked)), "{name}");
+            assert_eq!(validator.is_svg(data), result.is_ok(), "{name}");
+        }
+        let result = is_svg::check_svg(data);
+        assert!(!matches!(result, Err(Error::Panicked)), "{name}");
+        assert_eq!(is_svg::is_svg(data), result.is_ok(), "{name}");
+        assert_eq!(
+            is_svg::check_svg_reader(data).is_ok(),
+            result.is_ok(),
+            "{name}"
+        );
+        assert!(!matches!(is_svg::parse_svg(data), Err(Error::Panicked)));
+        let _ = is_svg::looks_like_svg(data);
+        let _ = is_svg::is_svg_whatwg(data);
+
+        let mut checker = is_svg::SvgChecker::new();
+        for chunk in data.chunks(64) {
+            checker.push(chunk);
+        }
+        assert!(!matches!(checker.finish(), Err(Error::Panicked)), "{name}");
+    });
+}
+
+fn assert_svg_string(data: &[u8]) {
+    on_small_stack(|| {
+        assert!(is_svg::is_svg(data));
+        assert!(is_svg::is_svg_string(data));
+        assert!(!is_svg::is_svgz(data));
+    });
+}
+
+#[test]
+fn absurd_numbers() {
+    assert_not_svg(include_bytes!("data/hostile/absurd-numbers.svg"));
+}
+
+#[test]
+fn billion_laughs() {
+    let data = include_bytes!("data/hostile/billion-laughs.svg");
+    let start = Instant::now();
+    assert_not_svg(data);
+    assert!(matches!(
+        is_svg::check_svg(data),
+        Err(Error::LimitExceeded(Limit::EntityExpansion(_)))
+    ));
+    assert!(matches!(
+        Validator::builder()
+            .max_entity_expansion(u64::MAX)
+            .build()
+            .check_svg(data),
+        Err(Error::NotXml {
+            source: roxmltree::Error::EntityReferenceLoop(_),
+            ..
+        })
+    ));
+    assert!(start.elapsed() < Duration::from_secs(10));
+}
+
+#[test]
+fn billion_laughs_in_attribute() {
+    let entities = (1..10).fold(String::from(r#"<!ENTITY a0 "1">"#), |mut decls, i| {
+        let refs = format!("&a{};", i - 1).repeat(10);
+        write!(decls, r#"<!ENTITY a{i} "{refs}">"#).unwrap();
+        decls
+    });
+    let data = format!(
+        r#"<!DOCTYPE svg [{entities}]><svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" id="&a9;"/>"#
+    );
+    assert!(matches!(
+        is_svg::check_svg(&data),
+        Err(Error::LimitExceeded(Limit::EntityExpansion(_)))
+    ));
+}
+
+#[test]
+fn entity_quadratic_blowup() {
+    // A large entity which is referenced many times, but not nested.
+    let data = format!(
+        r#"<!DOCTYPE svg [<!ENTITY a "{}">]><svg xmlns="http://www.w3.org/2000/svg" width="16" height="16"><desc>{}</desc></svg>"#,
+        "a".repeat(64 * 1024),
+        "&a;".repeat(64)
+    );
+    assert!(matches!(
+        is_svg::check_svg(&data),
+        Err(Error::LimitExceeded(Limit::EntityExpansion(1_048_576)))
+    ));
+    assert!(Validator::builder()
+        .max_entity_expansion(u64::MAX)
+        .build()
+        .is_svg(&data));
+}
+
+#[test]
+fn corrupted_crc() {
+    assert_not_svg(include_bytes!("data/hostile/corrupted-crc.svgz"));
+    #[cfg(feature = "gzip")]
+    assert!(matches!(
+        is_svg::check_svg(include_bytes!("data/hostile/corrupted-crc.svgz")),
+        Err(Error::GzipIntegrity)
+    ));
+}
+
+#[test]
+fn deeply_nested() {
+    assert_not_svg(include_bytes!("data/hostile/deeply-nested.svg"));
+    assert!(matches!(
+        on_small_stack(|| is_svg::check_svg(include_bytes!("data/hostile/deeply-nested.svg"))),
+        Err(Error::LimitExceeded(Limit::Depth(4096)))
+    ));
+}
+
+#[test]
+fn deeply_nested_svgz() {
+    assert_not_svg(include_bytes!("data/hostile/deeply-nested.svgz"));
+    #[cfg(feature = "gzip")]
+    assert!(on_small_stack(|| is_svg::decompress_svgz(include_bytes!(
+        "data/hostile/deeply-nested.svgz"
+    )))
+    .is_err());
+}
+
+#[test]
+fn deeply_nested_group() {
+    assert_svg_string(include_bytes!("data/hostile/deeply-nested-group.svg"));
+}
+
+#[test]
+fn deeply_nested_unknown() {
+    assert_svg_string(include_bytes!("data/hostile/deeply-nested-unknown.svg"));
+}
+
+#[test]
+fn moderately_nested_on_current_thread() {
+    // This is parsed without spawning a thread.
+    for depth in [255, 256] {
+        let svg = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16">{}{}</svg>"#,
+            "<g>".repeat(depth - 1),
+            "</g>".repeat(depth - 1)
+        );
+        assert!(on_small_stack(|| is_svg::is_svg_string(&svg)), "{depth}");
+        assert!(on_small_stack(|| is_svg::check_svg(svg.as_bytes())).is_ok());
+    }
+}
+
+#[test]
+fn deeply_nested_generated() {
+    // This is generated rather than stored, since it is large.
+    let depth = 200_000;
+    let svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16">{}{}</svg>"#,
+        "<g>".repeat(depth),
+        "</g>".repeat(depth)
+    );
+    let svgz = {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(svg.as_bytes()).unwrap();
+        encoder.finish().unwrap()
+    };
+    for data in [svg.as_bytes(), &svgz] {
+        assert_not_svg(data);
+        assert_no_panic(data, "deeply nested");
+    }
+    on_small_stack(|| {
+        assert!(matches!(
+            is_svg::check_svg(&svg),
+            Err(Error::LimitExceeded(Limit::Depth(4096)))
+        ));
+        assert!(matches!(
+            Validator::builder().max_depth(1024).build().check_svg(&svg),
+            Err(Error::LimitExceeded(Limit::Depth(1024)))
+        ));
+        #[cfg(feature = "gzip")]
+        assert!(matches!(
+            is_svg::check_svg(&svgz),
+            Err(Error::LimitExceeded(Limit::Depth(4096)))
+        ));
+    });
+}
+
+#[test]
+fn entity_nesting() {
+    assert_svg_string(include_bytes!("data/hostile/entity-nesting.svg"));
+}
+
+#[test]
+fn nested_svgz() {
+    let data = include_bytes!("data/hostile/nested.svgz");
+    if cfg!(feature = "gzip") {
+        // The inner layer is unwrapped by default.
+        assert!(is_svg::is_svg(data));
+        assert!(is_svg::is_svgz(data));
+        assert!(!is_svg::is_svg_string(data));
+        assert!(matches!(
+            Validator::strict().check_svg(data),
+            Err(Error::LimitExceeded(Limit::GzipNesting(0)))
+        ));
+    } else {
+        assert_not_svg(data);
+    }
+}
+
+#[test]
+fn recursive_reference() {
+    assert_svg_string(include_bytes!("data/hostile/recursive-reference.svg"));
+}
+
+#[test]
+fn recursive_use() {
+    assert_svg_string(include_bytes!("data/hostile/recursive-use.svg"));
+}
+
+#[test]
+fn truncated_svgz() {
+    assert_not_svg(include_bytes!("data/hostile/truncated.svgz"));
+}
+
+#[test]
+fn corpus_never_panics() {
+    let corpus = corpus();
+    assert!(!corpus.is_empty());
+    for (path, data) in corpus {
+        assert_no_panic(&data, &path.display().to_string());
+    }
+}
+
+#[test]
+fn mutated_corpus_never_panics() {
+    // A deterministic xorshift, so that a failure can be reproduced.
+    let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        usize::try_from(state % (1 << 32)).unwrap()
+    };
+    let corpus = corpus()
+        .into_iter()
+        .filter(|(_, data)| data.len() <= 64 * 1024)
+        .collect::<Vec<_>>();
+    for i in 0..256 {
+        let (path, data) = &corpus[next() % corpus.len()];
+        let mut data = data.clone();
+        for _ in 0..=next() % 8 {
+            if data.is_empty() {
+                break;
+            }
+            let pos = next() % data.len();
+            match next() % 4 {
+                0 => data[pos] = next().to_le_bytes()[0],
+                1 => data.truncate(pos),
+                2 => data.insert(pos, next().to_le_bytes()[0]),
+                _ => data[pos] = [b'\0', b'<', b'&', b'"', 0x80, 0xc0, 0xff][next() % 7],
+            }
+        }
+        assert_no_panic(&data, &format!("{} (mutation {i})", path.display()));
+    }
+}
+
+#[test]
+fn interior_nul() {
+    let data = include_bytes!("data/hostile/interior-nul.svg");
+    assert_not_svg(data);
+    assert!(matches!(
+        is_svg::check_svg(data),
+        Err(Error::NotXml {
+            source: roxmltree::Error::NonXmlChar('\0', _),
+            ..
+        })
+    ));
+}
+
+#[test]
+fn invalid_utf8() {
+    let data = include_bytes!("data/hostile/overlong-utf8.svg");
+    assert_not_svg(data);
+    assert!(matches!(
+        is_svg::check_svg(data),
+        Err(Error::NotUtf8(err)) if err.error_len().is_some()
+    ));
+
+    let data = include_bytes!("data/hostile/truncated-utf8.svg");
+    assert_not_svg(data);
+    assert!(matches!(
+        is_svg::check_svg(data),
+        Err(Error::NotUtf8(err)) if err.error_len().is_none()
+    ));
+}
+
+#[test]
+fn invalid_utf16() {
+    let data = include_bytes!("data/hostile/invalid-utf16.svg");
+    assert_not_svg(data);
+    assert!(matches!(is_svg::check_svg(data), Err(Error::NotUtf16)));
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn malformed_gzip_header() {
+    for data in [
+        &include_bytes!("data/hostile/gzip-absurd-xlen.svgz")[..],
+        include_bytes!("data/hostile/gzip-unterminated-name.svgz"),
+    ] {
+        assert_not_svg(data);
+        assert!(matches!(is_svg::check_svg(data), Err(Error::GzipDecode(_))));
+    }
+    // The size in the trailer does not match the decompressed data.
+    let data = include_bytes!("data/hostile/gzip-huge-isize.svgz");
+    assert_not_svg(data);
+    assert!(matches!(is_svg::check_svg(data), Err(Error::GzipIntegrity)));
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn gzip_empty_members() {
+    let data = include_bytes!("data/hostile/gzip-empty-members.svgz");
+    assert_not_svg(data);
+    assert!(matches!(is_svg::check_svg(data), Err(Error::Empty)));
+    assert!(matches!(
+        Validator::strict().check_svg(data),
+        Err(Error::GzipDecode(_))
+    ));
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn gzip_zeros() {
+    let data = include_bytes!("data/hostile/gzip-zeros.svgz");
+    assert_not_svg(data);
+    assert!(matches!(
+        is_svg::check_svg(data),
+        Err(Error::LimitExceeded(Limit::DecompressedSize(_)))
+    ));
+
+    let mut checker = is_svg::SvgChecker::new();
+    checker.push(data);
+    assert!(checker.is_rejected());
+    assert!(matches!(
+        checker.finish(),
+        Err(Error::LimitExceeded(Limit::DecompressedSize(_)))
+    ));
+}
+
+#[test]
+fn svg_checker_with_hostile() {
+    on_small_stack(|| {
+        for data in [
+            &include_bytes!("data/hostile/deeply-nested.svg")[..],
+            include_bytes!("data/hostile/deeply-nested.svgz"),
+            include_bytes!("data/hostile/truncated.svgz"),
+        ] {
+            let mut checker = is_svg::SvgChecker::new();
+            for chunk in data.chunks(64) {
+                checker.push(chunk);
+            }
+            assert!(checker.finish().is_err());
+        }
+    });
+}
+
+#[test]
+fn panic_is_contained() {
+    let opt = Options {
+        image_href_resolver: ImageHrefResolver {
+            resolve_string: Box::new(|_, _| panic!("resolver panicked")),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let data = r#"<svg xmlns="http://www.w3.org/2000/svg" width="8" height="8"><image href="image.png" width="8" height="8"/></svg>"#;
+    assert!(!is_svg::is_svg_with_options(data, &opt));
+    assert!(matches!(
+        is_svg::check_svg_with_options(data, &opt),
+        Err(Error::Panicked)
+    ));
+}
+
+#[test]
+#[should_panic(expected = "reader panicked")]
+fn panic_from_reader_is_propagated() {
+    struct PanickingReader;
+
+    impl Read for PanickingReader {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            panic!("reader panicked")
+        }
+    }
+
+    let _ = is_svg::is_svg_reader(PanickingReader);
+}
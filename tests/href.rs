@@ -0,0 +1,146 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use is_svg::{
+    usvg::{Group, Node, Paint, Path},
+    Error, ErrorKind, Validator, ValidatorBuilder,
+};
+
+const USE_HREF: &str = include_str!("data/href/use-href.svg");
+const USE_XLINK_HREF: &str = include_str!("data/href/use-xlink-href.svg");
+const USE_BOTH: &str = include_str!("data/href/use-both.svg");
+const USE_BOTH_EXTERNAL: &str = include_str!("data/href/use-both-external.svg");
+
+fn validators() -> [Validator; 6] {
+    [
+        Validator::lenient(),
+        Validator::new(),
+        Validator::strict(),
+        Validator::builder().fast(true).build(),
+        ValidatorBuilder::new()
+            .allow_external_references(false)
+            .build(),
+        ValidatorBuilder::new()
+            .reject_empty(true)
+            .require_renderable_content(true)
+            .build(),
+    ]
+}
+
+fn first_path(group: &Group) -> Option<&Path> {
+    group.children().iter().find_map(|node| match node {
+        Node::Group(group) => first_path(group),
+        Node::Path(path) => Some(path),
+        _ => None,
+    })
+}
+
+/// Returns the number of the gradient stops of the square which is referred to
+/// by the `use` element.
+fn used_stops(text: &str) -> Option<usize> {
+    let tree = is_svg::parse_svg(text).unwrap();
+    match first_path(tree.root())?.fill()?.paint() {
+        Paint::LinearGradient(gradient) => Some(gradient.stops().len()),
+        _ => None,
+    }
+}
+
+#[test]
+fn is_svg_with_href() {
+    for text in [USE_HREF, USE_XLINK_HREF, USE_BOTH] {
+        for validator in validators() {
+            assert!(validator.check_svg(text).is_ok(), "{text}");
+        }
+        assert!(is_svg::is_svg(text), "{text}");
+    }
+}
+
+#[test]
+fn parse_svg_with_href() {
+    assert_eq!(used_stops(USE_HREF), Some(2));
+    assert_eq!(used_stops(USE_XLINK_HREF), Some(2));
+}
+
+#[test]
+fn href_takes_precedence() {
+    assert_eq!(used_stops(USE_BOTH), Some(2));
+
+    // The `xlink:href` attribute is used if it is the only one.
+    let xlink_only = USE_BOTH
+        .replace(r##" href="#base""##, "")
+        .replace(r##" href="#square""##, "");
+    assert_eq!(used_stops(&xlink_only), None);
+    assert!(matches!(
+        ValidatorBuilder::new()
+            .require_renderable_content(true)
+            .build()
+            .check_svg(&xlink_only),
+        Err(Error::NothingToRender)
+    ));
+
+    // Either order of the attributes is the same.
+    let swapped = USE_BOTH.replace(
+        r##"href="#square" xlink:href="#missing""##,
+        r##"xlink:href="#missing" href="#square""##,
+    );
+    assert_ne!(swapped, USE_BOTH);
+    assert_eq!(used_stops(&swapped), Some(2));
+
+    // The same values refer to the same resource.
+    let same = USE_BOTH.replace("#missing", "#base");
+    assert_eq!(used_stops(&same), Some(2));
+}
+
+#[test]
+fn href_with_other_prefix() {
+    let text = USE_BOTH
+        .replace("xlink:", "x:")
+        .replace("xmlns:xlink", "xmlns:x");
+    assert_eq!(used_stops(&text), Some(2));
+
+    // The attribute which is not in the XLink namespace is not `xlink:href`.
+    let text = USE_XLINK_HREF.replace(
+        r#"xmlns:xlink="http://www.w3.org/1999/xlink""#,
+        r#"xmlns:xlink="https://example.com/xlink""#,
+    );
+    assert!(is_svg::is_svg(&text));
+    assert_eq!(used_stops(&text), None);
+}
+
+#[test]
+fn external_href_with_both() {
+    // The `xlink:href` attribute is tested even if it is overridden, since the
+    // user agents which only support SVG 1.1 refer to it.
+    let swapped = USE_BOTH_EXTERNAL.replace(
+        r##"href="#square" xlink:href="https://example.com/icons.svg#square""##,
+        r##"href="https://example.com/icons.svg#square" xlink:href="#square""##,
+    );
+    assert_ne!(swapped, USE_BOTH_EXTERNAL);
+    for text in [USE_BOTH_EXTERNAL, &swapped] {
+        for validator in [
+            Validator::strict(),
+            ValidatorBuilder::new()
+                .allow_external_references(false)
+                .build(),
+        ] {
+            assert!(matches!(
+                validator.check_svg(text),
+                Err(Error::ExternalReference { href, .. })
+                    if href == "https://example.com/icons.svg#square"
+            ));
+        }
+        assert!(Validator::lenient().is_svg(text));
+        assert!(Validator::new().is_svg(text));
+    }
+
+    // Only the `href` attribute is used.
+    let renderable = ValidatorBuilder::new()
+        .require_renderable_content(true)
+        .build();
+    assert!(renderable.is_svg(USE_BOTH_EXTERNAL));
+    assert_eq!(
+        renderable.check_svg(&swapped).unwrap_err().kind(),
+        ErrorKind::NothingToRender
+    );
+}
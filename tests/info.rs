@@ -0,0 +1,198 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(feature = "gzip")]
+use std::io::Write;
+
+#[cfg(feature = "gzip")]
+use flate2::{write::GzEncoder, Compression};
+use is_svg::{Error, SvgKind, ViewBox};
+
+const fn view_box(min_x: f32, min_y: f32, width: f32, height: f32) -> ViewBox {
+    ViewBox {
+        min_x,
+        min_y,
+        width,
+        height,
+    }
+}
+
+#[test]
+fn info_from_svg() {
+    let info = is_svg::info(include_str!("data/w3/svg-logo-v.svg")).unwrap();
+    assert_eq!(info.kind, SvgKind::Svg);
+    // `width="100%"` and `height="100%"` are not the intrinsic size.
+    assert_eq!(info.width, None);
+    assert_eq!(info.height, None);
+    assert_eq!(info.view_box, Some(view_box(0.0, 0.0, 300.0, 300.0)));
+    assert_eq!(info.version, None);
+    assert_eq!(info.base_profile, None);
+    assert_eq!(info.size, 4273);
+    assert_eq!(info.decompressed_size, None);
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn info_from_svgz() {
+    let info = is_svg::info(include_bytes!("data/w3/svg-logo-v.svgz")).unwrap();
+    assert_eq!(info.kind, SvgKind::Svgz);
+    assert_eq!(info.width, None);
+    assert_eq!(info.height, None);
+    assert_eq!(info.view_box, Some(view_box(0.0, 0.0, 300.0, 300.0)));
+    assert_eq!(info.version, None);
+    assert_eq!(info.base_profile, None);
+    assert_eq!(info.size, 1482);
+    assert_eq!(info.decompressed_size, Some(4273));
+
+    // The information other than the kind and the sizes is the same as the
+    // decompressed data.
+    let decompressed = is_svg::info(include_str!("data/w3/svg-logo-v.svg")).unwrap();
+    assert_eq!(info.view_box, decompressed.view_box);
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn info_from_nested_svgz() {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(include_bytes!("data/w3/svg-logo-v.svgz"))
+        .unwrap();
+    let nested = encoder.finish().unwrap();
+    let info = is_svg::info(&nested).unwrap();
+    assert_eq!(info.kind, SvgKind::NestedSvgz);
+    assert_eq!(info.size, nested.len());
+    assert_eq!(info.decompressed_size, Some(4273));
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn info_from_utf16_svgz() {
+    // The decompressed size is the size of the data in UTF-16.
+    let info = is_svg::info(include_bytes!("data/encoding/utf-16le.svgz")).unwrap();
+    assert_eq!(info.kind, SvgKind::Svgz);
+    assert_eq!(info.size, 1969);
+    assert_eq!(info.decompressed_size, Some(8628));
+    assert_eq!(info.view_box, Some(view_box(0.0, 0.0, 300.0, 300.0)));
+}
+
+#[test]
+fn info_with_version() {
+    let info = is_svg::info(include_str!("data/ferris/rustacean-flat-happy.svg")).unwrap();
+    assert_eq!(info.version.as_deref(), Some("1.1"));
+    assert_eq!(info.view_box, Some(view_box(0.0, 0.0, 1200.0, 800.0)));
+
+    let info = is_svg::info(include_str!("data/wikipedia/SVG_animation_using_CSS.svg")).unwrap();
+    assert_eq!(info.version.as_deref(), Some("1.1"));
+    assert_eq!(info.view_box, Some(view_box(-4.0, -4.0, 8.0, 8.0)));
+
+    let info = is_svg::info(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" version="1.2" baseProfile="tiny" width="16" height="16"/>"#,
+    )
+    .unwrap();
+    assert_eq!(info.version.as_deref(), Some("1.2"));
+    assert_eq!(info.base_profile.as_deref(), Some("tiny"));
+}
+
+#[test]
+fn info_with_absolute_size() {
+    for (width, height, expected) in [
+        ("16", "24", (16.0, 24.0)),
+        ("16px", " 24px", (16.0, 24.0)),
+        ("1in", "0.5in", (96.0, 48.0)),
+        ("2.54cm", "25.4mm", (96.0, 96.0)),
+        ("72pt", "6pc", (96.0, 96.0)),
+        ("1.6e1", ".5e2", (16.0, 50.0)),
+    ] {
+        let text = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}"/>"#
+        );
+        let info = is_svg::info(text).unwrap();
+        let (expected_width, expected_height) = expected;
+        assert!(
+            (info.width.unwrap() - expected_width).abs() < 1e-3,
+            "{width}"
+        );
+        assert!(
+            (info.height.unwrap() - expected_height).abs() < 1e-3,
+            "{height}"
+        );
+        assert_eq!(info.view_box, None);
+    }
+}
+
+#[test]
+fn info_with_relative_size() {
+    for size in ["100%", "1em", "2ex"] {
+        let text = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{size}" height="{size}" viewBox="0 0 16 16"/>"#
+        );
+        let info = is_svg::info(text).unwrap();
+        assert_eq!(info.width, None, "{size}");
+        assert_eq!(info.height, None, "{size}");
+        assert_eq!(info.view_box, Some(view_box(0.0, 0.0, 16.0, 16.0)));
+    }
+
+    let info =
+        is_svg::info(r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" viewBox="0,0,16,8"/>"#)
+            .unwrap();
+    assert_eq!(info.width, Some(16.0));
+    assert_eq!(info.height, None);
+    assert_eq!(info.view_box, Some(view_box(0.0, 0.0, 16.0, 8.0)));
+}
+
+#[test]
+fn info_with_invalid_view_box() {
+    for view_box in [
+        "0 0 16",
+        "0 0 16 16 16",
+        "0 0 0 16",
+        "0 0 16 -16",
+        "0 0 NaN 16",
+    ] {
+        let text = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" viewBox="{view_box}"/>"#
+        );
+        let info = is_svg::info(text).unwrap();
+        assert_eq!(info.view_box, None, "{view_box}");
+    }
+}
+
+#[test]
+fn info_from_invalid_data() {
+    assert!(matches!(
+        is_svg::info(include_bytes!("data/w3/svg-logo-v.png")),
+        Err(Error::NotUtf8(_))
+    ));
+    assert!(matches!(
+        is_svg::info(include_str!("data/resources/unclosed.svg")),
+        Err(Error::NotXml { .. })
+    ));
+    assert!(matches!(
+        is_svg::info(include_str!("data/root/quadratic-formula.mml")),
+        Err(Error::NotSvgRoot { .. })
+    ));
+    assert!(matches!(
+        is_svg::info(r#"<svg xmlns="http://www.w3.org/2000/svg" width="0" height="16"/>"#),
+        Err(Error::Parse(_))
+    ));
+}
+
+#[test]
+fn info_is_consistent_with_check_svg() {
+    for data in [
+        include_bytes!("data/w3/svg-logo-v.svg").as_slice(),
+        include_bytes!("data/w3/svg-logo-v.svgz"),
+        include_bytes!("data/w3/svg-logo-v.png"),
+        include_bytes!("data/resources/dtd.svg"),
+        include_bytes!("data/hostile/billion-laughs.svg"),
+        include_bytes!("data/blank/whitespace.svg"),
+    ] {
+        assert_eq!(
+            is_svg::info(data)
+                .map(|info| info.kind)
+                .map_err(|err| err.kind()),
+            is_svg::detect(data).ok_or_else(|| is_svg::check_svg(data).unwrap_err().kind())
+        );
+    }
+}
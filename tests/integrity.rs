@@ -0,0 +1,124 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::io::{self, Read, Write};
+
+use flate2::{read::DeflateDecoder, write::GzEncoder, Compression};
+use is_svg::{Decompressor, Error, GzipDecompressor, SvgChecker, Validator};
+
+/// The size of the gzip header without the optional fields.
+const HEADER_LEN: usize = 10;
+
+/// The size of the gzip trailer, which is the CRC-32 and the size.
+const TRAILER_LEN: usize = 8;
+
+fn assert_integrity_error(data: &[u8]) {
+    assert!(!is_svg::is_svg(data));
+    assert!(!is_svg::is_svgz(data));
+    assert!(matches!(is_svg::check_svg(data), Err(Error::GzipIntegrity)));
+    assert!(matches!(
+        is_svg::decompress_svgz(data),
+        Err(Error::GzipIntegrity)
+    ));
+    assert!(matches!(
+        is_svg::check_svg_reader(data),
+        Err(Error::GzipIntegrity)
+    ));
+    for validator in [Validator::lenient(), Validator::new(), Validator::strict()] {
+        assert!(matches!(
+            validator.check_svg(data),
+            Err(Error::GzipIntegrity)
+        ));
+    }
+    for chunk_size in [1, 64, data.len()] {
+        let mut checker = SvgChecker::new();
+        for chunk in data.chunks(chunk_size) {
+            checker.push(chunk);
+        }
+        assert!(matches!(checker.finish(), Err(Error::GzipIntegrity)));
+    }
+}
+
+#[test]
+fn crc_mismatch() {
+    assert_integrity_error(include_bytes!("data/integrity/crc-mismatch.svgz"));
+}
+
+#[test]
+fn isize_mismatch() {
+    assert_integrity_error(include_bytes!("data/integrity/isize-mismatch.svgz"));
+}
+
+#[test]
+fn integrity_with_gzip_decompressor() {
+    let validator = Validator::builder().decompressor(GzipDecompressor).build();
+    for data in [
+        include_bytes!("data/integrity/crc-mismatch.svgz").as_slice(),
+        include_bytes!("data/integrity/isize-mismatch.svgz"),
+        include_bytes!("data/integrity/deflate-bit-flip.svgz"),
+    ] {
+        assert!(matches!(
+            validator.check_svg(data),
+            Err(Error::GzipIntegrity)
+        ));
+        let err = GzipDecompressor
+            .decompress(data, None, &mut Vec::new())
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+    assert!(matches!(
+        validator.check_svg(include_bytes!("data/integrity/corrupt-deflate.svgz")),
+        Err(Error::GzipDecode(_))
+    ));
+}
+
+#[test]
+fn deflate_bit_flip() {
+    // A bit in the deflate stream is flipped, but it still inflates to a valid
+    // SVG image whose transformation differs, so only the trailer reveals it.
+    let data = include_bytes!("data/integrity/deflate-bit-flip.svgz");
+    let mut inflated = String::new();
+    DeflateDecoder::new(&data[HEADER_LEN..data.len() - TRAILER_LEN])
+        .read_to_string(&mut inflated)
+        .unwrap();
+    assert!(is_svg::is_svg(&inflated));
+    assert!(inflated.contains(r#"transform="scale(4) translate(40,79)""#));
+    assert_integrity_error(data);
+}
+
+#[test]
+fn corrupt_deflate() {
+    // The deflate stream itself is invalid, so the trailer is never reached.
+    let data = include_bytes!("data/integrity/corrupt-deflate.svgz");
+    assert!(!is_svg::is_svg(data));
+    assert!(matches!(is_svg::check_svg(data), Err(Error::GzipDecode(_))));
+    let mut checker = SvgChecker::new();
+    checker.push(data);
+    assert!(matches!(checker.finish(), Err(Error::GzipDecode(_))));
+}
+
+#[test]
+fn integrity_of_each_member() {
+    let svg = include_bytes!("data/w3/svg-logo-v.svg");
+    let (first, second) = svg.split_at(svg.len() / 2);
+    let members = [first, second].map(|part| {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(part).unwrap();
+        encoder.finish().unwrap()
+    });
+    assert!(is_svg::is_svg(members.concat()));
+    for index in 0..members.len() {
+        let mut corrupted = members.clone();
+        let crc = corrupted[index].len() - TRAILER_LEN;
+        corrupted[index][crc] ^= 0x01;
+        let data = corrupted.concat();
+        assert!(matches!(
+            is_svg::check_svg(&data),
+            Err(Error::GzipIntegrity)
+        ));
+        let mut checker = SvgChecker::new();
+        checker.push(&data);
+        assert!(matches!(checker.finish(), Err(Error::GzipIntegrity)));
+    }
+}
@@ -0,0 +1,195 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use is_svg::{usvg::Size, Error, ErrorKind};
+
+/// The default object size of the `img` element.
+const FALLBACK: (f32, f32) = (300.0, 150.0);
+
+/// The tolerance of the sizes which are converted from the physical units.
+const EPSILON: f32 = 1e-3;
+
+fn fallback() -> Size {
+    Size::from_wh(FALLBACK.0, FALLBACK.1).unwrap()
+}
+
+fn assert_size(actual: Size, expected: (f32, f32)) {
+    assert!(
+        (actual.width() - expected.0).abs() < EPSILON,
+        "{actual:?} != {expected:?}"
+    );
+    assert!(
+        (actual.height() - expected.1).abs() < EPSILON,
+        "{actual:?} != {expected:?}"
+    );
+}
+
+#[test]
+fn intrinsic_size_from_fixtures() {
+    for (text, expected) in [
+        // The height is computed from the aspect ratio of the `viewBox`.
+        (
+            include_str!("data/intrinsic-size/only-width.svg"),
+            (200.0, 100.0),
+        ),
+        (
+            include_str!("data/intrinsic-size/only-height.svg"),
+            (200.0, 100.0),
+        ),
+        // Without the aspect ratio, the other is taken from the fallback size.
+        (
+            include_str!("data/intrinsic-size/only-width-without-view-box.svg"),
+            (200.0, 150.0),
+        ),
+        // The `viewBox` is fitted in the fallback size.
+        (
+            include_str!("data/intrinsic-size/only-view-box.svg"),
+            (150.0, 150.0),
+        ),
+        (
+            include_str!("data/intrinsic-size/wide-view-box.svg"),
+            (300.0, 75.0),
+        ),
+        (include_str!("data/intrinsic-size/nothing.svg"), FALLBACK),
+        // The percentage is indeterminate, so only the height is used.
+        (
+            include_str!("data/intrinsic-size/percentage-width.svg"),
+            (200.0, 100.0),
+        ),
+        // The `viewBox` does not affect the absolute size.
+        (
+            include_str!("data/intrinsic-size/physical-units.svg"),
+            (192.0, 48.0),
+        ),
+    ] {
+        assert!(is_svg::is_svg(text), "{text}");
+        assert_size(is_svg::intrinsic_size(text, fallback()).unwrap(), expected);
+    }
+}
+
+#[test]
+fn intrinsic_size_from_resources() {
+    assert_size(
+        is_svg::intrinsic_size(include_str!("data/w3/svg-logo-v.svg"), fallback()).unwrap(),
+        (150.0, 150.0),
+    );
+    assert_size(
+        is_svg::intrinsic_size(include_str!("data/dimensions/a4.svg"), fallback()).unwrap(),
+        (793.700_8, 1_122.519_7),
+    );
+    // `width="50%"` and `height="100%"`.
+    assert_size(
+        is_svg::intrinsic_size(include_str!("data/dimensions/percentage.svg"), fallback()).unwrap(),
+        (150.0, 150.0),
+    );
+}
+
+#[test]
+fn intrinsic_size_with_fallback() {
+    let text = include_str!("data/intrinsic-size/only-view-box.svg");
+    for (fallback, expected) in [
+        ((300.0, 150.0), (150.0, 150.0)),
+        ((150.0, 300.0), (150.0, 150.0)),
+        ((64.0, 64.0), (64.0, 64.0)),
+    ] {
+        let fallback = Size::from_wh(fallback.0, fallback.1).unwrap();
+        assert_size(is_svg::intrinsic_size(text, fallback).unwrap(), expected);
+    }
+    let fallback = Size::from_wh(64.0, 32.0).unwrap();
+    assert_eq!(
+        is_svg::intrinsic_size(include_str!("data/intrinsic-size/nothing.svg"), fallback).unwrap(),
+        fallback
+    );
+    assert_size(
+        is_svg::intrinsic_size(
+            include_str!("data/intrinsic-size/only-width-without-view-box.svg"),
+            fallback,
+        )
+        .unwrap(),
+        (200.0, 32.0),
+    );
+}
+
+#[test]
+fn intrinsic_size_with_relative_lengths() {
+    for (width, height) in [
+        ("50%", "100%"),
+        ("1em", "2ex"),
+        ("auto", "auto"),
+        ("16 ", "8px "),
+    ] {
+        let text = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 16 8"/>"#
+        );
+        assert_size(
+            is_svg::intrinsic_size(text, fallback()).unwrap(),
+            (300.0, 150.0),
+        );
+    }
+}
+
+#[test]
+fn intrinsic_size_with_invalid_view_box() {
+    // The `viewBox` which is not valid has no aspect ratio.
+    let text = r#"<svg xmlns="http://www.w3.org/2000/svg" width="200" viewBox="0 0 -16 8"/>"#;
+    assert!(is_svg::view_box(text).is_err());
+    assert_size(
+        is_svg::intrinsic_size(text, fallback()).unwrap(),
+        (200.0, 150.0),
+    );
+}
+
+#[test]
+fn intrinsic_size_from_invalid_data() {
+    assert!(matches!(
+        is_svg::intrinsic_size(include_bytes!("data/w3/svg-logo-v.png"), fallback()),
+        Err(Error::NotUtf8(_))
+    ));
+    assert!(matches!(
+        is_svg::intrinsic_size(include_str!("data/resources/unclosed.svg"), fallback()),
+        Err(Error::NotXml { .. })
+    ));
+}
+
+#[test]
+fn invalid_intrinsic_size() {
+    // The aspect ratio is too small for the width, so the height overflows.
+    let text = r#"<svg xmlns="http://www.w3.org/2000/svg" width="1e30" viewBox="0 0 1e-30 1e30"/>"#;
+    assert!(is_svg::is_svg(text));
+    let err = is_svg::intrinsic_size(text, fallback()).unwrap_err();
+    match err {
+        Error::InvalidIntrinsicSize { width, height } => {
+            assert_eq!((width, height), (1e30, f32::INFINITY));
+        }
+        ref err => panic!("{err:?}"),
+    }
+    assert_eq!(err.kind(), ErrorKind::InvalidIntrinsicSize);
+
+    // The aspect ratio is too large for the fallback size, so the height
+    // underflows.
+    let text = r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1e30 1e-30"/>"#;
+    assert!(is_svg::is_svg(text));
+    let err = is_svg::intrinsic_size(text, fallback()).unwrap_err();
+    match err {
+        Error::InvalidIntrinsicSize { width, height } => {
+            assert_eq!((width, height), (300.0, 0.0));
+        }
+        ref err => panic!("{err:?}"),
+    }
+    assert_eq!(err.to_string(), "intrinsic size 300x0 was not valid");
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn intrinsic_size_from_svgz() {
+    assert_size(
+        is_svg::intrinsic_size(include_bytes!("data/w3/svg-logo-v.svgz"), fallback()).unwrap(),
+        (150.0, 150.0),
+    );
+    let data = is_svg::compress_svg(include_str!("data/intrinsic-size/only-height.svg")).unwrap();
+    assert_size(
+        is_svg::intrinsic_size(data, fallback()).unwrap(),
+        (200.0, 100.0),
+    );
+}
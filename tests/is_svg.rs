@@ -2,6 +2,14 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+mod common;
+
+use std::{fs, thread};
+
+use is_svg::Validator;
+
+const SVG: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16"/>"#;
+
 #[test]
 #[allow(clippy::cognitive_complexity, clippy::too_many_lines)]
 fn is_svg_from_svg() {
@@ -118,6 +126,7 @@ fn is_svg_from_svg() {
     )));
 }
 
+#[cfg(feature = "gzip")]
 #[test]
 fn is_svg_from_svgz() {
     assert!(is_svg::is_svg(include_bytes!("data/ferris/corro.svgz")));
@@ -148,6 +157,52 @@ fn is_svg_from_svgz() {
     )));
 }
 
+#[cfg(not(feature = "gzip"))]
+#[test]
+fn is_svg_from_svgz_without_gzip_feature() {
+    assert!(!is_svg::is_svg(include_bytes!("data/ferris/corro.svgz")));
+    assert!(!is_svg::is_svg(include_bytes!("data/w3/svg-logo-v.svgz")));
+}
+
+#[test]
+fn is_svg_with_processing_instructions() {
+    assert!(is_svg::is_svg(include_str!(
+        "data/processing-instruction/stylesheet.svg"
+    )));
+    assert!(is_svg::is_svg(include_str!(
+        "data/processing-instruction/unknown.svg"
+    )));
+    assert!(is_svg::is_svg(include_str!(
+        "data/processing-instruction/after-root.svg"
+    )));
+}
+
+#[test]
+fn is_svg_between_threads() {
+    let handles = (0..8)
+        .map(|i| {
+            thread::spawn(move || {
+                for _ in 0..16 {
+                    assert!(is_svg::is_svg(include_str!("data/w3/svg-logo-v.svg")));
+                    assert_eq!(
+                        is_svg::is_svg(include_bytes!("data/w3/svg-logo-v.svgz")),
+                        cfg!(feature = "gzip")
+                    );
+                    assert!(!is_svg::is_svg(include_bytes!("data/w3/svg-logo-v.png")));
+                    if i % 2 == 0 {
+                        assert!(is_svg::is_svg(include_str!(
+                            "data/usvg/preserve-text-simple-case.svg"
+                        )));
+                    }
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+}
+
 #[test]
 fn is_svg_from_invalid_svg() {
     assert!(!is_svg::is_svg(include_str!("data/resources/dtd.svg")));
@@ -164,6 +219,273 @@ fn is_svg_from_non_svg() {
     assert!(!is_svg::is_svg(include_bytes!("data/w3/svg-logo-v.webp")));
 }
 
+#[test]
+fn is_svg_with_leading_content() {
+    for (prefix, expected) in [
+        ("\u{feff}", true),
+        (" \t\r\n", true),
+        ("\u{feff}\n", true),
+        ("<!-- comment -->\n", true),
+        ("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n", true),
+        (
+            "<?xml version=\"1.0\"?><!-- comment --><!DOCTYPE svg>",
+            true,
+        ),
+        ("text", false),
+        ("\n text", false),
+        ("\u{feff}\u{feff}", false),
+        ("\x0c", false),
+    ] {
+        let data = format!("{prefix}{SVG}");
+        assert_eq!(is_svg::is_svg(&data), expected, "{prefix:?}");
+        assert_eq!(is_svg::check_svg(&data).is_ok(), expected, "{prefix:?}");
+    }
+}
+
+#[test]
+fn is_svg_from_xml_declaration() {
+    for data in [
+        include_str!("data/xml-declaration/bom.svg"),
+        include_str!("data/xml-declaration/double-quotes.svg"),
+        include_str!("data/xml-declaration/single-quotes.svg"),
+        include_str!("data/xml-declaration/standalone.svg"),
+        include_str!("data/xml-declaration/whitespace.svg"),
+    ] {
+        assert!(is_svg::is_svg(data), "{data:?}");
+        assert!(is_svg::check_svg(data).is_ok(), "{data:?}");
+        assert!(Validator::strict().is_svg(data), "{data:?}");
+        assert!(is_svg::looks_like_svg(data), "{data:?}");
+    }
+    // This has no root element.
+    assert!(!is_svg::is_svg(include_str!(
+        "data/resources/xml_declaration.svg"
+    )));
+}
+
+#[test]
+fn is_svg_with_xml_declaration() {
+    for declaration in [
+        "<?xml version='1.0'?>",
+        "<?xml version='1.0' encoding='utf-8' standalone='yes'?>",
+        "<?xml version=\"1.0\" standalone=\"no\"?>",
+        "<?xml version = \"1.0\"\tencoding = 'UTF-8' ?>",
+        "<?xml\r\n  version=\"1.0\"\r\n  encoding=\"UTF-8\"\r\n?>",
+        "<?xml version=\"1.1\"?>",
+    ] {
+        for separator in ["", "\n", "\r\n", "\n\n\t"] {
+            let data = format!("{declaration}{separator}{SVG}");
+            assert!(is_svg::is_svg(&data), "{data:?}");
+            assert!(Validator::strict().is_svg(&data), "{data:?}");
+            let data = format!("\u{feff}{data}");
+            assert!(is_svg::is_svg(&data), "{data:?}");
+        }
+    }
+    // The XML declaration must be at the start of the document.
+    assert!(!is_svg::is_svg(format!(" <?xml version=\"1.0\"?>{SVG}")));
+}
+
+#[test]
+fn is_svg_from_bom() {
+    for data in [
+        &include_bytes!("data/encoding/bom.svg")[..],
+        include_bytes!("data/encoding/bom.svgz"),
+    ] {
+        assert_eq!(
+            is_svg::is_svg(data),
+            cfg!(feature = "gzip") || data[0] != 0x1f
+        );
+        assert_eq!(is_svg::check_svg_reader(data).is_ok(), is_svg::is_svg(data));
+    }
+    assert_eq!(
+        is_svg::detect(include_bytes!("data/encoding/bom.svgz")),
+        cfg!(feature = "gzip").then_some(is_svg::SvgKind::Svgz)
+    );
+}
+
+#[test]
+fn is_svg_with_misplaced_bom() {
+    for (data, expected) in [
+        (format!(" \u{feff}{SVG}"), false),
+        (format!("<?xml version=\"1.0\"?>\u{feff}{SVG}"), false),
+        (format!("<!-- comment -->\u{feff}{SVG}"), false),
+        (
+            r#"<svg\u{feff} xmlns="http://www.w3.org/2000/svg" width="16" height="16"/>"#.to_owned(),
+            false,
+        ),
+        (format!("{SVG}\u{feff}"), false),
+        // Inside the root element, this is an ordinary character.
+        (
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"16\" height=\"16\">\u{feff}<title>\u{feff}</title></svg>"
+                .to_owned(),
+            true,
+        ),
+        (
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"16\" height=\"16\" id=\"\u{feff}\"/>"
+                .to_owned(),
+            true,
+        ),
+    ] {
+        assert_eq!(is_svg::is_svg(&data), expected, "{data:?}");
+        assert_eq!(Validator::lenient().is_svg(&data), expected, "{data:?}");
+    }
+
+    // The byte order mark before the gzip-compressed data is not skipped.
+    let mut data = b"\xef\xbb\xbf".to_vec();
+    data.extend_from_slice(include_bytes!("data/w3/svg-logo-v.svgz"));
+    assert!(!is_svg::is_svg(&data));
+}
+
+#[test]
+fn is_svg_from_utf16() {
+    for data in [
+        &include_bytes!("data/encoding/utf-16le.svg")[..],
+        include_bytes!("data/encoding/utf-16be.svg"),
+        include_bytes!("data/encoding/utf-16le.svgz"),
+    ] {
+        assert_eq!(
+            is_svg::is_svg(data),
+            cfg!(feature = "gzip") || data[0] != 0x1f
+        );
+        assert_eq!(is_svg::check_svg_reader(data).is_ok(), is_svg::is_svg(data));
+        assert_eq!(Validator::strict().is_svg(data), is_svg::is_svg(data));
+    }
+    let tree = is_svg::parse_svg(include_bytes!("data/encoding/utf-16be.svg")).unwrap();
+    assert_eq!(
+        tree.size(),
+        is_svg::parse_svg(include_str!("data/w3/svg-logo-v.svg"))
+            .unwrap()
+            .size()
+    );
+}
+
+#[test]
+fn is_svg_from_utf16_without_bom() {
+    let data = format!("<?xml version=\"1.0\" encoding=\"UTF-16\"?>{SVG}");
+    let le = data
+        .encode_utf16()
+        .flat_map(u16::to_le_bytes)
+        .collect::<Vec<_>>();
+    let be = data
+        .encode_utf16()
+        .flat_map(u16::to_be_bytes)
+        .collect::<Vec<_>>();
+    assert!(is_svg::is_svg(&le));
+    assert!(is_svg::is_svg(&be));
+
+    // Without the XML declaration, the encoding is unknown, so this is tested
+    // as a UTF-8 which has the null characters.
+    let le = SVG
+        .encode_utf16()
+        .flat_map(u16::to_le_bytes)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        is_svg::check_svg(le).unwrap_err().kind(),
+        is_svg::ErrorKind::NotXml
+    );
+}
+
+#[test]
+fn is_svg_from_invalid_utf16() {
+    let mut odd = include_bytes!("data/encoding/utf-16le.svg").to_vec();
+    odd.push(b'\n');
+    // An unpaired surrogate.
+    let mut surrogate = include_bytes!("data/encoding/utf-16be.svg").to_vec();
+    surrogate.extend_from_slice(b"\xd8\x00");
+    for data in [odd, surrogate, b"\xff\xfe".to_vec()] {
+        assert!(!is_svg::is_svg(&data));
+        assert!(matches!(
+            is_svg::check_svg(&data),
+            Err(is_svg::Error::NotUtf16 | is_svg::Error::NotXml { .. } | is_svg::Error::Empty)
+        ));
+    }
+    assert!(matches!(
+        is_svg::check_svg(b"\xff\xfe<"),
+        Err(is_svg::Error::NotUtf16)
+    ));
+}
+
+#[test]
+fn is_svg_from_prolog() {
+    for data in [
+        &include_bytes!("data/prolog/comment-banner.svg")[..],
+        include_bytes!("data/prolog/comment-banner.svgz"),
+        include_bytes!("data/prolog/comments-and-whitespace.svg"),
+        include_bytes!("data/prolog/declaration-then-comment.svg"),
+        include_bytes!("data/prolog/leading-whitespace.svg"),
+        include_bytes!("data/prolog/leading-whitespace.svgz"),
+    ] {
+        assert_eq!(
+            is_svg::is_svg(data),
+            cfg!(feature = "gzip") || data[0] != 0x1f
+        );
+        assert_eq!(is_svg::check_svg(data).is_ok(), is_svg::is_svg(data));
+        assert_eq!(is_svg::check_svg_reader(data).is_ok(), is_svg::is_svg(data));
+        assert_eq!(
+            Validator::builder().fast(true).build().is_svg(data),
+            is_svg::is_svg(data)
+        );
+    }
+
+    // The XML declaration is only allowed at the beginning of the document.
+    for data in [
+        include_str!("data/prolog/comment-then-declaration.svg"),
+        include_str!("data/prolog/whitespace-then-declaration.svg"),
+    ] {
+        assert!(!is_svg::is_svg(data));
+        assert!(matches!(
+            is_svg::check_svg(data),
+            Err(is_svg::Error::NotXml {
+                source: is_svg::usvg::roxmltree::Error::UnexpectedDeclaration(_),
+                ..
+            })
+        ));
+        assert!(!Validator::lenient().is_svg(data));
+        // Without the XML declaration, these are valid.
+        let end = data.find("?>").unwrap() + 2;
+        let start = data.find("<?xml").unwrap();
+        assert!(is_svg::is_svg(format!(
+            "{}{}",
+            &data[..start],
+            &data[end..]
+        )));
+    }
+}
+
+#[test]
+fn is_svg_with_large_leading_whitespace() {
+    let whitespace = " ".repeat(64 * 1024);
+    assert!(is_svg::is_svg(format!("{whitespace}{SVG}")));
+    assert!(!is_svg::is_svg(format!("{whitespace}text")));
+    assert!(!is_svg::is_svg(&whitespace));
+}
+
+#[test]
+fn is_svg_same_as_check_svg_from_fixtures() {
+    let paths = common::fixtures(common::DATA_DIR);
+    assert!(!paths.is_empty());
+
+    let validators = [Validator::lenient(), Validator::new(), Validator::strict()];
+    for path in paths {
+        let data = fs::read(&path).unwrap();
+        let expected = is_svg::check_svg(&data).is_ok();
+        assert_eq!(is_svg::is_svg(&data), expected, "{}", path.display());
+        assert_eq!(
+            is_svg::detect(&data).is_some(),
+            expected,
+            "{}",
+            path.display()
+        );
+        for validator in &validators {
+            assert_eq!(
+                validator.is_svg(&data),
+                validator.check_svg(&data).is_ok(),
+                "{}",
+                path.display()
+            );
+        }
+    }
+}
+
 #[test]
 fn is_svg_from_empty() {
     assert!(!is_svg::is_svg([]));
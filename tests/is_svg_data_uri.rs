@@ -0,0 +1,166 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::fmt::Write;
+
+use is_svg::Error;
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn base64(data: &[u8]) -> String {
+    let mut encoded = String::new();
+    for chunk in data.chunks(3) {
+        let bytes = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or_default(),
+            chunk.get(2).copied().unwrap_or_default(),
+        ];
+        let n = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                let index = usize::try_from((n >> (18 - 6 * i)) & 0x3f).unwrap();
+                encoded.push(char::from(BASE64_ALPHABET[index]));
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+fn percent_encode(data: &[u8]) -> String {
+    let mut encoded = String::new();
+    for &byte in data {
+        if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) {
+            encoded.push(char::from(byte));
+        } else {
+            write!(encoded, "%{byte:02X}").unwrap();
+        }
+    }
+    encoded
+}
+
+#[test]
+fn is_svg_data_uri_from_base64() {
+    for data in [
+        &include_bytes!("data/ferris/corro.svg")[..],
+        include_bytes!("data/usvg/path-simple-case.svg"),
+        include_bytes!("data/w3/svg-logo-v.svg"),
+    ] {
+        assert!(is_svg::is_svg_data_uri(&format!(
+            "data:image/svg+xml;base64,{}",
+            base64(data)
+        )));
+    }
+}
+
+#[test]
+fn is_svg_data_uri_from_percent_encoded() {
+    for data in [
+        &include_bytes!("data/ferris/corro.svg")[..],
+        include_bytes!("data/usvg/path-simple-case.svg"),
+        include_bytes!("data/w3/svg-logo-v.svg"),
+    ] {
+        assert!(is_svg::is_svg_data_uri(&format!(
+            "data:image/svg+xml,{}",
+            percent_encode(data)
+        )));
+        assert!(is_svg::is_svg_data_uri(&format!(
+            "data:image/svg+xml;charset=utf-8,{}",
+            percent_encode(data)
+        )));
+    }
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn is_svg_data_uri_from_svgz() {
+    let data = include_bytes!("data/w3/svg-logo-v.svgz");
+    assert!(is_svg::is_svg_data_uri(&format!(
+        "data:image/svg+xml;base64,{}",
+        base64(data)
+    )));
+    assert!(is_svg::is_svg_data_uri(&format!(
+        "data:image/svg+xml,{}",
+        percent_encode(data)
+    )));
+}
+
+#[test]
+fn is_svg_data_uri_with_case_insensitive_header() {
+    let data = include_bytes!("data/w3/svg-logo-v.svg");
+    assert!(is_svg::is_svg_data_uri(&format!(
+        "DATA:Image/SVG+XML;BASE64,{}",
+        base64(data)
+    )));
+}
+
+#[test]
+fn is_svg_data_uri_from_non_svg() {
+    assert!(!is_svg::is_svg_data_uri(&format!(
+        "data:image/svg+xml;base64,{}",
+        base64(include_bytes!("data/w3/svg-logo-v.png"))
+    )));
+    assert!(!is_svg::is_svg_data_uri(&format!(
+        "data:image/svg+xml,{}",
+        percent_encode(include_bytes!("data/resources/unclosed.svg"))
+    )));
+    assert!(!is_svg::is_svg_data_uri("data:image/svg+xml,"));
+}
+
+#[test]
+fn is_svg_data_uri_with_non_svg_media_type() {
+    let data = include_bytes!("data/w3/svg-logo-v.svg");
+    for media_type in ["", "text/plain", "image/png", "image/svg", "text/xml"] {
+        assert!(matches!(
+            is_svg::check_svg_data_uri(&format!("data:{media_type};base64,{}", base64(data))),
+            Err(Error::NotSvgMediaType)
+        ));
+    }
+}
+
+#[test]
+fn is_svg_data_uri_from_malformed_uri() {
+    let data = include_bytes!("data/w3/svg-logo-v.svg");
+    let encoded = base64(data);
+    for uri in [
+        String::new(),
+        String::from("data:"),
+        format!("image/svg+xml;base64,{encoded}"),
+        format!("http://example.com/image/svg+xml;base64,{encoded}"),
+        format!("data:image/svg+xml;base64{encoded}"),
+        format!("data:image/svg+xml;base64,{}", &encoded[1..]),
+        format!("data:image/svg+xml;base64,!{encoded}"),
+    ] {
+        assert!(!is_svg::is_svg_data_uri(&uri), "{uri}");
+        assert!(
+            matches!(
+                is_svg::check_svg_data_uri(&uri),
+                Err(Error::InvalidDataUri | Error::NotSvgMediaType)
+            ),
+            "{uri}"
+        );
+    }
+}
+
+#[test]
+fn check_svg_data_uri() {
+    assert!(is_svg::check_svg_data_uri(&format!(
+        "data:image/svg+xml;base64,{}",
+        base64(include_bytes!("data/w3/svg-logo-v.svg"))
+    ))
+    .is_ok());
+    assert!(matches!(
+        is_svg::check_svg_data_uri(&format!(
+            "data:image/svg+xml;base64,{}",
+            base64(include_bytes!("data/w3/svg-logo-v.png"))
+        )),
+        Err(Error::NotUtf8(_))
+    ));
+    assert!(matches!(
+        is_svg::check_svg_data_uri("data:image/svg+xml;base64,PHN2Zz4=="),
+        Err(Error::InvalidDataUri)
+    ));
+}
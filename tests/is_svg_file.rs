@@ -0,0 +1,101 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+use std::{fs, io};
+
+#[test]
+fn is_svg_file_from_svg() {
+    let paths = common::images("svg");
+    assert!(!paths.is_empty());
+    for path in paths {
+        assert!(is_svg::is_svg_file(&path).unwrap(), "{}", path.display());
+    }
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn is_svg_file_from_svgz() {
+    let paths = common::images("svgz");
+    assert!(!paths.is_empty());
+    for path in paths {
+        assert!(is_svg::is_svg_file(&path).unwrap(), "{}", path.display());
+    }
+}
+
+#[test]
+fn is_svg_file_from_invalid_svg() {
+    assert!(!is_svg::is_svg_file("tests/data/resources/dtd.svg").unwrap());
+    assert!(!is_svg::is_svg_file("tests/data/resources/unclosed.svg").unwrap());
+    assert!(!is_svg::is_svg_file("tests/data/resources/xml_declaration.svg").unwrap());
+}
+
+#[test]
+fn is_svg_file_from_non_svg() {
+    assert!(!is_svg::is_svg_file("tests/data/w3/svg-logo-v.jxl").unwrap());
+    assert!(!is_svg::is_svg_file("tests/data/w3/svg-logo-v.png").unwrap());
+    assert!(!is_svg::is_svg_file("tests/data/w3/svg-logo-v.webp").unwrap());
+}
+
+#[test]
+fn is_svg_file_from_empty() {
+    assert!(!is_svg::is_svg_file("tests/data/resources/empty.gz").unwrap());
+}
+
+#[test]
+fn is_svg_file_from_renamed_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let png = dir.path().join("svg-logo-v.svg");
+    fs::copy("tests/data/w3/svg-logo-v.png", &png).unwrap();
+    assert!(!is_svg::is_svg_file(png).unwrap());
+    let svg = dir.path().join("svg-logo-v.png");
+    fs::copy("tests/data/w3/svg-logo-v.svg", &svg).unwrap();
+    assert!(is_svg::is_svg_file(svg).unwrap());
+}
+
+#[test]
+fn is_svg_file_from_non_existent_file() {
+    assert_eq!(
+        is_svg::is_svg_file("tests/data/w3/non-existent.svg")
+            .unwrap_err()
+            .kind(),
+        io::ErrorKind::NotFound
+    );
+}
+
+#[test]
+fn is_svg_file_from_directory() {
+    assert!(is_svg::is_svg_file("tests/data").is_err());
+}
+
+#[test]
+fn is_svg_file_limited_under_and_over_limit() {
+    let path = "tests/data/w3/svg-logo-v.svg";
+    let size = fs::metadata(path).unwrap().len();
+    assert!(is_svg::is_svg_file_limited(path, u64::MAX).unwrap());
+    assert!(is_svg::is_svg_file_limited(path, size).unwrap());
+    assert!(!is_svg::is_svg_file_limited(path, size - 1).unwrap());
+    assert!(!is_svg::is_svg_file_limited(path, 0).unwrap());
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn is_svg_file_limited_from_svgz() {
+    // The limit applies to the compressed size.
+    let path = "tests/data/w3/svg-logo-v.svgz";
+    let size = fs::metadata(path).unwrap().len();
+    assert!(is_svg::is_svg_file_limited(path, size).unwrap());
+    assert!(!is_svg::is_svg_file_limited(path, size - 1).unwrap());
+}
+
+#[test]
+fn is_svg_file_limited_from_non_existent_file() {
+    assert_eq!(
+        is_svg::is_svg_file_limited("tests/data/w3/non-existent.svg", u64::MAX)
+            .unwrap_err()
+            .kind(),
+        io::ErrorKind::NotFound
+    );
+}
@@ -0,0 +1,52 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::{fs, io};
+
+#[test]
+fn is_svg_file_mmap_from_svg() {
+    assert!(is_svg::is_svg_file_mmap("tests/data/ferris/corro.svg").unwrap());
+    assert!(is_svg::is_svg_file_mmap("tests/data/w3/svg-logo-v.svg").unwrap());
+}
+
+#[test]
+fn is_svg_file_mmap_from_svgz() {
+    assert!(is_svg::is_svg_file_mmap("tests/data/ferris/corro.svgz").unwrap());
+    assert!(is_svg::is_svg_file_mmap("tests/data/w3/svg-logo-v.svgz").unwrap());
+}
+
+#[test]
+fn is_svg_file_mmap_from_non_svg() {
+    assert!(!is_svg::is_svg_file_mmap("tests/data/resources/unclosed.svg").unwrap());
+    assert!(!is_svg::is_svg_file_mmap("tests/data/w3/svg-logo-v.png").unwrap());
+    assert!(!is_svg::is_svg_file_mmap("tests/data/w3/svg-logo-v.svg.zst").unwrap());
+}
+
+#[test]
+fn is_svg_file_mmap_from_temp_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("image.bin");
+    fs::copy("tests/data/w3/svg-logo-v.svg", &path).unwrap();
+    assert!(is_svg::is_svg_file_mmap(&path).unwrap());
+    assert_eq!(
+        is_svg::is_svg_file_mmap(&path).unwrap(),
+        is_svg::is_svg_file(&path).unwrap()
+    );
+}
+
+#[test]
+fn is_svg_file_mmap_from_empty_file() {
+    let file = tempfile::NamedTempFile::new().unwrap();
+    assert!(!is_svg::is_svg_file_mmap(file.path()).unwrap());
+}
+
+#[test]
+fn is_svg_file_mmap_from_non_existent_file() {
+    assert_eq!(
+        is_svg::is_svg_file_mmap("tests/data/w3/non-existent.svg")
+            .unwrap_err()
+            .kind(),
+        io::ErrorKind::NotFound
+    );
+}
@@ -0,0 +1,125 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+#[cfg(feature = "gzip")]
+use std::{
+    io,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+#[cfg(feature = "gzip")]
+use is_svg::{Decompressor, GzipDecompressor, Validator};
+use is_svg::{Error, ErrorKind, Limit};
+
+#[cfg(feature = "gzip")]
+#[derive(Debug)]
+struct Counting(Arc<AtomicUsize>);
+
+#[cfg(feature = "gzip")]
+impl Decompressor for Counting {
+    fn decompress(&self, data: &[u8], limit: Option<u64>, buf: &mut Vec<u8>) -> io::Result<()> {
+        self.0.fetch_add(1, Ordering::Relaxed);
+        GzipDecompressor.decompress(data, limit, buf)
+    }
+}
+
+/// Returns a large document which would be rejected as not a valid UTF-8 if
+/// it were decoded.
+fn oversized() -> Vec<u8> {
+    let mut data =
+        br#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16"><!--"#.to_vec();
+    data.resize(16 * 1024 * 1024, b'-');
+    data.extend_from_slice(b"\xff--></svg>");
+    data
+}
+
+#[test]
+fn is_svg_limited_from_svg() {
+    let data = include_str!("data/w3/svg-logo-v.svg");
+    assert!(is_svg::is_svg_limited(data, u64::MAX));
+    assert!(is_svg::is_svg_limited(data, data.len() as u64));
+    assert!(!is_svg::is_svg_limited(data, data.len() as u64 - 1));
+    assert!(!is_svg::is_svg_limited(data, 0));
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn is_svg_limited_from_svgz() {
+    let data = include_bytes!("data/w3/svg-logo-v.svgz");
+    let decompressed = is_svg::decompress_svgz(data).unwrap();
+    assert!(data.len() < decompressed.len());
+
+    // The limit applies to the compressed size.
+    assert!(is_svg::is_svg_limited(data, data.len() as u64));
+    assert!(!is_svg::is_svg_limited(data, data.len() as u64 - 1));
+}
+
+#[test]
+fn is_svg_limited_from_non_svg() {
+    assert!(!is_svg::is_svg_limited(
+        include_bytes!("data/w3/svg-logo-v.png"),
+        u64::MAX
+    ));
+    assert!(!is_svg::is_svg_limited(
+        include_str!("data/resources/unclosed.svg"),
+        u64::MAX
+    ));
+    assert!(!is_svg::is_svg_limited([], u64::MAX));
+}
+
+#[test]
+fn check_svg_limited_from_oversized() {
+    let data = oversized();
+    assert!(matches!(is_svg::check_svg(&data), Err(Error::NotUtf8(_))));
+
+    // The data is rejected before it is decoded.
+    let err = is_svg::check_svg_limited(&data, 1024 * 1024).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::LimitExceeded);
+    assert!(matches!(
+        err,
+        Error::LimitExceeded(Limit::InputSize(1_048_576))
+    ));
+    assert!(matches!(
+        is_svg::check_svg_limited(&data, u64::MAX),
+        Err(Error::NotUtf8(_))
+    ));
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn check_svg_limited_from_oversized_svgz() {
+    let data = common::compress(&oversized());
+    let limit = data.len() as u64 - 1;
+    let err = is_svg::check_svg_limited(&data, limit).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::LimitExceeded);
+    assert!(matches!(err, Error::LimitExceeded(Limit::InputSize(l)) if l == limit));
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn max_size_does_not_decompress_oversized_svgz() {
+    let data = common::compress(&oversized());
+    let count = Arc::default();
+    let validator = Validator::builder()
+        .max_size(data.len() as u64 - 1)
+        .decompressor(Counting(Arc::clone(&count)))
+        .build();
+    assert!(matches!(
+        validator.check_svg(&data),
+        Err(Error::LimitExceeded(Limit::InputSize(_)))
+    ));
+    assert_eq!(count.load(Ordering::Relaxed), 0);
+
+    let validator = Validator::builder()
+        .max_size(data.len() as u64)
+        .decompressor(Counting(Arc::clone(&count)))
+        .build();
+    assert!(matches!(validator.check_svg(&data), Err(Error::NotUtf8(_))));
+    assert_eq!(count.load(Ordering::Relaxed), 1);
+}
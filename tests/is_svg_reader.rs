@@ -0,0 +1,93 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::io::{self, Cursor, Read};
+
+/// A reader which yields the data one byte at a time.
+struct OneByteReader<'a>(&'a [u8]);
+
+impl Read for OneByteReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match (self.0.split_first(), buf.first_mut()) {
+            (Some((&byte, rest)), Some(b)) => {
+                *b = byte;
+                self.0 = rest;
+                Ok(1)
+            }
+            _ => Ok(0),
+        }
+    }
+}
+
+/// A reader which always fails.
+struct BrokenReader;
+
+impl Read for BrokenReader {
+    fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+        Err(io::Error::from(io::ErrorKind::BrokenPipe))
+    }
+}
+
+#[test]
+fn is_svg_reader_from_svg() {
+    assert!(is_svg::is_svg_reader(Cursor::new(include_str!("data/ferris/corro.svg"))).unwrap());
+    assert!(
+        is_svg::is_svg_reader(Cursor::new(include_str!("data/usvg/path-simple-case.svg"))).unwrap()
+    );
+    assert!(is_svg::is_svg_reader(Cursor::new(include_str!("data/w3/svg-logo-v.svg"))).unwrap());
+    assert!(
+        is_svg::is_svg_reader(OneByteReader(include_bytes!("data/w3/svg-logo-v.svg"))).unwrap()
+    );
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn is_svg_reader_from_svgz() {
+    assert!(is_svg::is_svg_reader(Cursor::new(include_bytes!("data/ferris/corro.svgz"))).unwrap());
+    assert!(is_svg::is_svg_reader(Cursor::new(include_bytes!("data/w3/svg-logo-v.svgz"))).unwrap());
+    assert!(
+        is_svg::is_svg_reader(OneByteReader(include_bytes!("data/w3/svg-logo-v.svgz"))).unwrap()
+    );
+}
+
+#[test]
+fn is_svg_reader_from_invalid_svg() {
+    assert!(!is_svg::is_svg_reader(Cursor::new(include_str!("data/resources/dtd.svg"))).unwrap());
+    assert!(
+        !is_svg::is_svg_reader(Cursor::new(include_str!("data/resources/unclosed.svg"))).unwrap()
+    );
+    assert!(
+        !is_svg::is_svg_reader(OneByteReader(include_bytes!("data/resources/unclosed.svg")))
+            .unwrap()
+    );
+}
+
+#[test]
+fn is_svg_reader_from_non_svg() {
+    assert!(!is_svg::is_svg_reader(Cursor::new(include_bytes!("data/w3/svg-logo-v.png"))).unwrap());
+    assert!(
+        !is_svg::is_svg_reader(OneByteReader(include_bytes!("data/w3/svg-logo-v.png"))).unwrap()
+    );
+}
+
+#[test]
+fn is_svg_reader_from_empty() {
+    assert!(!is_svg::is_svg_reader(io::empty()).unwrap());
+    assert!(
+        !is_svg::is_svg_reader(Cursor::new(include_bytes!("data/resources/empty.gz"))).unwrap()
+    );
+}
+
+#[test]
+fn is_svg_reader_from_endless_reader() {
+    assert!(!is_svg::is_svg_reader(io::repeat(b' ')).unwrap());
+}
+
+#[test]
+fn is_svg_reader_from_broken_reader() {
+    assert_eq!(
+        is_svg::is_svg_reader(BrokenReader).unwrap_err().kind(),
+        io::ErrorKind::BrokenPipe
+    );
+}
@@ -0,0 +1,124 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+use std::{
+    io,
+    path::Path,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use is_svg::{Error, Limit};
+use tokio::io::{AsyncRead, BufReader, ReadBuf};
+
+/// A reader which always fails.
+struct BrokenReader;
+
+impl AsyncRead for BrokenReader {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        _: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        Poll::Ready(Err(io::Error::from(io::ErrorKind::BrokenPipe)))
+    }
+}
+
+async fn open(path: impl AsRef<Path>) -> BufReader<tokio::fs::File> {
+    BufReader::new(tokio::fs::File::open(path).await.unwrap())
+}
+
+#[tokio::test]
+async fn is_svg_reader_async_from_svg() {
+    let paths = common::images("svg");
+    assert!(!paths.is_empty());
+    for path in paths {
+        assert!(
+            is_svg::is_svg_reader_async(open(&path).await)
+                .await
+                .unwrap(),
+            "{}",
+            path.display()
+        );
+    }
+}
+
+#[tokio::test]
+async fn is_svg_reader_async_from_svgz() {
+    let paths = common::images("svgz");
+    assert!(!paths.is_empty());
+    for path in paths {
+        assert!(
+            is_svg::is_svg_reader_async(open(&path).await)
+                .await
+                .unwrap(),
+            "{}",
+            path.display()
+        );
+    }
+}
+
+#[tokio::test]
+async fn is_svg_reader_async_from_invalid_svg() {
+    for path in [
+        "tests/data/resources/dtd.svg",
+        "tests/data/resources/unclosed.svg",
+        "tests/data/resources/xml_declaration.svg",
+        "tests/data/resources/empty.gz",
+    ] {
+        assert!(!is_svg::is_svg_reader_async(open(path).await).await.unwrap());
+    }
+}
+
+#[tokio::test]
+async fn is_svg_reader_async_from_non_svg() {
+    for path in [
+        "tests/data/w3/svg-logo-v.png",
+        "tests/data/w3/svg-logo-v.jxl",
+        "tests/data/w3/svg-logo-v.webp",
+        "tests/data/w3/svg-logo-v.svg.lz",
+        "tests/data/w3/svg-logo-v.svg.zst",
+    ] {
+        assert!(!is_svg::is_svg_reader_async(open(path).await).await.unwrap());
+    }
+}
+
+#[tokio::test]
+async fn is_svg_reader_async_from_broken_reader() {
+    assert_eq!(
+        is_svg::is_svg_reader_async(BrokenReader)
+            .await
+            .unwrap_err()
+            .kind(),
+        io::ErrorKind::BrokenPipe
+    );
+}
+
+#[tokio::test]
+async fn check_svg_reader_async() {
+    assert!(
+        is_svg::check_svg_reader_async(open("tests/data/w3/svg-logo-v.svgz").await)
+            .await
+            .is_ok()
+    );
+    assert!(matches!(
+        is_svg::check_svg_reader_async(open("tests/data/w3/svg-logo-v.png").await).await,
+        Err(Error::NotUtf8(_))
+    ));
+    assert!(matches!(
+        is_svg::check_svg_reader_async(BrokenReader).await,
+        Err(Error::Io(_))
+    ));
+}
+
+#[tokio::test]
+async fn check_svg_reader_async_with_too_large_data() {
+    let data = vec![b' '; 64 * 1024 * 1024 + 1];
+    assert!(matches!(
+        is_svg::check_svg_reader_async(&data[..]).await,
+        Err(Error::LimitExceeded(Limit::InputSize(67_108_864)))
+    ));
+}
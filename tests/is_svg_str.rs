@@ -0,0 +1,191 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+use std::fs;
+
+#[test]
+#[allow(clippy::cognitive_complexity, clippy::too_many_lines)]
+fn is_svg_str_from_svg() {
+    assert!(is_svg::is_svg_str(include_str!("data/ferris/corro.svg")));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/ferris/cuddlyferris.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/ferris/rustacean-flat-gesture.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/ferris/rustacean-flat-happy.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/ferris/rustacean-flat-noshadow.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/ferris/rustacean-orig-noshadow.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/usvg/clip-path-with-complex-text.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/usvg/clip-path-with-object-units-multi-use.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/usvg/clip-path-with-text.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/usvg/ellipse-simple-case.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/usvg/filter-id-with-prefix.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/usvg/filter-with-object-units-multi-use.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/usvg/generate-id-clip-path-for-symbol.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/usvg/generate-id-filter-function-v1.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/usvg/generate-id-filter-function-v2.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/usvg/mask-with-object-units-multi-use.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/usvg/path-simple-case.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/usvg/preserve-id-clip-path-v1.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/usvg/preserve-id-clip-path-v2.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/usvg/preserve-id-fe-image-with-opacity.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/usvg/preserve-id-fe-image.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/usvg/preserve-id-filter.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/usvg/preserve-id-for-clip-path-in-pattern.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/usvg/preserve-text-in-clip-path.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/usvg/preserve-text-in-mask.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/usvg/preserve-text-in-pattern.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/usvg/preserve-text-multiple-font-families.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/usvg/preserve-text-on-path.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/usvg/preserve-text-simple-case.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/usvg/preserve-text-with-complex-text-decoration.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/usvg/preserve-text-with-dx-and-dy.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/usvg/preserve-text-with-nested-baseline-shift.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/usvg/preserve-text-with-rotate.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/usvg/text-simple-case.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/usvg/text-with-generated-gradients.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!("data/w3/svg-logo-v.svg")));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/wikipedia/SVG_animation_using_CSS.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/wikipedia/SVG_animation_using_ES.svg"
+    )));
+    assert!(is_svg::is_svg_str(include_str!(
+        "data/wikipedia/SVG_animation_using_SMIL.svg"
+    )));
+}
+
+#[test]
+fn is_svg_str_from_invalid_svg() {
+    assert!(!is_svg::is_svg_str(include_str!("data/resources/dtd.svg")));
+    assert!(!is_svg::is_svg_str(include_str!(
+        "data/resources/unclosed.svg"
+    )));
+    assert!(!is_svg::is_svg_str(include_str!(
+        "data/resources/xml_declaration.svg"
+    )));
+}
+
+#[test]
+fn is_svg_str_from_empty() {
+    assert!(!is_svg::is_svg_str(""));
+    assert!(!is_svg::is_svg_str(" \n"));
+}
+
+#[test]
+fn is_svg_str_from_mathml() {
+    assert!(!is_svg::is_svg_str(include_str!(
+        "data/resources/quadratic_formula.mml"
+    )));
+}
+
+#[test]
+fn is_svg_str_from_html() {
+    assert!(!is_svg::is_svg_str(include_str!(
+        "data/resources/index.html"
+    )));
+}
+
+#[test]
+fn is_svg_str_same_as_is_svg() {
+    for data in [
+        include_str!("data/ferris/corro.svg"),
+        include_str!("data/usvg/text-simple-case.svg"),
+        include_str!("data/w3/svg-logo-v.svg"),
+        include_str!("data/resources/dtd.svg"),
+        include_str!("data/resources/unclosed.svg"),
+        include_str!("data/resources/index.html"),
+    ] {
+        assert_eq!(is_svg::is_svg_str(data), is_svg::is_svg(data));
+    }
+}
+
+#[test]
+fn is_svg_str_same_as_is_svg_from_fixtures() {
+    let paths = common::fixtures(common::DATA_DIR);
+
+    let mut count = 0;
+    for path in paths {
+        let data = fs::read(&path).unwrap();
+        // Only the text fixtures can be passed as a string.
+        if let Ok(data) = String::from_utf8(data) {
+            assert_eq!(
+                is_svg::is_svg_str(&data),
+                is_svg::is_svg(&data),
+                "{}",
+                path.display()
+            );
+            count += 1;
+        }
+    }
+    assert!(count > 0);
+}
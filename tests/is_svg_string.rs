@@ -156,6 +156,14 @@ fn is_svg_string_from_svgz() {
     )));
 }
 
+#[test]
+fn is_svg_string_from_bom() {
+    assert!(is_svg::is_svg_string(include_str!("data/encoding/bom.svg")));
+    assert!(!is_svg::is_svg_string(include_bytes!(
+        "data/encoding/bom.svgz"
+    )));
+}
+
 #[test]
 fn is_svg_string_from_invalid_svg() {
     assert!(!is_svg::is_svg_string(include_str!(
@@ -213,3 +221,97 @@ fn is_svg_string_from_compressed_svg() {
         "data/w3/svg-logo-v.svg.zst"
     )));
 }
+
+#[test]
+fn is_svg_string_from_other_encodings() {
+    for data in [
+        &include_bytes!("data/encoding/utf-16le.svg")[..],
+        include_bytes!("data/encoding/utf-16be.svg"),
+    ] {
+        assert!(is_svg::is_svg(data));
+        assert!(!is_svg::is_svg_string(data));
+        assert!(!is_svg::Validator::new().is_svg_string(data));
+    }
+
+    // The legacy encodings are only transcoded if the `encoding` feature is
+    // enabled, but are never a valid UTF-8.
+    for data in [
+        &include_bytes!("data/encoding/iso-8859-1.svg")[..],
+        include_bytes!("data/encoding/windows-1252.svg"),
+    ] {
+        assert_eq!(is_svg::is_svg(data), cfg!(feature = "encoding"));
+        assert!(!is_svg::is_svg_string(data));
+        assert!(!is_svg::Validator::lenient().is_svg_string(data));
+    }
+}
+
+#[test]
+fn is_svg_string_from_invalid_utf8() {
+    let svg = |text: &[u8]| {
+        [
+            &br#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16"><title>"#[..],
+            text,
+            b"</title></svg>",
+        ]
+        .concat()
+    };
+    for text in [
+        // ISO-8859-1 without the XML declaration.
+        &b"Caf\xe9"[..],
+        // A lone surrogate (U+D800) encoded as WTF-8.
+        b"\xed\xa0\x80",
+        // A surrogate pair (U+1F600) encoded as WTF-8 (CESU-8).
+        b"\xed\xa0\xbd\xed\xb8\x80",
+        // An overlong encoding of `<`.
+        b"\xc0\xbc",
+        // A truncated sequence.
+        b"\xe2\x82",
+    ] {
+        let data = svg(text);
+        assert!(!is_svg::is_svg(&data), "{data:?}");
+        assert!(!is_svg::is_svg_string(&data), "{data:?}");
+        assert!(
+            !is_svg::Validator::lenient().is_svg_string(&data),
+            "{data:?}"
+        );
+    }
+}
+
+#[test]
+fn is_svg_string_from_unusual_utf8() {
+    for text in [
+        // Combining characters and a zero width joiner sequence.
+        "e\u{301}\u{308} \u{1f469}\u{200d}\u{1f52c}",
+        // Right-to-left text with the bidirectional controls.
+        "\u{202b}\u{5e9}\u{5dc}\u{5d5}\u{5dd}\u{202c}",
+        // The line and paragraph separators.
+        "\u{2028}\u{2029}",
+        // A zero width no-break space which is not at the start.
+        "\u{feff}",
+        // The noncharacters which are still allowed in XML.
+        "\u{fdd0}\u{1fffe}",
+        // The last code point.
+        "\u{10ffff}",
+    ] {
+        let data = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16"><title>{text}</title><!--{text}--></svg>"#
+        );
+        assert!(is_svg::is_svg_string(&data), "{data:?}");
+        assert!(is_svg::Validator::new().is_svg_string(&data), "{data:?}");
+    }
+    let data = "\u{feff}<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"16\" height=\"16\" id=\"\u{8a66}\u{9a13}\"/>";
+    assert!(is_svg::is_svg_string(data));
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn is_svg_string_from_zlib() {
+    use is_svg::{CompressionKind, ValidatorBuilder};
+
+    let data = include_bytes!("data/deflate/svg-logo-v.svg.zz");
+    let validator = ValidatorBuilder::new()
+        .accepted_compressions(&[CompressionKind::None, CompressionKind::Zlib])
+        .build();
+    assert!(validator.is_svg(data));
+    assert!(!validator.is_svg_string(data));
+}
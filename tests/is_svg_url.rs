@@ -0,0 +1,206 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+use std::{
+    fs,
+    io::{BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    path::Path,
+    thread,
+    time::Duration,
+};
+
+use is_svg::{Error, HttpOptions, Limit};
+
+/// Handles a request.
+///
+/// - `/plain/<path>`: Responds with the fixture at `<path>` as is.
+/// - `/gzip/<path>`: Responds with the fixture at `<path>` compressed with
+///   `Content-Encoding: gzip`.
+/// - `/slow/<path>`: Responds with the fixture at `<path>` after a delay.
+fn handle(mut stream: TcpStream) {
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).unwrap();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).unwrap() <= 2 {
+            break;
+        }
+    }
+    let target = request_line.split(' ').nth(1).unwrap_or_default();
+    let (mode, path) = target
+        .trim_start_matches('/')
+        .split_once('/')
+        .unwrap_or_default();
+    let Ok(body) = fs::read(Path::new("tests/data").join(path)) else {
+        stream
+            .write_all(b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+            .unwrap();
+        return;
+    };
+    let (body, encoding) = match mode {
+        "gzip" => (common::compress(&body), "Content-Encoding: gzip\r\n"),
+        "slow" => {
+            thread::sleep(Duration::from_secs(2));
+            (body, "")
+        }
+        _ => (body, ""),
+    };
+    let header = format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: image/svg+xml\r\n{encoding}Content-Length: {}\r\nConnection: close\r\n\r\n",
+        body.len()
+    );
+    let _ = stream.write_all(header.as_bytes());
+    let _ = stream.write_all(&body);
+}
+
+fn serve() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let stream = stream.unwrap();
+            thread::spawn(move || handle(stream));
+        }
+    });
+    format!("http://{addr}")
+}
+
+#[tokio::test]
+async fn is_svg_url_from_svg() {
+    let base = serve();
+    assert!(
+        is_svg::is_svg_url(&format!("{base}/plain/w3/svg-logo-v.svg"))
+            .await
+            .unwrap()
+    );
+    assert!(
+        is_svg::is_svg_url(&format!("{base}/plain/ferris/corro.svg"))
+            .await
+            .unwrap()
+    );
+}
+
+#[tokio::test]
+async fn is_svg_url_from_svgz() {
+    let base = serve();
+    assert!(
+        is_svg::is_svg_url(&format!("{base}/plain/w3/svg-logo-v.svgz"))
+            .await
+            .unwrap()
+    );
+}
+
+#[tokio::test]
+async fn is_svg_url_with_content_encoding() {
+    let base = serve();
+    assert!(
+        is_svg::is_svg_url(&format!("{base}/gzip/w3/svg-logo-v.svg"))
+            .await
+            .unwrap()
+    );
+    assert!(
+        is_svg::is_svg_url(&format!("{base}/gzip/w3/svg-logo-v.svgz"))
+            .await
+            .unwrap()
+    );
+    assert!(
+        !is_svg::is_svg_url(&format!("{base}/gzip/w3/svg-logo-v.png"))
+            .await
+            .unwrap()
+    );
+}
+
+#[tokio::test]
+async fn is_svg_url_from_non_svg() {
+    let base = serve();
+    assert!(
+        !is_svg::is_svg_url(&format!("{base}/plain/w3/svg-logo-v.png"))
+            .await
+            .unwrap()
+    );
+    assert!(
+        !is_svg::is_svg_url(&format!("{base}/plain/resources/unclosed.svg"))
+            .await
+            .unwrap()
+    );
+}
+
+#[tokio::test]
+async fn is_svg_url_from_error_status() {
+    let base = serve();
+    assert!(matches!(
+        is_svg::is_svg_url(&format!("{base}/plain/non-existent.svg")).await,
+        Err(Error::Http(err)) if err.status() == Some(reqwest::StatusCode::NOT_FOUND)
+    ));
+}
+
+#[tokio::test]
+async fn check_svg_url_with_max_size() {
+    let base = serve();
+    let url = format!("{base}/plain/w3/svg-logo-v.svg");
+    assert!(
+        is_svg::check_svg_url(&url, &HttpOptions::new().max_size(4273))
+            .await
+            .is_ok()
+    );
+    assert!(matches!(
+        is_svg::check_svg_url(&url, &HttpOptions::new().max_size(4272)).await,
+        Err(Error::LimitExceeded(Limit::InputSize(4272)))
+    ));
+    assert!(matches!(
+        is_svg::check_svg_url(
+            &format!("{base}/gzip/w3/svg-logo-v.svg"),
+            &HttpOptions::new().max_size(4272)
+        )
+        .await,
+        Err(Error::LimitExceeded(Limit::InputSize(4272)))
+    ));
+}
+
+#[tokio::test]
+async fn check_svg_url_with_timeout() {
+    let base = serve();
+    assert!(matches!(
+        is_svg::check_svg_url(
+            &format!("{base}/slow/w3/svg-logo-v.svg"),
+            &HttpOptions::new().timeout(Duration::from_millis(100))
+        )
+        .await,
+        Err(Error::Http(err)) if err.is_timeout()
+    ));
+}
+
+#[cfg(feature = "http-blocking")]
+#[test]
+fn is_svg_url_blocking() {
+    let base = serve();
+    assert!(is_svg::is_svg_url_blocking(&format!("{base}/plain/w3/svg-logo-v.svg")).unwrap());
+    assert!(is_svg::is_svg_url_blocking(&format!("{base}/plain/w3/svg-logo-v.svgz")).unwrap());
+    assert!(is_svg::is_svg_url_blocking(&format!("{base}/gzip/w3/svg-logo-v.svg")).unwrap());
+    assert!(!is_svg::is_svg_url_blocking(&format!("{base}/plain/w3/svg-logo-v.png")).unwrap());
+    assert!(is_svg::is_svg_url_blocking(&format!("{base}/plain/non-existent.svg")).is_err());
+}
+
+#[cfg(feature = "http-blocking")]
+#[test]
+fn check_svg_url_blocking() {
+    let base = serve();
+    let url = format!("{base}/plain/w3/svg-logo-v.svg");
+    assert!(is_svg::check_svg_url_blocking(&url, &HttpOptions::new()).is_ok());
+    assert!(matches!(
+        is_svg::check_svg_url_blocking(&url, &HttpOptions::new().max_size(4272)),
+        Err(Error::LimitExceeded(Limit::InputSize(4272)))
+    ));
+    assert!(matches!(
+        is_svg::check_svg_url_blocking(
+            &format!("{base}/slow/w3/svg-logo-v.svg"),
+            &HttpOptions::new().timeout(Duration::from_millis(100))
+        ),
+        Err(Error::Http(err)) if err.is_timeout()
+    ));
+}
@@ -0,0 +1,128 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[test]
+fn is_svg_whatwg_from_svg() {
+    assert!(is_svg::is_svg_whatwg(include_str!(
+        "data/usvg/path-simple-case.svg"
+    )));
+    assert!(is_svg::is_svg_whatwg(include_str!(
+        "data/w3/svg-logo-v.svg"
+    )));
+    assert!(is_svg::is_svg_whatwg(include_str!(
+        "data/wikipedia/SVG_animation_using_CSS.svg"
+    )));
+}
+
+#[test]
+fn is_svg_whatwg_with_prolog() {
+    assert!(is_svg::is_svg_whatwg(
+        "\x0c\t<?xml version=\"1.0\"?><!-- comment --><!DOCTYPE svg><svg>"
+    ));
+    assert!(!is_svg::is_svg_whatwg("<!-- <svg>"));
+    assert!(!is_svg::is_svg_whatwg("<?xml <svg>"));
+    assert!(!is_svg::is_svg_whatwg("<html><svg>"));
+}
+
+#[test]
+fn is_svg_whatwg_requires_tag_terminating_byte() {
+    assert!(is_svg::is_svg_whatwg("<svg>"));
+    assert!(is_svg::is_svg_whatwg(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\"/>"
+    ));
+    assert!(!is_svg::is_svg_whatwg("<svg"));
+    assert!(!is_svg::is_svg_whatwg("<svg/>"));
+    assert!(!is_svg::is_svg_whatwg("<svg\n>"));
+    assert!(!is_svg::is_svg_whatwg("<svgx>"));
+}
+
+#[test]
+fn is_svg_whatwg_from_non_svg() {
+    assert!(!is_svg::is_svg_whatwg(include_bytes!(
+        "data/w3/svg-logo-v.png"
+    )));
+    assert!(!is_svg::is_svg_whatwg(include_bytes!(
+        "data/w3/svg-logo-v.webp"
+    )));
+    assert!(!is_svg::is_svg_whatwg(include_str!(
+        "data/resources/index.html"
+    )));
+    assert!(!is_svg::is_svg_whatwg(include_str!(
+        "data/resources/quadratic_formula.mml"
+    )));
+    assert!(!is_svg::is_svg_whatwg([]));
+}
+
+#[test]
+fn is_svg_whatwg_agrees_on_xml_declaration() {
+    let data = include_str!("data/resources/xml_declaration.svg");
+    assert!(!is_svg::is_svg_whatwg(data));
+    assert!(!is_svg::is_svg(data));
+}
+
+#[test]
+fn is_svg_whatwg_agrees_on_xml_declaration_with_root() {
+    for data in [
+        include_str!("data/xml-declaration/double-quotes.svg"),
+        include_str!("data/xml-declaration/single-quotes.svg"),
+        include_str!("data/xml-declaration/standalone.svg"),
+        include_str!("data/xml-declaration/whitespace.svg"),
+    ] {
+        assert!(is_svg::is_svg_whatwg(data));
+        assert!(is_svg::is_svg(data));
+    }
+}
+
+#[test]
+fn is_svg_whatwg_disagrees_on_byte_order_mark() {
+    let data = include_str!("data/xml-declaration/bom.svg");
+    assert!(!is_svg::is_svg_whatwg(data));
+    assert!(is_svg::is_svg(data));
+}
+
+#[test]
+fn is_svg_whatwg_disagrees_on_malformed_svg() {
+    let data = include_str!("data/resources/unclosed.svg");
+    assert!(is_svg::is_svg_whatwg(data));
+    assert!(!is_svg::is_svg(data));
+}
+
+#[test]
+fn is_svg_whatwg_disagrees_on_upper_case_tag() {
+    let data = "<SVG xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 1 1\"></SVG>";
+    assert!(is_svg::is_svg_whatwg(data));
+    assert!(!is_svg::is_svg(data));
+}
+
+#[test]
+fn is_svg_whatwg_disagrees_on_namespace_prefix() {
+    let data = "<svg:svg xmlns:svg=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 1 1\"></svg:svg>";
+    assert!(!is_svg::is_svg_whatwg(data));
+    assert!(is_svg::is_svg(data));
+}
+
+#[test]
+fn is_svg_whatwg_disagrees_on_long_prolog() {
+    let data = format!(
+        "<!--{}-->\n<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 1 1\"></svg>",
+        " ".repeat(1445)
+    );
+    assert!(!is_svg::is_svg_whatwg(&data));
+    assert!(is_svg::is_svg(&data));
+}
+
+#[test]
+fn is_svg_whatwg_disagrees_on_newline_after_tag() {
+    let data = include_str!("data/ferris/corro.svg");
+    assert!(!is_svg::is_svg_whatwg(data));
+    assert!(is_svg::is_svg(data));
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn is_svg_whatwg_disagrees_on_svgz() {
+    let data = include_bytes!("data/w3/svg-logo-v.svgz");
+    assert!(!is_svg::is_svg_whatwg(data));
+    assert!(is_svg::is_svg(data));
+}
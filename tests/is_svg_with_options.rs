@@ -0,0 +1,109 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use is_svg::Options;
+use usvg::ImageHrefResolver;
+
+const IMAGE_SVG: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" width="8" height="8"><image href="image.png" width="8" height="8"/></svg>"#;
+
+#[test]
+fn is_svg_with_options_from_svg() {
+    let opt = Options::default();
+    assert!(is_svg::is_svg_with_options(
+        include_str!("data/ferris/corro.svg"),
+        &opt
+    ));
+    assert!(is_svg::is_svg_with_options(
+        include_str!("data/usvg/preserve-id-fe-image.svg"),
+        &opt
+    ));
+    assert!(is_svg::is_svg_with_options(
+        include_str!("data/w3/svg-logo-v.svg"),
+        &opt
+    ));
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn is_svg_with_options_from_svgz() {
+    let opt = Options::default();
+    assert!(is_svg::is_svg_with_options(
+        include_bytes!("data/ferris/corro.svgz"),
+        &opt
+    ));
+    assert!(is_svg::is_svg_with_options(
+        include_bytes!("data/w3/svg-logo-v.svgz"),
+        &opt
+    ));
+}
+
+#[test]
+fn is_svg_with_options_from_non_svg() {
+    let opt = Options::default();
+    assert!(!is_svg::is_svg_with_options(
+        include_str!("data/resources/unclosed.svg"),
+        &opt
+    ));
+    assert!(!is_svg::is_svg_with_options(
+        include_bytes!("data/w3/svg-logo-v.png"),
+        &opt
+    ));
+}
+
+#[test]
+fn is_svg_with_options_with_resources_dir() {
+    let opt = Options {
+        resources_dir: Some("tests/data/w3".into()),
+        ..Default::default()
+    };
+    assert!(is_svg::is_svg_with_options(IMAGE_SVG, &opt));
+    #[cfg(feature = "gzip")]
+    assert!(is_svg::is_svg_with_options(
+        common::compress(IMAGE_SVG.as_bytes()),
+        &opt
+    ));
+}
+
+#[test]
+fn is_svg_with_options_with_image_href_resolver() {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    let opt = Options {
+        image_href_resolver: ImageHrefResolver {
+            resolve_string: Box::new(|href, _| {
+                assert_eq!(href, "image.png");
+                COUNT.fetch_add(1, Ordering::SeqCst);
+                None
+            }),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    assert!(is_svg::is_svg_with_options(IMAGE_SVG, &opt));
+    assert_eq!(COUNT.load(Ordering::SeqCst), 1);
+    #[cfg(feature = "gzip")]
+    assert!(is_svg::is_svg_with_options(
+        common::compress(IMAGE_SVG.as_bytes()),
+        &opt
+    ));
+    #[cfg(feature = "gzip")]
+    assert_eq!(COUNT.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn check_svg_with_options_from_non_svg() {
+    let opt = Options::default();
+    assert!(matches!(
+        is_svg::check_svg_with_options(include_str!("data/resources/unclosed.svg"), &opt),
+        Err(is_svg::Error::NotXml { .. })
+    ));
+    assert!(matches!(
+        is_svg::check_svg_with_options(include_bytes!("data/w3/svg-logo-v.png"), &opt),
+        Err(is_svg::Error::NotUtf8(_))
+    ));
+}
@@ -120,6 +120,7 @@ fn is_svgz_from_svg() {
     )));
 }
 
+#[cfg(feature = "gzip")]
 #[test]
 fn is_svgz_from_svgz() {
     assert!(is_svg::is_svgz(include_bytes!("data/ferris/corro.svgz")));
@@ -150,6 +151,20 @@ fn is_svgz_from_svgz() {
     )));
 }
 
+#[cfg(feature = "gzip")]
+#[test]
+fn is_svgz_from_bom() {
+    assert!(is_svg::is_svgz(include_bytes!("data/encoding/bom.svgz")));
+    assert!(!is_svg::is_svgz(include_str!("data/encoding/bom.svg")));
+}
+
+#[cfg(not(feature = "gzip"))]
+#[test]
+fn is_svgz_from_svgz_without_gzip_feature() {
+    assert!(!is_svg::is_svgz(include_bytes!("data/ferris/corro.svgz")));
+    assert!(!is_svg::is_svgz(include_bytes!("data/w3/svg-logo-v.svgz")));
+}
+
 #[test]
 fn is_svgz_from_invalid_svg() {
     assert!(!is_svg::is_svgz(include_str!("data/resources/dtd.svg")));
@@ -0,0 +1,172 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+use std::{fmt::Write, fs, path::Path};
+
+use is_svg::Error;
+
+const SVG_OPEN: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" width="10" height="10">"#;
+
+#[test]
+fn differential() {
+    let paths = common::fixtures(common::DATA_DIR);
+    assert!(!paths.is_empty());
+
+    let mut disagreements = Vec::new();
+    for path in paths {
+        let data = fs::read(&path).unwrap();
+        let (full, light) = (is_svg::is_svg(&data), is_svg::is_svg_light(&data));
+        if full {
+            assert!(light, "{}", path.display());
+        }
+        if full != light {
+            disagreements.push(path);
+        }
+    }
+    // The sizes of these are too large.
+    assert_eq!(
+        disagreements,
+        [
+            "tests/data/hostile/absurd-numbers.svg",
+            "tests/data/numbers/f32-overflow-width.svg",
+            "tests/data/numbers/huge-exponent-view-box.svg",
+            "tests/data/numbers/huge-exponent-width.svg",
+            "tests/data/numbers/many-digits-height.svg",
+        ]
+        .map(Path::new)
+    );
+}
+
+#[test]
+fn is_svg_light_from_svg() {
+    assert!(is_svg::is_svg_light(include_str!("data/ferris/corro.svg")));
+    assert!(is_svg::is_svg_light(include_str!("data/w3/svg-logo-v.svg")));
+    assert!(is_svg::is_svg_light(include_str!(
+        "data/wikipedia/SVG_animation_using_SMIL.svg"
+    )));
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn is_svg_light_from_svgz() {
+    assert!(is_svg::is_svg_light(include_bytes!(
+        "data/ferris/corro.svgz"
+    )));
+    assert!(is_svg::is_svg_light(include_bytes!(
+        "data/w3/svg-logo-v.svgz"
+    )));
+}
+
+#[test]
+fn is_svg_light_from_non_svg() {
+    assert!(!is_svg::is_svg_light(include_bytes!(
+        "data/w3/svg-logo-v.png"
+    )));
+    assert!(!is_svg::is_svg_light(include_str!(
+        "data/resources/unclosed.svg"
+    )));
+    assert!(!is_svg::is_svg_light(include_str!(
+        "data/resources/dtd.svg"
+    )));
+    assert!(!is_svg::is_svg_light([]));
+}
+
+#[test]
+fn check_svg_light_from_non_svg_root() {
+    assert!(matches!(
+        is_svg::check_svg_light(r#"<math xmlns="http://www.w3.org/1998/Math/MathML"/>"#),
+        Err(Error::NotSvgRoot { .. })
+    ));
+    assert!(matches!(
+        is_svg::check_svg_light(r#"<svg width="10" height="10"/>"#),
+        Err(Error::NotSvgRoot { .. })
+    ));
+    assert!(matches!(
+        is_svg::check_svg_light(r#"<svg xmlns="http://example.com/" width="10" height="10"/>"#),
+        Err(Error::NotSvgRoot { .. })
+    ));
+}
+
+#[test]
+fn check_svg_light_from_non_xml() {
+    assert!(matches!(
+        is_svg::check_svg_light(include_bytes!("data/w3/svg-logo-v.png")),
+        Err(Error::NotUtf8(_))
+    ));
+    assert!(matches!(
+        is_svg::check_svg_light(include_str!("data/resources/unclosed.svg")),
+        Err(Error::NotXml { .. })
+    ));
+}
+
+#[test]
+fn agree_on_entity_expansion() {
+    let data = include_bytes!("data/hostile/billion-laughs.svg");
+    assert!(matches!(
+        is_svg::check_svg_light(data),
+        Err(Error::LimitExceeded(is_svg::Limit::EntityExpansion(_)))
+    ));
+}
+
+#[test]
+fn agree_on_invalid_attribute() {
+    let svg = format!(r#"{SVG_OPEN}<path d="M foo"/><rect fill="none-color" width="-1"/></svg>"#);
+    assert!(is_svg::is_svg(&svg));
+    assert!(is_svg::is_svg_light(&svg));
+}
+
+#[test]
+fn agree_on_unresolved_reference() {
+    let svg = format!(r##"{SVG_OPEN}<use href="#missing"/><rect fill="url(#missing)"/></svg>"##);
+    assert!(is_svg::is_svg(&svg));
+    assert!(is_svg::is_svg_light(&svg));
+}
+
+#[test]
+fn agree_on_missing_size() {
+    let svg = r#"<svg xmlns="http://www.w3.org/2000/svg"/>"#;
+    assert!(is_svg::is_svg(svg));
+    assert!(is_svg::is_svg_light(svg));
+}
+
+#[test]
+fn disagree_on_invalid_size() {
+    let svg = r#"<svg xmlns="http://www.w3.org/2000/svg" width="0" height="10"/>"#;
+    assert!(matches!(
+        is_svg::check_svg(svg),
+        Err(Error::Parse(is_svg::usvg::Error::InvalidSize))
+    ));
+    assert!(is_svg::check_svg_light(svg).is_ok());
+}
+
+#[test]
+fn disagree_on_deeply_nested_elements() {
+    let svg = format!(
+        "{SVG_OPEN}{}{}</svg>",
+        "<g>".repeat(1100),
+        "</g>".repeat(1100)
+    );
+    assert!(!is_svg::is_svg(&svg));
+    assert!(is_svg::is_svg_light(&svg));
+}
+
+#[test]
+fn disagree_on_use_expansion() {
+    let mut defs = String::new();
+    for i in 1..30 {
+        write!(
+            defs,
+            r##"<g id="g{i}"><use href="#g{0}"/><use href="#g{0}"/></g>"##,
+            i - 1
+        )
+        .unwrap();
+    }
+    let svg = format!(
+        r##"{SVG_OPEN}<defs><g id="g0"><rect width="1" height="1"/></g>{defs}</defs><use href="#g29"/></svg>"##
+    );
+    assert!(!is_svg::is_svg(&svg));
+    assert!(is_svg::is_svg_light(&svg));
+}
@@ -0,0 +1,195 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+use is_svg::{
+    usvg::roxmltree::{self, TextPos},
+    Error, Location, Validator, ValidatorBuilder,
+};
+
+const UNQUOTED_ATTRIBUTE: &str = include_str!("data/location/unquoted-attribute.svg");
+const UNCLOSED: &str = include_str!("data/resources/unclosed.svg");
+
+/// The location of the unquoted attribute value in [`UNQUOTED_ATTRIBUTE`].
+///
+/// The column is counted in characters, so it differs from the one in bytes
+/// since the line has the non-ASCII characters.
+const UNQUOTED_ATTRIBUTE_LOCATION: Location = Location {
+    position: TextPos { row: 6, col: 57 },
+    offset: 286,
+    decompressed: false,
+};
+
+fn validators() -> [Validator; 4] {
+    [
+        Validator::lenient(),
+        Validator::new(),
+        Validator::strict(),
+        Validator::builder().fast(true).build(),
+    ]
+}
+
+#[test]
+fn location_of_unquoted_attribute() {
+    assert!(UNQUOTED_ATTRIBUTE[UNQUOTED_ATTRIBUTE_LOCATION.offset..].starts_with("16 "));
+
+    let err = is_svg::check_svg(UNQUOTED_ATTRIBUTE).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::NotXml {
+            source: roxmltree::Error::InvalidChar2(..),
+            ..
+        }
+    ));
+    assert_eq!(err.location(), Some(UNQUOTED_ATTRIBUTE_LOCATION));
+    assert_eq!(err.position(), Some(TextPos::new(6, 57)));
+    assert_eq!(err.byte_offset(), Some(286));
+    assert_eq!(err.to_string(), "data was not a valid XML document at 6:57");
+
+    for validator in validators() {
+        let err = validator.check_svg(UNQUOTED_ATTRIBUTE).unwrap_err();
+        assert_eq!(err.location(), Some(UNQUOTED_ATTRIBUTE_LOCATION));
+    }
+    let err = is_svg::check_svg_reader(UNQUOTED_ATTRIBUTE.as_bytes()).unwrap_err();
+    assert_eq!(err.location(), Some(UNQUOTED_ATTRIBUTE_LOCATION));
+    for chunk_size in [1, 64, UNQUOTED_ATTRIBUTE.len()] {
+        let err = common::check_chunked(UNQUOTED_ATTRIBUTE.as_bytes(), chunk_size).unwrap_err();
+        assert_eq!(err.location(), Some(UNQUOTED_ATTRIBUTE_LOCATION));
+    }
+}
+
+#[test]
+fn location_of_unclosed_root() {
+    // The root element which is not closed is located at the end.
+    let err = is_svg::check_svg(UNCLOSED).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::NotXml {
+            source: roxmltree::Error::UnclosedRootNode,
+            ..
+        }
+    ));
+    assert_eq!(err.position(), Some(TextPos::new(2, 1)));
+    assert_eq!(err.byte_offset(), Some(UNCLOSED.len()));
+    assert_eq!(err.to_string(), "data was not a valid XML document at 2:1");
+
+    let text = UNCLOSED.trim_end();
+    let err = is_svg::check_svg(text).unwrap_err();
+    assert_eq!(err.position(), Some(TextPos::new(1, 75)));
+    assert_eq!(err.byte_offset(), Some(74));
+}
+
+#[test]
+fn location_with_added_namespace() {
+    // The location refers to the data, not to the document which has the added
+    // namespace declaration.
+    let text = r#"<svg width="24" height="24"><rect width=16 height="16"/></svg>"#;
+    let err = Validator::lenient().check_svg(text).unwrap_err();
+    let offset = text.find("16 ").unwrap();
+    assert_eq!(err.byte_offset(), Some(offset));
+    assert_eq!(
+        err.position(),
+        Some(TextPos::new(1, u32::try_from(offset).unwrap() + 1))
+    );
+    let err = Validator::new().check_svg(text).unwrap_err();
+    assert_eq!(err.byte_offset(), Some(offset));
+
+    let text = "<svg>\n<g></svg>";
+    let err = Validator::lenient().check_svg(text).unwrap_err();
+    assert_eq!(err.position(), Some(TextPos::new(2, 4)));
+    assert_eq!(err.byte_offset(), text.find("</svg>"));
+}
+
+#[test]
+fn location_of_policy_errors() {
+    let text = include_str!("data/policy/script.svg");
+    let err = Validator::strict().check_svg(text).unwrap_err();
+    let location = err.location().unwrap();
+    assert!(matches!(err, Error::DisallowedElement { .. }));
+    assert_eq!(location.position, TextPos::new(4, 5));
+    assert!(text[location.offset..].starts_with("<script"));
+
+    let text = r#"<svg xmlns="http://www.w3.org/2000/svg"><image href="../image.png"/></svg>"#;
+    let err = ValidatorBuilder::new()
+        .allow_external_references(false)
+        .build()
+        .check_svg(text)
+        .unwrap_err();
+    assert!(matches!(err, Error::ExternalReference { .. }));
+    assert_eq!(err.position(), Some(TextPos::new(1, 41)));
+    assert_eq!(err.byte_offset(), Some(text.find("<image").unwrap()));
+}
+
+#[test]
+fn no_location() {
+    for err in [
+        is_svg::check_svg(include_bytes!("data/w3/svg-logo-v.png")).unwrap_err(),
+        is_svg::check_svg(include_str!("data/resources/dtd.svg")).unwrap_err(),
+        is_svg::check_svg(include_str!("data/resources/xml_declaration.svg")).unwrap_err(),
+        is_svg::check_svg("").unwrap_err(),
+        is_svg::check_svg(r#"<svg xmlns="http://www.w3.org/2000/svg" width="0"/>"#).unwrap_err(),
+        ValidatorBuilder::new()
+            .allow_dtd(false)
+            .build()
+            .check_svg(include_str!("data/dtd/illustrator.svg"))
+            .unwrap_err(),
+    ] {
+        assert_eq!(err.location(), None, "{err:?}");
+        assert_eq!(err.position(), None, "{err:?}");
+        assert_eq!(err.byte_offset(), None, "{err:?}");
+    }
+}
+
+#[test]
+fn location_in_utf16() {
+    // The location refers to the document which is decoded to UTF-8.
+    let data = "\u{feff}"
+        .chars()
+        .chain(UNQUOTED_ATTRIBUTE.chars())
+        .collect::<String>()
+        .encode_utf16()
+        .flat_map(u16::to_le_bytes)
+        .collect::<Vec<_>>();
+    let err = is_svg::check_svg(data).unwrap_err();
+    assert_eq!(err.location(), Some(UNQUOTED_ATTRIBUTE_LOCATION));
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn location_in_svgz() {
+    let data = include_bytes!("data/location/unquoted-attribute.svgz");
+    let location = Location {
+        decompressed: true,
+        ..UNQUOTED_ATTRIBUTE_LOCATION
+    };
+    let err = is_svg::check_svg(data).unwrap_err();
+    assert_eq!(err.location(), Some(location));
+    assert_eq!(
+        err.to_string(),
+        "data was not a valid XML document at 6:57 of the decompressed data"
+    );
+    for validator in validators() {
+        assert_eq!(
+            validator.check_svg(data).unwrap_err().location(),
+            Some(location)
+        );
+    }
+    for chunk_size in [1, 64, data.len()] {
+        let err = common::check_chunked(data, chunk_size).unwrap_err();
+        assert_eq!(err.location(), Some(location));
+    }
+    assert_eq!(
+        is_svg::decompress_svgz(data).unwrap_err().location(),
+        Some(location)
+    );
+
+    // The location of the error before decompressing does not exist.
+    assert_eq!(
+        is_svg::check_svg(&data[..data.len() / 2])
+            .unwrap_err()
+            .location(),
+        None
+    );
+}
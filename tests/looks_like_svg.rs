@@ -0,0 +1,200 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+use std::fs;
+
+#[test]
+fn looks_like_svg_from_svg() {
+    let paths = common::images("svg");
+    assert!(!paths.is_empty());
+    for path in paths {
+        assert!(
+            is_svg::looks_like_svg(fs::read(&path).unwrap()),
+            "{}",
+            path.display()
+        );
+    }
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn looks_like_svg_from_svgz() {
+    let paths = common::images("svgz");
+    assert!(!paths.is_empty());
+    for path in paths {
+        assert!(
+            is_svg::looks_like_svg(fs::read(&path).unwrap()),
+            "{}",
+            path.display()
+        );
+    }
+}
+
+#[test]
+fn looks_like_svg_from_non_svg() {
+    assert!(!is_svg::looks_like_svg(include_bytes!(
+        "data/w3/svg-logo-v.png"
+    )));
+    assert!(!is_svg::looks_like_svg(include_bytes!(
+        "data/w3/svg-logo-v.jxl"
+    )));
+    assert!(!is_svg::looks_like_svg(include_bytes!(
+        "data/w3/svg-logo-v.webp"
+    )));
+    assert!(!is_svg::looks_like_svg(include_bytes!(
+        "data/w3/svg-logo-v.svg.lz"
+    )));
+    assert!(!is_svg::looks_like_svg(include_bytes!(
+        "data/w3/svg-logo-v.svg.zst"
+    )));
+    assert!(!is_svg::looks_like_svg(include_str!(
+        "data/resources/index.html"
+    )));
+    assert!(!is_svg::looks_like_svg(include_str!(
+        "data/resources/quadratic_formula.mml"
+    )));
+    assert!(!is_svg::looks_like_svg(include_bytes!(
+        "data/resources/empty.gz"
+    )));
+}
+
+#[test]
+fn looks_like_svg_without_element() {
+    assert!(!is_svg::looks_like_svg(include_str!(
+        "data/resources/dtd.svg"
+    )));
+    assert!(!is_svg::looks_like_svg(include_str!(
+        "data/resources/xml_declaration.svg"
+    )));
+    assert!(!is_svg::looks_like_svg([]));
+    assert!(!is_svg::looks_like_svg(" \r\n"));
+}
+
+#[test]
+fn looks_like_svg_may_accept_invalid_svg() {
+    assert!(is_svg::looks_like_svg(include_str!(
+        "data/resources/unclosed.svg"
+    )));
+    assert!(!is_svg::is_svg(include_str!("data/resources/unclosed.svg")));
+}
+
+#[test]
+fn looks_like_svg_with_prolog() {
+    assert!(is_svg::looks_like_svg("\u{feff}<svg/>"));
+    assert!(is_svg::looks_like_svg(
+        "<?xml version=\"1.0\"?>\n<?xml-stylesheet href=\"a.css\"?>\n<svg>"
+    ));
+    assert!(is_svg::looks_like_svg("<!-- <html> -->\n<!---->\n<svg\n>"));
+    assert!(is_svg::looks_like_svg(
+        "<!DOCTYPE svg PUBLIC \"-//W3C//DTD SVG 1.1//EN\" \"http://www.w3.org/Graphics/SVG/1.1/DTD/svg11.dtd\">\n<svg>"
+    ));
+    assert!(is_svg::looks_like_svg(
+        "<!DOCTYPE svg [\n<!ENTITY a \"<html>\">\n<!ELEMENT svg ANY>\n]>\n<svg>"
+    ));
+}
+
+#[test]
+fn looks_like_svg_from_prolog_fixtures() {
+    for data in [
+        &include_bytes!("data/prolog/comment-banner.svg")[..],
+        include_bytes!("data/prolog/comments-and-whitespace.svg"),
+        include_bytes!("data/prolog/declaration-then-comment.svg"),
+    ] {
+        assert!(is_svg::looks_like_svg(data));
+    }
+    #[cfg(feature = "gzip")]
+    assert!(is_svg::looks_like_svg(include_bytes!(
+        "data/prolog/comment-banner.svgz"
+    )));
+
+    // The whitespace is counted in the window.
+    let data = include_bytes!("data/prolog/leading-whitespace.svg");
+    assert!(!is_svg::looks_like_svg(data));
+    assert!(is_svg::looks_like_svg_within(data, data.len()));
+}
+
+#[test]
+fn looks_like_svg_with_namespace_prefix() {
+    assert!(is_svg::looks_like_svg(
+        "<svg:svg xmlns:svg=\"http://www.w3.org/2000/svg\"/>"
+    ));
+    assert!(!is_svg::looks_like_svg("<:svg/>"));
+    assert!(!is_svg::looks_like_svg("<svg:html/>"));
+}
+
+#[test]
+fn looks_like_svg_with_other_element() {
+    assert!(!is_svg::looks_like_svg("<svgx/>"));
+    assert!(!is_svg::looks_like_svg("<SVG/>"));
+    assert!(!is_svg::looks_like_svg("<html><svg/></html>"));
+    assert!(!is_svg::looks_like_svg("svg"));
+}
+
+#[test]
+fn looks_like_svg_from_truncated_data() {
+    let data = include_bytes!("data/w3/svg-logo-v.svg");
+    for len in 0..=data.len() {
+        let _ = is_svg::looks_like_svg(&data[..len]);
+    }
+    let data = include_bytes!("data/w3/svg-logo-v.svgz");
+    for len in 0..=data.len() {
+        let _ = is_svg::looks_like_svg(&data[..len]);
+    }
+    assert!(!is_svg::looks_like_svg("<!-- <svg>"));
+    assert!(!is_svg::looks_like_svg("<!DOCTYPE svg [ <svg>"));
+    assert!(!is_svg::looks_like_svg("<?xml version=\"1.0\""));
+    assert!(!is_svg::looks_like_svg("<sv"));
+    assert!(is_svg::looks_like_svg("<svg"));
+}
+
+#[test]
+fn looks_like_svg_within_window() {
+    let svg = "<svg xmlns=\"http://www.w3.org/2000/svg\"/>";
+    for prefix in [" ".repeat(100), format!("<!--{}-->", "-".repeat(100))] {
+        let data = format!("{prefix}{svg}");
+        let end = prefix.len() + 4;
+        assert!(is_svg::looks_like_svg_within(&data, end));
+        assert!(is_svg::looks_like_svg_within(&data, usize::MAX));
+        assert!(!is_svg::looks_like_svg_within(&data, end - 1));
+        assert!(!is_svg::looks_like_svg_within(&data, 0));
+    }
+    // The name which continues after the window is not `svg`.
+    assert!(!is_svg::looks_like_svg_within("<svgx/>", 4));
+    assert!(is_svg::looks_like_svg_within("<svg", 4));
+}
+
+#[test]
+fn looks_like_svg_within_default_window() {
+    let svg = "<svg xmlns=\"http://www.w3.org/2000/svg\"/>";
+    let data = format!("{}{svg}", " ".repeat(4 * 1024 - 4));
+    assert!(is_svg::looks_like_svg(&data));
+    let data = format!("{}{svg}", " ".repeat(4 * 1024 - 3));
+    assert!(!is_svg::looks_like_svg(&data));
+    assert!(is_svg::is_svg(&data));
+
+    let data = format!("<!--{}-->{svg}", " ".repeat(1024 * 1024));
+    assert!(!is_svg::looks_like_svg(&data));
+    assert!(is_svg::looks_like_svg_within(&data, data.len()));
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn looks_like_svg_within_window_from_svgz() {
+    use std::io::Write;
+
+    use flate2::{write::GzEncoder, Compression};
+
+    let data = format!(
+        "{}<svg xmlns=\"http://www.w3.org/2000/svg\"/>",
+        " ".repeat(8 * 1024)
+    );
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(data.as_bytes()).unwrap();
+    let svgz = encoder.finish().unwrap();
+    assert!(!is_svg::looks_like_svg(&svgz));
+    assert!(is_svg::looks_like_svg_within(&svgz, 8 * 1024 + 4));
+    assert!(is_svg::is_svg(&svgz));
+}
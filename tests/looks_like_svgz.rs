@@ -0,0 +1,96 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+#[test]
+fn looks_like_svgz_from_svgz() {
+    assert!(is_svg::looks_like_svgz(include_bytes!(
+        "data/ferris/corro.svgz"
+    )));
+    assert!(is_svg::looks_like_svgz(include_bytes!(
+        "data/w3/svg-logo-v.svgz"
+    )));
+    assert!(is_svg::looks_like_svgz(include_bytes!(
+        "data/wikipedia/SVG_animation_using_CSS.svgz"
+    )));
+}
+
+#[test]
+fn looks_like_svgz_with_bom_and_whitespace() {
+    assert!(is_svg::looks_like_svgz(common::compress(
+        b"\xef\xbb\xbf \r\n<svg/>"
+    )));
+    assert!(is_svg::looks_like_svgz(common::compress(
+        b"\n\t<?xml version=\"1.0\"?>"
+    )));
+}
+
+#[test]
+fn looks_like_svgz_with_comments() {
+    assert!(is_svg::looks_like_svgz(include_bytes!(
+        "data/prolog/comment-banner.svgz"
+    )));
+    assert!(is_svg::looks_like_svgz(common::compress(
+        b"<!-- a -->\n<!---->\n<svg/>"
+    )));
+    // The XML declaration after the comment is not allowed.
+    assert!(!is_svg::looks_like_svgz(common::compress(
+        b"<!-- a --><?xml version=\"1.0\"?><svg/>"
+    )));
+    assert!(!is_svg::looks_like_svgz(common::compress(
+        b"<!-- a --><html/>"
+    )));
+    assert!(!is_svg::looks_like_svgz(common::compress(b"<!-- a <svg/>")));
+    // The whitespace beyond the first 1 KiB is not examined.
+    assert!(!is_svg::looks_like_svgz(include_bytes!(
+        "data/prolog/leading-whitespace.svgz"
+    )));
+}
+
+#[test]
+fn looks_like_svgz_from_truncated_svgz() {
+    let data = include_bytes!("data/w3/svg-logo-v.svgz");
+    assert!(is_svg::looks_like_svgz(&data[..data.len() / 2]));
+    assert!(!is_svg::is_svgz(&data[..data.len() / 2]));
+}
+
+#[test]
+fn looks_like_svgz_disagrees_with_is_svgz() {
+    let data = common::compress(include_bytes!("data/resources/xml_declaration.svg"));
+    assert!(is_svg::looks_like_svgz(&data));
+    assert!(!is_svg::is_svgz(&data));
+}
+
+#[test]
+fn looks_like_svgz_from_non_svgz() {
+    assert!(!is_svg::looks_like_svgz(include_str!(
+        "data/w3/svg-logo-v.svg"
+    )));
+    assert!(!is_svg::looks_like_svgz(include_bytes!(
+        "data/w3/svg-logo-v.png"
+    )));
+    assert!(!is_svg::looks_like_svgz(common::compress(include_bytes!(
+        "data/w3/svg-logo-v.png"
+    ))));
+    assert!(!is_svg::looks_like_svgz(common::compress(b"<html></html>")));
+}
+
+#[test]
+fn looks_like_svgz_from_empty_gz() {
+    assert!(!is_svg::looks_like_svgz(include_bytes!(
+        "data/resources/empty.gz"
+    )));
+}
+
+#[test]
+fn looks_like_svgz_from_empty() {
+    assert!(!is_svg::looks_like_svgz([]));
+}
+
+#[test]
+fn looks_like_svgz_from_one_byte() {
+    assert!(!is_svg::looks_like_svgz([0x1f]));
+    assert!(!is_svg::looks_like_svgz(is_svg::GZIP_MAGIC_NUMBER));
+}
@@ -0,0 +1,153 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+use std::time::{Duration, Instant};
+
+use is_svg::{ErrorKind, SvgKind, Validator, ValidatorBuilder};
+
+/// The bound which only catches the pathological regressions, since the
+/// validation of the generated documents takes a few seconds even in the
+/// debug build.
+const TIME_LIMIT: Duration = Duration::from_secs(60);
+
+/// Returns the elements of the document which is larger than `size` bytes.
+fn elements(size: usize) -> Vec<String> {
+    let mut elements = vec![
+        r#"<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink" width="1024" height="1024" viewBox="0 0 1024 1024">"#.to_owned(),
+        "<style>.a{color:#2a7ae2;fill:currentColor}.b{stroke:#000;stroke-width:2}</style>".to_owned(),
+        "<defs>".to_owned(),
+        r##"<linearGradient id="g"><stop offset="0" stop-color="#fff"/><stop offset="1" stop-color="#000"/></linearGradient>"##.to_owned(),
+        r#"<path id="p" d="M0 0h4v4h-4z"/>"#.to_owned(),
+        "</defs>".to_owned(),
+    ];
+    let mut len = elements.iter().map(String::len).sum::<usize>();
+    for i in 0.. {
+        if len >= size {
+            break;
+        }
+        let (x, y) = (i % 1024, i / 1024 % 1024);
+        let element = match i % 4 {
+            0 => format!(r#"<rect class="a" x="{x}" y="{y}" width="1" height="1"/>"#),
+            1 => format!(r#"<path class="b" d="M{x} {y}l1 1-1 1z"/>"#),
+            2 => format!(r##"<use href="#p" x="{x}" y="{y}" fill="url(#g)"/>"##),
+            _ => format!(r#"<g transform="translate({x} {y})"><circle r="1"/></g>"#),
+        };
+        len += element.len();
+        elements.push(element);
+    }
+    elements.push("</svg>".to_owned());
+    elements
+}
+
+/// Returns the minified document and its pretty-printed equivalent.
+fn documents(size: usize) -> (String, String) {
+    let elements = elements(size);
+    (elements.concat(), elements.join("\n  "))
+}
+
+fn validators() -> [Validator; 6] {
+    [
+        Validator::lenient(),
+        Validator::new(),
+        Validator::strict(),
+        Validator::builder().fast(true).build(),
+        ValidatorBuilder::new().reject_empty(true).build(),
+        ValidatorBuilder::new()
+            .require_renderable_content(true)
+            .build(),
+    ]
+}
+
+#[test]
+fn documents_have_same_content() {
+    let (minified, pretty) = documents(64 * 1024);
+    assert!(!minified.contains('\n'));
+    assert_ne!(minified, pretty);
+    assert_eq!(pretty.replace("\n  ", ""), minified);
+}
+
+#[test]
+fn check_svg_with_minified() {
+    let (minified, pretty) = documents(256 * 1024);
+    let start = Instant::now();
+    for validator in validators() {
+        assert!(validator.check_svg(&minified).is_ok());
+        assert_eq!(
+            validator.check_svg(&minified).map_err(|err| err.kind()),
+            validator.check_svg(&pretty).map_err(|err| err.kind())
+        );
+    }
+    assert!(is_svg::check_svg_reader(minified.as_bytes()).is_ok());
+    for chunk_size in [4096, minified.len()] {
+        assert!(matches!(
+            common::check_chunked(minified.as_bytes(), chunk_size),
+            Ok(SvgKind::Svg)
+        ));
+    }
+    assert!(start.elapsed() < TIME_LIMIT);
+}
+
+#[test]
+fn parse_svg_with_minified() {
+    let (minified, pretty) = documents(128 * 1024);
+    let (minified, pretty) = (
+        is_svg::parse_svg(&minified).unwrap(),
+        is_svg::parse_svg(&pretty).unwrap(),
+    );
+    assert_eq!(minified.size(), pretty.size());
+    assert_eq!(
+        minified.root().children().len(),
+        pretty.root().children().len()
+    );
+    assert_eq!(
+        minified.root().abs_bounding_box(),
+        pretty.root().abs_bounding_box()
+    );
+}
+
+#[test]
+fn check_svg_with_truncated_minified() {
+    let (minified, pretty) = documents(256 * 1024);
+    let (minified, pretty) = (
+        &minified[..minified.len() - "</svg>".len()],
+        &pretty[..pretty.len() - "</svg>".len()],
+    );
+    let start = Instant::now();
+    for validator in validators() {
+        assert_eq!(
+            validator.check_svg(minified).unwrap_err().kind(),
+            ErrorKind::NotXml
+        );
+        assert_eq!(
+            validator.check_svg(pretty).unwrap_err().kind(),
+            ErrorKind::NotXml
+        );
+    }
+    assert!(start.elapsed() < TIME_LIMIT);
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn check_svgz_with_minified() {
+    let (minified, pretty) = documents(256 * 1024);
+    let (minified, pretty) = (
+        is_svg::compress_svg(minified).unwrap(),
+        is_svg::compress_svg(pretty).unwrap(),
+    );
+    let start = Instant::now();
+    for validator in [Validator::new(), Validator::strict()] {
+        assert!(validator.check_svg(&minified).is_ok());
+        assert_eq!(
+            validator.check_svg(&minified).map_err(|err| err.kind()),
+            validator.check_svg(&pretty).map_err(|err| err.kind())
+        );
+    }
+    assert!(matches!(
+        common::check_chunked(&minified, 4096),
+        Ok(SvgKind::Svgz)
+    ));
+    assert!(start.elapsed() < TIME_LIMIT);
+}
@@ -0,0 +1,124 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+use is_svg::{
+    CompressionKind, Error, GzipDecompressor, Limit, SvgKind, Validator, ValidatorBuilder,
+};
+
+const NESTED: &[u8] = include_bytes!("data/hostile/nested.svgz");
+
+#[test]
+fn detect_nested_svgz() {
+    assert_eq!(is_svg::detect(NESTED), Some(SvgKind::NestedSvgz));
+    assert_eq!(Validator::new().detect(NESTED), Some(SvgKind::NestedSvgz));
+    assert_eq!(
+        Validator::lenient().detect(NESTED),
+        Some(SvgKind::NestedSvgz)
+    );
+    assert_eq!(Validator::strict().detect(NESTED), None);
+    for chunk_size in [1, 64, NESTED.len()] {
+        assert!(matches!(
+            common::check_chunked(NESTED, chunk_size),
+            Ok(SvgKind::NestedSvgz)
+        ));
+    }
+
+    // The singly gzip-compressed data is still reported as is.
+    let svgz = include_bytes!("data/w3/svg-logo-v.svgz");
+    assert_eq!(is_svg::detect(svgz), Some(SvgKind::Svgz));
+    assert!(matches!(common::check_chunked(svgz, 64), Ok(SvgKind::Svgz)));
+}
+
+#[test]
+fn nested_svgz_kind() {
+    assert_eq!(SvgKind::NestedSvgz.extension(), "svgz.gz");
+    assert_eq!(SvgKind::NestedSvgz.to_string(), "svgz.gz");
+    assert_eq!(SvgKind::NestedSvgz.compression(), CompressionKind::Gzip);
+    assert_eq!("svgz.gz".parse(), Ok(SvgKind::NestedSvgz));
+}
+
+#[test]
+fn decompress_nested_svgz() {
+    assert_eq!(
+        is_svg::decompress_svgz(NESTED).unwrap(),
+        include_str!("data/w3/svg-logo-v.svg")
+    );
+    assert!(matches!(
+        is_svg::decompress_svgz(common::compress(NESTED)),
+        Err(Error::LimitExceeded(Limit::GzipNesting(1)))
+    ));
+}
+
+#[test]
+fn triply_gzip_compressed() {
+    let triple = common::compress(NESTED);
+    assert!(!is_svg::is_svg(&triple));
+    assert!(!is_svg::is_svgz(&triple));
+    for validator in [Validator::lenient(), Validator::new()] {
+        assert!(matches!(
+            validator.check_svg(&triple),
+            Err(Error::LimitExceeded(Limit::GzipNesting(1)))
+        ));
+    }
+    assert!(matches!(
+        common::check_chunked(&triple, 64),
+        Err(Error::LimitExceeded(Limit::GzipNesting(1)))
+    ));
+
+    let validator = ValidatorBuilder::new().max_gzip_nesting(2).build();
+    assert_eq!(validator.detect(&triple), Some(SvgKind::NestedSvgz));
+    assert!(validator.is_svgz(&triple));
+    assert!(matches!(
+        validator.check_svg(common::compress(&triple)),
+        Err(Error::LimitExceeded(Limit::GzipNesting(2)))
+    ));
+}
+
+#[test]
+fn max_gzip_nesting() {
+    let validator = ValidatorBuilder::new().max_gzip_nesting(0).build();
+    assert!(matches!(
+        validator.check_svg(NESTED),
+        Err(Error::LimitExceeded(Limit::GzipNesting(0)))
+    ));
+    assert!(validator.is_svg(include_bytes!("data/w3/svg-logo-v.svgz")));
+    assert!(matches!(
+        Validator::strict().check_svg(NESTED),
+        Err(Error::LimitExceeded(Limit::GzipNesting(0)))
+    ));
+    assert!(ValidatorBuilder::strict()
+        .max_gzip_nesting(1)
+        .build()
+        .is_svg(NESTED));
+}
+
+#[test]
+fn nesting_with_decompressor() {
+    let validator = ValidatorBuilder::new()
+        .decompressor(GzipDecompressor)
+        .build();
+    assert_eq!(validator.detect(NESTED), Some(SvgKind::NestedSvgz));
+    assert!(matches!(
+        validator.check_svg(common::compress(NESTED)),
+        Err(Error::LimitExceeded(Limit::GzipNesting(1)))
+    ));
+}
+
+#[test]
+fn nested_non_svg() {
+    // The error is the same as the singly gzip-compressed data.
+    for data in [&b"<html></html>"[..], b"", b"\x00\x01\x02"] {
+        let nested = common::compress(&common::compress(data));
+        assert!(!is_svg::is_svg(&nested));
+        assert_eq!(
+            is_svg::check_svg(&nested).unwrap_err().kind(),
+            is_svg::check_svg(common::compress(data))
+                .unwrap_err()
+                .kind()
+        );
+        assert_eq!(is_svg::detect(&nested), None);
+    }
+}
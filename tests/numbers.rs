@@ -0,0 +1,144 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The verdicts on the absurd numeric values are pinned by these tests, so
+//! that a change of them caused by updating `usvg` is detected.
+
+use is_svg::{
+    usvg::{self, Node},
+    Error, Validator, ValidatorBuilder,
+};
+
+fn validators() -> [Validator; 4] {
+    [
+        Validator::lenient(),
+        Validator::new(),
+        Validator::strict(),
+        ValidatorBuilder::new().fast(true).build(),
+    ]
+}
+
+fn size(width: f32, height: f32) -> usvg::Size {
+    usvg::Size::from_wh(width, height).unwrap()
+}
+
+/// Asserts that `data` is rejected because its size is invalid.
+fn assert_invalid_size(data: &str) {
+    for validator in validators() {
+        assert!(matches!(
+            validator.check_svg(data),
+            Err(Error::Parse(usvg::Error::InvalidSize))
+        ));
+    }
+}
+
+/// Asserts that `data` is accepted, and returns its tree.
+fn assert_valid(data: &str) -> usvg::Tree {
+    for validator in validators() {
+        assert!(validator.check_svg(data).is_ok());
+    }
+    is_svg::parse_svg(data).unwrap()
+}
+
+#[test]
+fn size_overflowing_f32() {
+    // `usvg` rejects these.
+    assert_invalid_size(include_str!("data/numbers/f32-overflow-width.svg"));
+}
+
+#[test]
+fn size_overflowing_f64() {
+    // `usvg` ignores these as if these were not specified, but these are
+    // rejected as well as the size which overflows `f32`.
+    for data in [
+        include_str!("data/numbers/huge-exponent-width.svg"),
+        include_str!("data/numbers/huge-exponent-view-box.svg"),
+        include_str!("data/numbers/many-digits-height.svg"),
+    ] {
+        assert_invalid_size(data);
+    }
+    for (attrs, expected) in [
+        (r#"width="1e39%" height="16""#, true),
+        (r#"width="16" height="-1e999""#, true),
+        (r#"width="16" height="16" viewBox="0,0,16,1E+999""#, true),
+        // The other attributes are left to `usvg`.
+        (
+            r#"width="16" height="16" viewBox="0 0 16 16" x="1e999""#,
+            false,
+        ),
+    ] {
+        let data = format!(r#"<svg xmlns="http://www.w3.org/2000/svg" {attrs}/>"#);
+        assert_eq!(
+            matches!(
+                is_svg::check_svg(&data),
+                Err(Error::Parse(usvg::Error::InvalidSize))
+            ),
+            expected,
+            "{data}"
+        );
+    }
+}
+
+#[test]
+fn size_with_keywords() {
+    // `NaN` and `Infinity` are not numbers in SVG, so these are ignored, and
+    // the size is the bounding box of the content.
+    let tree = assert_valid(include_str!("data/numbers/keyword-size.svg"));
+    assert_eq!(tree.size(), size(8.0, 8.0));
+}
+
+#[test]
+fn negative_view_box() {
+    // The invalid `viewBox` is ignored.
+    let tree = assert_valid(include_str!("data/numbers/negative-view-box.svg"));
+    assert_eq!(tree.size(), size(16.0, 16.0));
+}
+
+#[test]
+fn invalid_stroke_width() {
+    // The invalid widths fall back to the default width, and the negative
+    // width disables the stroke.
+    let tree = assert_valid(include_str!("data/numbers/stroke-width.svg"));
+    let widths = tree
+        .root()
+        .children()
+        .iter()
+        .map(|node| match node {
+            Node::Path(path) => path.stroke().map(|stroke| stroke.width().get()),
+            _ => unreachable!(),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(widths, [Some(1.0), Some(1.0), Some(1.0), None]);
+}
+
+#[test]
+fn huge_exponents_in_content() {
+    // The elements with the numbers which overflow are dropped or clamped.
+    let tree = assert_valid(include_str!("data/numbers/huge-exponent-content.svg"));
+    assert_eq!(tree.size(), size(16.0, 16.0));
+    assert_eq!(tree.root().children().len(), 1);
+}
+
+#[test]
+fn many_digits_in_content() {
+    let tree = assert_valid(include_str!("data/numbers/many-digits-content.svg"));
+    assert_eq!(tree.root().children().len(), 1);
+    assert_eq!(
+        tree.root().bounding_box(),
+        usvg::Rect::from_ltrb(8.0, 0.0, 8.5, 0.0).unwrap()
+    );
+}
+
+#[test]
+fn scientific_notation_in_path_data() {
+    let tree = assert_valid(include_str!("data/numbers/scientific-path.svg"));
+    let Node::Path(path) = &tree.root().children()[0] else {
+        unreachable!();
+    };
+    assert_eq!(
+        path.bounding_box(),
+        usvg::Rect::from_ltrb(-5.0, -0.1, 300.0, 9.9).unwrap()
+    );
+    assert_eq!(tree.root().children().len(), 2);
+}
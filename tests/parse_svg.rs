@@ -0,0 +1,59 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use is_svg::{
+    usvg::{roxmltree, Size},
+    Error,
+};
+
+#[test]
+fn parse_svg_from_svg() {
+    let tree = is_svg::parse_svg(include_str!("data/ferris/corro.svg")).unwrap();
+    assert!(tree.root().has_children());
+    let tree = is_svg::parse_svg(include_str!("data/usvg/path-simple-case.svg")).unwrap();
+    assert_eq!(tree.size(), Size::from_wh(200.0, 200.0).unwrap());
+    let tree = is_svg::parse_svg(include_str!("data/w3/svg-logo-v.svg")).unwrap();
+    assert_eq!(tree.size(), Size::from_wh(300.0, 300.0).unwrap());
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn parse_svg_from_svgz() {
+    let tree = is_svg::parse_svg(include_bytes!("data/ferris/corro.svgz")).unwrap();
+    assert!(tree.root().has_children());
+    let tree = is_svg::parse_svg(include_bytes!("data/w3/svg-logo-v.svgz")).unwrap();
+    assert_eq!(tree.size(), Size::from_wh(300.0, 300.0).unwrap());
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn parse_svg_same_as_svgz() {
+    let svg = is_svg::parse_svg(include_str!("data/w3/svg-logo-v.svg")).unwrap();
+    let svgz = is_svg::parse_svg(include_bytes!("data/w3/svg-logo-v.svgz")).unwrap();
+    assert_eq!(svg.size(), svgz.size());
+    assert_eq!(
+        svg.root().abs_bounding_box(),
+        svgz.root().abs_bounding_box()
+    );
+    assert_eq!(svg.root().children().len(), svgz.root().children().len());
+}
+
+#[test]
+fn parse_svg_from_invalid_svg() {
+    assert!(matches!(
+        is_svg::parse_svg(include_str!("data/resources/unclosed.svg")),
+        Err(Error::NotXml {
+            source: roxmltree::Error::UnclosedRootNode,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn parse_svg_from_non_svg() {
+    assert!(matches!(
+        is_svg::parse_svg(include_bytes!("data/w3/svg-logo-v.png")),
+        Err(Error::NotUtf8(_))
+    ));
+}
@@ -0,0 +1,438 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+use std::{fs, path::Path};
+
+use is_svg::{ErrorKind, Validator};
+
+/// The verdict of each preset; [`None`] means the data is accepted.
+type Verdicts = [Option<ErrorKind>; 3];
+
+const OK: Option<ErrorKind> = None;
+const DISALLOWED_ELEMENT: Option<ErrorKind> = Some(ErrorKind::DisallowedElement);
+const EMPTY: Option<ErrorKind> = Some(ErrorKind::Empty);
+const ENTITY_EXPANSION_LIMIT: Option<ErrorKind> = Some(ErrorKind::EntityExpansionLimit);
+const EXTERNAL_REFERENCE: Option<ErrorKind> = Some(ErrorKind::ExternalReference);
+const GZIP: Option<ErrorKind> = Some(ErrorKind::Gzip);
+const GZIP_INTEGRITY: Option<ErrorKind> = Some(ErrorKind::GzipIntegrity);
+const LIMIT_EXCEEDED: Option<ErrorKind> = Some(ErrorKind::LimitExceeded);
+const NOT_SVG_ROOT: Option<ErrorKind> = Some(ErrorKind::NotSvgRoot);
+const NOT_UTF8: Option<ErrorKind> = Some(ErrorKind::NotUtf8);
+const NOT_UTF16: Option<ErrorKind> = Some(ErrorKind::NotUtf16);
+const NOT_XML: Option<ErrorKind> = Some(ErrorKind::NotXml);
+const PARSE: Option<ErrorKind> = Some(ErrorKind::Parse);
+const UNSUPPORTED_COMPRESSION: Option<ErrorKind> = Some(ErrorKind::UnsupportedCompression);
+
+/// The verdict on the data in the legacy encodings, which is not a valid UTF-8.
+const LEGACY_ENCODING: Option<ErrorKind> = if cfg!(feature = "encoding") {
+    OK
+} else {
+    NOT_UTF8
+};
+
+/// The verdicts of the lenient, the default and the strict presets for every
+/// fixture in `tests/data`.
+const MATRIX: &[(&str, Verdicts)] = &[
+    ("animation/smil-stress.svg", [OK, OK, OK]),
+    ("blank/comments.svg", [EMPTY, EMPTY, EMPTY]),
+    ("blank/comments.svgz", [EMPTY, EMPTY, EMPTY]),
+    ("blank/whitespace.svg", [EMPTY, EMPTY, EMPTY]),
+    ("blank/whitespace.svgz", [EMPTY, EMPTY, EMPTY]),
+    ("blank/zero-bytes.svg", [EMPTY, EMPTY, EMPTY]),
+    ("blank/zero-bytes.svgz", [EMPTY, EMPTY, EMPTY]),
+    ("cdata/script.svg", [OK, OK, DISALLOWED_ELEMENT]),
+    ("cdata/style-brackets.svg", [OK, OK, OK]),
+    ("cdata/style-brackets.svgz", [OK, OK, OK]),
+    ("cdata/style-end-tag.svg", [OK, OK, OK]),
+    ("cdata/style-end-tag.svgz", [OK, OK, OK]),
+    ("cdata/text.svg", [OK, OK, OK]),
+    (
+        "deflate/svg-logo-v.svg.deflate",
+        [NOT_UTF8, NOT_UTF8, NOT_UTF8],
+    ),
+    (
+        "deflate/svg-logo-v.svg.zz",
+        [
+            UNSUPPORTED_COMPRESSION,
+            UNSUPPORTED_COMPRESSION,
+            UNSUPPORTED_COMPRESSION,
+        ],
+    ),
+    ("dimensions/a4.svg", [OK, OK, OK]),
+    ("dimensions/missing.svg", [OK, OK, OK]),
+    ("dimensions/percentage.svg", [OK, OK, OK]),
+    ("dimensions/pixels.svg", [OK, OK, OK]),
+    ("dtd/illustrator.svg", [OK, OK, NOT_XML]),
+    ("empty/comments-only.svg", [OK, OK, OK]),
+    ("empty/defs-only.svg", [OK, OK, OK]),
+    ("empty/self-closing.svg", [OK, OK, OK]),
+    ("empty/whitespace-only.svg", [OK, OK, OK]),
+    ("encoding/bom.svg", [OK, OK, OK]),
+    ("encoding/bom.svgz", [OK, OK, OK]),
+    (
+        "encoding/iso-8859-1.svg",
+        [LEGACY_ENCODING, LEGACY_ENCODING, LEGACY_ENCODING],
+    ),
+    ("encoding/utf-16be.svg", [OK, OK, OK]),
+    ("encoding/utf-16le.svg", [OK, OK, OK]),
+    ("encoding/utf-16le.svgz", [OK, OK, OK]),
+    (
+        "encoding/windows-1252.svg",
+        [LEGACY_ENCODING, LEGACY_ENCODING, LEGACY_ENCODING],
+    ),
+    ("ferris/README.adoc", [NOT_XML, NOT_XML, NOT_XML]),
+    ("ferris/corro.svg", [OK, OK, OK]),
+    ("ferris/corro.svgz", [OK, OK, OK]),
+    ("ferris/cuddlyferris.svg", [OK, OK, NOT_XML]),
+    ("ferris/cuddlyferris.svgz", [OK, OK, NOT_XML]),
+    ("ferris/rustacean-flat-gesture.svg", [OK, OK, NOT_XML]),
+    ("ferris/rustacean-flat-gesture.svgz", [OK, OK, NOT_XML]),
+    ("ferris/rustacean-flat-happy.svg", [OK, OK, NOT_XML]),
+    ("ferris/rustacean-flat-happy.svgz", [OK, OK, NOT_XML]),
+    ("ferris/rustacean-flat-noshadow.svg", [OK, OK, NOT_XML]),
+    ("ferris/rustacean-flat-noshadow.svgz", [OK, OK, NOT_XML]),
+    ("ferris/rustacean-orig-noshadow.svg", [OK, OK, NOT_XML]),
+    ("ferris/rustacean-orig-noshadow.svgz", [OK, OK, NOT_XML]),
+    ("gzip-header/all-flags.svgz", [OK, OK, OK]),
+    (
+        "gzip-header/bad-fhcrc.svgz",
+        [GZIP_INTEGRITY, GZIP_INTEGRITY, GZIP_INTEGRITY],
+    ),
+    ("gzip-header/fcomment.svgz", [OK, OK, OK]),
+    ("gzip-header/fextra.svgz", [OK, OK, OK]),
+    ("gzip-header/fhcrc.svgz", [OK, OK, OK]),
+    ("gzip-header/fname.svgz", [OK, OK, OK]),
+    ("hostile/absurd-numbers.svg", [PARSE, PARSE, PARSE]),
+    (
+        "hostile/billion-laughs.svg",
+        [ENTITY_EXPANSION_LIMIT, ENTITY_EXPANSION_LIMIT, NOT_XML],
+    ),
+    (
+        "hostile/corrupted-crc.svgz",
+        [GZIP_INTEGRITY, GZIP_INTEGRITY, GZIP_INTEGRITY],
+    ),
+    ("hostile/deeply-nested-group.svg", [OK, OK, LIMIT_EXCEEDED]),
+    (
+        "hostile/deeply-nested-unknown.svg",
+        [OK, OK, LIMIT_EXCEEDED],
+    ),
+    (
+        "hostile/deeply-nested.svg",
+        [LIMIT_EXCEEDED, LIMIT_EXCEEDED, LIMIT_EXCEEDED],
+    ),
+    (
+        "hostile/deeply-nested.svgz",
+        [LIMIT_EXCEEDED, LIMIT_EXCEEDED, LIMIT_EXCEEDED],
+    ),
+    ("hostile/entity-nesting.svg", [OK, OK, LIMIT_EXCEEDED]),
+    ("hostile/external-entity.svg", [NOT_XML, NOT_XML, NOT_XML]),
+    ("hostile/gzip-absurd-xlen.svgz", [GZIP, GZIP, GZIP]),
+    ("hostile/gzip-empty-members.svgz", [EMPTY, EMPTY, GZIP]),
+    (
+        "hostile/gzip-huge-isize.svgz",
+        [GZIP_INTEGRITY, GZIP_INTEGRITY, GZIP_INTEGRITY],
+    ),
+    ("hostile/gzip-unterminated-name.svgz", [GZIP, GZIP, GZIP]),
+    (
+        "hostile/gzip-zeros.svgz",
+        [LIMIT_EXCEEDED, LIMIT_EXCEEDED, LIMIT_EXCEEDED],
+    ),
+    ("hostile/interior-nul.svg", [NOT_XML, NOT_XML, NOT_XML]),
+    (
+        "hostile/invalid-utf16.svg",
+        [NOT_UTF16, NOT_UTF16, NOT_UTF16],
+    ),
+    ("hostile/nested.svgz", [OK, OK, LIMIT_EXCEEDED]),
+    ("hostile/overlong-utf8.svg", [NOT_UTF8, NOT_UTF8, NOT_UTF8]),
+    ("hostile/recursive-reference.svg", [OK, OK, OK]),
+    ("hostile/recursive-use.svg", [OK, OK, OK]),
+    ("hostile/truncated-utf8.svg", [NOT_UTF8, NOT_UTF8, NOT_UTF8]),
+    ("hostile/truncated.svgz", [GZIP, GZIP, GZIP]),
+    ("href/use-both-external.svg", [OK, OK, EXTERNAL_REFERENCE]),
+    ("href/use-both.svg", [OK, OK, OK]),
+    ("href/use-href.svg", [OK, OK, OK]),
+    ("href/use-xlink-href.svg", [OK, OK, OK]),
+    ("integrity/corrupt-deflate.svgz", [GZIP, GZIP, GZIP]),
+    (
+        "integrity/crc-mismatch.svgz",
+        [GZIP_INTEGRITY, GZIP_INTEGRITY, GZIP_INTEGRITY],
+    ),
+    (
+        "integrity/deflate-bit-flip.svgz",
+        [GZIP_INTEGRITY, GZIP_INTEGRITY, GZIP_INTEGRITY],
+    ),
+    (
+        "integrity/isize-mismatch.svgz",
+        [GZIP_INTEGRITY, GZIP_INTEGRITY, GZIP_INTEGRITY],
+    ),
+    ("intrinsic-size/nothing.svg", [OK, OK, OK]),
+    ("intrinsic-size/only-height.svg", [OK, OK, OK]),
+    ("intrinsic-size/only-view-box.svg", [OK, OK, OK]),
+    (
+        "intrinsic-size/only-width-without-view-box.svg",
+        [OK, OK, OK],
+    ),
+    ("intrinsic-size/only-width.svg", [OK, OK, OK]),
+    ("intrinsic-size/percentage-width.svg", [OK, OK, OK]),
+    ("intrinsic-size/physical-units.svg", [OK, OK, OK]),
+    ("intrinsic-size/wide-view-box.svg", [OK, OK, OK]),
+    ("invisible/clipped.svg", [OK, OK, OK]),
+    ("invisible/display-none.svg", [OK, OK, OK]),
+    ("invisible/off-canvas.svg", [OK, OK, OK]),
+    ("invisible/zero-opacity.svg", [OK, OK, OK]),
+    ("invisible/zero-size.svg", [OK, OK, OK]),
+    ("invisible/zero-view-box.svg", [OK, OK, OK]),
+    (
+        "location/unquoted-attribute.svg",
+        [NOT_XML, NOT_XML, NOT_XML],
+    ),
+    (
+        "location/unquoted-attribute.svgz",
+        [NOT_XML, NOT_XML, NOT_XML],
+    ),
+    (
+        "namespace/conflicting-namespace.svg",
+        [NOT_SVG_ROOT, NOT_SVG_ROOT, NOT_SVG_ROOT],
+    ),
+    (
+        "namespace/missing-namespace.svg",
+        [OK, NOT_SVG_ROOT, NOT_SVG_ROOT],
+    ),
+    ("namespace/prefixed.svg", [OK, OK, OK]),
+    (
+        "namespace/unnamespaced.svg",
+        [OK, NOT_SVG_ROOT, NOT_SVG_ROOT],
+    ),
+    (
+        "namespace/wrong-namespace.svg",
+        [NOT_SVG_ROOT, NOT_SVG_ROOT, NOT_SVG_ROOT],
+    ),
+    ("numbers/f32-overflow-width.svg", [PARSE, PARSE, PARSE]),
+    ("numbers/huge-exponent-content.svg", [OK, OK, OK]),
+    ("numbers/huge-exponent-view-box.svg", [PARSE, PARSE, PARSE]),
+    ("numbers/huge-exponent-width.svg", [PARSE, PARSE, PARSE]),
+    ("numbers/keyword-size.svg", [OK, OK, OK]),
+    ("numbers/many-digits-content.svg", [OK, OK, OK]),
+    ("numbers/many-digits-height.svg", [PARSE, PARSE, PARSE]),
+    ("numbers/negative-view-box.svg", [OK, OK, OK]),
+    ("numbers/scientific-path.svg", [OK, OK, OK]),
+    ("numbers/stroke-width.svg", [OK, OK, OK]),
+    ("policy/clean-icon.svg", [OK, OK, OK]),
+    ("policy/script.svg", [OK, OK, DISALLOWED_ELEMENT]),
+    ("policy/sodipodi.svg", [OK, OK, OK]),
+    ("processing-instruction/after-root.svg", [OK, OK, OK]),
+    (
+        "processing-instruction/stylesheet.svg",
+        [OK, OK, EXTERNAL_REFERENCE],
+    ),
+    ("processing-instruction/unknown.svg", [OK, OK, OK]),
+    ("prolog/comment-banner.svg", [OK, OK, OK]),
+    ("prolog/comment-banner.svgz", [OK, OK, OK]),
+    (
+        "prolog/comment-then-declaration.svg",
+        [NOT_XML, NOT_XML, NOT_XML],
+    ),
+    ("prolog/comments-and-whitespace.svg", [OK, OK, OK]),
+    ("prolog/declaration-then-comment.svg", [OK, OK, OK]),
+    ("prolog/leading-whitespace.svg", [OK, OK, LIMIT_EXCEEDED]),
+    ("prolog/leading-whitespace.svgz", [OK, OK, OK]),
+    (
+        "prolog/whitespace-then-declaration.svg",
+        [NOT_XML, NOT_XML, NOT_XML],
+    ),
+    ("reference/absolute-url.svg", [OK, OK, EXTERNAL_REFERENCE]),
+    ("reference/data-uri.svg", [OK, OK, OK]),
+    ("reference/file-relative.svg", [OK, OK, EXTERNAL_REFERENCE]),
+    ("reference/fragment-only.svg", [OK, OK, OK]),
+    (
+        "reference/style-attribute.svg",
+        [OK, OK, EXTERNAL_REFERENCE],
+    ),
+    ("reference/style-element.svg", [OK, OK, EXTERNAL_REFERENCE]),
+    ("resources/dtd.svg", [NOT_XML, NOT_XML, NOT_XML]),
+    ("resources/empty.gz", [EMPTY, EMPTY, EMPTY]),
+    ("resources/index.html", [NOT_XML, NOT_XML, NOT_XML]),
+    (
+        "resources/quadratic_formula.mml",
+        [NOT_XML, NOT_XML, NOT_XML],
+    ),
+    ("resources/unclosed.svg", [NOT_XML, NOT_XML, NOT_XML]),
+    ("resources/xml_declaration.svg", [NOT_XML, NOT_XML, NOT_XML]),
+    (
+        "root/index.xhtml",
+        [NOT_SVG_ROOT, NOT_SVG_ROOT, NOT_SVG_ROOT],
+    ),
+    (
+        "root/prefixed-math.mml",
+        [NOT_SVG_ROOT, NOT_SVG_ROOT, NOT_SVG_ROOT],
+    ),
+    (
+        "root/quadratic-formula.mml",
+        [NOT_SVG_ROOT, NOT_SVG_ROOT, NOT_SVG_ROOT],
+    ),
+    ("root/truncated-content.mml", [NOT_XML, NOT_XML, NOT_XML]),
+    ("root/truncated-start-tag.mml", [NOT_XML, NOT_XML, NOT_XML]),
+    ("root/truncated-start-tag.svg", [NOT_XML, NOT_XML, NOT_XML]),
+    (
+        "root/uppercase.svg",
+        [NOT_SVG_ROOT, NOT_SVG_ROOT, NOT_SVG_ROOT],
+    ),
+    ("svg2/hatch.svg", [OK, OK, OK]),
+    ("svg2/made-up-element.svg", [OK, OK, OK]),
+    ("svg2/meshgradient.svg", [OK, OK, OK]),
+    ("switch/every-branch-fails.svg", [OK, OK, OK]),
+    ("switch/multilingual.svg", [OK, OK, OK]),
+    ("usvg/README.adoc", [NOT_XML, NOT_XML, NOT_XML]),
+    ("usvg/clip-path-with-complex-text.svg", [OK, OK, OK]),
+    (
+        "usvg/clip-path-with-object-units-multi-use.svg",
+        [OK, OK, OK],
+    ),
+    ("usvg/clip-path-with-text.svg", [OK, OK, OK]),
+    ("usvg/ellipse-simple-case.svg", [OK, OK, OK]),
+    ("usvg/filter-id-with-prefix.svg", [OK, OK, OK]),
+    ("usvg/filter-with-object-units-multi-use.svg", [OK, OK, OK]),
+    ("usvg/generate-id-clip-path-for-symbol.svg", [OK, OK, OK]),
+    ("usvg/generate-id-filter-function-v1.svg", [OK, OK, OK]),
+    ("usvg/generate-id-filter-function-v2.svg", [OK, OK, OK]),
+    ("usvg/mask-with-object-units-multi-use.svg", [OK, OK, OK]),
+    ("usvg/path-simple-case.svg", [OK, OK, OK]),
+    ("usvg/preserve-id-clip-path-v1.svg", [OK, OK, OK]),
+    ("usvg/preserve-id-clip-path-v2.svg", [OK, OK, OK]),
+    ("usvg/preserve-id-fe-image-with-opacity.svg", [OK, OK, OK]),
+    ("usvg/preserve-id-fe-image.svg", [OK, OK, OK]),
+    ("usvg/preserve-id-filter.svg", [OK, OK, OK]),
+    (
+        "usvg/preserve-id-for-clip-path-in-pattern.svg",
+        [OK, OK, OK],
+    ),
+    ("usvg/preserve-text-in-clip-path.svg", [OK, OK, OK]),
+    ("usvg/preserve-text-in-mask.svg", [OK, OK, OK]),
+    ("usvg/preserve-text-in-pattern.svg", [OK, OK, OK]),
+    (
+        "usvg/preserve-text-multiple-font-families.svg",
+        [OK, OK, OK],
+    ),
+    ("usvg/preserve-text-on-path.svg", [OK, OK, OK]),
+    ("usvg/preserve-text-simple-case.svg", [OK, OK, OK]),
+    (
+        "usvg/preserve-text-with-complex-text-decoration.svg",
+        [OK, OK, OK],
+    ),
+    ("usvg/preserve-text-with-dx-and-dy.svg", [OK, OK, OK]),
+    (
+        "usvg/preserve-text-with-nested-baseline-shift.svg",
+        [OK, OK, OK],
+    ),
+    ("usvg/preserve-text-with-rotate.svg", [OK, OK, OK]),
+    ("usvg/text-simple-case.svg", [OK, OK, OK]),
+    ("usvg/text-with-generated-gradients.svg", [OK, OK, OK]),
+    ("w3/README.adoc", [NOT_XML, NOT_XML, NOT_XML]),
+    ("w3/svg-logo-v.jxl", [NOT_UTF8, NOT_UTF8, NOT_UTF8]),
+    ("w3/svg-logo-v.png", [NOT_UTF8, NOT_UTF8, NOT_UTF8]),
+    ("w3/svg-logo-v.svg", [OK, OK, OK]),
+    (
+        "w3/svg-logo-v.svg.lz",
+        [
+            UNSUPPORTED_COMPRESSION,
+            UNSUPPORTED_COMPRESSION,
+            UNSUPPORTED_COMPRESSION,
+        ],
+    ),
+    (
+        "w3/svg-logo-v.svg.zst",
+        [
+            UNSUPPORTED_COMPRESSION,
+            UNSUPPORTED_COMPRESSION,
+            UNSUPPORTED_COMPRESSION,
+        ],
+    ),
+    ("w3/svg-logo-v.svgz", [OK, OK, OK]),
+    ("w3/svg-logo-v.webp", [NOT_UTF8, NOT_UTF8, NOT_UTF8]),
+    ("wikipedia/README.adoc", [NOT_XML, NOT_XML, NOT_XML]),
+    ("wikipedia/SVG_animation_using_CSS.svg", [OK, OK, OK]),
+    ("wikipedia/SVG_animation_using_CSS.svgz", [OK, OK, OK]),
+    (
+        "wikipedia/SVG_animation_using_ES.svg",
+        [OK, OK, DISALLOWED_ELEMENT],
+    ),
+    (
+        "wikipedia/SVG_animation_using_ES.svgz",
+        [OK, OK, DISALLOWED_ELEMENT],
+    ),
+    ("wikipedia/SVG_animation_using_SMIL.svg", [OK, OK, OK]),
+    ("wikipedia/SVG_animation_using_SMIL.svgz", [OK, OK, OK]),
+    ("xml-declaration/bom.svg", [OK, OK, OK]),
+    ("xml-declaration/double-quotes.svg", [OK, OK, OK]),
+    ("xml-declaration/single-quotes.svg", [OK, OK, OK]),
+    ("xml-declaration/standalone.svg", [OK, OK, OK]),
+    ("xml-declaration/whitespace.svg", [OK, OK, OK]),
+];
+
+#[test]
+fn matrix_covers_all_fixtures() {
+    let paths = common::fixtures(common::DATA_DIR);
+    let mut paths = paths
+        .iter()
+        .map(|path| {
+            path.strip_prefix(common::DATA_DIR)
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .replace('\\', "/")
+        })
+        .collect::<Vec<_>>();
+    paths.sort();
+    let expected = MATRIX.iter().map(|(path, _)| *path).collect::<Vec<_>>();
+    assert_eq!(paths, expected);
+}
+
+#[test]
+fn verdicts() {
+    let presets = [Validator::lenient(), Validator::new(), Validator::strict()];
+    let mut mismatches = Vec::new();
+    for (path, expected) in MATRIX {
+        let data = fs::read(Path::new("tests/data").join(path)).unwrap();
+        let actual = presets
+            .iter()
+            .map(|validator| validator.check_svg(&data).err().map(|err| err.kind()))
+            .collect::<Vec<_>>();
+        if actual != expected {
+            mismatches.push((path, actual));
+        }
+    }
+    assert!(mismatches.is_empty(), "{mismatches:#?}");
+}
+
+#[test]
+fn default_is_new() {
+    let (default, new) = (Validator::default(), Validator::new());
+    for (path, _) in MATRIX {
+        let data = fs::read(Path::new("tests/data").join(path)).unwrap();
+        assert_eq!(
+            default.check_svg(&data).err().map(|err| err.kind()),
+            new.check_svg(&data).err().map(|err| err.kind()),
+            "{path}"
+        );
+    }
+}
+
+#[test]
+fn presets_can_be_changed() {
+    let svg = r#"<svg width="24" height="24"/>"#;
+    assert!(Validator::lenient().is_svg(svg));
+    assert!(!is_svg::ValidatorBuilder::lenient()
+        .allow_missing_namespace(false)
+        .build()
+        .is_svg(svg));
+
+    let svg = include_str!("data/ferris/corro.svg");
+    assert!(Validator::strict().is_svg(svg));
+    assert!(!is_svg::ValidatorBuilder::strict()
+        .max_size(1024)
+        .build()
+        .is_svg(svg));
+}
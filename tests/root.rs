@@ -0,0 +1,185 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+#[cfg(feature = "gzip")]
+use std::io::Write;
+
+#[cfg(feature = "gzip")]
+use flate2::{write::GzEncoder, Compression};
+use is_svg::{Error, ErrorKind, Validator, ValidatorBuilder};
+
+const QUADRATIC_FORMULA: &str = include_str!("data/root/quadratic-formula.mml");
+const PREFIXED_MATH: &str = include_str!("data/root/prefixed-math.mml");
+const INDEX: &str = include_str!("data/root/index.xhtml");
+const UPPERCASE: &str = include_str!("data/root/uppercase.svg");
+
+/// The documents which are truncated before the root element is closed.
+const TRUNCATED: [&str; 3] = [
+    include_str!("data/root/truncated-start-tag.mml"),
+    include_str!("data/root/truncated-content.mml"),
+    include_str!("data/root/truncated-start-tag.svg"),
+];
+
+fn validators() -> [Validator; 6] {
+    [
+        Validator::lenient(),
+        Validator::new(),
+        Validator::strict(),
+        Validator::builder().fast(true).build(),
+        ValidatorBuilder::new().require_svg_namespace(true).build(),
+        ValidatorBuilder::new()
+            .require_renderable_content(true)
+            .build(),
+    ]
+}
+
+/// Returns the name of the root element in the error, or [`None`] if the
+/// error is not [`Error::NotSvgRoot`].
+fn found(result: Result<(), Error>) -> Option<String> {
+    match result {
+        Err(Error::NotSvgRoot { found }) => Some(found),
+        _ => None,
+    }
+}
+
+#[test]
+fn not_svg_root() {
+    for (text, name) in [
+        (QUADRATIC_FORMULA, "math"),
+        (PREFIXED_MATH, "m:math"),
+        (INDEX, "html"),
+        (UPPERCASE, "SVG"),
+    ] {
+        assert!(!is_svg::is_svg(text), "{text}");
+        assert_eq!(found(is_svg::check_svg(text)), Some(name.into()));
+        assert_eq!(
+            found(is_svg::check_svg_reader(text.as_bytes())),
+            Some(name.into())
+        );
+        for validator in validators() {
+            assert_eq!(found(validator.check_svg(text)), Some(name.into()));
+        }
+        for chunk_size in [1, 64, text.len()] {
+            assert!(matches!(
+                common::check_chunked(text.as_bytes(), chunk_size),
+                Err(Error::NotSvgRoot { found }) if found == name
+            ));
+        }
+    }
+}
+
+#[test]
+fn svg_root_without_namespace() {
+    let text = r#"<svg width="16" height="16"/>"#;
+    assert!(Validator::lenient().is_svg(text));
+    for validator in [Validator::new(), Validator::strict()] {
+        assert_eq!(found(validator.check_svg(text)), Some("svg".into()));
+    }
+
+    // The prefix is not significant if the namespace is not the SVG namespace.
+    let text = r#"<svg:svg xmlns:svg="https://example.com/" width="16" height="16"/>"#;
+    for validator in validators() {
+        assert_eq!(found(validator.check_svg(text)), Some("svg:svg".into()));
+    }
+}
+
+#[test]
+fn not_xml() {
+    // The named entities are not declared in the MathML document.
+    let text = include_str!("data/resources/quadratic_formula.mml");
+    assert_eq!(
+        is_svg::check_svg(text).unwrap_err().kind(),
+        ErrorKind::NotXml
+    );
+    assert_eq!(
+        is_svg::check_svg(include_str!("data/resources/index.html"))
+            .unwrap_err()
+            .kind(),
+        ErrorKind::NotXml
+    );
+}
+
+#[test]
+fn truncated() {
+    for text in TRUNCATED {
+        assert!(!is_svg::is_svg(text), "{text}");
+        for validator in validators() {
+            assert!(
+                matches!(validator.check_svg(text), Err(Error::NotXml { .. })),
+                "{text}"
+            );
+        }
+        assert!(matches!(
+            common::check_chunked(text.as_bytes(), 1),
+            Err(Error::NotXml { .. })
+        ));
+    }
+    // The root element cannot be determined without the root element.
+    for text in [
+        "",
+        "<",
+        "<?xml version=\"1.0\"?>",
+        "<!-- <svg xmlns=\"http://www.w3.org/2000/svg\"/> -->",
+    ] {
+        assert!(
+            !matches!(is_svg::check_svg(text), Err(Error::NotSvgRoot { .. })),
+            "{text}"
+        );
+    }
+}
+
+#[test]
+fn kind_is_distinct() {
+    assert_eq!(
+        is_svg::check_svg(QUADRATIC_FORMULA).unwrap_err().kind(),
+        ErrorKind::NotSvgRoot
+    );
+    assert_eq!(
+        is_svg::check_svg(TRUNCATED[1]).unwrap_err().kind(),
+        ErrorKind::NotXml
+    );
+    assert_eq!(
+        is_svg::check_svg(UPPERCASE.replace("SVG", "svg").replace("16", "0"))
+            .unwrap_err()
+            .kind(),
+        ErrorKind::Parse
+    );
+}
+
+#[test]
+fn display() {
+    assert_eq!(
+        is_svg::check_svg(PREFIXED_MATH).unwrap_err().to_string(),
+        "root element `m:math` was not the svg element"
+    );
+}
+
+#[cfg(feature = "light")]
+#[test]
+fn not_svg_root_from_light() {
+    for (text, name) in [(QUADRATIC_FORMULA, "math"), (UPPERCASE, "SVG")] {
+        assert_eq!(found(is_svg::check_svg_light(text)), Some(name.into()));
+    }
+    for text in TRUNCATED {
+        assert!(matches!(
+            is_svg::check_svg_light(text),
+            Err(Error::NotXml { .. })
+        ));
+    }
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn not_svg_root_from_svgz() {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(QUADRATIC_FORMULA.as_bytes()).unwrap();
+    let data = encoder.finish().unwrap();
+    assert_eq!(found(is_svg::check_svg(&data)), Some("math".into()));
+    assert!(matches!(
+        common::check_chunked(&data, 16),
+        Err(Error::NotSvgRoot { found }) if found == "math"
+    ));
+}
@@ -0,0 +1,265 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::num::NonZeroUsize;
+
+use is_svg::{CompressionKind, Error, Limit, SvgChecker, SvgKind, Validator};
+use serde_json::json;
+
+#[test]
+fn serialize_svg_kind() {
+    assert_eq!(serde_json::to_value(SvgKind::Svg).unwrap(), json!("svg"));
+    assert_eq!(serde_json::to_value(SvgKind::Svgz).unwrap(), json!("svgz"));
+}
+
+#[test]
+fn deserialize_svg_kind() {
+    assert_eq!(
+        serde_json::from_value::<SvgKind>(json!("svg")).unwrap(),
+        SvgKind::Svg
+    );
+    assert_eq!(
+        serde_json::from_value::<SvgKind>(json!("svgz")).unwrap(),
+        SvgKind::Svgz
+    );
+    assert!(serde_json::from_value::<SvgKind>(json!("Svg")).is_err());
+    assert!(serde_json::from_value::<SvgKind>(json!("png")).is_err());
+}
+
+#[test]
+fn svg_kind_round_trip() {
+    for kind in [
+        SvgKind::Svg,
+        SvgKind::Svgz,
+        SvgKind::NestedSvgz,
+        SvgKind::ZlibSvg,
+        SvgKind::DeflateSvg,
+    ] {
+        let json = serde_json::to_string(&kind).unwrap();
+        assert_eq!(json, format!("\"{kind}\""));
+        assert_eq!(serde_json::from_str::<SvgKind>(&json).unwrap(), kind);
+    }
+}
+
+#[test]
+fn compression_kind_round_trip() {
+    for kind in [
+        CompressionKind::None,
+        CompressionKind::Gzip,
+        CompressionKind::Zlib,
+        CompressionKind::Deflate,
+        CompressionKind::Zstd,
+        CompressionKind::Xz,
+        CompressionKind::Lzip,
+    ] {
+        let json = serde_json::to_string(&kind).unwrap();
+        assert_eq!(json, format!("\"{kind}\""));
+        assert_eq!(
+            serde_json::from_str::<CompressionKind>(&json).unwrap(),
+            kind
+        );
+    }
+    assert!(serde_json::from_value::<CompressionKind>(json!("Gzip")).is_err());
+}
+
+#[test]
+fn serialize_limit() {
+    assert_eq!(
+        serde_json::to_value(Limit::InputSize(1024)).unwrap(),
+        json!({ "InputSize": 1024 })
+    );
+    assert_eq!(
+        serde_json::to_value(Limit::DecompressedSize(2048)).unwrap(),
+        json!({ "DecompressedSize": 2048 })
+    );
+    assert_eq!(
+        serde_json::to_value(Limit::EntityDepth(10)).unwrap(),
+        json!({ "EntityDepth": 10 })
+    );
+}
+
+#[test]
+fn limit_round_trip() {
+    for limit in [
+        Limit::InputSize(1024),
+        Limit::DecompressedSize(u64::MAX),
+        Limit::Depth(4096),
+        Limit::Elements(1000),
+        Limit::AttributeLength(1024),
+        Limit::EntityDepth(10),
+        Limit::EntityExpansion(1024),
+    ] {
+        let json = serde_json::to_string(&limit).unwrap();
+        assert_eq!(serde_json::from_str::<Limit>(&json).unwrap(), limit);
+    }
+}
+
+#[test]
+fn serialize_capabilities() {
+    assert_eq!(
+        serde_json::to_value(is_svg::capabilities()).unwrap(),
+        json!({
+            "gzip": cfg!(feature = "gzip"),
+            "text": cfg!(feature = "text"),
+            "light": cfg!(feature = "light"),
+        })
+    );
+}
+
+#[test]
+fn serialize_cache_stats() {
+    let validator = Validator::builder().cache(NonZeroUsize::new(16)).build();
+    for _ in 0..3 {
+        assert!(validator.is_svg(include_str!("data/w3/svg-logo-v.svg")));
+    }
+    let stats = validator.cache_stats().unwrap();
+    assert_eq!(
+        serde_json::to_value(stats).unwrap(),
+        json!({ "hits": 2, "misses": 1 })
+    );
+    let json = serde_json::to_string(&stats).unwrap();
+    assert_eq!(
+        serde_json::from_str::<is_svg::CacheStats>(&json).unwrap(),
+        stats
+    );
+}
+
+#[test]
+fn capabilities_round_trip() {
+    let capabilities = is_svg::capabilities();
+    let json = serde_json::to_string(&capabilities).unwrap();
+    assert_eq!(
+        serde_json::from_str::<is_svg::Capabilities>(&json).unwrap(),
+        capabilities
+    );
+}
+
+#[test]
+fn serialize_svg_info() {
+    let info = is_svg::info(include_str!("data/wikipedia/SVG_animation_using_CSS.svg")).unwrap();
+    assert_eq!(
+        serde_json::to_value(&info).unwrap(),
+        json!({
+            "kind": "svg",
+            "width": null,
+            "height": null,
+            "view_box": { "min_x": -4.0, "min_y": -4.0, "width": 8.0, "height": 8.0 },
+            "version": "1.1",
+            "base_profile": null,
+            "size": info.size,
+            "decompressed_size": null,
+        })
+    );
+}
+
+#[test]
+fn svg_info_round_trip() {
+    let info = is_svg::info(include_str!("data/w3/svg-logo-v.svg")).unwrap();
+    let json = serde_json::to_string(&info).unwrap();
+    assert_eq!(
+        serde_json::from_str::<is_svg::SvgInfo>(&json).unwrap(),
+        info
+    );
+}
+
+#[test]
+fn serialize_error() {
+    let err = is_svg::check_svg(include_bytes!("data/w3/svg-logo-v.png")).unwrap_err();
+    assert_eq!(
+        serde_json::to_value(&err).unwrap(),
+        json!({
+            "kind": "NotUtf8",
+            "message": "data was not a valid UTF-8",
+            "source": "invalid utf-8 sequence of 1 bytes from index 0",
+        })
+    );
+
+    let err =
+        is_svg::check_svg(include_bytes!("data/resources/quadratic_formula.mml")).unwrap_err();
+    let value = serde_json::to_value(&err).unwrap();
+    assert_eq!(value["kind"], "NotXml");
+    assert_eq!(
+        value["message"],
+        "data was not a valid XML document at 15:13"
+    );
+    assert!(value["source"].is_string());
+
+    let err = Error::NotGzip;
+    assert_eq!(
+        serde_json::to_value(&err).unwrap(),
+        json!({
+            "kind": "NotGzip",
+            "message": "data was not gzip-compressed",
+            "source": null,
+        })
+    );
+}
+
+#[test]
+fn serialize_limit_exceeded_error() {
+    let mut checker = SvgChecker::new().max_size(1024);
+    checker.push(include_bytes!("data/w3/svg-logo-v.svg"));
+    let err = checker.finish().unwrap_err();
+    assert_eq!(
+        serde_json::to_value(&err).unwrap(),
+        json!({
+            "kind": "LimitExceeded",
+            "message": "data exceeded the input size limit of 1024 bytes",
+            "source": null,
+        })
+    );
+}
+
+#[cfg(feature = "walk")]
+mod walk {
+    use std::path::Path;
+
+    use is_svg::{DirScanner, SvgEntry, SvgKind};
+    use serde_json::json;
+
+    #[test]
+    fn serialize_svg_entry() {
+        let entries = is_svg::scan_dir("tests/data/w3").unwrap();
+        let entry = entries
+            .iter()
+            .find(|entry| entry.kind() == SvgKind::Svgz)
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(entry).unwrap(),
+            json!({
+                "path": entry.path(),
+                "kind": "svgz",
+                "size": entry.size(),
+            })
+        );
+    }
+
+    #[test]
+    fn svg_entry_round_trip() {
+        for entry in is_svg::scan_dir("tests/data/w3").unwrap() {
+            let json = serde_json::to_string(&entry).unwrap();
+            assert_eq!(serde_json::from_str::<SvgEntry>(&json).unwrap(), entry);
+        }
+    }
+
+    #[test]
+    fn serialize_scan_report() {
+        let report = DirScanner::new("tests/data/w3")
+            .extensions(&["svg"])
+            .scan()
+            .unwrap();
+        let value = serde_json::to_value(&report).unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "entries": [{
+                    "path": Path::new("tests/data/w3").join("svg-logo-v.svg"),
+                    "kind": "svg",
+                    "size": include_bytes!("data/w3/svg-logo-v.svg").len(),
+                }],
+                "errors": [],
+            })
+        );
+    }
+}
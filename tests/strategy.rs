@@ -0,0 +1,53 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+use is_svg::strategy::{arb_non_svg, arb_svgz, arb_valid_svg};
+use proptest::{prelude::*, prop_oneof};
+
+fn arb_any() -> impl Strategy<Value = Vec<u8>> {
+    prop_oneof![
+        arb_valid_svg().prop_map(String::into_bytes),
+        arb_svgz(),
+        arb_non_svg(),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn valid_svg_is_svg_string(svg in arb_valid_svg()) {
+        prop_assert!(is_svg::is_svg_string(&svg));
+        prop_assert!(is_svg::is_svg_str(&svg));
+    }
+
+    #[test]
+    fn svgz_is_svgz(data in arb_svgz()) {
+        prop_assert!(is_svg::is_svgz(&data));
+        prop_assert!(is_svg::is_svg_string(is_svg::decompress_svgz(&data).unwrap()));
+    }
+
+    #[test]
+    fn non_svg_is_not_svg(data in arb_non_svg()) {
+        prop_assert!(!is_svg::is_svg(&data));
+    }
+
+    #[test]
+    fn is_svg_string_and_is_svgz_are_mutually_exclusive(data in arb_any()) {
+        prop_assert!(!(is_svg::is_svg_string(&data) && is_svg::is_svgz(&data)));
+    }
+
+    #[test]
+    fn is_svg_is_preserved_by_compression(data in arb_any()) {
+        prop_assume!(!is_svg::has_gzip_magic(&data));
+        prop_assert_eq!(is_svg::is_svg(common::compress(&data)), is_svg::is_svg(&data));
+    }
+
+    #[test]
+    fn compress_svg_round_trip(svg in arb_valid_svg()) {
+        let data = is_svg::compress_svg(&svg).unwrap();
+        prop_assert!(is_svg::is_svgz(&data));
+        prop_assert_eq!(is_svg::decompress_svgz(&data).unwrap(), svg);
+    }
+}
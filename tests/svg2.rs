@@ -0,0 +1,111 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+use is_svg::{Error, SvgKind, Validator, ValidatorBuilder};
+
+const MESHGRADIENT: &str = include_str!("data/svg2/meshgradient.svg");
+const HATCH: &str = include_str!("data/svg2/hatch.svg");
+const MADE_UP_ELEMENT: &str = include_str!("data/svg2/made-up-element.svg");
+
+const FIXTURES: [&str; 3] = [MESHGRADIENT, HATCH, MADE_UP_ELEMENT];
+
+#[test]
+fn is_svg_with_svg2_only_elements() {
+    for text in FIXTURES {
+        assert!(is_svg::is_svg(text), "{text}");
+        assert!(is_svg::check_svg(text).is_ok(), "{text}");
+        assert!(is_svg::parse_svg(text).is_ok(), "{text}");
+        for validator in [
+            Validator::lenient(),
+            Validator::new(),
+            Validator::strict(),
+            Validator::builder().fast(true).build(),
+            ValidatorBuilder::new().reject_empty(true).build(),
+            ValidatorBuilder::new()
+                .require_renderable_content(true)
+                .build(),
+        ] {
+            assert!(validator.check_svg(text).is_ok(), "{text}");
+        }
+        for chunk_size in [1, 64, text.len()] {
+            assert!(
+                matches!(
+                    common::check_chunked(text.as_bytes(), chunk_size),
+                    Ok(SvgKind::Svg)
+                ),
+                "{text}"
+            );
+        }
+    }
+}
+
+#[test]
+fn is_svg_with_unpainted_shape() {
+    // The shape which is only painted with the mesh gradient is not rendered.
+    let text = MESHGRADIENT.replace(r##" stroke="#000000""##, "");
+    assert!(is_svg::is_svg(&text));
+    assert!(ValidatorBuilder::new()
+        .reject_empty(true)
+        .build()
+        .is_svg(&text));
+    assert!(matches!(
+        ValidatorBuilder::new()
+            .require_renderable_content(true)
+            .build()
+            .check_svg(&text),
+        Err(Error::NothingToRender)
+    ));
+}
+
+#[test]
+fn uses_svg2_only_features() {
+    assert!(is_svg::uses_svg2_only_features(MESHGRADIENT));
+    assert!(is_svg::uses_svg2_only_features(HATCH));
+    assert!(is_svg::uses_svg2_only_features(
+        r#"<svg xmlns="http://www.w3.org/2000/svg"><solidcolor id="a" solid-color="red"/></svg>"#
+    ));
+    // The unknown element is not the feature of SVG 2.
+    assert!(!is_svg::uses_svg2_only_features(MADE_UP_ELEMENT));
+    assert!(!is_svg::uses_svg2_only_features(include_str!(
+        "data/w3/svg-logo-v.svg"
+    )));
+}
+
+#[test]
+fn uses_svg2_only_features_in_other_namespace() {
+    assert!(!is_svg::uses_svg2_only_features(
+        r#"<svg xmlns="http://www.w3.org/2000/svg"><x:hatch xmlns:x="https://example.com/"/></svg>"#
+    ));
+    assert!(!is_svg::uses_svg2_only_features(
+        "<svg><meshgradient/></svg>"
+    ));
+    assert!(is_svg::uses_svg2_only_features(
+        r#"<s:svg xmlns:s="http://www.w3.org/2000/svg"><s:meshgradient/></s:svg>"#
+    ));
+}
+
+#[test]
+fn uses_svg2_only_features_from_non_xml() {
+    assert!(!is_svg::uses_svg2_only_features(include_bytes!(
+        "data/w3/svg-logo-v.png"
+    )));
+    assert!(!is_svg::uses_svg2_only_features("<hatch"));
+    assert!(!is_svg::uses_svg2_only_features([]));
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn uses_svg2_only_features_from_svgz() {
+    for text in FIXTURES {
+        let data = is_svg::compress_svg(text).unwrap();
+        assert!(is_svg::is_svgz(&data), "{text}");
+        assert_eq!(
+            is_svg::uses_svg2_only_features(&data),
+            is_svg::uses_svg2_only_features(text),
+            "{text}"
+        );
+    }
+}
@@ -0,0 +1,168 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+use std::fs;
+
+use is_svg::{Error, Limit, SvgChecker, SvgKind};
+
+#[test]
+fn svg_checker_matches_slice_api() {
+    let paths = common::fixtures_in(&["ferris", "resources", "usvg", "w3", "wikipedia"]);
+    assert!(!paths.is_empty());
+    for path in paths {
+        let data = fs::read(&path).unwrap();
+        for chunk_size in [1, 64, data.len().max(1)] {
+            assert_eq!(
+                common::check_chunked(&data, chunk_size).ok(),
+                is_svg::detect(&data),
+                "{}",
+                path.display()
+            );
+        }
+    }
+}
+
+#[test]
+fn svg_checker_from_empty() {
+    assert!(matches!(SvgChecker::new().finish(), Err(Error::Empty)));
+}
+
+#[test]
+fn svg_checker_rejects_early() {
+    let mut checker = SvgChecker::new();
+    checker.push(&include_bytes!("data/w3/svg-logo-v.png")[..1]);
+    assert!(checker.is_rejected());
+    checker.push(&include_bytes!("data/w3/svg-logo-v.png")[1..]);
+    assert!(matches!(checker.finish(), Err(Error::NotUtf8(_))));
+
+    let mut checker = SvgChecker::new();
+    checker.push(b" \t\r\n");
+    assert!(!checker.is_rejected());
+    checker.push(b"hello");
+    assert!(checker.is_rejected());
+    assert!(matches!(checker.finish(), Err(Error::NotXml { .. })));
+
+    let mut checker = SvgChecker::new();
+    checker.push(b"\xef\xbb");
+    assert!(!checker.is_rejected());
+    checker.push(b"\xbf<svg");
+    assert!(!checker.is_rejected());
+
+    let mut checker = SvgChecker::new();
+    checker.push(&common::compress(b"hello")[..]);
+    assert!(checker.is_rejected());
+    assert!(matches!(checker.finish(), Err(Error::NotXml { .. })));
+}
+
+#[test]
+fn svg_checker_does_not_reject_valid_data_early() {
+    let data = include_bytes!("data/w3/svg-logo-v.svg");
+    let mut checker = SvgChecker::new();
+    for chunk in data.chunks(1) {
+        checker.push(chunk);
+        assert!(!checker.is_rejected());
+    }
+    assert_eq!(checker.finish().unwrap(), SvgKind::Svg);
+}
+
+#[test]
+fn svg_checker_does_not_reject_utf16_early() {
+    for data in [
+        &include_bytes!("data/encoding/utf-16le.svg")[..],
+        include_bytes!("data/encoding/utf-16be.svg"),
+    ] {
+        let mut checker = SvgChecker::new();
+        for chunk in data.chunks(1) {
+            checker.push(chunk);
+            assert!(!checker.is_rejected());
+        }
+        assert_eq!(checker.finish().unwrap(), SvgKind::Svg);
+    }
+}
+
+#[test]
+fn svg_checker_with_max_size() {
+    let data = include_bytes!("data/w3/svg-logo-v.svg");
+    let len = u64::try_from(data.len()).unwrap();
+
+    let mut checker = SvgChecker::new().max_size(len);
+    checker.push(data);
+    assert_eq!(checker.finish().unwrap(), SvgKind::Svg);
+
+    let mut checker = SvgChecker::new().max_size(len - 1);
+    for chunk in data.chunks(64) {
+        checker.push(chunk);
+    }
+    assert!(checker.is_rejected());
+    assert!(matches!(
+        checker.finish(),
+        Err(Error::LimitExceeded(Limit::InputSize(limit))) if limit == len - 1
+    ));
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn svg_checker_with_max_decompressed_size() {
+    let data = common::compress(&vec![b' '; 1024 * 1024]);
+    let mut checker = SvgChecker::new().max_size(64 * 1024);
+    checker.push(&data);
+    assert!(checker.is_rejected());
+    assert!(matches!(
+        checker.finish(),
+        Err(Error::LimitExceeded(Limit::DecompressedSize(65536)))
+    ));
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn svg_checker_from_truncated_svgz() {
+    let data = include_bytes!("data/w3/svg-logo-v.svgz");
+    let mut checker = SvgChecker::new();
+    checker.push(&data[..data.len() / 2]);
+    assert!(matches!(checker.finish(), Err(Error::GzipDecode(_))));
+    // The truncated trailer is not reported as the one which does not match,
+    // as well as the slice API.
+    let mut checker = SvgChecker::new();
+    checker.push(&data[..data.len() - 4]);
+    assert!(matches!(checker.finish(), Err(Error::GzipDecode(_))));
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn svg_checker_with_multiple_members() {
+    let svg = include_bytes!("data/w3/svg-logo-v.svg");
+    let (first, second) = svg.split_at(svg.len() / 2);
+    let halves = [common::compress(first), common::compress(second)].concat();
+    let twice = [common::compress(svg), common::compress(svg)].concat();
+    let trailing = [&common::compress(svg)[..], &[0; 16]].concat();
+    for chunk_size in [1, 64, halves.len()] {
+        // All the members are decompressed, rather than only the first one.
+        assert_eq!(
+            common::check_chunked(&halves, chunk_size).unwrap(),
+            SvgKind::Svgz
+        );
+        assert!(matches!(
+            common::check_chunked(&twice, chunk_size),
+            Err(Error::NotXml { .. })
+        ));
+        assert!(matches!(
+            common::check_chunked(&trailing, chunk_size),
+            Err(Error::GzipDecode(_))
+        ));
+    }
+    for data in [halves, twice, trailing] {
+        assert_eq!(common::check_chunked(&data, 64).ok(), is_svg::detect(&data));
+    }
+
+    // The limit applies to the concatenation of the members.
+    let member = common::compress(&vec![b' '; 48 * 1024]);
+    let mut checker = SvgChecker::new().max_size(64 * 1024);
+    checker.push(&[member.clone(), member].concat());
+    assert!(matches!(
+        checker.finish(),
+        Err(Error::LimitExceeded(Limit::DecompressedSize(65536)))
+    ));
+}
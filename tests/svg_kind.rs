@@ -0,0 +1,130 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use is_svg::{CompressionKind, Error, KindFilter, SvgKind};
+
+#[test]
+fn display() {
+    assert_eq!(SvgKind::Svg.to_string(), "svg");
+    assert_eq!(SvgKind::Svgz.to_string(), "svgz");
+}
+
+#[test]
+fn compression() {
+    assert_eq!(SvgKind::Svg.compression(), CompressionKind::None);
+    assert_eq!(SvgKind::Svgz.compression(), CompressionKind::Gzip);
+}
+
+#[test]
+fn from_str() {
+    assert_eq!("svg".parse(), Ok(SvgKind::Svg));
+    assert_eq!("svgz".parse(), Ok(SvgKind::Svgz));
+}
+
+#[test]
+fn from_str_case_insensitive() {
+    assert_eq!("SVG".parse(), Ok(SvgKind::Svg));
+    assert_eq!("Svg".parse(), Ok(SvgKind::Svg));
+    assert_eq!("SVGZ".parse(), Ok(SvgKind::Svgz));
+    assert_eq!("sVgZ".parse(), Ok(SvgKind::Svgz));
+}
+
+#[test]
+fn from_str_mime_type() {
+    assert_eq!("image/svg+xml".parse(), Ok(SvgKind::Svg));
+    assert_eq!("IMAGE/SVG+XML".parse(), Ok(SvgKind::Svg));
+}
+
+#[test]
+fn from_str_round_trip() {
+    for kind in [SvgKind::Svg, SvgKind::Svgz] {
+        assert_eq!(kind.to_string().parse(), Ok(kind));
+        assert_eq!(kind.extension().parse(), Ok(kind));
+    }
+}
+
+#[test]
+fn from_str_with_unknown_string() {
+    for s in [
+        "",
+        "png",
+        ".svg",
+        "svg ",
+        " svg",
+        "svg.gz",
+        "svgzz",
+        "image/svg",
+        "image/svg+xml; charset=utf-8",
+        "image/svg+xml+gzip",
+    ] {
+        assert!(s.parse::<SvgKind>().is_err());
+    }
+}
+
+#[test]
+fn parse_svg_kind_error_display() {
+    assert_eq!(
+        "png".parse::<SvgKind>().unwrap_err().to_string(),
+        "string was not a valid SVG kind"
+    );
+}
+
+#[test]
+fn try_from_svg() {
+    assert_eq!(
+        SvgKind::try_from(&include_bytes!("data/w3/svg-logo-v.svg")[..]).unwrap(),
+        SvgKind::Svg
+    );
+    assert_eq!(
+        SvgKind::try_from(&include_bytes!("data/ferris/corro.svg")[..]).unwrap(),
+        SvgKind::Svg
+    );
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn try_from_svgz() {
+    assert_eq!(
+        SvgKind::try_from(&include_bytes!("data/w3/svg-logo-v.svgz")[..]).unwrap(),
+        SvgKind::Svgz
+    );
+    assert_eq!(
+        SvgKind::try_from(&include_bytes!("data/ferris/corro.svgz")[..]).unwrap(),
+        SvgKind::Svgz
+    );
+}
+
+#[test]
+fn try_from_non_svg() {
+    assert!(matches!(
+        SvgKind::try_from(&include_bytes!("data/w3/svg-logo-v.png")[..]),
+        Err(Error::NotUtf8(_))
+    ));
+    #[cfg(feature = "gzip")]
+    assert!(matches!(
+        SvgKind::try_from(&include_bytes!("data/resources/empty.gz")[..]),
+        Err(Error::Empty)
+    ));
+}
+
+#[test]
+fn kind_filter_default() {
+    assert_eq!(KindFilter::default(), KindFilter::Any);
+}
+
+#[test]
+fn kind_filter_matches() {
+    assert!(KindFilter::Any.matches(SvgKind::Svg));
+    assert!(KindFilter::Any.matches(SvgKind::Svgz));
+    assert!(KindFilter::Svg.matches(SvgKind::Svg));
+    assert!(!KindFilter::Svg.matches(SvgKind::Svgz));
+    assert!(!KindFilter::Svgz.matches(SvgKind::Svg));
+    assert!(KindFilter::Svgz.matches(SvgKind::Svgz));
+}
+
+#[test]
+fn kind_filter_from_svg_kind() {
+    assert_eq!(KindFilter::from(SvgKind::Svg), KindFilter::Svg);
+    assert_eq!(KindFilter::from(SvgKind::Svgz), KindFilter::Svgz);
+}
@@ -0,0 +1,131 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use is_svg::{Error, Options, Validator, ValidatorBuilder};
+
+const MULTILINGUAL: &str = include_str!("data/switch/multilingual.svg");
+const EVERY_BRANCH_FAILS: &str = include_str!("data/switch/every-branch-fails.svg");
+
+/// The document which only has the content to render in French.
+const FRENCH_ONLY: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" width="24" height="24">
+  <switch>
+    <rect systemLanguage="fr" width="24" height="24"/>
+    <g systemLanguage="en"/>
+  </switch>
+</svg>"#;
+
+fn language_lists() -> Vec<Vec<String>> {
+    [
+        &["en"][..],
+        &["en-US"],
+        &["fr"],
+        &["fr-CA"],
+        &["de", "en"],
+        &["ja"],
+        &["zh-Hant-TW"],
+        &["x-none"],
+        &[],
+    ]
+    .iter()
+    .map(|languages| languages.iter().map(|&language| language.into()).collect())
+    .collect()
+}
+
+fn presets() -> [ValidatorBuilder; 4] {
+    [
+        ValidatorBuilder::lenient(),
+        ValidatorBuilder::new(),
+        ValidatorBuilder::strict(),
+        ValidatorBuilder::new().fast(true),
+    ]
+}
+
+#[test]
+fn is_svg_with_switch() {
+    for text in [MULTILINGUAL, EVERY_BRANCH_FAILS] {
+        assert!(is_svg::is_svg(text), "{text}");
+        assert!(is_svg::check_svg(text).is_ok(), "{text}");
+        for validator in [Validator::lenient(), Validator::new(), Validator::strict()] {
+            assert!(validator.is_svg(text), "{text}");
+        }
+    }
+}
+
+#[test]
+fn languages() {
+    for languages in language_lists() {
+        for builder in presets() {
+            let validator = builder.languages(languages.clone()).build();
+            assert!(validator.is_svg(MULTILINGUAL), "{languages:?}");
+            assert!(validator.is_svg(EVERY_BRANCH_FAILS), "{languages:?}");
+            assert!(validator.is_svg(FRENCH_ONLY), "{languages:?}");
+        }
+        // The fallback is rendered for every language.
+        let validator = ValidatorBuilder::new()
+            .reject_empty(true)
+            .require_renderable_content(true)
+            .languages(languages.clone())
+            .build();
+        assert!(validator.is_svg(MULTILINGUAL), "{languages:?}");
+    }
+}
+
+#[test]
+fn languages_for_rendered_content() {
+    let validator = |languages: &[&str]| {
+        ValidatorBuilder::new()
+            .require_renderable_content(true)
+            .languages(languages.iter().map(|&language| language.into()).collect())
+            .build()
+    };
+    assert!(validator(&["fr"]).is_svg(FRENCH_ONLY));
+    assert!(validator(&["de", "fr"]).is_svg(FRENCH_ONLY));
+    for languages in [&["en"][..], &["ja"], &[]] {
+        assert!(matches!(
+            validator(languages).check_svg(FRENCH_ONLY),
+            Err(Error::NothingToRender)
+        ));
+    }
+    // The default is English.
+    assert!(!ValidatorBuilder::new()
+        .require_renderable_content(true)
+        .build()
+        .is_svg(FRENCH_ONLY));
+
+    // Nothing is rendered if every branch fails the conditions.
+    for languages in language_lists() {
+        let validator = ValidatorBuilder::new()
+            .require_renderable_content(true)
+            .languages(languages.clone())
+            .build();
+        assert!(
+            matches!(
+                validator.check_svg(EVERY_BRANCH_FAILS),
+                Err(Error::NothingToRender)
+            ),
+            "{languages:?}"
+        );
+    }
+}
+
+#[test]
+fn languages_overrides_options() {
+    let validator = ValidatorBuilder::new()
+        .require_renderable_content(true)
+        .options(Options {
+            languages: vec!["en".into()],
+            ..Default::default()
+        })
+        .languages(vec!["fr".into()])
+        .build();
+    assert!(validator.is_svg(FRENCH_ONLY));
+
+    // The options override the languages set before.
+    let validator = ValidatorBuilder::new()
+        .require_renderable_content(true)
+        .languages(vec!["fr".into()])
+        .options(Options::default())
+        .build();
+    assert!(!validator.is_svg(FRENCH_ONLY));
+}
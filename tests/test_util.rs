@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use is_svg::{
+    test_util::{self, DOCTYPE_ONLY_SVG, EMPTY_GZIP, SAMPLE_PNG, SAMPLE_SVG, UNCLOSED_SVG},
+    usvg::{roxmltree, Size},
+    Error, SvgKind,
+};
+
+#[test]
+fn sample_svg() {
+    assert_eq!(is_svg::detect(SAMPLE_SVG), Some(SvgKind::Svg));
+    assert_eq!(
+        is_svg::parse_svg(SAMPLE_SVG).unwrap().size(),
+        Size::from_wh(16.0, 16.0).unwrap()
+    );
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn sample_svgz() {
+    let svgz = test_util::SAMPLE_SVGZ;
+    assert_eq!(is_svg::detect(svgz), Some(SvgKind::Svgz));
+    assert_eq!(is_svg::decompress_svgz(svgz).unwrap(), SAMPLE_SVG);
+}
+
+#[test]
+fn sample_png() {
+    assert!(SAMPLE_PNG.starts_with(&include_bytes!("data/w3/svg-logo-v.png")[..8]));
+    assert!(matches!(
+        is_svg::check_svg(SAMPLE_PNG),
+        Err(Error::NotUtf8(_))
+    ));
+    assert!(matches!(
+        is_svg::check_svg(include_bytes!("data/w3/svg-logo-v.png")),
+        Err(Error::NotUtf8(_))
+    ));
+}
+
+#[test]
+fn unclosed_svg() {
+    assert_eq!(UNCLOSED_SVG, include_str!("data/resources/unclosed.svg"));
+    assert!(matches!(
+        is_svg::check_svg(UNCLOSED_SVG),
+        Err(Error::NotXml {
+            source: roxmltree::Error::UnclosedRootNode,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn doctype_only_svg() {
+    assert_eq!(DOCTYPE_ONLY_SVG, include_str!("data/resources/dtd.svg"));
+    assert!(matches!(
+        is_svg::check_svg(DOCTYPE_ONLY_SVG),
+        Err(Error::NotXml {
+            source: roxmltree::Error::NoRootNode,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn empty_gzip() {
+    assert!(is_svg::has_gzip_magic(EMPTY_GZIP));
+    #[cfg(feature = "gzip")]
+    assert!(matches!(is_svg::check_svg(EMPTY_GZIP), Err(Error::Empty)));
+    #[cfg(feature = "gzip")]
+    assert!(matches!(
+        is_svg::check_svg(include_bytes!("data/resources/empty.gz")),
+        Err(Error::Empty)
+    ));
+}
+
+#[test]
+fn minimal_svg() {
+    for (width, height) in [(1, 1), (100, 50), (4096, 4096)] {
+        let svg = test_util::minimal_svg(width, height);
+        assert_eq!(is_svg::detect(&svg), Some(SvgKind::Svg));
+        #[allow(clippy::cast_precision_loss)]
+        let size = Size::from_wh(width as f32, height as f32).unwrap();
+        assert_eq!(is_svg::parse_svg(&svg).unwrap().size(), size);
+    }
+}
+
+#[test]
+fn minimal_svg_with_zero_size() {
+    assert!(!is_svg::is_svg(test_util::minimal_svg(0, 100)));
+    assert!(!is_svg::is_svg(test_util::minimal_svg(100, 0)));
+}
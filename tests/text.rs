@@ -0,0 +1,59 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::fs;
+
+use is_svg::usvg::{Group, Node};
+
+fn text_fixtures() -> Vec<String> {
+    let mut texts = Vec::new();
+    for entry in fs::read_dir("tests/data/usvg").unwrap() {
+        let path = entry.unwrap().path();
+        if path.extension().map_or(false, |ext| ext == "svg") {
+            let text = fs::read_to_string(&path).unwrap();
+            if text.contains("<text") {
+                texts.push(text);
+            }
+        }
+    }
+    texts
+}
+
+fn count_text(group: &Group) -> usize {
+    group
+        .children()
+        .iter()
+        .map(|node| match node {
+            Node::Group(group) => count_text(group),
+            Node::Text(_) => 1,
+            _ => 0,
+        })
+        .sum()
+}
+
+#[test]
+fn is_svg_from_svg_with_text() {
+    let texts = text_fixtures();
+    assert!(!texts.is_empty());
+    for text in texts {
+        assert!(is_svg::is_svg(&text), "{text}");
+        assert!(is_svg::is_svg_string(&text), "{text}");
+    }
+}
+
+#[test]
+fn parse_svg_from_svg_with_text() {
+    // Without the `text` feature, the text elements are skipped. With it, the
+    // text elements are dropped since no fonts are loaded by default.
+    for text in text_fixtures() {
+        let tree = is_svg::parse_svg(&text).unwrap();
+        assert_eq!(count_text(tree.root()), 0, "{text}");
+    }
+}
+
+#[cfg(feature = "text")]
+#[test]
+fn no_fonts_are_loaded_by_default() {
+    assert!(is_svg::Options::default().fontdb.is_empty());
+}
@@ -0,0 +1,91 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+use std::{
+    fs,
+    io::{self, Cursor, Read},
+};
+
+use is_svg::{Error, Limit, SvgKind, ValidatingReader};
+
+/// A reader which yields the data at most `N` bytes at a time.
+struct ChunkReader<'a, const N: usize>(&'a [u8]);
+
+impl<const N: usize> Read for ChunkReader<'_, N> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.0.len().min(buf.len()).min(N);
+        let (chunk, rest) = self.0.split_at(n);
+        buf[..n].copy_from_slice(chunk);
+        self.0 = rest;
+        Ok(n)
+    }
+}
+
+fn copy_through(reader: impl Read) -> (Vec<u8>, Result<SvgKind, Error>) {
+    let mut reader = ValidatingReader::new(reader);
+    let mut buf = Vec::new();
+    io::copy(&mut reader, &mut buf).unwrap();
+    (buf, reader.verdict())
+}
+
+#[test]
+fn validating_reader_matches_is_svg() {
+    let paths = common::fixtures_in(&["ferris", "resources", "w3", "wikipedia"]);
+    assert!(!paths.is_empty());
+    for path in paths {
+        let data = fs::read(&path).unwrap();
+        for (buf, verdict) in [
+            copy_through(Cursor::new(&data)),
+            copy_through(ChunkReader::<1>(&data)),
+            copy_through(ChunkReader::<7>(&data)),
+            copy_through(ChunkReader::<64>(&data)),
+        ] {
+            assert_eq!(buf, data, "{}", path.display());
+            assert_eq!(verdict.ok(), is_svg::detect(&data), "{}", path.display());
+        }
+    }
+}
+
+#[test]
+fn validating_reader_from_svg() {
+    let (_, verdict) = copy_through(Cursor::new(include_str!("data/w3/svg-logo-v.svg")));
+    assert_eq!(verdict.unwrap(), SvgKind::Svg);
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn validating_reader_from_svgz() {
+    let (_, verdict) = copy_through(ChunkReader::<3>(include_bytes!("data/w3/svg-logo-v.svgz")));
+    assert_eq!(verdict.unwrap(), SvgKind::Svgz);
+}
+
+#[test]
+fn validating_reader_with_max_size() {
+    let data = include_bytes!("data/w3/svg-logo-v.svg");
+    let len = u64::try_from(data.len()).unwrap();
+
+    let mut reader = ValidatingReader::new(Cursor::new(data)).max_size(len);
+    io::copy(&mut reader, &mut io::sink()).unwrap();
+    assert_eq!(reader.verdict().unwrap(), SvgKind::Svg);
+
+    let mut reader = ValidatingReader::new(Cursor::new(data)).max_size(len - 1);
+    let mut buf = Vec::new();
+    io::copy(&mut reader, &mut buf).unwrap();
+    assert_eq!(buf, data);
+    assert!(matches!(
+        reader.verdict(),
+        Err(Error::LimitExceeded(Limit::InputSize(limit))) if limit == len - 1
+    ));
+}
+
+#[test]
+fn validating_reader_before_end() {
+    let mut reader = ValidatingReader::new(Cursor::new(include_str!("data/w3/svg-logo-v.svg")));
+    let mut buf = [u8::default(); 64];
+    reader.read_exact(&mut buf).unwrap();
+    assert_eq!(reader.get_ref().position(), 64);
+    assert!(reader.verdict().is_err());
+}
@@ -0,0 +1,1007 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::{
+    sync::Arc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use is_svg::{
+    usvg::roxmltree, CompressionKind, Error, ErrorKind, Limit, Options, SvgKind, Validator,
+    ValidatorBuilder,
+};
+
+#[test]
+fn is_svg() {
+    let validator = Validator::new();
+    assert!(validator.is_svg(include_str!("data/ferris/corro.svg")));
+    #[cfg(feature = "gzip")]
+    assert!(validator.is_svg(include_bytes!("data/ferris/corro.svgz")));
+    assert!(validator.is_svg(include_str!("data/w3/svg-logo-v.svg")));
+    #[cfg(feature = "gzip")]
+    assert!(validator.is_svg(include_bytes!("data/w3/svg-logo-v.svgz")));
+    assert!(!validator.is_svg(include_str!("data/resources/unclosed.svg")));
+    assert!(!validator.is_svg(include_bytes!("data/w3/svg-logo-v.png")));
+}
+
+#[test]
+fn is_svg_string() {
+    let validator = Validator::new();
+    assert!(validator.is_svg_string(include_str!("data/w3/svg-logo-v.svg")));
+    assert!(!validator.is_svg_string(include_bytes!("data/w3/svg-logo-v.svgz")));
+    assert!(!validator.is_svg_string(include_bytes!("data/w3/svg-logo-v.png")));
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn is_svgz() {
+    let validator = Validator::new();
+    assert!(!validator.is_svgz(include_str!("data/w3/svg-logo-v.svg")));
+    assert!(validator.is_svgz(include_bytes!("data/w3/svg-logo-v.svgz")));
+    assert!(!validator.is_svgz(include_bytes!("data/w3/svg-logo-v.png")));
+}
+
+#[test]
+fn detect() {
+    let validator = Validator::new();
+    assert_eq!(
+        validator.detect(include_str!("data/w3/svg-logo-v.svg")),
+        Some(SvgKind::Svg)
+    );
+    #[cfg(feature = "gzip")]
+    assert_eq!(
+        validator.detect(include_bytes!("data/w3/svg-logo-v.svgz")),
+        Some(SvgKind::Svgz)
+    );
+    assert_eq!(
+        validator.detect(include_bytes!("data/w3/svg-logo-v.png")),
+        None
+    );
+}
+
+#[test]
+fn check_svg() {
+    let validator = Validator::new();
+    assert!(validator
+        .check_svg(include_str!("data/w3/svg-logo-v.svg"))
+        .is_ok());
+    assert!(matches!(
+        validator.check_svg(include_str!("data/resources/unclosed.svg")),
+        Err(Error::NotXml { .. })
+    ));
+    assert!(matches!(
+        validator.check_svg(include_bytes!("data/w3/svg-logo-v.png")),
+        Err(Error::NotUtf8(_))
+    ));
+}
+
+#[test]
+fn max_size() {
+    let svg = include_str!("data/w3/svg-logo-v.svg");
+    #[cfg(feature = "gzip")]
+    let svgz = include_bytes!("data/w3/svg-logo-v.svgz");
+
+    let validator = Validator::builder().max_size(svg.len() as u64).build();
+    assert!(validator.is_svg(svg));
+    #[cfg(feature = "gzip")]
+    assert!(validator.is_svg(svgz));
+
+    let validator = Validator::builder().max_size(svg.len() as u64 - 1).build();
+    assert!(matches!(
+        validator.check_svg(svg),
+        Err(Error::LimitExceeded(Limit::InputSize(limit))) if limit == svg.len() as u64 - 1
+    ));
+    #[cfg(feature = "gzip")]
+    assert!(validator.is_svg(svgz));
+
+    #[cfg(feature = "gzip")]
+    let validator = Validator::builder().max_size(svgz.len() as u64 - 1).build();
+    #[cfg(feature = "gzip")]
+    assert!(!validator.is_svg(svgz));
+}
+
+#[test]
+fn accepted_compressions() {
+    let svg = include_str!("data/w3/svg-logo-v.svg");
+    let svgz = include_bytes!("data/w3/svg-logo-v.svgz");
+    let zst = include_bytes!("data/w3/svg-logo-v.svg.zst");
+    let lz = include_bytes!("data/w3/svg-logo-v.svg.lz");
+
+    let validator = Validator::new();
+    assert_eq!(
+        validator.detect(svg).map(SvgKind::compression),
+        Some(CompressionKind::None)
+    );
+    #[cfg(feature = "gzip")]
+    assert_eq!(
+        validator.detect(svgz).map(SvgKind::compression),
+        Some(CompressionKind::Gzip)
+    );
+    assert!(matches!(
+        validator.check_svg(zst),
+        Err(Error::UnsupportedCompression(CompressionKind::Zstd))
+    ));
+    assert!(matches!(
+        validator.check_svg(lz),
+        Err(Error::UnsupportedCompression(CompressionKind::Lzip))
+    ));
+    // The data which is not compressed is not rejected by this.
+    assert_eq!(
+        validator
+            .check_svg(include_bytes!("data/w3/svg-logo-v.png"))
+            .unwrap_err()
+            .kind(),
+        ErrorKind::NotUtf8
+    );
+
+    // Only the data which is not compressed.
+    let validator = Validator::builder()
+        .accepted_compressions(&[CompressionKind::None])
+        .build();
+    assert!(validator.is_svg(svg));
+    let err = validator.check_svg(svgz).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnsupportedCompression);
+    assert!(matches!(
+        err,
+        Error::UnsupportedCompression(CompressionKind::Gzip)
+    ));
+    assert!(!validator.is_svg(zst));
+
+    // Only the gzip-compressed data.
+    let validator = Validator::builder()
+        .accepted_compressions(&[CompressionKind::Gzip])
+        .build();
+    assert!(matches!(
+        validator.check_svg(svg),
+        Err(Error::UnsupportedCompression(CompressionKind::None))
+    ));
+    #[cfg(feature = "gzip")]
+    assert!(validator.is_svgz(svgz));
+
+    // The formats which are not supported are always rejected.
+    let validator = Validator::builder()
+        .accepted_compressions(&[
+            CompressionKind::None,
+            CompressionKind::Gzip,
+            CompressionKind::Zstd,
+            CompressionKind::Xz,
+            CompressionKind::Lzip,
+        ])
+        .build();
+    assert!(validator.is_svg(svg));
+    assert!(matches!(
+        validator.check_svg(zst),
+        Err(Error::UnsupportedCompression(CompressionKind::Zstd))
+    ));
+    assert!(matches!(
+        validator.check_svg(lz),
+        Err(Error::UnsupportedCompression(CompressionKind::Lzip))
+    ));
+
+    // Nothing is accepted.
+    let validator = Validator::builder().accepted_compressions(&[]).build();
+    assert!(!validator.is_svg(svg));
+    assert!(!validator.is_svg(svgz));
+}
+
+#[test]
+fn max_depth() {
+    let nested = |depth: usize| {
+        format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16">{}{}</svg>"#,
+            "<g>".repeat(depth - 1),
+            "</g>".repeat(depth - 1)
+        )
+    };
+    let svg = nested(200);
+    assert!(Validator::new().is_svg(&svg));
+    assert!(Validator::builder().max_depth(200).build().is_svg(&svg));
+    assert!(matches!(
+        Validator::builder().max_depth(199).build().check_svg(&svg),
+        Err(Error::LimitExceeded(Limit::Depth(199)))
+    ));
+
+    let svg = nested(5000);
+    assert!(matches!(
+        Validator::builder()
+            .max_depth(usize::MAX)
+            .build()
+            .check_svg(&svg),
+        Err(Error::LimitExceeded(Limit::Depth(4096)))
+    ));
+}
+
+#[test]
+fn max_elements() {
+    // The root element, and `count - 1` elements including the comments which
+    // are not counted.
+    let svg = |count: usize| {
+        format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16"><!-- <rect/> -->{}</svg>"#,
+            r#"<rect width="1" height="1"/>"#.repeat(count - 1)
+        )
+    };
+    assert!(Validator::new().is_svg(svg(10_000)));
+    assert!(Validator::builder()
+        .max_elements(1000)
+        .build()
+        .is_svg(svg(1000)));
+    assert!(matches!(
+        Validator::builder()
+            .max_elements(1000)
+            .build()
+            .check_svg(svg(1001)),
+        Err(Error::LimitExceeded(Limit::Elements(1000)))
+    ));
+    assert!(matches!(
+        Validator::builder()
+            .max_elements(1000)
+            .build()
+            .check_svg(svg(100_000)),
+        Err(Error::LimitExceeded(Limit::Elements(1000)))
+    ));
+}
+
+#[test]
+fn max_attribute_len() {
+    let svg = |len: usize| {
+        format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16"><path d="M0 0{}Z"/></svg>"#,
+            "h1".repeat(len / 2)
+        )
+    };
+    assert!(Validator::new().is_svg(svg(64 * 1024)));
+    assert!(Validator::builder()
+        .max_attribute_len(1024)
+        .build()
+        .is_svg(svg(1000)));
+    assert!(matches!(
+        Validator::builder()
+            .max_attribute_len(1024)
+            .build()
+            .check_svg(svg(1024)),
+        Err(Error::LimitExceeded(Limit::AttributeLength(1024)))
+    ));
+    assert!(matches!(
+        Validator::builder()
+            .max_attribute_len(1024)
+            .build()
+            .check_svg(format!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" d='{}'"#,
+                "h1".repeat(1024)
+            )),
+        Err(Error::LimitExceeded(Limit::AttributeLength(1024)))
+    ));
+
+    let start = Instant::now();
+    assert!(matches!(
+        Validator::builder()
+            .max_attribute_len(1024 * 1024)
+            .build()
+            .check_svg(svg(50 * 1024 * 1024)),
+        Err(Error::LimitExceeded(Limit::AttributeLength(_)))
+    ));
+    assert!(start.elapsed() < Duration::from_secs(10));
+}
+
+#[test]
+fn max_entity_depth() {
+    let svg = include_str!("data/hostile/entity-nesting.svg");
+    assert!(Validator::new().is_svg(svg));
+    assert!(Validator::builder().max_entity_depth(9).build().is_svg(svg));
+    assert!(Validator::builder()
+        .max_entity_depth(u32::MAX)
+        .build()
+        .is_svg(svg));
+    assert!(matches!(
+        Validator::builder()
+            .max_entity_depth(8)
+            .build()
+            .check_svg(svg),
+        Err(Error::LimitExceeded(Limit::EntityDepth(8)))
+    ));
+    assert!(matches!(
+        Validator::builder()
+            .max_entity_depth(0)
+            .build()
+            .check_svg(svg),
+        Err(Error::LimitExceeded(Limit::EntityDepth(0)))
+    ));
+}
+
+#[test]
+fn max_entity_expansion() {
+    let svg = r#"<!DOCTYPE svg [<!ENTITY a "0123456789"><!ENTITY b "&a;&a;">]><svg xmlns="http://www.w3.org/2000/svg" width="16" height="16"><desc>&b;&a;</desc><!-- &b; --></svg>"#;
+    assert!(Validator::builder()
+        .max_entity_expansion(30)
+        .build()
+        .is_svg(svg));
+    assert!(matches!(
+        Validator::builder()
+            .max_entity_expansion(29)
+            .build()
+            .check_svg(svg),
+        Err(Error::LimitExceeded(Limit::EntityExpansion(29)))
+    ));
+    assert!(matches!(
+        Validator::builder()
+            .max_entity_expansion(19)
+            .build()
+            .check_svg(svg),
+        Err(Error::LimitExceeded(Limit::EntityExpansion(19)))
+    ));
+}
+
+#[test]
+fn allow_dtd() {
+    let external = r#"<!DOCTYPE svg PUBLIC "-//W3C//DTD SVG 1.1//EN" "http://www.w3.org/Graphics/SVG/1.1/DTD/svg11.dtd">
+<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16"/>"#;
+    let internal = r#"<!DOCTYPE svg [<!ENTITY size "16"><!ELEMENT svg ANY>]>
+<svg xmlns="http://www.w3.org/2000/svg" width="&size;" height="&size;"/>"#;
+
+    for validator in [
+        Validator::new(),
+        Validator::builder().allow_dtd(true).build(),
+    ] {
+        assert!(validator.is_svg(external));
+        assert!(validator.is_svg(internal));
+        // This does not have the root element.
+        assert!(!validator.is_svg(include_str!("data/resources/dtd.svg")));
+        assert!(matches!(
+            validator.check_svg(include_bytes!("data/hostile/billion-laughs.svg")),
+            Err(Error::LimitExceeded(Limit::EntityExpansion(_)))
+        ));
+    }
+
+    let validator = Validator::builder().allow_dtd(false).build();
+    for data in [
+        external,
+        internal,
+        include_str!("data/resources/dtd.svg"),
+        include_str!("data/hostile/billion-laughs.svg"),
+    ] {
+        assert!(matches!(
+            validator.check_svg(data),
+            Err(Error::NotXml {
+                source: roxmltree::Error::DtdDetected,
+                ..
+            })
+        ));
+    }
+    assert!(validator.is_svg(include_str!("data/w3/svg-logo-v.svg")));
+}
+
+#[test]
+fn require_svg_namespace() {
+    let prefixed = include_str!("data/namespace/prefixed.svg");
+    let unnamespaced = include_str!("data/namespace/unnamespaced.svg");
+    let wrong_namespace = include_str!("data/namespace/wrong-namespace.svg");
+
+    for validator in [
+        Validator::new(),
+        Validator::builder().require_svg_namespace(false).build(),
+    ] {
+        assert!(validator.is_svg(prefixed));
+        for data in [unnamespaced, wrong_namespace] {
+            assert_eq!(
+                validator.check_svg(data).unwrap_err().kind(),
+                ErrorKind::NotSvgRoot
+            );
+        }
+    }
+
+    let validator = Validator::builder().require_svg_namespace(true).build();
+    assert!(validator.is_svg(prefixed));
+    assert!(validator.is_svg(include_str!("data/w3/svg-logo-v.svg")));
+    #[cfg(feature = "gzip")]
+    assert!(validator.is_svg(include_bytes!("data/w3/svg-logo-v.svgz")));
+    for data in [unnamespaced, wrong_namespace] {
+        assert!(matches!(
+            validator.check_svg(data),
+            Err(Error::NotSvgRoot { .. })
+        ));
+    }
+    // The `svg` element in the SVG namespace which is not the root element.
+    assert!(matches!(
+        validator.check_svg(
+            r#"<html xmlns="http://www.w3.org/1999/xhtml"><svg xmlns="http://www.w3.org/2000/svg" width="16" height="16"/></html>"#
+        ),
+        Err(Error::NotSvgRoot { .. })
+    ));
+}
+
+#[test]
+fn allow_missing_namespace() {
+    let missing = [
+        include_str!("data/namespace/missing-namespace.svg"),
+        include_str!("data/namespace/unnamespaced.svg"),
+        r#"<svg width="16" height="16"/>"#,
+        "<svg\n\twidth='16'\n\theight='16'>\n</svg>",
+    ];
+    let declared = [
+        include_str!("data/namespace/prefixed.svg"),
+        include_str!("data/w3/svg-logo-v.svg"),
+        include_str!("data/ferris/corro.svg"),
+    ];
+    let conflicting = [
+        include_str!("data/namespace/conflicting-namespace.svg"),
+        include_str!("data/namespace/wrong-namespace.svg"),
+    ];
+
+    for validator in [
+        Validator::new(),
+        Validator::builder().allow_missing_namespace(false).build(),
+    ] {
+        for data in missing.iter().chain(&conflicting) {
+            assert_eq!(
+                validator.check_svg(data).unwrap_err().kind(),
+                ErrorKind::NotSvgRoot
+            );
+        }
+        assert!(declared.iter().all(|data| validator.is_svg(data)));
+    }
+
+    let validator = Validator::builder().allow_missing_namespace(true).build();
+    assert!(missing.iter().all(|data| validator.is_svg(data)));
+    assert!(declared.iter().all(|data| validator.is_svg(data)));
+    for data in conflicting {
+        assert_eq!(
+            validator.check_svg(data).unwrap_err().kind(),
+            ErrorKind::NotSvgRoot
+        );
+    }
+    // Only the unprefixed `svg` element is changed.
+    assert!(!validator.is_svg(r#"<html><svg width="16" height="16"/></html>"#));
+    assert!(!validator.is_svg(r#"<s:svg width="16" height="16"/>"#));
+    // The malformed root element is not changed.
+    assert!(matches!(
+        validator.check_svg(r#"<svg width="16 height="16"/>"#),
+        Err(Error::NotXml { .. })
+    ));
+    assert!(!validator.is_svg(include_str!("data/resources/unclosed.svg")));
+    #[cfg(feature = "gzip")]
+    assert!(validator.is_svg(is_svg::compress_svg(declared[1]).unwrap()));
+
+    let validator = Validator::builder()
+        .allow_missing_namespace(true)
+        .require_svg_namespace(true)
+        .build();
+    assert!(missing.iter().all(|data| validator.is_svg(data)));
+    for data in conflicting {
+        assert!(matches!(
+            validator.check_svg(data),
+            Err(Error::NotSvgRoot { .. })
+        ));
+    }
+}
+
+#[test]
+fn reject_empty() {
+    let empty = [
+        include_str!("data/empty/comments-only.svg"),
+        include_str!("data/empty/defs-only.svg"),
+        include_str!("data/empty/self-closing.svg"),
+        include_str!("data/empty/whitespace-only.svg"),
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="24" height="24"><g><g/></g><rect width="0" height="24"/></svg>"#,
+    ];
+    let non_empty = [
+        include_str!("data/ferris/corro.svg"),
+        include_str!("data/w3/svg-logo-v.svg"),
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="24" height="24"><rect width="1" height="1"/></svg>"#,
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="24" height="24"><defs><circle id="dot" r="4"/></defs><use href="#dot"/></svg>"##,
+    ];
+
+    for validator in [
+        Validator::new(),
+        Validator::builder().reject_empty(false).build(),
+    ] {
+        assert!(empty
+            .iter()
+            .chain(&non_empty)
+            .all(|data| validator.is_svg(data)));
+    }
+
+    let validator = Validator::builder().reject_empty(true).build();
+    for data in empty {
+        let err = validator.check_svg(data).unwrap_err();
+        assert!(matches!(err, Error::EmptyDocument));
+        assert_eq!(err.kind(), ErrorKind::EmptyDocument);
+    }
+    assert!(non_empty.iter().all(|data| validator.is_svg(data)));
+    // The invalid data is rejected as before.
+    assert!(matches!(
+        validator.check_svg(include_str!("data/resources/unclosed.svg")),
+        Err(Error::NotXml { .. })
+    ));
+}
+
+#[test]
+fn require_renderable_content() {
+    let invisible = [
+        include_str!("data/invisible/clipped.svg"),
+        include_str!("data/invisible/display-none.svg"),
+        include_str!("data/invisible/off-canvas.svg"),
+        include_str!("data/invisible/zero-opacity.svg"),
+        include_str!("data/invisible/zero-size.svg"),
+        include_str!("data/invisible/zero-view-box.svg"),
+    ];
+    let visible = [
+        include_str!("data/ferris/corro.svg"),
+        include_str!("data/w3/svg-logo-v.svg"),
+        include_str!("data/wikipedia/SVG_animation_using_SMIL.svg"),
+        // Partially outside the canvas.
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="24" height="24"><rect x="20" y="-4" width="8" height="8"/></svg>"#,
+        // Partially clipped away.
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="24" height="24"><clipPath id="c"><rect x="4" width="8" height="8"/></clipPath><rect width="8" height="8" clip-path="url(#c)"/></svg>"#,
+        // Only the stroke has an area.
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="24" height="24"><path d="M0 12H24" stroke="black"/></svg>"#,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="24" height="24" viewBox="0 0 12 12"><g opacity="0.5"><circle cx="6" cy="6" r="4"/></g></svg>"#,
+    ];
+
+    for validator in [
+        Validator::new(),
+        Validator::builder()
+            .require_renderable_content(false)
+            .build(),
+    ] {
+        assert!(invisible
+            .iter()
+            .chain(&visible)
+            .all(|data| validator.is_svg(data)));
+    }
+
+    let validator = Validator::builder()
+        .require_renderable_content(true)
+        .build();
+    for data in invisible
+        .iter()
+        .chain(&[include_str!("data/empty/defs-only.svg")])
+    {
+        let err = validator.check_svg(data).unwrap_err();
+        assert!(matches!(err, Error::NothingToRender));
+        assert_eq!(err.kind(), ErrorKind::NothingToRender);
+    }
+    for data in visible {
+        assert!(validator.is_svg(data));
+    }
+    #[cfg(feature = "gzip")]
+    assert!(validator.is_svg(include_bytes!("data/w3/svg-logo-v.svgz")));
+}
+
+#[test]
+fn allowed_elements() {
+    const ALLOWED: &[&str] = &[
+        "svg",
+        "g",
+        "defs",
+        "path",
+        "rect",
+        "circle",
+        "ellipse",
+        "line",
+        "polyline",
+        "polygon",
+        "linearGradient",
+        "radialGradient",
+        "stop",
+        "metadata",
+        "title",
+    ];
+    let clean = include_str!("data/policy/clean-icon.svg");
+    let script = include_str!("data/policy/script.svg");
+    let sodipodi = include_str!("data/policy/sodipodi.svg");
+
+    let validator = Validator::new();
+    assert!([clean, script, sodipodi]
+        .iter()
+        .all(|data| validator.is_svg(data)));
+
+    let validator = Validator::builder().allowed_elements(ALLOWED).build();
+    assert!(validator.is_svg(clean));
+    match validator.check_svg(script).unwrap_err() {
+        Error::DisallowedElement { name, location } => {
+            assert_eq!(name, "script");
+            assert_eq!(location.position, roxmltree::TextPos::new(4, 5));
+        }
+        err => panic!("unexpected error: {err:?}"),
+    }
+    assert_eq!(
+        validator.check_svg(script).unwrap_err().kind(),
+        ErrorKind::DisallowedElement
+    );
+    // The first element which is not allowed.
+    assert!(matches!(
+        validator.check_svg(sodipodi),
+        Err(Error::DisallowedElement { name, .. }) if name == "sodipodi:namedview"
+    ));
+    for (data, element) in [
+        (
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="24" height="24"><foreignObject width="24" height="24"><div xmlns="http://www.w3.org/1999/xhtml"/></foreignObject></svg>"#,
+            "foreignObject",
+        ),
+        (
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="24" height="24"><rect width="24" height="24"><animate attributeName="x" to="2"/></rect></svg>"#,
+            "animate",
+        ),
+        (
+            r#"<s:svg xmlns:s="http://www.w3.org/2000/svg" width="24" height="24"><s:image href="a.png"/></s:svg>"#,
+            "s:image",
+        ),
+    ] {
+        assert!(matches!(
+            validator.check_svg(data),
+            Err(Error::DisallowedElement { name, .. }) if name == element
+        ));
+    }
+    // The root element is also tested.
+    assert!(matches!(
+        Validator::builder()
+            .allowed_elements(&["rect"])
+            .build()
+            .check_svg(clean),
+        Err(Error::DisallowedElement { name, .. }) if name == "svg"
+    ));
+
+    let validator = Validator::builder()
+        .allowed_elements(ALLOWED)
+        .allow_foreign_elements(true)
+        .build();
+    assert!(validator.is_svg(clean));
+    assert!(validator.is_svg(sodipodi));
+    assert!(!validator.is_svg(script));
+    // The SVG elements in the foreign elements are still tested.
+    assert!(matches!(
+        validator.check_svg(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" xmlns:x="http://example.com/" width="24" height="24"><x:data><script>alert(1)</script></x:data></svg>"#
+        ),
+        Err(Error::DisallowedElement { name, .. }) if name == "script"
+    ));
+
+    // This has no effect without `allowed_elements`.
+    let validator = Validator::builder().allow_foreign_elements(false).build();
+    assert!(validator.is_svg(sodipodi));
+}
+
+#[test]
+fn deny_elements() {
+    let css = include_str!("data/wikipedia/SVG_animation_using_CSS.svg");
+    let es = include_str!("data/wikipedia/SVG_animation_using_ES.svg");
+    let smil = include_str!("data/wikipedia/SVG_animation_using_SMIL.svg");
+
+    let validator = Validator::builder().deny_elements(&["script"]).build();
+    assert!(validator.is_svg(css));
+    assert!(validator.is_svg(smil));
+    assert!(validator.is_svg(include_str!("data/policy/clean-icon.svg")));
+    assert!(validator.is_svg(include_str!("data/policy/sodipodi.svg")));
+    assert!(matches!(
+        validator.check_svg(es),
+        Err(Error::DisallowedElement { name, .. }) if name == "script"
+    ));
+    #[cfg(feature = "gzip")]
+    assert!(matches!(
+        validator.check_svg(include_bytes!("data/wikipedia/SVG_animation_using_ES.svgz")),
+        Err(Error::DisallowedElement { name, .. }) if name == "script"
+    ));
+    let err = validator
+        .check_svg(include_str!("data/policy/script.svg"))
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::DisallowedElement);
+    assert!(matches!(
+        err,
+        Error::DisallowedElement { name, location }
+            if name == "script" && location.position == roxmltree::TextPos::new(4, 5)
+    ));
+
+    let validator = Validator::builder()
+        .deny_elements(&["script", "foreignObject", "animate*"])
+        .build();
+    assert!(validator.is_svg(css));
+    assert!(!validator.is_svg(es));
+    assert!(matches!(
+        validator.check_svg(smil),
+        Err(Error::DisallowedElement { name, .. }) if name == "animateTransform"
+    ));
+    // The elements in the `defs` and `symbol` elements.
+    for (data, element) in [
+        (
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="24" height="24"><defs><foreignObject/></defs></svg>"#,
+            "foreignObject",
+        ),
+        (
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="24" height="24"><symbol id="s"><rect width="1" height="1"><animate/></rect></symbol></svg>"#,
+            "animate",
+        ),
+        (
+            r#"<svg xmlns="http://www.w3.org/2000/svg" xmlns:x="http://example.com/" width="24" height="24"><x:script/></svg>"#,
+            "x:script",
+        ),
+    ] {
+        assert!(matches!(
+            validator.check_svg(data),
+            Err(Error::DisallowedElement { name, .. }) if name == element
+        ));
+    }
+    // The local names are case-sensitive.
+    assert!(validator.is_svg(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" xmlns:x="http://example.com/" width="24" height="24"><x:Script/></svg>"#
+    ));
+
+    // Only the elements in the given namespace are denied.
+    let validator = Validator::builder()
+        .deny_elements(&["{http://www.w3.org/2000/svg}script"])
+        .build();
+    assert!(!validator.is_svg(es));
+    assert!(validator.is_svg(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" xmlns:x="http://example.com/" width="24" height="24"><x:script/></svg>"#
+    ));
+
+    // The denied elements take precedence over the allowed elements.
+    let validator = Validator::builder()
+        .allowed_elements(&["svg", "circle", "script"])
+        .deny_elements(&["script"])
+        .build();
+    assert!(matches!(
+        validator.check_svg(es),
+        Err(Error::DisallowedElement { name, .. }) if name == "script"
+    ));
+    assert!(matches!(
+        validator.check_svg(smil),
+        Err(Error::DisallowedElement { name, .. }) if name == "animateTransform"
+    ));
+}
+
+#[test]
+fn allow_external_references() {
+    let fragment_only = include_str!("data/reference/fragment-only.svg");
+    let data_uri = include_str!("data/reference/data-uri.svg");
+    let file_relative = include_str!("data/reference/file-relative.svg");
+    let absolute_url = include_str!("data/reference/absolute-url.svg");
+    let style_attribute = include_str!("data/reference/style-attribute.svg");
+    let style_element = include_str!("data/reference/style-element.svg");
+
+    let validator = Validator::new();
+    for data in [
+        fragment_only,
+        data_uri,
+        file_relative,
+        absolute_url,
+        style_attribute,
+        style_element,
+    ] {
+        assert!(validator.is_svg(data));
+    }
+
+    let validator = Validator::builder()
+        .allow_external_references(false)
+        .build();
+    assert!(validator.is_svg(fragment_only));
+    assert!(validator.is_svg(data_uri));
+    assert!(validator.is_svg(include_str!("data/policy/clean-icon.svg")));
+    let err = validator.check_svg(file_relative).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ExternalReference);
+    assert!(matches!(
+        err,
+        Error::ExternalReference { href, location }
+            if href == "../../etc/passwd" && location.position == roxmltree::TextPos::new(3, 3)
+    ));
+    for (data, reference) in [
+        (absolute_url, "http://example.com/icons.svg#user"),
+        (style_attribute, "https://example.com/filters.svg#blur"),
+        (style_element, "fonts/icon.woff2"),
+        (
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="24" height="24"><rect width="24" height="24" fill="url(paint.svg#red)"/></svg>"#,
+            "paint.svg#red",
+        ),
+        (
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="24" height="24"><style><![CDATA[@import "theme.css";]]></style></svg>"#,
+            "theme.css",
+        ),
+        (
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="24" height="24"><style>@import url("theme.css");</style></svg>"#,
+            "theme.css",
+        ),
+        (
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="24" height="24"><a href="https://example.com/"><rect width="24" height="24"/></a></svg>"#,
+            "https://example.com/",
+        ),
+    ] {
+        assert!(matches!(
+            validator.check_svg(data),
+            Err(Error::ExternalReference { href, .. }) if href == reference
+        ));
+    }
+    #[cfg(feature = "gzip")]
+    assert!(validator.is_svg(include_bytes!("data/w3/svg-logo-v.svgz")));
+
+    // The `xml-stylesheet` processing instructions.
+    let err = validator
+        .check_svg(include_str!("data/processing-instruction/stylesheet.svg"))
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        Error::ExternalReference { href, location }
+            if href == "style.css" && location.position == roxmltree::TextPos::new(2, 1)
+    ));
+    assert!(validator.is_svg(include_str!("data/processing-instruction/unknown.svg")));
+    for data in [
+        r##"<?xml-stylesheet type="text/css" href="#style"?><svg xmlns="http://www.w3.org/2000/svg" width="24" height="24"><style id="style">rect { fill: red; }</style></svg>"##,
+        r#"<?xml-stylesheet type="text/css" href="data:text/css,rect%7Bfill:red%7D"?><svg xmlns="http://www.w3.org/2000/svg" width="24" height="24"/>"#,
+        r#"<?xml-stylesheet?><svg xmlns="http://www.w3.org/2000/svg" width="24" height="24"/>"#,
+        r#"<?other-stylesheet href="style.css"?><svg xmlns="http://www.w3.org/2000/svg" width="24" height="24"/>"#,
+    ] {
+        assert!(validator.is_svg(data));
+    }
+    assert!(matches!(
+        validator.check_svg(
+            r#"<?xml-stylesheet title='dark' href = 'dark.css' ?><svg xmlns="http://www.w3.org/2000/svg" width="24" height="24"/>"#
+        ),
+        Err(Error::ExternalReference { href, .. }) if href == "dark.css"
+    ));
+}
+
+#[test]
+fn processing_instructions() {
+    let stylesheet = include_str!("data/processing-instruction/stylesheet.svg");
+    let unknown = include_str!("data/processing-instruction/unknown.svg");
+    let after_root = include_str!("data/processing-instruction/after-root.svg");
+
+    for validator in [Validator::lenient(), Validator::new()] {
+        for data in [stylesheet, unknown, after_root] {
+            assert_eq!(validator.detect(data), Some(SvgKind::Svg));
+        }
+    }
+
+    // The strict preset rejects only the external stylesheet.
+    let validator = Validator::strict();
+    assert!(matches!(
+        validator.check_svg(stylesheet),
+        Err(Error::ExternalReference { href, .. }) if href == "style.css"
+    ));
+    assert!(validator.is_svg(unknown));
+    assert!(validator.is_svg(after_root));
+}
+
+#[test]
+fn timeout() {
+    let validator = Validator::builder()
+        .timeout(Duration::from_secs(60))
+        .build();
+    assert!(validator.is_svg(include_str!("data/ferris/corro.svg")));
+    #[cfg(feature = "gzip")]
+    assert!(validator.is_svg(include_bytes!("data/ferris/corro.svgz")));
+    assert!(validator.is_svg(include_str!("data/w3/svg-logo-v.svg")));
+    assert!(!validator.is_svg(include_str!("data/resources/unclosed.svg")));
+
+    let validator = Validator::builder().timeout(Duration::ZERO).build();
+    assert!(matches!(
+        validator.check_svg(include_str!("data/w3/svg-logo-v.svg")),
+        Err(Error::Timeout)
+    ));
+    assert_eq!(
+        validator
+            .check_svg(include_str!("data/w3/svg-logo-v.svg"))
+            .unwrap_err()
+            .kind(),
+        ErrorKind::Timeout
+    );
+    // The error before the timeout is tested is returned as is.
+    assert!(matches!(
+        validator.check_svg(include_bytes!("data/w3/svg-logo-v.png")),
+        Err(Error::NotUtf8(_))
+    ));
+
+    let validator = Validator::builder().timeout(Duration::MAX).build();
+    assert!(validator.is_svg(include_str!("data/w3/svg-logo-v.svg")));
+}
+
+#[test]
+fn timeout_with_slow_data() {
+    let svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16">{}</svg>"#,
+        r#"<path d="M 0 0 C 1 1 2 2 3 3 C 4 4 5 5 6 6 Z" stroke="black"/>"#.repeat(100_000)
+    );
+    let validator = Validator::builder()
+        .timeout(Duration::from_millis(1))
+        .build();
+    assert!(matches!(validator.check_svg(&svg), Err(Error::Timeout)));
+}
+
+#[test]
+fn options() {
+    let validator = Validator::builder()
+        .options(Options {
+            resources_dir: Some("tests/data/w3".into()),
+            ..Default::default()
+        })
+        .build();
+    assert!(validator.is_svg(include_str!("data/w3/svg-logo-v.svg")));
+    #[cfg(feature = "gzip")]
+    assert!(validator.is_svg(include_bytes!("data/w3/svg-logo-v.svgz")));
+}
+
+#[test]
+fn send_sync() {
+    const fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<Validator>();
+    assert_send_sync::<ValidatorBuilder>();
+}
+
+#[test]
+fn clone() {
+    let validator = Validator::builder()
+        .max_size(1024)
+        .deny_elements(&["script"])
+        .build();
+    let cloned = validator.clone();
+    drop(validator);
+    assert!(cloned.is_svg(include_str!("data/usvg/path-simple-case.svg")));
+    assert!(matches!(
+        cloned.check_svg(include_str!("data/w3/svg-logo-v.svg")),
+        Err(Error::LimitExceeded(Limit::InputSize(1024)))
+    ));
+    assert!(matches!(
+        cloned.check_svg(include_str!("data/policy/script.svg")),
+        Err(Error::DisallowedElement { .. })
+    ));
+}
+
+#[test]
+fn share_between_threads() {
+    let validator = Arc::new(Validator::new());
+    let handles = (0..4)
+        .map(|_| {
+            let validator = Arc::clone(&validator);
+            thread::spawn(move || {
+                validator.is_svg(include_str!("data/w3/svg-logo-v.svg"))
+                    && validator.is_svgz(include_bytes!("data/w3/svg-logo-v.svgz"))
+                        == cfg!(feature = "gzip")
+            })
+        })
+        .collect::<Vec<_>>();
+    for handle in handles {
+        assert!(handle.join().unwrap());
+    }
+}
+
+#[test]
+fn stress_between_threads() {
+    const CORPUS: &[(&[u8], Option<SvgKind>)] = &[
+        (include_bytes!("data/w3/svg-logo-v.svg"), Some(SvgKind::Svg)),
+        #[cfg(feature = "gzip")]
+        (
+            include_bytes!("data/w3/svg-logo-v.svgz"),
+            Some(SvgKind::Svgz),
+        ),
+        (include_bytes!("data/w3/svg-logo-v.png"), None),
+        (
+            include_bytes!("data/usvg/clip-path-with-complex-text.svg"),
+            Some(SvgKind::Svg),
+        ),
+        (
+            include_bytes!("data/usvg/path-simple-case.svg"),
+            Some(SvgKind::Svg),
+        ),
+        (include_bytes!("data/policy/script.svg"), None),
+        (include_bytes!("data/resources/unclosed.svg"), None),
+        (include_bytes!("data/hostile/billion-laughs.svg"), None),
+    ];
+
+    let validator = Validator::builder().deny_elements(&["script"]).build();
+    let handles = (0..8)
+        .map(|i| {
+            let validator = validator.clone();
+            thread::spawn(move || {
+                for j in 0..64 {
+                    let (data, kind) = CORPUS[(i + j) % CORPUS.len()];
+                    assert_eq!(validator.detect(data), kind);
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+}
@@ -0,0 +1,200 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use is_svg::{Error, ErrorKind, ViewBox};
+
+const fn view_box(min_x: f32, min_y: f32, width: f32, height: f32) -> ViewBox {
+    ViewBox {
+        min_x,
+        min_y,
+        width,
+        height,
+    }
+}
+
+fn svg_with_view_box(value: &str) -> String {
+    format!(r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" viewBox="{value}"/>"#)
+}
+
+/// Returns the size of the tree which has only the `viewBox` attribute, which
+/// is the size of the `viewBox` if `usvg` uses it.
+fn tree_size(value: &str) -> (f32, f32) {
+    let text = format!(r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{value}"/>"#);
+    let size = is_svg::parse_svg(text).unwrap().size();
+    (size.width(), size.height())
+}
+
+#[test]
+fn view_box_is_same_as_usvg() {
+    for value in ["0 0 16 16", "0 0 16 16,", "0,0,16,16 ,", "-8-8 16 16"] {
+        let view_box = is_svg::view_box(svg_with_view_box(value)).unwrap().unwrap();
+        assert_eq!(
+            tree_size(value),
+            (view_box.width, view_box.height),
+            "{value:?}"
+        );
+    }
+}
+
+#[test]
+fn view_box_from_resources() {
+    assert_eq!(
+        is_svg::view_box(include_str!("data/w3/svg-logo-v.svg")).unwrap(),
+        Some(view_box(0.0, 0.0, 300.0, 300.0))
+    );
+    assert_eq!(
+        is_svg::view_box(include_str!("data/wikipedia/SVG_animation_using_SMIL.svg")).unwrap(),
+        Some(view_box(-4.0, -4.0, 8.0, 8.0))
+    );
+    assert_eq!(
+        is_svg::view_box(include_str!("data/dimensions/a4.svg")).unwrap(),
+        Some(view_box(0.0, 0.0, 210.0, 297.0))
+    );
+    assert_eq!(
+        is_svg::view_box(include_str!("data/dimensions/pixels.svg")).unwrap(),
+        None
+    );
+}
+
+#[test]
+fn view_box_with_separators() {
+    for value in [
+        "0 0 16 16",
+        "0,0,16,16",
+        "0, 0, 16, 16",
+        "0 ,0 , 16 ,16",
+        "  0 0 16 16  ",
+        "\t0\n0\r\n16 16\n",
+        "0,0 16,16",
+        // The separator after the last number is also allowed.
+        "0 0 16 16,",
+        "0 0 16 16 , ",
+        "0,0,16,16,\n",
+    ] {
+        assert_eq!(
+            is_svg::view_box(svg_with_view_box(value)).unwrap(),
+            Some(view_box(0.0, 0.0, 16.0, 16.0)),
+            "{value:?}"
+        );
+    }
+    // The sign also separates the numbers.
+    assert_eq!(
+        is_svg::view_box(svg_with_view_box("-8-8 16 16")).unwrap(),
+        Some(view_box(-8.0, -8.0, 16.0, 16.0))
+    );
+}
+
+#[test]
+fn view_box_with_numbers() {
+    for (value, expected) in [
+        ("0 0 1.6e1 1.6E+1", view_box(0.0, 0.0, 16.0, 16.0)),
+        ("-1e-1 +.5 .5e2 50.", view_box(-0.1, 0.5, 50.0, 50.0)),
+        ("-0 -0.0 1 1", view_box(0.0, 0.0, 1.0, 1.0)),
+        ("1e38 -1e38 3e38 3e38", view_box(1e38, -1e38, 3e38, 3e38)),
+    ] {
+        assert_eq!(
+            is_svg::view_box(svg_with_view_box(value)).unwrap(),
+            Some(expected),
+            "{value:?}"
+        );
+    }
+}
+
+#[test]
+fn invalid_view_box() {
+    for value in [
+        "",
+        " ",
+        "0 0 16",
+        "0 0 0 16",
+        "0 0 16 0",
+        "0 0 -16 16",
+        "0 0 16 -16",
+        "0,,0,16,16",
+        ",0 0 16 16",
+        "0 0 16px 16px",
+        "0 0 NaN 16",
+        "0 0 inf 16",
+        "0 0 16e 16",
+        "0 0 . 16",
+        "none",
+    ] {
+        let text = svg_with_view_box(value);
+        // `usvg` ignores the attribute.
+        assert!(is_svg::is_svg(&text), "{value:?}");
+        assert_eq!(tree_size(value), (100.0, 100.0), "{value:?}");
+        match is_svg::view_box(&text) {
+            Err(Error::InvalidViewBox { value: found }) => assert_eq!(found, value),
+            result => panic!("{value:?}: {result:?}"),
+        }
+    }
+    // `usvg` uses the first four numbers, but the data after them is not
+    // allowed.
+    for value in ["0 0 16 16 16", "0 0 16 16,,", "0 0 16 16, 16"] {
+        assert_eq!(tree_size(value), (16.0, 16.0), "{value:?}");
+        assert!(
+            matches!(
+                is_svg::view_box(svg_with_view_box(value)),
+                Err(Error::InvalidViewBox { .. })
+            ),
+            "{value:?}"
+        );
+    }
+    let err = is_svg::view_box(include_str!("data/numbers/negative-view-box.svg")).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidViewBox);
+    assert_eq!(err.to_string(), "viewBox `0 0 -16 -16` was not valid");
+}
+
+#[test]
+fn view_box_from_invalid_data() {
+    assert!(matches!(
+        is_svg::view_box(include_bytes!("data/w3/svg-logo-v.png")),
+        Err(Error::NotUtf8(_))
+    ));
+    assert!(matches!(
+        is_svg::view_box(include_str!("data/resources/unclosed.svg")),
+        Err(Error::NotXml { .. })
+    ));
+    // The invalid SVG data is rejected before the attribute is parsed.
+    assert!(matches!(
+        is_svg::view_box(include_str!("data/numbers/huge-exponent-view-box.svg")),
+        Err(Error::Parse(_))
+    ));
+    assert!(matches!(
+        is_svg::view_box(svg_with_view_box("0 0 1e39 16")),
+        Err(Error::Parse(_))
+    ));
+}
+
+#[test]
+fn view_box_is_same_as_info() {
+    for text in [
+        include_str!("data/w3/svg-logo-v.svg"),
+        include_str!("data/dimensions/percentage.svg"),
+        include_str!("data/dimensions/pixels.svg"),
+    ] {
+        assert_eq!(
+            is_svg::view_box(text).unwrap(),
+            is_svg::info(text).unwrap().view_box
+        );
+    }
+    // `info` does not tell the invalid attribute from the missing attribute.
+    let text = include_str!("data/numbers/negative-view-box.svg");
+    assert!(is_svg::view_box(text).is_err());
+    assert_eq!(is_svg::info(text).unwrap().view_box, None);
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn view_box_from_svgz() {
+    assert_eq!(
+        is_svg::view_box(include_bytes!("data/w3/svg-logo-v.svgz")).unwrap(),
+        Some(view_box(0.0, 0.0, 300.0, 300.0))
+    );
+    let data = is_svg::compress_svg(svg_with_view_box("0 0 16")).unwrap();
+    assert!(matches!(
+        is_svg::view_box(data),
+        Err(Error::InvalidViewBox { value }) if value == "0 0 16"
+    ));
+}
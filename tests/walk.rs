@@ -0,0 +1,128 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::{fs, io, path::Path};
+
+use is_svg::{DirScanner, SvgKind};
+use tempfile::TempDir;
+
+fn fixture_dir() -> TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    let nested = dir.path().join("nested/deeper");
+    fs::create_dir_all(&nested).unwrap();
+    for (src, dst) in [
+        ("tests/data/w3/svg-logo-v.svg", "svg-logo-v.svg"),
+        ("tests/data/w3/svg-logo-v.svgz", "svg-logo-v.svgz"),
+        ("tests/data/w3/svg-logo-v.png", "svg-logo-v.png"),
+        ("tests/data/ferris/corro.svg", "nested/corro.SVG"),
+        ("tests/data/ferris/corro.svgz", "nested/deeper/corro.svgz"),
+        ("tests/data/resources/unclosed.svg", "nested/unclosed.svg"),
+        ("tests/data/w3/svg-logo-v.svg", "nested/deeper/renamed.txt"),
+    ] {
+        fs::copy(src, dir.path().join(dst)).unwrap();
+    }
+    dir
+}
+
+fn relative_paths(root: &Path, entries: &[is_svg::SvgEntry]) -> Vec<String> {
+    entries
+        .iter()
+        .map(|entry| {
+            entry
+                .path()
+                .strip_prefix(root)
+                .unwrap()
+                .to_string_lossy()
+                .replace('\\', "/")
+        })
+        .collect()
+}
+
+#[test]
+fn scan_dir() {
+    let dir = fixture_dir();
+    let entries = is_svg::scan_dir(dir.path()).unwrap();
+    assert_eq!(
+        relative_paths(dir.path(), &entries),
+        [
+            "nested/corro.SVG",
+            "nested/deeper/corro.svgz",
+            "nested/deeper/renamed.txt",
+            "svg-logo-v.svg",
+            "svg-logo-v.svgz"
+        ]
+    );
+    assert_eq!(entries[0].kind(), SvgKind::Svg);
+    assert_eq!(entries[1].kind(), SvgKind::Svgz);
+    assert_eq!(
+        entries[3].size(),
+        fs::metadata("tests/data/w3/svg-logo-v.svg").unwrap().len()
+    );
+    assert_eq!(
+        entries[4].size(),
+        fs::metadata("tests/data/w3/svg-logo-v.svgz").unwrap().len()
+    );
+}
+
+#[test]
+fn scan_dir_with_extensions() {
+    let dir = fixture_dir();
+    let report = DirScanner::new(dir.path())
+        .extensions(&["svg"])
+        .scan()
+        .unwrap();
+    assert_eq!(
+        relative_paths(dir.path(), report.entries()),
+        ["nested/corro.SVG", "svg-logo-v.svg"]
+    );
+    assert!(report.errors().is_empty());
+
+    let report = DirScanner::new(dir.path())
+        .extensions(&["svgz", "txt"])
+        .scan()
+        .unwrap();
+    assert_eq!(
+        relative_paths(dir.path(), &report.into_entries()),
+        [
+            "nested/deeper/corro.svgz",
+            "nested/deeper/renamed.txt",
+            "svg-logo-v.svgz"
+        ]
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn scan_dir_does_not_follow_symlink_loop() {
+    let dir = fixture_dir();
+    std::os::unix::fs::symlink(dir.path(), dir.path().join("nested/loop")).unwrap();
+    let entries = is_svg::scan_dir(dir.path()).unwrap();
+    assert_eq!(entries.len(), 5);
+}
+
+#[test]
+fn scan_dir_from_empty_dir() {
+    let dir = tempfile::tempdir().unwrap();
+    assert!(is_svg::scan_dir(dir.path()).unwrap().is_empty());
+}
+
+#[test]
+fn scan_dir_from_non_existent_dir() {
+    assert_eq!(
+        is_svg::scan_dir("tests/data/non-existent")
+            .unwrap_err()
+            .kind(),
+        io::ErrorKind::NotFound
+    );
+}
+
+#[test]
+fn scan_dir_from_file() {
+    assert_eq!(
+        is_svg::scan_dir("tests/data/w3/svg-logo-v.svg")
+            .unwrap_err()
+            .kind(),
+        io::ErrorKind::InvalidInput
+    );
+}
@@ -0,0 +1,123 @@
+// SPDX-FileCopyrightText: 2024 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+use axum::{
+    body::Body,
+    http::{header, Request, StatusCode},
+    routing::post,
+    Extension, Router,
+};
+use http_body_util::BodyExt;
+use is_svg::{SvgBody, SvgBodyLimit};
+use tower::ServiceExt;
+
+async fn upload(body: SvgBody) -> String {
+    format!("{:?} {}", body.kind(), body.data().len())
+}
+
+fn app() -> Router {
+    Router::new().route("/", post(upload))
+}
+
+async fn post_body(app: Router, body: &[u8], encoding: Option<&str>) -> (StatusCode, String) {
+    let mut request = Request::post("/");
+    if let Some(encoding) = encoding {
+        request = request.header(header::CONTENT_ENCODING, encoding);
+    }
+    let response = app
+        .oneshot(request.body(Body::from(body.to_vec())).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+#[tokio::test]
+async fn svg_body_from_svg() {
+    let data = include_bytes!("data/w3/svg-logo-v.svg");
+    assert_eq!(
+        post_body(app(), data, None).await,
+        (StatusCode::OK, format!("Svg {}", data.len()))
+    );
+    let data = include_bytes!("data/ferris/corro.svg");
+    assert_eq!(
+        post_body(app(), data, Some("identity")).await,
+        (StatusCode::OK, format!("Svg {}", data.len()))
+    );
+}
+
+#[tokio::test]
+async fn svg_body_from_svgz() {
+    let data = include_bytes!("data/w3/svg-logo-v.svgz");
+    assert_eq!(
+        post_body(app(), data, None).await,
+        (StatusCode::OK, format!("Svgz {}", data.len()))
+    );
+}
+
+#[tokio::test]
+async fn svg_body_with_content_encoding() {
+    let data = include_bytes!("data/w3/svg-logo-v.svg");
+    assert_eq!(
+        post_body(app(), &common::compress(data), Some("gzip")).await,
+        (StatusCode::OK, format!("Svg {}", data.len()))
+    );
+    let data = include_bytes!("data/w3/svg-logo-v.svgz");
+    assert_eq!(
+        post_body(app(), &common::compress(data), Some("x-gzip")).await,
+        (StatusCode::OK, format!("Svgz {}", data.len()))
+    );
+    assert_eq!(
+        post_body(
+            app(),
+            include_bytes!("data/w3/svg-logo-v.svg"),
+            Some("gzip")
+        )
+        .await
+        .0,
+        StatusCode::BAD_REQUEST
+    );
+    assert_eq!(
+        post_body(app(), include_bytes!("data/w3/svg-logo-v.svg"), Some("br"))
+            .await
+            .0,
+        StatusCode::UNSUPPORTED_MEDIA_TYPE
+    );
+}
+
+#[tokio::test]
+async fn svg_body_from_non_svg() {
+    for data in [
+        &include_bytes!("data/w3/svg-logo-v.png")[..],
+        include_bytes!("data/resources/unclosed.svg"),
+        include_bytes!("data/resources/empty.gz"),
+        b"",
+    ] {
+        assert_eq!(
+            post_body(app(), data, None).await.0,
+            StatusCode::UNSUPPORTED_MEDIA_TYPE
+        );
+    }
+}
+
+#[tokio::test]
+async fn svg_body_with_limit() {
+    let data = include_bytes!("data/w3/svg-logo-v.svg");
+    let len = u64::try_from(data.len()).unwrap();
+    let app = |limit| app().layer(Extension(SvgBodyLimit::new(limit)));
+    assert_eq!(post_body(app(len), data, None).await.0, StatusCode::OK);
+    assert_eq!(
+        post_body(app(len - 1), data, None).await.0,
+        StatusCode::PAYLOAD_TOO_LARGE
+    );
+    assert_eq!(
+        post_body(app(len - 1), &common::compress(data), Some("gzip"))
+            .await
+            .0,
+        StatusCode::PAYLOAD_TOO_LARGE
+    );
+}